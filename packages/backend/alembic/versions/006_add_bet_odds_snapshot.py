@@ -0,0 +1,25 @@
+"""Add odds snapshot columns to bets
+
+Revision ID: 006
+Revises: 005
+Create Date: 2026-10-16
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+revision: str = "006"
+down_revision: Union[str, None] = "005"
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    op.add_column("bets", sa.Column("odds_side_a_eth", sa.Float(), nullable=True))
+    op.add_column("bets", sa.Column("odds_side_b_eth", sa.Float(), nullable=True))
+
+
+def downgrade() -> None:
+    op.drop_column("bets", "odds_side_b_eth")
+    op.drop_column("bets", "odds_side_a_eth")
@@ -0,0 +1,25 @@
+"""Add placement tx hash and settled amount to bets
+
+Revision ID: 007
+Revises: 006
+Create Date: 2026-10-16
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+revision: str = "007"
+down_revision: Union[str, None] = "006"
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    op.add_column("bets", sa.Column("tx_hash", sa.String(66), nullable=True))
+    op.add_column("bets", sa.Column("settled_eth", sa.Float(), nullable=True))
+
+
+def downgrade() -> None:
+    op.drop_column("bets", "settled_eth")
+    op.drop_column("bets", "tx_hash")
@@ -0,0 +1,40 @@
+"""Add revenue_events ledger for fee, sweep and dust inflows
+
+Revision ID: 008
+Revises: 007
+Create Date: 2026-10-16
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+from sqlalchemy.dialects.postgresql import UUID
+
+revision: str = "008"
+down_revision: Union[str, None] = "007"
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    op.create_table(
+        "revenue_events",
+        sa.Column("id", UUID(as_uuid=True), primary_key=True),
+        sa.Column("kind", sa.String(16), nullable=False),
+        sa.Column("asset", sa.String(42), nullable=False, server_default="eth"),
+        sa.Column("amount_wei", sa.Numeric(78, 0), nullable=False),
+        sa.Column("onchain_match_id", sa.String(64), nullable=False),
+        sa.Column("destination", sa.String(42), nullable=True),
+        sa.Column("tx_hash", sa.String(66), nullable=False),
+        sa.Column("log_index", sa.Integer, nullable=False),
+        sa.Column("occurred_at", sa.DateTime(timezone=True), server_default=sa.func.now(), nullable=False),
+        sa.UniqueConstraint("tx_hash", "log_index", name="uq_revenue_event_log"),
+    )
+    op.create_index("ix_revenue_events_onchain_match_id", "revenue_events", ["onchain_match_id"])
+    op.create_index("ix_revenue_events_occurred_at", "revenue_events", ["occurred_at"])
+
+
+def downgrade() -> None:
+    op.drop_index("ix_revenue_events_occurred_at", table_name="revenue_events")
+    op.drop_index("ix_revenue_events_onchain_match_id", table_name="revenue_events")
+    op.drop_table("revenue_events")
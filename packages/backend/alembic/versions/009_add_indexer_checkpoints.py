@@ -0,0 +1,39 @@
+"""Add indexer_checkpoints and indexed_logs for resume-safe event indexing
+
+Revision ID: 009
+Revises: 008
+Create Date: 2026-10-16
+"""
+from typing import Sequence, Union
+
+from alembic import op
+import sqlalchemy as sa
+
+revision: str = "009"
+down_revision: Union[str, None] = "008"
+branch_labels: Union[str, Sequence[str], None] = None
+depends_on: Union[str, Sequence[str], None] = None
+
+
+def upgrade() -> None:
+    op.create_table(
+        "indexer_checkpoints",
+        sa.Column("name", sa.String(32), primary_key=True),
+        sa.Column("block_number", sa.BigInteger, nullable=False),
+        sa.Column("updated_at", sa.DateTime(timezone=True), server_default=sa.func.now(), nullable=False),
+    )
+    op.create_table(
+        "indexed_logs",
+        sa.Column("tx_hash", sa.String(66), primary_key=True),
+        sa.Column("log_index", sa.Integer, primary_key=True),
+        sa.Column("block_number", sa.BigInteger, nullable=False),
+        sa.Column("event", sa.String(32), nullable=False),
+        sa.Column("indexed_at", sa.DateTime(timezone=True), server_default=sa.func.now(), nullable=False),
+    )
+    op.create_index("ix_indexed_logs_block_number", "indexed_logs", ["block_number"])
+
+
+def downgrade() -> None:
+    op.drop_index("ix_indexed_logs_block_number", table_name="indexed_logs")
+    op.drop_table("indexed_logs")
+    op.drop_table("indexer_checkpoints")
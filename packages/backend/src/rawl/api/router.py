@@ -2,7 +2,18 @@ from __future__ import annotations
 
 from fastapi import APIRouter
 
-from rawl.api.routes import bets, fighters, internal, leaderboard, matches, odds, pretrained, stats
+from rawl.api.routes import (
+    bets,
+    fighters,
+    internal,
+    leaderboard,
+    matches,
+    odds,
+    pretrained,
+    reserves,
+    revenue,
+    stats,
+)
 
 api_router = APIRouter()
 
@@ -14,3 +25,5 @@ api_router.include_router(leaderboard.router)
 api_router.include_router(internal.router)
 api_router.include_router(pretrained.router)
 api_router.include_router(stats.router)
+api_router.include_router(reserves.router)
+api_router.include_router(revenue.router)
@@ -6,10 +6,17 @@ from datetime import datetime, timezone
 
 from fastapi import APIRouter, HTTPException, Query
 from pydantic import BaseModel, Field, field_validator
-from sqlalchemy import select
+from sqlalchemy import func, select
 from sqlalchemy.orm import aliased
 
-from rawl.api.schemas.bet import BetResponse, BetWithMatchResponse, RecordBetRequest
+from rawl.api.schemas.bet import (
+    BetResponse,
+    BetWithMatchResponse,
+    RecordBetRequest,
+    WalletActivityResponse,
+)
+from rawl.api.schemas.common import CursorParams
+from rawl.config import settings
 from rawl.db.models.bet import Bet
 from rawl.db.models.fighter import Fighter
 from rawl.db.models.match import Match
@@ -20,6 +27,16 @@ logger = logging.getLogger(__name__)
 router = APIRouter(tags=["bets"])
 
 
+def _winner_side(match: Match) -> str | None:
+    """Winner as "a"/"b" so callers can compare directly against bet.side."""
+    if match.winner_id and match.status == "resolved":
+        if match.winner_id == match.fighter_a_id:
+            return "a"
+        if match.winner_id == match.fighter_b_id:
+            return "b"
+    return None
+
+
 def _bet_with_match(bet: Bet, match: Match, name_a: str | None, name_b: str | None):
     data = {c.key: getattr(bet, c.key) for c in Bet.__table__.columns}
     data["game_id"] = match.game_id
@@ -27,12 +44,7 @@ def _bet_with_match(bet: Bet, match: Match, name_a: str | None, name_b: str | No
     data["fighter_b_name"] = name_b
     data["match_status"] = match.status
     data["match_winner_id"] = match.winner_id
-    # Derive winner side so frontend can compare directly against bet.side
-    if match.winner_id and match.status == "resolved":
-        if match.winner_id == match.fighter_a_id:
-            data["winner_side"] = "a"
-        elif match.winner_id == match.fighter_b_id:
-            data["winner_side"] = "b"
+    data["winner_side"] = _winner_side(match)
     return BetWithMatchResponse.model_validate(data)
 
 
@@ -69,6 +81,83 @@ async def list_bets(
     return [_bet_with_match(bet, match, na, nb) for bet, match, na, nb in rows]
 
 
+@router.get("/bets/export", response_model=WalletActivityResponse)
+async def export_wallet_activity(
+    db: DbSession,
+    wallet: str = Query(..., max_length=42),
+    cursor: str | None = Query(None),
+    limit: int = Query(100, ge=1, le=500),
+):
+    """Export a wallet's betting activity as signed, cursor-paginated pages.
+
+    Verify a page by recovering the EIP-191 signer of ``payload`` and comparing
+    it to the published export signer; follow ``next_cursor`` inside the
+    payload for the next page.
+    """
+    from rawl.services.activity_export import (
+        FORMAT,
+        activity_record,
+        canonical_payload,
+        sign_payload,
+    )
+
+    params = CursorParams(cursor=cursor, limit=limit)
+    query = (
+        select(Bet, Match)
+        .join(Match, Bet.match_id == Match.id)
+        # record_bet keeps the caller's casing, the event listener stores lowercase
+        .where(func.lower(Bet.wallet_address) == wallet.lower())
+    )
+    decoded = params.decode_cursor()
+    if decoded:
+        ts, bid = decoded
+        query = query.where(
+            (Bet.created_at < ts) | ((Bet.created_at == ts) & (Bet.id < uuid.UUID(bid)))
+        )
+    query = query.order_by(Bet.created_at.desc(), Bet.id.desc()).limit(limit + 1)
+
+    result = await db.execute(query)
+    rows = result.all()
+    has_more = len(rows) > limit
+    items = rows[:limit]
+
+    next_cursor = None
+    if has_more and items:
+        last_bet = items[-1][0]
+        next_cursor = CursorParams.encode_cursor(last_bet.created_at, str(last_bet.id))
+
+    export = {
+        "format": FORMAT,
+        "wallet": wallet.lower(),
+        "chain_id": settings.base_chain_id,
+        "generated_at": datetime.now(timezone.utc).isoformat(),
+        "items": [
+            activity_record(
+                bet_id=str(bet.id),
+                match_id=str(bet.match_id),
+                created_at=bet.created_at,
+                side=bet.side,
+                amount_eth=bet.amount_eth,
+                status=bet.status,
+                tx_hash=bet.tx_hash,
+                settled_eth=bet.settled_eth,
+                settled_at=bet.claimed_at,
+                match_status=match.status,
+                winner_side=_winner_side(match),
+                contract=settings.contract_address.lower(),
+            )
+            for bet, match in items
+        ],
+        "next_cursor": next_cursor,
+        "has_more": has_more,
+    }
+    payload = canonical_payload(export)
+    if not settings.activity_export_signing_key:
+        return WalletActivityResponse(payload=payload)
+    signer, signature = sign_payload(payload, settings.activity_export_signing_key)
+    return WalletActivityResponse(payload=payload, signer=signer, signature=signature)
+
+
 class SyncBetRequest(BaseModel):
     wallet_address: str = Field(..., max_length=42)
 
@@ -184,6 +273,7 @@ async def record_bet(
         side=body.side,
         amount_eth=body.amount_eth,
         onchain_bet_id=f"{match_id_hex}:{body.wallet_address}",
+        tx_hash=body.tx_hash,
         status="confirmed",
     )
     db.add(bet)
@@ -0,0 +1,43 @@
+from __future__ import annotations
+
+import json
+import logging
+
+from fastapi import APIRouter, HTTPException
+
+from rawl.api.schemas.reserves import ReservesResponse
+from rawl.dependencies import DbSession
+from rawl.redis_client import redis_pool
+
+logger = logging.getLogger(__name__)
+
+router = APIRouter(tags=["reserves"])
+
+CACHE_KEY = "platform:reserves"
+CACHE_TTL = 60  # seconds
+
+
+@router.get("/reserves", response_model=ReservesResponse)
+async def get_reserves(db: DbSession) -> ReservesResponse:
+    """Proof-of-reserves feed: per-match on-chain liabilities vs contract balance."""
+    try:
+        cached = await redis_pool.get(CACHE_KEY)
+        if cached is not None:
+            return ReservesResponse.model_validate(json.loads(cached))
+    except Exception:
+        logger.warning("Redis cache read failed for reserves", exc_info=True)
+
+    from rawl.services.reserves import build_reserves_report
+
+    try:
+        report = await build_reserves_report(db)
+    except Exception:
+        logger.exception("Failed to build reserves report")
+        raise HTTPException(status_code=503, detail="Chain unavailable")
+
+    data = ReservesResponse.model_validate(report)
+    try:
+        await redis_pool.set(CACHE_KEY, data.model_dump_json(), ex=CACHE_TTL)
+    except Exception:
+        logger.warning("Redis cache write failed for reserves", exc_info=True)
+    return data
@@ -0,0 +1,42 @@
+from __future__ import annotations
+
+from datetime import datetime, timedelta, timezone
+
+from fastapi import APIRouter, HTTPException, Query
+
+from rawl.api.schemas.revenue import RevenueResponse
+from rawl.dependencies import DbSession
+
+router = APIRouter(tags=["revenue"])
+
+DEFAULT_RANGE = timedelta(days=30)
+MAX_RANGE = timedelta(days=366 * 3)
+
+
+@router.get("/revenue", response_model=RevenueResponse)
+async def get_revenue(
+    db: DbSession,
+    start: datetime | None = Query(None, alias="from"),
+    end: datetime | None = Query(None, alias="to"),
+    granularity: str = Query("day", pattern="^(day|week|month)$"),
+) -> RevenueResponse:
+    """Fees, unclaimed sweeps and cash-out dust per day, week or month.
+
+    ``from`` is inclusive and ``to`` exclusive; both default to the last 30
+    days. Naive timestamps are read as UTC.
+    """
+    from rawl.services.revenue import build_revenue_report
+
+    end = _utc(end) if end else datetime.now(timezone.utc)
+    start = _utc(start) if start else end - DEFAULT_RANGE
+    if start >= end:
+        raise HTTPException(status_code=422, detail="from must be before to")
+    if end - start > MAX_RANGE:
+        raise HTTPException(status_code=422, detail="Range is limited to three years")
+
+    report = await build_revenue_report(db, start, end, granularity)
+    return RevenueResponse.model_validate(report)
+
+
+def _utc(ts: datetime) -> datetime:
+    return ts.replace(tzinfo=timezone.utc) if ts.tzinfo is None else ts
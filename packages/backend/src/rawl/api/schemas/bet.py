@@ -13,7 +13,11 @@ class BetResponse(BaseModel):
     wallet_address: str
     side: str
     amount_eth: float
+    odds_side_a_eth: float | None = None
+    odds_side_b_eth: float | None = None
     onchain_bet_id: str | None = None
+    tx_hash: str | None = None
+    settled_eth: float | None = None
     status: str
     created_at: datetime
     claimed_at: datetime | None = None
@@ -28,6 +32,14 @@ class BetWithMatchResponse(BetResponse):
     winner_side: str | None = None  # "a", "b", or null
 
 
+class WalletActivityResponse(BaseModel):
+    """Signed export page — ``payload`` is the canonical JSON string that was signed."""
+
+    payload: str
+    signer: str | None = None  # null when export signing is not configured
+    signature: str | None = None
+
+
 _EVM_ADDRESS_RE = re.compile(r"^0x[0-9a-fA-F]{40}$")
 
 
@@ -0,0 +1,24 @@
+from __future__ import annotations
+
+import uuid
+
+from pydantic import BaseModel
+
+
+class MatchReserveEntry(BaseModel):
+    match_id: uuid.UUID
+    onchain_match_id: str  # bytes32 hex
+    status: int  # on-chain MatchStatus
+    open_bets: int
+    liability_wei: int
+    fee_reserved_wei: int
+
+
+class ReservesResponse(BaseModel):
+    contract: str
+    chain_id: int
+    block_number: int
+    balance_wei: int
+    total_liability_wei: int
+    surplus_wei: int
+    matches: list[MatchReserveEntry]
@@ -0,0 +1,26 @@
+from __future__ import annotations
+
+from datetime import datetime
+
+from pydantic import BaseModel
+
+
+class AssetRevenue(BaseModel):
+    asset: str  # "eth", or a token address
+    fees: int  # base units
+    sweeps: int
+    dust: int
+    total: int
+
+
+class RevenuePeriod(BaseModel):
+    period_start: datetime
+    assets: list[AssetRevenue]
+
+
+class RevenueResponse(BaseModel):
+    start: datetime  # the request's from, inclusive
+    end: datetime  # the request's to, exclusive
+    granularity: str
+    periods: list[RevenuePeriod]
+    totals: list[AssetRevenue]
@@ -54,6 +54,16 @@ async def timeout_stale_matches(ctx):
     await _timeout_stale_matches_async()
 
 
+async def oracle_heartbeat(ctx):
+    from rawl.evm.client import evm_client
+    await evm_client.oracle_heartbeat_on_chain()
+
+
+async def push_match_summaries(ctx):
+    from rawl.services.summary_pusher import _push_summaries_async
+    await _push_summaries_async()
+
+
 async def retry_failed_uploads(ctx):
     from rawl.engine.failed_upload_handler import retry_failed_uploads as _retry
     await _retry()
@@ -105,6 +115,9 @@ class WorkerSettings:
         cron(check_match_heartbeats,   second=0,                                      unique=True),
         cron(reconcile_bets,           second=0,                                      unique=True),
         cron(timeout_stale_matches,    second=30,                                     unique=True),
+        cron(push_match_summaries,     second=45,                                     unique=True),
+        # Well inside the contract's oracleSilencePeriod — see setOracleLiveness
+        cron(oracle_heartbeat,         minute={0, 10, 20, 30, 40, 50}, second=15,     unique=True),
         cron(retry_failed_uploads,     minute={0, 5, 10, 15, 20, 25, 30, 35, 40, 45, 50, 55},
                                        second=0,                                      unique=True),
         cron(seasonal_reset,           month={1, 4, 7, 10}, day=1, hour=0, minute=0,
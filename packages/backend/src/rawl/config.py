@@ -50,6 +50,7 @@ class Settings(BaseSettings):
     contract_address: str = ""  # from CONTRACT_ADDRESS env
     base_confirm_timeout: int = 30
     base_max_retries: int = 3
+    activity_export_signing_key: str = ""  # signs wallet-activity exports, empty = unsigned
 
     # Match defaults
     default_match_format: int = 3
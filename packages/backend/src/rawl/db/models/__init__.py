@@ -5,6 +5,8 @@ from rawl.db.models.training_job import TrainingJob
 from rawl.db.models.bet import Bet
 from rawl.db.models.calibration_match import CalibrationMatch
 from rawl.db.models.failed_upload import FailedUpload
+from rawl.db.models.revenue_event import RevenueEvent
+from rawl.db.models.indexer import IndexedLog, IndexerCheckpoint
 
 __all__ = [
     "User",
@@ -14,4 +16,7 @@ __all__ = [
     "Bet",
     "CalibrationMatch",
     "FailedUpload",
+    "RevenueEvent",
+    "IndexerCheckpoint",
+    "IndexedLog",
 ]
@@ -23,10 +23,16 @@ class Bet(Base):
     wallet_address: Mapped[str] = mapped_column(String(42), nullable=False, index=True)
     side: Mapped[str] = mapped_column(String(1), nullable=False)  # "a" or "b"
     amount_eth: Mapped[float] = mapped_column(Float, nullable=False)
+    # Pool totals just before this bet landed ("odds when you bet"), from BetPlaced
+    odds_side_a_eth: Mapped[float | None] = mapped_column(Float, nullable=True)
+    odds_side_b_eth: Mapped[float | None] = mapped_column(Float, nullable=True)
     onchain_bet_id: Mapped[str | None] = mapped_column(String(128), nullable=True)
+    tx_hash: Mapped[str | None] = mapped_column(String(66), nullable=True)  # placement tx
+    # ETH paid back on claim, refund or cash-out — realized P&L is settled_eth - amount_eth
+    settled_eth: Mapped[float | None] = mapped_column(Float, nullable=True)
     status: Mapped[str] = mapped_column(
         String(20), nullable=False, default="pending"
-    )  # pending, confirmed, claimed, refunded, expired
+    )  # pending, confirmed, claimed, refunded, cashed_out, expired
     created_at: Mapped[datetime] = mapped_column(
         DateTime(timezone=True), server_default=func.now(), nullable=False
     )
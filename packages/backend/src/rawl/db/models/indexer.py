@@ -0,0 +1,38 @@
+from __future__ import annotations
+
+from datetime import datetime
+
+from sqlalchemy import BigInteger, DateTime, Integer, String, func
+from sqlalchemy.orm import Mapped, mapped_column
+
+from rawl.db.base import Base
+
+
+class IndexerCheckpoint(Base):
+    """Last block an indexer stream has fully applied to the mirror tables."""
+
+    __tablename__ = "indexer_checkpoints"
+
+    name: Mapped[str] = mapped_column(String(32), primary_key=True)
+    block_number: Mapped[int] = mapped_column(BigInteger, nullable=False)
+    updated_at: Mapped[datetime] = mapped_column(
+        DateTime(timezone=True), server_default=func.now(), onupdate=func.now(), nullable=False
+    )
+
+
+class IndexedLog(Base):
+    """A contract log whose effects are in the mirror — written in the same transaction.
+
+    Replays after a crash and backfills over already-indexed ranges skip these,
+    so no log is ever applied twice.
+    """
+
+    __tablename__ = "indexed_logs"
+
+    tx_hash: Mapped[str] = mapped_column(String(66), primary_key=True)
+    log_index: Mapped[int] = mapped_column(Integer, primary_key=True)
+    block_number: Mapped[int] = mapped_column(BigInteger, nullable=False, index=True)
+    event: Mapped[str] = mapped_column(String(32), nullable=False)
+    indexed_at: Mapped[datetime] = mapped_column(
+        DateTime(timezone=True), server_default=func.now(), nullable=False
+    )
@@ -0,0 +1,31 @@
+from __future__ import annotations
+
+import uuid
+from datetime import datetime
+
+from sqlalchemy import DateTime, Integer, Numeric, String, UniqueConstraint, func
+from sqlalchemy.dialects.postgresql import UUID
+from sqlalchemy.orm import Mapped, mapped_column
+
+from rawl.db.base import Base
+
+
+class RevenueEvent(Base):
+    """One platform inflow indexed from the contract — the ledger behind /revenue."""
+
+    __tablename__ = "revenue_events"
+    # A log is indexed once even when the listener replays a block range
+    __table_args__ = (UniqueConstraint("tx_hash", "log_index", name="uq_revenue_event_log"),)
+
+    id: Mapped[uuid.UUID] = mapped_column(UUID(as_uuid=True), primary_key=True, default=uuid.uuid4)
+    kind: Mapped[str] = mapped_column(String(16), nullable=False)  # fees, sweeps, dust
+    # Native ETH today; an ERC-20 address once token pools land
+    asset: Mapped[str] = mapped_column(String(42), nullable=False, default="eth")
+    amount_wei: Mapped[int] = mapped_column(Numeric(78, 0), nullable=False)
+    onchain_match_id: Mapped[str] = mapped_column(String(64), nullable=False, index=True)
+    destination: Mapped[str | None] = mapped_column(String(42), nullable=True)
+    tx_hash: Mapped[str] = mapped_column(String(66), nullable=False)
+    log_index: Mapped[int] = mapped_column(Integer, nullable=False)
+    occurred_at: Mapped[datetime] = mapped_column(
+        DateTime(timezone=True), server_default=func.now(), nullable=False, index=True
+    )
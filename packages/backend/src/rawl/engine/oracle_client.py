@@ -2,6 +2,7 @@ from __future__ import annotations
 
 import asyncio
 import logging
+import time
 
 from rawl.config import settings
 
@@ -36,6 +37,7 @@ class OracleClient:
             winner: "P1" or "P2"
             match_hash: SHA-256 hex string of match result (log-only, not passed to chain)
         """
+        await self._wait_for_resolution_delay(match_id)
         try:
             return await asyncio.wait_for(
                 self._submit_resolve_inner(match_id, winner, match_hash),
@@ -56,6 +58,19 @@ class OracleClient:
             logger.error("cancelMatch RPC timed out", extra={"match_id": match_id})
             return None
 
+    async def _wait_for_resolution_delay(self, match_id: str) -> None:
+        """Sleep until the contract's minResolutionDelay after lock has passed."""
+        from rawl.evm.client import evm_client
+
+        ready_at = await evm_client.resolution_ready_at(match_id)
+        wait = ready_at - time.time() if ready_at else 0
+        if wait > 0:
+            logger.info(
+                "Waiting for min resolution delay",
+                extra={"match_id": match_id, "seconds": round(wait)},
+            )
+            await asyncio.sleep(wait)
+
     async def _submit_lock_inner(self, match_id: str) -> str | None:
         from rawl.evm.client import evm_client
 
@@ -71,6 +86,22 @@ class OracleClient:
     ) -> str | None:
         from rawl.evm.client import evm_client
 
+        # Switchboard-bound matches settle from their feed; an oracle resolve would revert
+        if await evm_client.result_feed(match_id):
+            logger.info("Submitting resolve_from_feed", extra={"match_id": match_id})
+            return await self._retry(
+                lambda: evm_client.resolve_from_feed_on_chain(match_id),
+                "resolve_from_feed",
+                match_id,
+            )
+
+        # A key rotated out of a match no longer counts toward its result
+        if not await evm_client.oracle_can_resolve(match_id):
+            logger.warning(
+                "Oracle key rotated out of match, not resolving", extra={"match_id": match_id}
+            )
+            return None
+
         # Convert "P1"/"P2" to contract u8 (0=SideA, 1=SideB)
         winner_u8 = 0 if winner == "P1" else 1
 
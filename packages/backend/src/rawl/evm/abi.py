@@ -7,7 +7,8 @@ from pathlib import Path
 # Bundled ABI (copied from Foundry out/ at build time — works in Docker / installed package)
 _BUNDLED = Path(__file__).parent / "RawlBetting.json"
 
-# Foundry output path (works in local dev monorepo)
+# Foundry output path (works in local dev monorepo). It holds only the router's own entry points;
+# the module functions the router delegates to are in the bundle alone (scripts/sync_abi.py)
 _FOUNDRY = (
     Path(__file__).parent.parent.parent.parent.parent
     / "contracts"
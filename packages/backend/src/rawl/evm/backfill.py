@@ -0,0 +1,30 @@
+"""Backfill the bet mirror from historical RawlBetting logs.
+
+  python -m rawl.evm.backfill --from-block 18200000
+  python -m rawl.evm.backfill --from-block 18200000 --to-block 18250000
+
+Every log is applied at most once (tracked in indexed_logs), so a range the
+live listener already covered, or one it is covering right now, is safe to
+run. The listener's checkpoint is left where it is.
+"""
+from __future__ import annotations
+
+import argparse
+import asyncio
+import logging
+
+from rawl.evm.event_listener import EventListener
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description="Backfill the bet mirror from contract logs")
+    parser.add_argument("--from-block", type=int, required=True, help="First block to index")
+    parser.add_argument("--to-block", type=int, help="Last block to index (default: head)")
+    args = parser.parse_args()
+
+    logging.basicConfig(level=logging.INFO, format="%(asctime)s %(levelname)s %(message)s")
+    asyncio.run(EventListener().backfill(args.from_block, args.to_block))
+
+
+if __name__ == "__main__":
+    main()
@@ -10,8 +10,9 @@ import asyncio
 import logging
 import uuid
 
+from eth_abi import encode
 from eth_account import Account
-from web3 import AsyncWeb3, AsyncHTTPProvider
+from web3 import AsyncWeb3, AsyncHTTPProvider, Web3
 
 from rawl.config import settings
 from rawl.evm.abi import CONTRACT_ABI
@@ -22,6 +23,16 @@ logger = logging.getLogger(__name__)
 
 BACKOFF = [1, 2, 4]
 
+# On-chain MatchCategory enum order (append only), keyed by Match.match_type
+MATCH_CATEGORIES = (
+    "ranked",
+    "challenge",
+    "exhibition",
+    "tournament",
+    "community",
+    "price_prediction",
+)
+
 
 def match_id_to_bytes(match_id: str) -> bytes:
     """Convert UUID string to 32-byte bytes for contract bytes32 param.
@@ -32,6 +43,25 @@ def match_id_to_bytes(match_id: str) -> bytes:
     return uuid.UUID(match_id).bytes.ljust(32, b"\x00")
 
 
+def derive_match_id(fighter_a: str, fighter_b: str, start_time: int, nonce: int) -> bytes:
+    """Canonical match id, mirroring MatchIds.derive in the contract.
+
+    keccak256(abi.encode(fighterA, fighterB, startTime, nonce)) — the id
+    createMatchWithDerivedId accepts for this fight and no other.
+    """
+    return Web3.keccak(
+        encode(
+            ["address", "address", "uint64", "uint64"],
+            [
+                Web3.to_checksum_address(fighter_a),
+                Web3.to_checksum_address(fighter_b),
+                start_time,
+                nonce,
+            ],
+        )
+    )
+
+
 class EVMClient:
     """Drop-in replacement for SolanaClient. Same public API."""
 
@@ -109,7 +139,7 @@ class EVMClient:
         except Exception as e:
             return str(e)
 
-    async def _send_tx(self, fn_call, instruction_name: str) -> str:
+    async def _send_tx(self, fn_call, instruction_name: str, value: int = 0) -> str:
         """Build, sign, send, and confirm a contract transaction with retry."""
         await self._ensure_initialized()
 
@@ -123,6 +153,7 @@ class EVMClient:
                 tx = await fn_call.build_transaction(
                     {
                         "from": self._oracle.address,
+                        "value": value,
                         "nonce": nonce,
                         "chainId": settings.base_chain_id,
                         "maxPriorityFeePerGas": priority_fee,
@@ -164,34 +195,120 @@ class EVMClient:
 
     # ── Match operations (return tx hash hex string) ──
 
+    async def register_fighter_on_chain(self, fighter: str, name: str, owner: str) -> str:
+        """Register a fighter in the on-chain registry (name stored as keccak256 hash)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.registerFighter(
+            self._w3.to_checksum_address(fighter),
+            self._w3.keccak(text=name),
+            self._w3.to_checksum_address(owner),
+        )
+        return await self._send_tx(fn, "register_fighter")
+
+    async def _ensure_fighter_registered(self, fighter: str) -> None:
+        """Register a fighter on first use — createMatch rejects unregistered fighters."""
+        data = await self._contract.functions.fighters(
+            self._w3.to_checksum_address(fighter)
+        ).call()
+        if int(data[1], 16) == 0:  # owner == address(0) means unregistered
+            # Fighters are identified by owner wallet on-chain, so they own themselves
+            await self.register_fighter_on_chain(fighter, fighter.lower(), fighter)
+
     async def create_match_on_chain(
-        self, match_id: str, fighter_a: str, fighter_b: str
+        self, match_id: str, fighter_a: str, fighter_b: str, match_type: str = "ranked"
     ) -> str:
         await self._ensure_initialized()
+        await self._ensure_fighter_registered(fighter_a)
+        await self._ensure_fighter_registered(fighter_b)
         mid = match_id_to_bytes(match_id)
-        fn = self._contract.functions.createMatch(
+        # Refundable bond, returned by settleCreatorBond once the match is cleaned up
+        bond = await self._contract.functions.creatorBond().call()
+        fn = self._contract.functions.createMatchInCategory(
             mid,
             self._w3.to_checksum_address(fighter_a),
             self._w3.to_checksum_address(fighter_b),
             self._w3.to_wei("0.001", "ether"),  # default minBet
             0,  # no betting window limit
+            MATCH_CATEGORIES.index(match_type),
         )
-        return await self._send_tx(fn, "create_match")
+        return await self._send_tx(fn, "create_match", value=bond)
 
     async def lock_match_on_chain(self, match_id: str) -> str:
         await self._ensure_initialized()
         fn = self._contract.functions.lockMatch(match_id_to_bytes(match_id))
         return await self._send_tx(fn, "lock_match")
 
+    async def lock_match_with_commitment_on_chain(self, match_id: str, commitment: bytes) -> str:
+        """Lock and seal the result as resultCommitment(matchId, winner, salt)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.lockMatchWithCommitment(
+            match_id_to_bytes(match_id), commitment
+        )
+        return await self._send_tx(fn, "lock_match_with_commitment")
+
+    async def unlock_match_on_chain(self, match_id: str) -> str:
+        """Reopen a mistakenly locked match; only within UNLOCK_WINDOW of the lock."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.unlockMatch(match_id_to_bytes(match_id))
+        return await self._send_tx(fn, "unlock_match")
+
+    async def request_chaos_result_on_chain(self, match_id: str) -> str:
+        """Ask VRF to draw a locked chaos exhibition's winner (the oracle never reports it)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.requestChaosResult(match_id_to_bytes(match_id))
+        return await self._send_tx(fn, "request_chaos_result")
+
+    def _op_id(self, action: str, match_id: str) -> bytes:
+        """Deterministic idempotency key so a retried tx no-ops if the first one landed.
+
+        Scoped to this oracle: with an M-of-N resolution threshold every oracle
+        attests separately, and a shared key would skip all but the first.
+        """
+        return self._w3.keccak(text=f"{action}:{match_id}:{self._oracle.address}")
+
     async def resolve_match_on_chain(self, match_id: str, winner: int) -> str:
         """Resolve match. winner: 0=SideA, 1=SideB."""
         await self._ensure_initialized()
-        fn = self._contract.functions.resolveMatch(match_id_to_bytes(match_id), winner)
+        fn = self._contract.functions.resolveMatchWithOpId(
+            match_id_to_bytes(match_id), winner, self._op_id("resolve", match_id)
+        )
         return await self._send_tx(fn, "resolve_match")
 
+    async def amend_resolution_on_chain(self, match_id: str, winner: int) -> str:
+        """Correct a just-resolved winner. Reverts after AMEND_WINDOW or the first claim."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.amendResolution(match_id_to_bytes(match_id), winner)
+        return await self._send_tx(fn, "amend_resolution")
+
+    async def reveal_result_on_chain(self, match_id: str, winner: int, salt: bytes) -> str:
+        """Resolve a committed match by opening its lock-time commitment."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.revealResult(match_id_to_bytes(match_id), winner, salt)
+        return await self._send_tx(fn, "reveal_result")
+
+    async def forfeit_match_on_chain(self, match_id: str, winner: int) -> str:
+        """Award a never-locked match to the fighter who showed up (walkover)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.forfeitMatch(match_id_to_bytes(match_id), winner)
+        return await self._send_tx(fn, "forfeit_match")
+
+    async def forfeit_no_contest_on_chain(self, match_id: str) -> str:
+        """Cancel a never-locked match with refunds when neither fighter can go on."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.forfeitNoContest(match_id_to_bytes(match_id))
+        return await self._send_tx(fn, "forfeit_no_contest")
+
+    async def resolve_from_feed_on_chain(self, match_id: str) -> str:
+        """Resolve a feed-bound match from its Switchboard feed (permissionless on-chain)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.resolveFromFeed(match_id_to_bytes(match_id))
+        return await self._send_tx(fn, "resolve_from_feed")
+
     async def cancel_match_on_chain(self, match_id: str) -> str:
         await self._ensure_initialized()
-        fn = self._contract.functions.cancelMatch(match_id_to_bytes(match_id))
+        fn = self._contract.functions.cancelMatchWithOpId(
+            match_id_to_bytes(match_id), self._op_id("cancel", match_id)
+        )
         return await self._send_tx(fn, "cancel_match")
 
     async def timeout_match_on_chain(self, match_id: str) -> str:
@@ -200,16 +317,44 @@ class EVMClient:
         fn = self._contract.functions.timeoutMatch(match_id_to_bytes(match_id))
         return await self._send_tx(fn, "timeout_match")
 
+    async def oracle_heartbeat_on_chain(self) -> str:
+        """Prove the oracle is alive so timeoutMatch keeps the full timeout."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.oracleHeartbeat()
+        return await self._send_tx(fn, "oracle_heartbeat")
+
+    async def push_summaries_on_chain(self, match_id: str, max_count: int) -> str:
+        """Push end-of-match summaries to subscribers (permissionless on-chain)."""
+        await self._ensure_initialized()
+        fn = self._contract.functions.pushSummaries(match_id_to_bytes(match_id), max_count)
+        return await self._send_tx(fn, "push_summaries")
+
     # ── Read operations ──
 
-    async def get_match_pool(self, match_id: str) -> dict | None:
+    async def get_reserve_snapshot(self) -> tuple[int, int]:
+        """Return (contract balance wei, block number) pinned to the same block."""
+        await self._ensure_initialized()
+        block = await self._w3.eth.get_block_number()
+        balance = await self._w3.eth.get_balance(
+            self._w3.to_checksum_address(settings.contract_address), block_identifier=block
+        )
+        return balance, block
+
+    async def get_match_pool(
+        self, match_id: str, block_identifier: int | str = "latest"
+    ) -> dict | None:
         """Fetch match pool data from contract. Returns None if not found."""
         await self._ensure_initialized()
         try:
-            data = await self._contract.functions.matches(match_id_to_bytes(match_id)).call()
+            data = await self._contract.functions.matches(match_id_to_bytes(match_id)).call(
+                block_identifier=block_identifier
+            )
             # Struct returns as tuple: (fighterA, fighterB, status, winner, ...)
             if data[2] == 0:  # MatchStatus.None = not initialized
                 return None
+            loss_floor_bps = await self._contract.functions.lossFloors(
+                match_id_to_bytes(match_id)
+            ).call(block_identifier=block_identifier)
             return {
                 "fighter_a": data[0],
                 "fighter_b": data[1],
@@ -229,19 +374,22 @@ class EVMClient:
                 "min_bet": data[15],
                 "betting_window": data[16],
                 "fees_withdrawn": data[17],
+                "loss_floor_bps": loss_floor_bps,
             }
         except Exception:
             logger.exception("Failed to fetch match pool %s", match_id)
             return None
 
-    async def get_bet(self, match_id: str, bettor_address: str) -> dict | None:
+    async def get_bet(
+        self, match_id: str, bettor_address: str, block_identifier: int | str = "latest"
+    ) -> dict | None:
         """Fetch bet info from contract. Returns None if no bet."""
         await self._ensure_initialized()
         try:
             data = await self._contract.functions.bets(
                 match_id_to_bytes(match_id),
                 self._w3.to_checksum_address(bettor_address),
-            ).call()
+            ).call(block_identifier=block_identifier)
             if data[0] == 0:  # amount == 0 means no bet
                 return None
             return {"amount": data[0], "side": data[1], "claimed": data[2]}
@@ -249,6 +397,98 @@ class EVMClient:
             logger.exception("Failed to fetch bet for %s on %s", bettor_address, match_id)
             return None
 
+    async def get_solvency(
+        self, match_id: str, block_identifier: int | str = "latest"
+    ) -> dict | None:
+        """Fetch the contract's own solvency view for a match. Returns None on RPC error."""
+        await self._ensure_initialized()
+        try:
+            data = await self._contract.functions.getSolvency(
+                match_id_to_bytes(match_id)
+            ).call(block_identifier=block_identifier)
+            return {
+                "vault_balance": data[0],
+                "outstanding_liability": data[1],
+                "fee_reserved": data[2],
+                "surplus": data[3],
+            }
+        except Exception:
+            logger.exception("Failed to fetch solvency for %s", match_id)
+            return None
+
+    async def pending_summaries(self, match_id: str) -> int | None:
+        """Subscribers of a settled match not yet pushed to. Returns None on RPC error."""
+        await self._ensure_initialized()
+        try:
+            return await self._contract.functions.pendingSummaries(
+                match_id_to_bytes(match_id)
+            ).call()
+        except Exception:
+            logger.exception("Failed to fetch pending summaries for %s", match_id)
+            return None
+
+    async def result_feed(self, match_id: str) -> bytes | None:
+        """Switchboard feed id bound to a match, None if oracle-resolved or on RPC error."""
+        await self._ensure_initialized()
+        try:
+            feed_id = await self._contract.functions.resultFeeds(
+                match_id_to_bytes(match_id)
+            ).call()
+        except Exception:
+            logger.exception("Failed to fetch result feed for %s", match_id)
+            return None
+        return feed_id if any(feed_id) else None
+
+    async def oracle_can_resolve(self, match_id: str) -> bool:
+        """False when the authority rotated this oracle's key out of the match.
+
+        True on RPC error — the resolve tx itself is the final check.
+        """
+        await self._ensure_initialized()
+        try:
+            replacement = await self._contract.functions.matchOracles(
+                match_id_to_bytes(match_id), self._oracle.address
+            ).call()
+        except Exception:
+            logger.exception("Failed to fetch match oracle for %s", match_id)
+            return True
+        return int(replacement, 16) == 0
+
+    async def resolution_ready_at(self, match_id: str) -> int | None:
+        """Earliest unix time an oracle result is accepted (lock + minResolutionDelay).
+
+        None on RPC error.
+        """
+        await self._ensure_initialized()
+        try:
+            data = await self._contract.functions.matches(match_id_to_bytes(match_id)).call()
+            delay = await self._contract.functions.minResolutionDelay().call()
+        except Exception:
+            logger.exception("Failed to fetch resolution delay for %s", match_id)
+            return None
+        return data[12] + delay  # lockTimestamp
+
+    async def get_fighter_record(self, fighter: str) -> dict | None:
+        """Fetch a fighter's on-chain record. Returns None if unregistered."""
+        await self._ensure_initialized()
+        try:
+            data = await self._contract.functions.fighters(
+                self._w3.to_checksum_address(fighter)
+            ).call()
+            if int(data[1], 16) == 0:  # owner == address(0) means unregistered
+                return None
+            return {
+                "name_hash": data[0],
+                "owner": data[1],
+                "wins": data[2],
+                "losses": data[3],
+                "draws": data[4],
+                "total_volume": data[5],
+            }
+        except Exception:
+            logger.exception("Failed to fetch fighter record for %s", fighter)
+            return None
+
     async def bet_exists(self, match_id: str, bettor_address: str) -> bool | None:
         """Three-state check: True (bet exists), False (no bet), None (RPC error)."""
         await self._ensure_initialized()
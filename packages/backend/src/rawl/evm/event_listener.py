@@ -2,11 +2,19 @@
 
 Subscribes to RawlBetting contract events via HTTP polling (with WebSocket
 upgrade when available). Handles BetPlaced, MatchLocked, MatchResolved,
-MatchCancelled, PayoutClaimed, BetRefunded events.
+MatchCancelled, PayoutClaimed, BetRefunded, CashedOut, PositionSold events, and records
+FeesWithdrawn, UnclaimedSwept and cash-out dust in the revenue ledger.
+
+Each log is applied in one transaction together with its indexed_logs row, so
+a log is in the mirror exactly once: a crash mid-range replays from the
+indexer_checkpoints block on restart and skips what was already written, and
+`python -m rawl.evm.backfill` can walk any historical range, even alongside
+the live listener.
 """
 from __future__ import annotations
 
 import asyncio
+import functools
 import json
 import logging
 from datetime import datetime, timezone
@@ -15,6 +23,7 @@ from web3 import AsyncWeb3, AsyncHTTPProvider
 
 from rawl.config import settings
 from rawl.evm.abi import CONTRACT_ABI
+from rawl.evm.event_schema import EVENT_SCHEMA, to_payload, validate_payload
 from rawl.redis_client import redis_pool
 
 logger = logging.getLogger(__name__)
@@ -22,10 +31,23 @@ logger = logging.getLogger(__name__)
 POLL_INTERVAL = 2  # seconds
 RECONNECT_BACKOFF_INITIAL = 1
 RECONNECT_BACKOFF_MAX = 30
-REDIS_LAST_BLOCK_KEY = "evm:last_block"
+REDIS_LAST_BLOCK_KEY = "evm:last_block"  # pre-checkpoint cursor, read once to migrate
+CHECKPOINT_NAME = "rawl_betting"
 ODDS_TTL = 300  # 5 minutes
 MAX_BLOCK_RANGE = 2000  # max blocks per eth_getLogs call (public RPC safe)
-MAX_CATCHUP_BLOCKS = 10000  # if further behind than this, skip to head
+
+# On-chain RawlBetting.CancelReason codes → matches.cancel_reason
+CANCEL_REASONS = {
+    1: "operator_cancel",
+    2: "timeout",
+    3: "empty_side",
+    4: "expired",
+    5: "emergency",
+    6: "undersubscribed",
+    7: "vetoed",
+    8: "no_show",
+    9: "organizer_request",
+}
 
 
 class EventListener:
@@ -35,39 +57,19 @@ class EventListener:
         self._running = False
         self._w3: AsyncWeb3 | None = None
         self._contract = None
-        self._last_block: int = 0
+        self._last_block: int | None = None
 
-    async def start(self) -> None:
-        """Start the event polling loop. Runs until stop() is called."""
-        self._running = True
+    def _connect(self) -> None:
         self._w3 = AsyncWeb3(AsyncHTTPProvider(settings.base_rpc_url))
         self._contract = self._w3.eth.contract(
             address=self._w3.to_checksum_address(settings.contract_address),
             abi=CONTRACT_ABI,
         )
 
-        # Restore last processed block from Redis
-        current_head = await self._w3.eth.get_block_number()
-        try:
-            stored = await redis_pool.get(REDIS_LAST_BLOCK_KEY)
-            if stored:
-                stored_block = int(stored)
-                gap = current_head - stored_block
-                if gap > MAX_CATCHUP_BLOCKS:
-                    logger.warning(
-                        "Stored block %d is %d blocks behind head %d (> %d) — skipping to head",
-                        stored_block, gap, current_head, MAX_CATCHUP_BLOCKS,
-                    )
-                    self._last_block = current_head
-                else:
-                    self._last_block = stored_block
-                    logger.info("Resuming event listener from block %d (%d behind)", stored_block, gap)
-        except Exception:
-            pass
-
-        if self._last_block == 0:
-            self._last_block = current_head
-            logger.info("Starting event listener from current block %d", self._last_block)
+    async def start(self) -> None:
+        """Start the event polling loop. Runs until stop() is called."""
+        self._running = True
+        self._connect()
 
         backoff = RECONNECT_BACKOFF_INITIAL
         while self._running:
@@ -83,27 +85,74 @@ class EventListener:
         """Signal the listener to stop."""
         self._running = False
 
+    async def backfill(self, from_block: int, to_block: int | None = None) -> None:
+        """Apply every contract log in a historical range, leaving the live checkpoint alone.
+
+        Logs already in indexed_logs are skipped, so overlapping a range the
+        listener has covered is harmless.
+        """
+        self._connect()
+        if to_block is None:
+            to_block = await self._w3.eth.get_block_number()
+        logger.info("Backfilling blocks %d-%d", from_block, to_block)
+        await self._process_blocks(from_block, to_block, checkpoint=False)
+
+    async def _load_checkpoint(self) -> int:
+        """Block to resume after: the DB checkpoint, else the old Redis cursor, else head."""
+        from rawl.db.models.indexer import IndexerCheckpoint
+        from rawl.db.session import worker_session_factory
+
+        async with worker_session_factory() as db:
+            checkpoint = await db.get(IndexerCheckpoint, CHECKPOINT_NAME)
+        if checkpoint:
+            logger.info("Resuming event listener from block %d", checkpoint.block_number)
+            return checkpoint.block_number
+
+        try:
+            stored = await redis_pool.get(REDIS_LAST_BLOCK_KEY)
+            if stored:
+                logger.info("Resuming event listener from Redis cursor %d", int(stored))
+                return int(stored)
+        except Exception:
+            pass
+
+        head = await self._w3.eth.get_block_number()
+        logger.info(
+            "No checkpoint — starting from current block %d; backfill for earlier history", head
+        )
+        return head
+
+    async def _save_checkpoint(self, block_number: int) -> None:
+        from rawl.db.models.indexer import IndexerCheckpoint
+        from rawl.db.session import worker_session_factory
+
+        async with worker_session_factory() as db:
+            await db.merge(IndexerCheckpoint(name=CHECKPOINT_NAME, block_number=block_number))
+            await db.commit()
+
     async def _poll_loop(self) -> None:
         """Continuously poll for new events."""
         while self._running:
             try:
+                if self._last_block is None:
+                    self._last_block = await self._load_checkpoint()
                 current_block = await self._w3.eth.get_block_number()
                 if current_block > self._last_block:
                     await self._process_blocks(self._last_block + 1, current_block)
-                    self._last_block = current_block
-                    # Persist to Redis
-                    try:
-                        await redis_pool.set(REDIS_LAST_BLOCK_KEY, str(current_block))
-                    except Exception:
-                        pass
             except Exception:
                 logger.exception("Error in poll iteration")
                 raise  # Trigger reconnect
 
             await asyncio.sleep(POLL_INTERVAL)
 
-    async def _process_blocks(self, from_block: int, to_block: int) -> None:
-        """Fetch and process logs for a block range, chunked to avoid RPC limits."""
+    async def _process_blocks(
+        self, from_block: int, to_block: int, checkpoint: bool = True
+    ) -> None:
+        """Fetch and process logs for a block range, chunked to avoid RPC limits.
+
+        A log that fails to apply raises out of here instead of being skipped:
+        the checkpoint stays before its chunk, so the next attempt retries it.
+        """
         chunk_start = from_block
         while chunk_start <= to_block:
             chunk_end = min(chunk_start + MAX_BLOCK_RANGE - 1, to_block)
@@ -115,54 +164,104 @@ class EventListener:
                 }
             )
             for log in logs:
-                try:
-                    await self._handle_log(log)
-                except Exception:
-                    logger.exception("Error handling log in block %d", log.get("blockNumber", 0))
+                await self._handle_log(log)
+            if checkpoint:
+                await self._save_checkpoint(chunk_end)
+                self._last_block = chunk_end
             chunk_start = chunk_end + 1
 
+    def _decode(self, log):
+        """Decode a log against the events the mirror tracks, None for anything else."""
+        events = self._contract.events
+        for event_cls in [
+            events.BetPlaced,
+            events.MatchLocked,
+            events.MatchResolved,
+            events.MatchCancelled,
+            events.PayoutClaimed,
+            events.BetRefunded,
+            events.NoWinnersRefunded,
+            events.CashedOut,
+            events.PositionSold,
+            events.FeesWithdrawn,
+            events.UnclaimedSwept,
+        ]:
+            try:
+                return event_cls().process_log(log)
+            except Exception:
+                continue
+        return None
+
     async def _handle_log(self, log) -> None:
-        """Decode and route a single event log."""
-        try:
-            events = self._contract.events
-            # Try each event type
-            for event_cls in [
-                events.BetPlaced,
-                events.MatchLocked,
-                events.MatchResolved,
-                events.MatchCancelled,
-                events.PayoutClaimed,
-                events.BetRefunded,
-                events.NoWinnersRefunded,
-            ]:
-                try:
-                    decoded = event_cls().process_log(log)
-                    await self._dispatch_event(decoded["event"], decoded["args"])
-                    return
-                except Exception:
-                    continue
-        except Exception:
-            pass  # Unknown event — ignore
+        """Decode a log and apply it, once, in a single transaction."""
+        decoded = self._decode(log)
+        if decoded is None:
+            return
+
+        from rawl.db.models.indexer import IndexedLog
+        from rawl.db.session import worker_session_factory
+
+        event_name = decoded["event"]
+        tx_hash = self._w3.to_hex(decoded["transactionHash"])
+        log_index = decoded["logIndex"]
 
-    async def _dispatch_event(self, event_name: str, args) -> None:
-        """Route decoded event to handler."""
+        async with worker_session_factory() as db:
+            if await db.get(IndexedLog, (tx_hash, log_index)):
+                return  # Replayed after a restart, or covered by a backfill
+            db.add(
+                IndexedLog(
+                    tx_hash=tx_hash,
+                    log_index=log_index,
+                    block_number=decoded["blockNumber"],
+                    event=event_name,
+                )
+            )
+            odds_match = await self._dispatch_event(
+                db, event_name, decoded["args"], tx_hash, log_index
+            )
+            await db.commit()
+
+        if odds_match:
+            await self._publish_odds(odds_match)
+
+    async def _dispatch_event(
+        self, db, event_name: str, args, tx_hash: str | None = None, log_index: int | None = None
+    ) -> str | None:
+        """Route decoded event to handler. Returns the match whose odds moved, if any."""
+        if EVENT_SCHEMA:
+            violations = validate_payload(event_name, to_payload(event_name, args))
+            if violations:
+                # Contract and schema have drifted — handle anyway, but make it loud
+                logger.warning("%s does not match the event schema: %s", event_name, violations)
         match_id_hex = args.get("matchId", b"").hex() if isinstance(args.get("matchId"), bytes) else ""
         # Convert bytes32 match_id back to UUID format for DB lookup
         match_id_uuid = self._bytes32_to_uuid(args.get("matchId", b""))
 
         handler = {
-            "BetPlaced": self._handle_bet_placed,
+            "BetPlaced": functools.partial(self._handle_bet_placed, tx_hash=tx_hash),
             "MatchLocked": self._handle_match_locked,
             "MatchResolved": self._handle_match_resolved,
             "MatchCancelled": self._handle_match_cancelled,
             "PayoutClaimed": self._handle_payout_claimed,
             "BetRefunded": self._handle_bet_refunded,
             "NoWinnersRefunded": self._handle_bet_refunded,
+            "CashedOut": functools.partial(
+                self._handle_cashed_out, tx_hash=tx_hash, log_index=log_index
+            ),
+            "PositionSold": self._handle_position_sold,
+            "FeesWithdrawn": functools.partial(
+                self._handle_revenue, kind="fees", tx_hash=tx_hash, log_index=log_index
+            ),
+            "UnclaimedSwept": functools.partial(
+                self._handle_revenue, kind="sweeps", tx_hash=tx_hash, log_index=log_index
+            ),
         }.get(event_name)
 
-        if handler:
-            await handler(args, match_id_uuid)
-            logger.info("Processed event %s for match %s", event_name, match_id_uuid or match_id_hex)
+        if not handler:
+            return None
+        odds_match = await handler(db, args, match_id_uuid)
+        logger.info("Processed event %s for match %s", event_name, match_id_uuid or match_id_hex)
+        return odds_match
 
     @staticmethod
     def _bytes32_to_uuid(b: bytes) -> str | None:
@@ -175,7 +274,9 @@ class EventListener:
         except Exception:
             return None
 
-    async def _handle_bet_placed(self, args, match_id_uuid: str | None) -> None:
+    async def _handle_bet_placed(
+        self, db, args, match_id_uuid: str | None, tx_hash: str | None = None
+    ) -> str | None:
         """Create/update Bet row in DB, update match side totals."""
         if not match_id_uuid:
             return
@@ -184,153 +285,250 @@ class EventListener:
 
         from rawl.db.models.bet import Bet
         from rawl.db.models.match import Match
-        from rawl.db.session import worker_session_factory
 
         bettor = args["bettor"]
         side = "a" if args["side"] == 0 else "b"
         amount_wei = args["amount"]
         amount_eth = amount_wei / 1e18
-
-        async with worker_session_factory() as db:
-            # Check for existing bet record
-            existing = await db.execute(
-                select(Bet).where(
-                    Bet.match_id == match_id_uuid,
-                    Bet.wallet_address == bettor.lower(),
-                )
+        odds_a_eth = args["sideATotal"] / 1e18
+        odds_b_eth = args["sideBTotal"] / 1e18
+
+        # Check for existing bet record
+        existing = await db.execute(
+            select(Bet).where(
+                Bet.match_id == match_id_uuid,
+                Bet.wallet_address == bettor.lower(),
             )
-            bet = existing.scalar_one_or_none()
-
-            if bet:
-                bet.status = "confirmed"
-                bet.amount_eth = amount_eth
+        )
+        bet = existing.scalar_one_or_none()
+
+        if bet:
+            bet.status = "confirmed"
+            bet.amount_eth = amount_eth
+            bet.odds_side_a_eth = odds_a_eth
+            bet.odds_side_b_eth = odds_b_eth
+            bet.tx_hash = tx_hash or bet.tx_hash
+        else:
+            bet = Bet(
+                match_id=match_id_uuid,
+                wallet_address=bettor.lower(),
+                side=side,
+                amount_eth=amount_eth,
+                odds_side_a_eth=odds_a_eth,
+                odds_side_b_eth=odds_b_eth,
+                onchain_bet_id=f"{match_id_uuid}:{bettor.lower()}",
+                tx_hash=tx_hash,
+                status="confirmed",
+            )
+            db.add(bet)
+
+        # Update match side totals
+        match_result = await db.execute(select(Match).where(Match.id == match_id_uuid))
+        match = match_result.scalar_one_or_none()
+        if match:
+            if side == "a":
+                match.side_a_total = (match.side_a_total or 0) + amount_eth
             else:
-                bet = Bet(
-                    match_id=match_id_uuid,
-                    wallet_address=bettor.lower(),
-                    side=side,
-                    amount_eth=amount_eth,
-                    onchain_bet_id=f"{match_id_uuid}:{bettor.lower()}",
-                    status="confirmed",
-                )
-                db.add(bet)
+                match.side_b_total = (match.side_b_total or 0) + amount_eth
 
-            # Update match side totals
-            match_result = await db.execute(select(Match).where(Match.id == match_id_uuid))
-            match = match_result.scalar_one_or_none()
-            if match:
-                if side == "a":
-                    match.side_a_total = (match.side_a_total or 0) + amount_eth
-                else:
-                    match.side_b_total = (match.side_b_total or 0) + amount_eth
-
-            await db.commit()
+        return match_id_uuid
 
-        # Publish odds to Redis
-        await self._publish_odds(match_id_uuid)
-
-    async def _handle_match_locked(self, args, match_id_uuid: str | None) -> None:
+    async def _handle_match_locked(self, db, args, match_id_uuid: str | None) -> None:
         if not match_id_uuid:
             return
 
         from sqlalchemy import select
 
         from rawl.db.models.match import Match
-        from rawl.db.session import worker_session_factory
 
-        async with worker_session_factory() as db:
-            result = await db.execute(select(Match).where(Match.id == match_id_uuid))
-            match = result.scalar_one_or_none()
-            if match:
-                match.status = "locked"
-                match.locked_at = datetime.now(timezone.utc)
-                await db.commit()
-
-    async def _handle_match_resolved(self, args, match_id_uuid: str | None) -> None:
+        result = await db.execute(select(Match).where(Match.id == match_id_uuid))
+        match = result.scalar_one_or_none()
+        if match:
+            match.status = "locked"
+            match.locked_at = datetime.now(timezone.utc)
+
+    async def _handle_match_resolved(self, db, args, match_id_uuid: str | None) -> None:
         if not match_id_uuid:
             return
 
         from sqlalchemy import select
 
         from rawl.db.models.match import Match
-        from rawl.db.session import worker_session_factory
 
         winner_side = args["winner"]  # 0=SideA, 1=SideB
 
-        async with worker_session_factory() as db:
-            result = await db.execute(select(Match).where(Match.id == match_id_uuid))
-            match = result.scalar_one_or_none()
-            if match:
-                match.status = "resolved"
-                match.resolved_at = datetime.now(timezone.utc)
-                # Update side totals from event data
-                match.side_a_total = args.get("sideATotal", 0) / 1e18
-                match.side_b_total = args.get("sideBTotal", 0) / 1e18
-                await db.commit()
-
-    async def _handle_match_cancelled(self, args, match_id_uuid: str | None) -> None:
+        result = await db.execute(select(Match).where(Match.id == match_id_uuid))
+        match = result.scalar_one_or_none()
+        if match:
+            match.status = "resolved"
+            match.resolved_at = datetime.now(timezone.utc)
+            # Update side totals from event data
+            match.side_a_total = args.get("sideATotal", 0) / 1e18
+            match.side_b_total = args.get("sideBTotal", 0) / 1e18
+
+    async def _handle_match_cancelled(self, db, args, match_id_uuid: str | None) -> None:
+        """Mark the match cancelled, keeping any more specific reason the backend set first."""
         if not match_id_uuid:
             return
 
         from sqlalchemy import select
 
         from rawl.db.models.match import Match
-        from rawl.db.session import worker_session_factory
 
-        async with worker_session_factory() as db:
-            result = await db.execute(select(Match).where(Match.id == match_id_uuid))
-            match = result.scalar_one_or_none()
-            if match:
-                match.status = "cancelled"
-                match.cancelled_at = datetime.now(timezone.utc)
-                await db.commit()
-
-    async def _handle_payout_claimed(self, args, match_id_uuid: str | None) -> None:
+        result = await db.execute(select(Match).where(Match.id == match_id_uuid))
+        match = result.scalar_one_or_none()
+        if match:
+            match.status = "cancelled"
+            match.cancelled_at = datetime.now(timezone.utc)
+            if not match.cancel_reason:
+                match.cancel_reason = CANCEL_REASONS.get(args.get("reason", 0))
+
+    async def _handle_payout_claimed(self, db, args, match_id_uuid: str | None) -> None:
         if not match_id_uuid:
             return
 
         from sqlalchemy import select
 
         from rawl.db.models.bet import Bet
-        from rawl.db.session import worker_session_factory
 
         bettor = args["bettor"]
 
-        async with worker_session_factory() as db:
-            result = await db.execute(
-                select(Bet).where(
-                    Bet.match_id == match_id_uuid,
-                    Bet.wallet_address == bettor.lower(),
-                )
+        result = await db.execute(
+            select(Bet).where(
+                Bet.match_id == match_id_uuid,
+                Bet.wallet_address == bettor.lower(),
             )
-            bet = result.scalar_one_or_none()
-            if bet:
-                bet.status = "claimed"
-                bet.claimed_at = datetime.now(timezone.utc)
-                await db.commit()
+        )
+        bet = result.scalar_one_or_none()
+        if bet:
+            bet.status = "claimed"
+            bet.claimed_at = datetime.now(timezone.utc)
+            bet.settled_eth = args["amount"] / 1e18
 
-    async def _handle_bet_refunded(self, args, match_id_uuid: str | None) -> None:
+    async def _handle_bet_refunded(self, db, args, match_id_uuid: str | None) -> None:
         if not match_id_uuid:
             return
 
         from sqlalchemy import select
 
         from rawl.db.models.bet import Bet
-        from rawl.db.session import worker_session_factory
 
         bettor = args["bettor"]
 
-        async with worker_session_factory() as db:
-            result = await db.execute(
-                select(Bet).where(
-                    Bet.match_id == match_id_uuid,
-                    Bet.wallet_address == bettor.lower(),
-                )
+        result = await db.execute(
+            select(Bet).where(
+                Bet.match_id == match_id_uuid,
+                Bet.wallet_address == bettor.lower(),
+            )
+        )
+        bet = result.scalar_one_or_none()
+        if bet:
+            bet.status = "refunded"
+            bet.settled_eth = args["amount"] / 1e18
+
+    async def _handle_cashed_out(
+        self,
+        db,
+        args,
+        match_id_uuid: str | None,
+        tx_hash: str | None = None,
+        log_index: int | None = None,
+    ) -> str | None:
+        """Mark the bet cashed out and drop its stake from the match side totals."""
+        # The haircut is kept for the dust treasury whether or not the match is mirrored
+        if args.get("retained"):
+            await self._record_revenue(db, args, "dust", args["retained"], tx_hash, log_index)
+        if not match_id_uuid:
+            return
+
+        from sqlalchemy import select
+
+        from rawl.db.models.bet import Bet
+        from rawl.db.models.match import Match
+
+        bettor = args["bettor"]
+
+        result = await db.execute(
+            select(Bet).where(
+                Bet.match_id == match_id_uuid,
+                Bet.wallet_address == bettor.lower(),
             )
-            bet = result.scalar_one_or_none()
-            if bet:
-                bet.status = "refunded"
-                await db.commit()
+        )
+        bet = result.scalar_one_or_none()
+        if not bet:
+            return
+        bet.status = "cashed_out"
+        bet.claimed_at = datetime.now(timezone.utc)
+        bet.settled_eth = args["amount"] / 1e18
+
+        match_result = await db.execute(select(Match).where(Match.id == match_id_uuid))
+        match = match_result.scalar_one_or_none()
+        if match:
+            if bet.side == "a":
+                match.side_a_total = max((match.side_a_total or 0) - bet.amount_eth, 0)
+            else:
+                match.side_b_total = max((match.side_b_total or 0) - bet.amount_eth, 0)
+
+        return match_id_uuid
+
+    async def _handle_position_sold(self, db, args, match_id_uuid: str | None) -> None:
+        """Mark a Locked bet sold to the cash-out reserve; its stake stays in the pool."""
+        if not match_id_uuid:
+            return
+
+        from sqlalchemy import select
+
+        from rawl.db.models.bet import Bet
+
+        bettor = args["bettor"]
+
+        result = await db.execute(
+            select(Bet).where(
+                Bet.match_id == match_id_uuid,
+                Bet.wallet_address == bettor.lower(),
+            )
+        )
+        bet = result.scalar_one_or_none()
+        if bet:
+            bet.status = "cashed_out"
+            bet.claimed_at = datetime.now(timezone.utc)
+            bet.settled_eth = args["amount"] / 1e18
+
+    async def _handle_revenue(
+        self,
+        db,
+        args,
+        match_id_uuid: str | None,
+        kind: str,
+        tx_hash: str | None = None,
+        log_index: int | None = None,
+    ) -> None:
+        await self._record_revenue(db, args, kind, args["amount"], tx_hash, log_index)
+
+    async def _record_revenue(
+        self, db, args, kind: str, amount: int, tx_hash: str | None, log_index: int | None
+    ) -> None:
+        """Append one inflow to revenue_events; a replayed log is skipped."""
+        if tx_hash is None or log_index is None:
+            return
+
+        from sqlalchemy.dialects.postgresql import insert
+
+        from rawl.db.models.revenue_event import RevenueEvent
+
+        destination = args.get("treasury")
+        await db.execute(
+            insert(RevenueEvent)
+            .values(
+                kind=kind,
+                amount_wei=amount,
+                onchain_match_id=args["matchId"].hex(),
+                destination=destination.lower() if destination else None,
+                tx_hash=tx_hash,
+                log_index=log_index,
+            )
+            .on_conflict_do_nothing(constraint="uq_revenue_event_log")
+        )
 
     async def _publish_odds(self, match_id_uuid: str) -> None:
         """Publish current odds to Redis for real-time display."""
@@ -0,0 +1,99 @@
+"""Contract event payloads in the shared JSON Schema encoding.
+
+The schema is generated from the contracts by scripts/generate_event_schemas.py.
+Decoded web3 args are converted to its language-neutral payload (0x hex for bytes,
+decimal strings for integers wider than 48 bits) so the indexer and anything it
+forwards events to agree with the frontend on every shape.
+"""
+from __future__ import annotations
+
+import json
+import re
+from pathlib import Path
+
+# Bundled copy (refreshed by deploy-base.sh — works in Docker / installed package)
+_BUNDLED = Path(__file__).parent / "events.schema.json"
+
+# Shared package path (works in local dev monorepo)
+_SHARED = (
+    Path(__file__).parent.parent.parent.parent.parent
+    / "shared"
+    / "schema"
+    / "events.schema.json"
+)
+
+
+def load_event_schema() -> dict:
+    """Load the event schema from the bundled copy, falling back to packages/shared."""
+    for path in (_BUNDLED, _SHARED):
+        if path.exists():
+            return json.loads(path.read_text())
+    return {}
+
+
+EVENT_SCHEMA = load_event_schema()
+
+
+def event_definition(event: str, contract: str = "RawlBetting") -> dict | None:
+    return EVENT_SCHEMA.get("$defs", {}).get(f"{contract}.{event}")
+
+
+def _encode(value, spec: dict):
+    if spec.get("type") == "array":
+        return [_encode(item, spec.get("items", {})) for item in value]
+    if isinstance(value, bytes | bytearray):
+        return "0x" + bytes(value).hex()
+    if isinstance(value, int) and not isinstance(value, bool) and spec.get("type") == "string":
+        return str(value)
+    return value
+
+
+def to_payload(event: str, args, contract: str = "RawlBetting") -> dict:
+    """Decoded event args in the schema's encoding. Unknown events pass through as-is."""
+    definition = event_definition(event, contract) or {}
+    properties = definition.get("properties", {})
+    return {name: _encode(value, properties.get(name, {})) for name, value in dict(args).items()}
+
+
+def _check(value, spec: dict, path: str) -> list[str]:
+    kind = spec.get("type")
+    if kind == "integer":
+        if not isinstance(value, int) or isinstance(value, bool):
+            return [f"{path}: expected integer"]
+        if "enum" in spec and value not in spec["enum"]:
+            return [f"{path}: {value} is not a known enum value"]
+        if value < spec.get("minimum", value) or value > spec.get("maximum", value):
+            return [f"{path}: {value} out of range"]
+    elif kind == "string":
+        if not isinstance(value, str):
+            return [f"{path}: expected string"]
+        if "pattern" in spec and not re.search(spec["pattern"], value):
+            return [f"{path}: {value!r} does not match {spec['pattern']}"]
+    elif kind == "boolean":
+        if not isinstance(value, bool):
+            return [f"{path}: expected boolean"]
+    elif kind == "array":
+        if not isinstance(value, list):
+            return [f"{path}: expected array"]
+        errors = []
+        for i, item in enumerate(value):
+            errors += _check(item, spec.get("items", {}), f"{path}[{i}]")
+        return errors
+    return []
+
+
+def validate_payload(event: str, payload: dict, contract: str = "RawlBetting") -> list[str]:
+    """Schema violations in a payload, empty when it conforms.
+
+    Covers the keywords the generator emits — not a general JSON Schema validator.
+    """
+    definition = event_definition(event, contract)
+    if definition is None:
+        return [f"{contract}.{event}: not in the event schema"]
+    properties = definition["properties"]
+    errors = [f"{name}: missing" for name in definition["required"] if name not in payload]
+    errors += [f"{name}: unexpected" for name in payload if name not in properties]
+    for name, value in payload.items():
+        if name in properties:
+            errors += _check(value, properties[name], name)
+    return errors
@@ -0,0 +1,105 @@
+"""Bet tickets — signed bet intents compact enough for a QR code.
+
+    rawl:bet?c=<chainId>&k=<contract>&m=<match uuid hex>&s=<a|b>&v=<wei>&x=<expiry>&sig=<0x…>
+
+The issuer signs ``ticket_message`` with personal_sign (EIP-191). Same format
+as the frontend's lib/betTicket.ts; ``scripts/rawl_cli.py redeem-ticket``
+turns a scanned ticket into a placeBet transaction.
+"""
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from urllib.parse import parse_qs, urlencode
+
+from eth_account import Account
+from eth_account.messages import encode_defunct
+
+SCHEME = "rawl:bet"
+_MATCH_HEX = re.compile(r"^[0-9a-fA-F]{32}$")
+
+
+@dataclass(frozen=True)
+class BetTicket:
+    chain_id: int
+    contract: str
+    match_id: str  # match UUID hex, no dashes
+    side: str  # "a" or "b"
+    amount: int  # wei
+    expiry: int  # unix seconds
+    signature: str = ""
+
+
+def ticket_message(ticket: BetTicket) -> str:
+    """Canonical signed payload — keep in sync with betTicketMessage in the frontend."""
+    return ":".join(
+        [
+            "rawl-bet-ticket:v1",
+            str(ticket.chain_id),
+            ticket.contract.lower(),
+            ticket.match_id.replace("-", "").lower(),
+            ticket.side,
+            str(ticket.amount),
+            str(ticket.expiry),
+        ]
+    )
+
+
+def sign_ticket(ticket: BetTicket, private_key: str) -> BetTicket:
+    signed = Account.sign_message(encode_defunct(text=ticket_message(ticket)), private_key)
+    signature = "0x" + signed.signature.hex().removeprefix("0x")
+    return BetTicket(**{**ticket.__dict__, "signature": signature})
+
+
+def encode_ticket(ticket: BetTicket) -> str:
+    params = {
+        "c": ticket.chain_id,
+        "k": ticket.contract,
+        "m": ticket.match_id.replace("-", ""),
+        "s": ticket.side,
+        "v": ticket.amount,
+        "x": ticket.expiry,
+        "sig": ticket.signature,
+    }
+    return f"{SCHEME}?{urlencode(params)}"
+
+
+def decode_ticket(uri: str) -> BetTicket:
+    """Parse a scanned ticket URI. Raises ValueError if malformed; the signature is not checked."""
+    if not uri.startswith(f"{SCHEME}?"):
+        raise ValueError("Not a Rawl bet ticket")
+    params = {k: v[0] for k, v in parse_qs(uri[len(SCHEME) + 1 :]).items()}
+    missing = [k for k in ("c", "k", "m", "s", "v", "x", "sig") if not params.get(k)]
+    if missing:
+        raise ValueError(f"Bet ticket is missing {', '.join(missing)}")
+    if params["s"] not in ("a", "b"):
+        raise ValueError("Bet ticket side must be a or b")
+    if not _MATCH_HEX.match(params["m"]):
+        raise ValueError("Bet ticket match id is malformed")
+
+    return BetTicket(
+        chain_id=int(params["c"]),
+        contract=params["k"],
+        match_id=params["m"],
+        side=params["s"],
+        amount=int(params["v"]),
+        expiry=int(params["x"]),
+        signature=params["sig"],
+    )
+
+
+def ticket_signer(ticket: BetTicket) -> str:
+    """Address that signed the ticket."""
+    return Account.recover_message(
+        encode_defunct(text=ticket_message(ticket)), signature=ticket.signature
+    )
+
+
+def verify_ticket(ticket: BetTicket, issuer: str, now: int) -> bool:
+    """True if ``issuer`` signed this ticket and it hasn't expired at ``now``."""
+    if ticket.expiry <= now:
+        return False
+    try:
+        return ticket_signer(ticket).lower() == issuer.lower()
+    except Exception:
+        return False
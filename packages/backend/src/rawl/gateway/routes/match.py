@@ -196,7 +196,8 @@ async def create_custom_match(
             owner_b = owner_b_result.scalar_one_or_none()
             if owner_b:
                 tx_hash = await evm_client.create_match_on_chain(
-                    str(match.id), user.wallet_address, owner_b.wallet_address
+                    str(match.id), user.wallet_address, owner_b.wallet_address,
+                    match_type=match.match_type,
                 )
                 match.onchain_match_id = str(match.id).replace("-", "")[:32]
                 await db.commit()
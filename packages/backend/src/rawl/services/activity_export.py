@@ -0,0 +1,92 @@
+"""Wallet-activity export for a bettor's history.
+
+Each record is one bet in the shape wallets and tax tools expect: timestamp,
+amount, the contract it was placed with, the placement tx and realized P&L.
+A page is serialized to canonical JSON and signed with EIP-191
+(``personal_sign``), so a holder of the export can prove it came from Rawl
+by recovering the signer from ``payload`` and ``signature`` alone.
+"""
+from __future__ import annotations
+
+import json
+from datetime import datetime
+
+from eth_account import Account
+from eth_account.messages import encode_defunct
+
+FORMAT = "rawl.wallet-activity/1"
+
+
+def realized_pnl(
+    status: str,
+    amount_eth: float,
+    settled_eth: float | None,
+    match_status: str,
+    side: str,
+    winner_side: str | None,
+) -> float | None:
+    """ETH gained or lost once the position is closed, None while it is still open.
+
+    Losing bets never emit a settlement event, so a resolved match on the other
+    side counts as a full loss until a loss-floor rebate is claimed.
+    """
+    if settled_eth is not None:
+        return settled_eth - amount_eth
+    if (
+        status == "confirmed"
+        and match_status == "resolved"
+        and winner_side is not None
+        and side != winner_side
+    ):
+        return -amount_eth
+    return None
+
+
+def activity_record(
+    *,
+    bet_id: str,
+    match_id: str,
+    created_at: datetime,
+    side: str,
+    amount_eth: float,
+    status: str,
+    tx_hash: str | None,
+    settled_eth: float | None,
+    settled_at: datetime | None,
+    match_status: str,
+    winner_side: str | None,
+    contract: str,
+) -> dict:
+    return {
+        "id": bet_id,
+        "type": "bet",
+        "timestamp": created_at.isoformat(),
+        "match_id": match_id,
+        "side": side,
+        "amount_eth": amount_eth,
+        "status": status,
+        "counterparty": contract,
+        "tx_hash": tx_hash,
+        "settled_eth": settled_eth,
+        "settled_at": settled_at.isoformat() if settled_at else None,
+        "realized_pnl_eth": realized_pnl(
+            status, amount_eth, settled_eth, match_status, side, winner_side
+        ),
+    }
+
+
+def canonical_payload(export: dict) -> str:
+    """Deterministic JSON — the exact string that is signed and returned."""
+    return json.dumps(export, sort_keys=True, separators=(",", ":"))
+
+
+def sign_payload(payload: str, private_key: str) -> tuple[str, str]:
+    """Sign a canonical payload, returning (signer address, 0x signature)."""
+    account = Account.from_key(private_key)
+    signed = account.sign_message(encode_defunct(text=payload))
+    return account.address, "0x" + signed.signature.hex().removeprefix("0x")
+
+
+def recover_signer(payload: str, signature: str) -> str:
+    """Address that produced ``signature`` over ``payload``."""
+    return Account.recover_message(encode_defunct(text=payload), signature=signature)
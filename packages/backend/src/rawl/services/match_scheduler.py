@@ -123,7 +123,8 @@ async def _create_onchain_pool(db, match, fighter_a, fighter_b) -> bool:
             return False
 
         tx_hash = await evm_client.create_match_on_chain(
-            str(match.id), user_a.wallet_address, user_b.wallet_address
+            str(match.id), user_a.wallet_address, user_b.wallet_address,
+            match_type=match.match_type,
         )
 
         # Store the onchain reference for the event listener
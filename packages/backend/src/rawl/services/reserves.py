@@ -0,0 +1,102 @@
+"""Proof-of-reserves feed for the RawlBetting contract.
+
+All matches share one contract balance, so the feed lists every active
+match pool with the wei it still owes (unclaimed payouts/refunds plus the
+unwithdrawn fee). Per-match figures come from the contract's own
+``getSolvency`` view read at a single block — the same definition the
+watchtower and explorers use — so third parties can re-read them from RPC
+and compare the total against ``eth_getBalance`` for that block.
+"""
+from __future__ import annotations
+
+import logging
+
+from rawl.config import settings
+
+logger = logging.getLogger(__name__)
+
+# On-chain MatchStatus / MatchWinner enum values
+STATUS_OPEN = 1
+STATUS_LOCKED = 2
+STATUS_RESOLVED = 3
+STATUS_CANCELLED = 4
+WINNER_SIDE_A = 1
+
+ACTIVE_DB_STATUSES = ("open", "locked")
+SETTLED_DB_STATUSES = ("resolved", "cancelled")
+
+
+def open_bets(pool: dict) -> int:
+    """Unsettled bets the match still owes something to.
+
+    Losing bets stay unclaimed forever, so they only count when a loss floor
+    rebates them or nobody backed the winner (everyone is refunded).
+    """
+    if pool["status"] != STATUS_RESOLVED or pool.get("loss_floor_bps", 0):
+        return pool["bet_count"]
+    winning_side_a = pool["winner"] == WINNER_SIDE_A
+    winning_total = pool["side_a_total"] if winning_side_a else pool["side_b_total"]
+    return pool["winning_bet_count"] if winning_total else pool["bet_count"]
+
+
+async def build_reserves_report(db) -> dict:
+    """Build the machine-readable proof-of-reserves feed.
+
+    Active = open/locked matches, plus resolved/cancelled matches that still
+    have confirmed (unclaimed) bets in the mirror DB.
+    """
+    from sqlalchemy import or_, select
+
+    from rawl.db.models.bet import Bet
+    from rawl.db.models.match import Match
+    from rawl.evm.client import evm_client
+
+    has_open_bets = (
+        select(Bet.id).where(Bet.match_id == Match.id, Bet.status == "confirmed").exists()
+    )
+    result = await db.execute(
+        select(Match).where(
+            Match.onchain_match_id.is_not(None),
+            or_(
+                Match.status.in_(ACTIVE_DB_STATUSES),
+                Match.status.in_(SETTLED_DB_STATUSES) & has_open_bets,
+            ),
+        )
+    )
+    matches = result.scalars().all()
+
+    balance, block = await evm_client.get_reserve_snapshot()
+
+    entries = []
+    total_liability = 0
+    for match in matches:
+        match_id = str(match.id)
+        pool = await evm_client.get_match_pool(match_id, block_identifier=block)
+        solvency = await evm_client.get_solvency(match_id, block_identifier=block)
+        if pool is None or solvency is None:
+            logger.warning("Match pool missing on-chain", extra={"match_id": match_id})
+            continue
+
+        liability = solvency["outstanding_liability"]
+        reserved = solvency["fee_reserved"]
+        total_liability += liability + reserved
+        entries.append(
+            {
+                "match_id": match_id,
+                "onchain_match_id": "0x" + match.onchain_match_id.ljust(64, "0"),
+                "status": pool["status"],
+                "open_bets": open_bets(pool),
+                "liability_wei": liability,
+                "fee_reserved_wei": reserved,
+            }
+        )
+
+    return {
+        "contract": settings.contract_address,
+        "chain_id": settings.base_chain_id,
+        "block_number": block,
+        "balance_wei": balance,
+        "total_liability_wei": total_liability,
+        "surplus_wei": balance - total_liability,
+        "matches": entries,
+    }
@@ -0,0 +1,82 @@
+"""Historical platform revenue for finance reporting.
+
+Reads the ``revenue_events`` ledger the event listener fills from
+``FeesWithdrawn`` (fees), ``UnclaimedSwept`` (sweeps) and the treasury's
+retained share of ``CashedOut`` (dust), and buckets it by UTC day, ISO week
+(Monday start) or calendar month. Amounts stay in the asset's base unit and
+are broken down per asset, so token pools slot in next to native ETH.
+"""
+from __future__ import annotations
+
+from collections.abc import Iterable
+from datetime import datetime, timedelta, timezone
+
+GRANULARITIES = ("day", "week", "month")
+KINDS = ("fees", "sweeps", "dust")
+
+
+def period_start(ts: datetime, granularity: str) -> datetime:
+    """Start (UTC midnight) of the day, week or month containing ``ts``."""
+    day = ts.astimezone(timezone.utc).replace(hour=0, minute=0, second=0, microsecond=0)
+    if granularity == "day":
+        return day
+    if granularity == "week":
+        return day - timedelta(days=day.weekday())
+    if granularity == "month":
+        return day.replace(day=1)
+    raise ValueError(f"Unknown granularity: {granularity}")
+
+
+def _asset_rows(by_asset: dict[str, dict[str, int]]) -> list[dict]:
+    return [
+        {"asset": asset, **totals, "total": sum(totals.values())}
+        for asset, totals in sorted(by_asset.items())
+    ]
+
+
+def aggregate_revenue(
+    events: Iterable[tuple[datetime, str, str, int]], granularity: str
+) -> tuple[list[dict], list[dict]]:
+    """Bucket ``(occurred_at, kind, asset, amount)`` rows into periods.
+
+    Returns the non-empty periods in order, and the per-asset totals over all
+    of them.
+    """
+    buckets: dict[datetime, dict[str, dict[str, int]]] = {}
+    totals: dict[str, dict[str, int]] = {}
+    for occurred_at, kind, asset, amount in events:
+        if kind not in KINDS:
+            continue
+        by_asset = buckets.setdefault(period_start(occurred_at, granularity), {})
+        by_asset.setdefault(asset, dict.fromkeys(KINDS, 0))[kind] += int(amount)
+        totals.setdefault(asset, dict.fromkeys(KINDS, 0))[kind] += int(amount)
+
+    periods = [
+        {"period_start": start, "assets": _asset_rows(by_asset)}
+        for start, by_asset in sorted(buckets.items())
+    ]
+    return periods, _asset_rows(totals)
+
+
+async def build_revenue_report(db, start: datetime, end: datetime, granularity: str) -> dict:
+    """Revenue recorded in ``[start, end)``, bucketed by ``granularity``."""
+    from sqlalchemy import select
+
+    from rawl.db.models.revenue_event import RevenueEvent
+
+    result = await db.execute(
+        select(
+            RevenueEvent.occurred_at,
+            RevenueEvent.kind,
+            RevenueEvent.asset,
+            RevenueEvent.amount_wei,
+        ).where(RevenueEvent.occurred_at >= start, RevenueEvent.occurred_at < end)
+    )
+    periods, totals = aggregate_revenue(result.all(), granularity)
+    return {
+        "start": start,
+        "end": end,
+        "granularity": granularity,
+        "periods": periods,
+        "totals": totals,
+    }
@@ -0,0 +1,57 @@
+"""ARQ cron task delivering end-of-match summaries to match subscribers.
+
+push_match_summaries (every 60s):
+  - For matches settled in the last day, pushes the on-chain MatchSummary log
+    to every wallet that subscribed to the match, PUSH_BATCH_SIZE per tx.
+    The contract keeps the cursor, so a failed or partial run just resumes.
+"""
+from __future__ import annotations
+
+import logging
+from datetime import datetime, timedelta, timezone
+
+logger = logging.getLogger(__name__)
+
+PUSH_BATCH_SIZE = 64  # subscribers per pushSummaries tx
+LOOKBACK = timedelta(days=1)
+
+
+async def _push_summaries_async():
+    from sqlalchemy import func, or_, select
+
+    from rawl.db.models.match import Match
+    from rawl.db.session import worker_session_factory
+    from rawl.evm.client import evm_client
+
+    try:
+        cutoff = datetime.now(timezone.utc) - LOOKBACK
+        settled_at = func.coalesce(Match.resolved_at, Match.cancelled_at)
+
+        async with worker_session_factory() as db:
+            result = await db.execute(
+                select(Match.id).where(
+                    Match.onchain_match_id.is_not(None),
+                    or_(Match.status == "resolved", Match.status == "cancelled"),
+                    settled_at >= cutoff,
+                )
+            )
+            match_ids = [str(mid) for mid in result.scalars().all()]
+
+        for match_id in match_ids:
+            try:
+                pending = await evm_client.pending_summaries(match_id)
+                while pending:
+                    sig = await evm_client.push_summaries_on_chain(match_id, PUSH_BATCH_SIZE)
+                    logger.info(
+                        "Pushed match summaries",
+                        extra={"match_id": match_id, "tx_hash": sig},
+                    )
+                    pending = max(pending - PUSH_BATCH_SIZE, 0)
+            except Exception:
+                logger.exception(
+                    "Error pushing match summaries",
+                    extra={"match_id": match_id},
+                )
+
+    except Exception:
+        logger.exception("Push match summaries task failed")
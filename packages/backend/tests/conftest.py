@@ -232,6 +232,9 @@ def mock_evm():
     mock.close = AsyncMock()
     mock.get_health = AsyncMock(return_value=True)
     mock.create_match_on_chain = AsyncMock(return_value="0xfake_tx_hash")
+    mock.result_feed = AsyncMock(return_value=None)
+    mock.oracle_can_resolve = AsyncMock(return_value=True)
+    mock.resolution_ready_at = AsyncMock(return_value=None)
     with patch("rawl.evm.client.evm_client", mock):
         yield mock
 
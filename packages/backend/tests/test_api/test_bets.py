@@ -1,6 +1,7 @@
-"""Integration tests for GET /api/bets and POST /api/matches/{id}/bets."""
+"""Integration tests for GET /api/bets, GET /api/bets/export and POST /api/matches/{id}/bets."""
 from __future__ import annotations
 
+import json
 import uuid
 from unittest.mock import patch
 
@@ -84,3 +85,54 @@ class TestRecordBet:
         }
         r = await client.post(f"/api/matches/{match_id}/bets", json=body)
         assert r.status_code == 409
+
+
+class TestExportWalletActivity:
+    async def test_export_unsigned_by_default(self, client, seed_bets):
+        r = await client.get("/api/bets/export", params={"wallet": WALLET_BET_A})
+        assert r.status_code == 200
+        data = r.json()
+        assert data["signature"] is None
+        export = json.loads(data["payload"])
+        assert export["format"] == "rawl.wallet-activity/1"
+        assert len(export["items"]) == 1
+        assert export["items"][0]["amount_eth"] == 2.0
+        assert export["items"][0]["realized_pnl_eth"] is None  # match still open
+
+    async def test_export_matches_wallet_case_insensitively(self, client, seed_bets):
+        r = await client.get("/api/bets/export", params={"wallet": WALLET_BET_A.lower()})
+        assert len(json.loads(r.json()["payload"])["items"]) == 1
+
+    async def test_export_signed_payload_recovers_signer(self, client, seed_bets):
+        from eth_account import Account
+
+        from rawl.services.activity_export import recover_signer
+
+        account = Account.create()
+        key = account.key.hex()
+        with patch("rawl.api.routes.bets.settings.activity_export_signing_key", key):
+            r = await client.get("/api/bets/export", params={"wallet": WALLET_BET_A})
+        data = r.json()
+        assert data["signer"] == account.address
+        assert recover_signer(data["payload"], data["signature"]) == account.address
+
+    async def test_export_paginates(self, client, db_session, seed_matches):
+        from rawl.db.models.bet import Bet
+
+        for match in seed_matches[:2]:
+            db_session.add(
+                Bet(match_id=match.id, wallet_address=WALLET_NEW, side="a",
+                    amount_eth=1.0, status="confirmed")
+            )
+        await db_session.flush()
+
+        r = await client.get("/api/bets/export", params={"wallet": WALLET_NEW, "limit": 1})
+        first = json.loads(r.json()["payload"])
+        assert first["has_more"] is True
+        r = await client.get(
+            "/api/bets/export",
+            params={"wallet": WALLET_NEW, "limit": 1, "cursor": first["next_cursor"]},
+        )
+        second = json.loads(r.json()["payload"])
+        assert second["has_more"] is False
+        assert second["items"][0]["id"] != first["items"][0]["id"]
@@ -0,0 +1,70 @@
+"""Unit tests for rawl.evm.event_listener — each log is applied to the mirror exactly once."""
+from __future__ import annotations
+
+from contextlib import asynccontextmanager
+from unittest.mock import AsyncMock, MagicMock, patch
+
+import pytest
+from sqlalchemy import func, select
+
+from rawl.db.models.indexer import IndexedLog
+from rawl.evm.event_listener import EventListener
+
+TX_HASH = bytes.fromhex("ab" * 32)
+
+
+def _listener(dispatch: AsyncMock) -> EventListener:
+    listener = EventListener()
+    listener._w3 = MagicMock()
+    listener._w3.to_hex = lambda b: "0x" + b.hex()
+    listener._decode = lambda log: {
+        "event": "MatchLocked",
+        "args": {"matchId": bytes(32), "timestamp": 1_700_000_000},
+        "transactionHash": TX_HASH,
+        "logIndex": 3,
+        "blockNumber": 100,
+    }
+    listener._dispatch_event = dispatch
+    return listener
+
+
+@pytest.fixture
+def worker_sessions(db_session):
+    @asynccontextmanager
+    async def _session():
+        try:
+            yield db_session
+        except Exception:
+            db_session.expunge_all()  # what closing a real worker session does to pending rows
+            raise
+
+    with patch("rawl.db.session.worker_session_factory", _session):
+        yield db_session
+
+
+async def _indexed(db) -> int:
+    return (await db.execute(select(func.count()).select_from(IndexedLog))).scalar_one()
+
+
+class TestEventListenerReplay:
+    async def test_replayed_log_is_applied_once(self, worker_sessions):
+        dispatch = AsyncMock(return_value=None)
+        listener = _listener(dispatch)
+
+        await listener._handle_log({})
+        await listener._handle_log({})  # restart replays the same block
+
+        dispatch.assert_awaited_once()
+        assert await _indexed(worker_sessions) == 1
+
+    async def test_failed_log_is_retried_not_dropped(self, worker_sessions):
+        dispatch = AsyncMock(side_effect=[RuntimeError("db hiccup"), None])
+        listener = _listener(dispatch)
+
+        with pytest.raises(RuntimeError):
+            await listener._handle_log({})
+        assert await _indexed(worker_sessions) == 0
+
+        await listener._handle_log({})
+        assert dispatch.await_count == 2
+        assert await _indexed(worker_sessions) == 1
@@ -0,0 +1,56 @@
+"""Unit tests for rawl.evm.event_schema — decoded events against the shared schema."""
+from __future__ import annotations
+
+from rawl.evm.event_schema import EVENT_SCHEMA, to_payload, validate_payload
+
+MATCH_ID = bytes.fromhex("0b6f3c2e9a414d2b8f7e1c5d9e3a7b60") + bytes(16)
+BETTOR = "0x00000000000000000000000000000000000000aA"
+
+
+class TestEventSchema:
+    def test_indexed_events_are_in_the_schema(self):
+        defs = EVENT_SCHEMA["$defs"]
+        for event in (
+            "BetPlaced",
+            "MatchLocked",
+            "MatchResolved",
+            "MatchCancelled",
+            "PayoutClaimed",
+            "BetRefunded",
+            "NoWinnersRefunded",
+            "CashedOut",
+            "PositionSold",
+            "FeesWithdrawn",
+            "UnclaimedSwept",
+        ):
+            assert f"RawlBetting.{event}" in defs
+
+    def test_decoded_bet_placed_conforms(self):
+        args = {
+            "matchId": MATCH_ID,
+            "bettor": BETTOR,
+            "side": 1,
+            "amount": 10**18,
+            "sideATotal": 3 * 10**18,
+            "sideBTotal": 10**18,
+        }
+        payload = to_payload("BetPlaced", args)
+        assert payload["matchId"] == "0x" + MATCH_ID.hex()
+        assert payload["amount"] == str(10**18)  # wider than 48 bits → decimal string
+        assert payload["side"] == 1
+        assert validate_payload("BetPlaced", payload) == []
+
+    def test_drift_is_reported(self):
+        payload = to_payload(
+            "MatchCancelled", {"matchId": MATCH_ID, "reason": 99, "timestamp": 1_700_000_000}
+        )
+        assert validate_payload("MatchCancelled", payload) == [
+            "reason: 99 is not a known enum value"
+        ]
+        assert validate_payload("MatchLocked", {"matchId": "0x00"}) == [
+            "timestamp: missing",
+            "matchId: '0x00' does not match ^0x[0-9a-fA-F]{64}$",
+        ]
+        assert validate_payload("NoSuchEvent", {}) == [
+            "RawlBetting.NoSuchEvent: not in the event schema"
+        ]
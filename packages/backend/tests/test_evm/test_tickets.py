@@ -0,0 +1,47 @@
+"""Unit tests for rawl.evm.tickets — bet ticket URI encoding and signatures."""
+from __future__ import annotations
+
+import pytest
+from eth_account import Account
+
+from rawl.evm.tickets import BetTicket, decode_ticket, encode_ticket, sign_ticket, verify_ticket
+
+ISSUER = Account.create()
+NOW = 1_700_000_000
+
+
+def _ticket(**kw) -> BetTicket:
+    fields = {
+        "chain_id": 8453,
+        "contract": "0x00000000000000000000000000000000000000aA",
+        "match_id": "0b6f3c2e-9a41-4d2b-8f7e-1c5d9e3a7b60",
+        "side": "a",
+        "amount": 10**16,
+        "expiry": NOW + 3600,
+    }
+    fields.update(kw)
+    return BetTicket(**fields)
+
+
+class TestBetTickets:
+    def test_round_trip_keeps_signature_valid(self):
+        signed = sign_ticket(_ticket(), ISSUER.key.hex())
+        decoded = decode_ticket(encode_ticket(signed))
+        assert decoded.match_id == "0b6f3c2e9a414d2b8f7e1c5d9e3a7b60"
+        assert decoded.amount == 10**16
+        assert verify_ticket(decoded, ISSUER.address, NOW)
+
+    def test_tampered_amount_fails_verification(self):
+        signed = sign_ticket(_ticket(), ISSUER.key.hex())
+        uri = encode_ticket(signed).replace("v=10000000000000000", "v=90000000000000000")
+        assert not verify_ticket(decode_ticket(uri), ISSUER.address, NOW)
+
+    def test_expired_ticket_fails_verification(self):
+        signed = sign_ticket(_ticket(expiry=NOW), ISSUER.key.hex())
+        assert not verify_ticket(signed, ISSUER.address, NOW)
+
+    def test_malformed_uri_raises(self):
+        with pytest.raises(ValueError):
+            decode_ticket("rawl:bet?c=8453&m=xyz")
+        with pytest.raises(ValueError):
+            decode_ticket("https://example.com")
@@ -0,0 +1,47 @@
+"""Unit tests for rawl.services.activity_export — P&L and signature round-trip."""
+from __future__ import annotations
+
+import json
+
+from eth_account import Account
+
+from rawl.services.activity_export import (
+    canonical_payload,
+    realized_pnl,
+    recover_signer,
+    sign_payload,
+)
+
+
+class TestRealizedPnl:
+    def test_settled_bet_uses_settled_amount(self):
+        assert realized_pnl("claimed", 1.0, 1.94, "resolved", "a", "a") == 0.94
+        assert realized_pnl("refunded", 1.0, 1.0, "cancelled", "a", None) == 0.0
+
+    def test_unclaimed_loser_is_full_loss(self):
+        assert realized_pnl("confirmed", 1.0, None, "resolved", "b", "a") == -1.0
+
+    def test_open_position_has_no_pnl(self):
+        assert realized_pnl("confirmed", 1.0, None, "locked", "a", None) is None
+        assert realized_pnl("confirmed", 1.0, None, "resolved", "a", "a") is None
+
+
+class TestSignedPayload:
+    def test_canonical_payload_is_key_order_independent(self):
+        assert canonical_payload({"b": 1, "a": 2}) == canonical_payload({"a": 2, "b": 1})
+        assert json.loads(canonical_payload({"a": [1, 2]})) == {"a": [1, 2]}
+
+    def test_signature_round_trip(self):
+        account = Account.create()
+        payload = canonical_payload({"wallet": "0xabc", "items": []})
+        signer, signature = sign_payload(payload, account.key.hex())
+        assert signer == account.address
+        assert signature.startswith("0x")
+        assert recover_signer(payload, signature) == account.address
+
+    def test_tampered_payload_recovers_other_address(self):
+        account = Account.create()
+        payload = canonical_payload({"items": [{"amount_eth": 1.0}]})
+        _, signature = sign_payload(payload, account.key.hex())
+        tampered = canonical_payload({"items": [{"amount_eth": 9.0}]})
+        assert recover_signer(tampered, signature) != account.address
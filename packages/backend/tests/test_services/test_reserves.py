@@ -0,0 +1,41 @@
+"""Unit tests for rawl.services.reserves — liability itself comes from getSolvency on-chain."""
+from __future__ import annotations
+
+from rawl.services.reserves import (
+    STATUS_CANCELLED,
+    STATUS_LOCKED,
+    STATUS_RESOLVED,
+    open_bets,
+)
+
+ETH = 10**18
+
+
+def _pool(status: int, winner: int = 0, a: int = 1 * ETH, b: int = 2 * ETH, **kw) -> dict:
+    pool = {
+        "status": status,
+        "winner": winner,
+        "side_a_total": a,
+        "side_b_total": b,
+        "bet_count": 5,
+        "winning_bet_count": 2,
+        "fee_bps": 300,
+        "fees_withdrawn": False,
+    }
+    pool.update(kw)
+    return pool
+
+
+class TestOpenBets:
+    def test_unresolved_counts_every_unsettled_bet(self):
+        assert open_bets(_pool(STATUS_LOCKED)) == 5
+        assert open_bets(_pool(STATUS_CANCELLED)) == 5
+
+    def test_resolved_counts_only_unclaimed_winners(self):
+        assert open_bets(_pool(STATUS_RESOLVED, winner=1)) == 2
+
+    def test_loss_floor_keeps_losers_open(self):
+        assert open_bets(_pool(STATUS_RESOLVED, winner=1, loss_floor_bps=2000)) == 5
+
+    def test_no_winners_refunds_everyone(self):
+        assert open_bets(_pool(STATUS_RESOLVED, winner=2, b=0, winning_bet_count=0)) == 5
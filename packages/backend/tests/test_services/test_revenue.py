@@ -0,0 +1,54 @@
+"""Unit tests for rawl.services.revenue period bucketing."""
+from __future__ import annotations
+
+from datetime import datetime, timezone
+
+import pytest
+
+from rawl.services.revenue import aggregate_revenue, period_start
+
+ETH = 10**18
+TOKEN = "0x833589fcd6edb6e08f4c7c32d4f71b54bda02913"
+
+
+def _at(day: int, hour: int = 12) -> datetime:
+    return datetime(2026, 9, day, hour, tzinfo=timezone.utc)
+
+
+class TestPeriodStart:
+    def test_day_week_and_month(self):
+        ts = _at(17, 23)  # Thursday
+        assert period_start(ts, "day") == datetime(2026, 9, 17, tzinfo=timezone.utc)
+        assert period_start(ts, "week") == datetime(2026, 9, 14, tzinfo=timezone.utc)
+        assert period_start(ts, "month") == datetime(2026, 9, 1, tzinfo=timezone.utc)
+
+    def test_unknown_granularity(self):
+        with pytest.raises(ValueError):
+            period_start(_at(1), "year")
+
+
+class TestAggregateRevenue:
+    def test_buckets_by_kind_and_asset(self):
+        events = [
+            (_at(14), "fees", "eth", 3 * ETH),
+            (_at(16), "sweeps", "eth", ETH),
+            (_at(16), "dust", TOKEN, 500),
+            (_at(21), "fees", "eth", 2 * ETH),
+        ]
+        periods, totals = aggregate_revenue(events, "week")
+
+        assert [p["period_start"].day for p in periods] == [14, 21]
+        first = periods[0]["assets"]
+        assert [a["asset"] for a in first] == [TOKEN, "eth"]
+        assert first[1] == {
+            "asset": "eth",
+            "fees": 3 * ETH,
+            "sweeps": ETH,
+            "dust": 0,
+            "total": 4 * ETH,
+        }
+        eth_total = next(t for t in totals if t["asset"] == "eth")
+        assert eth_total["total"] == 6 * ETH
+
+    def test_empty_range(self):
+        assert aggregate_revenue([], "month") == ([], [])
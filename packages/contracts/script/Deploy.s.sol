@@ -3,6 +3,19 @@ pragma solidity ^0.8.24;
 
 import "forge-std/Script.sol";
 import "../src/RawlBetting.sol";
+import "../src/modules/MatchesModule.sol";
+import "../src/modules/SchedulingModule.sol";
+import "../src/modules/LifecycleModule.sol";
+import "../src/modules/ResolutionModule.sol";
+import "../src/modules/OraclesModule.sol";
+import "../src/modules/RandomnessModule.sol";
+import "../src/modules/DisputesModule.sol";
+import "../src/modules/PromosModule.sol";
+import "../src/modules/TreasuryModule.sol";
+import "../src/modules/AdminModule.sol";
+import "../src/modules/AccountsModule.sol";
+import "../src/modules/ViewsModule.sol";
+import "../src/SeasonFutures.sol";
 
 contract DeployScript is Script {
     function run() public {
@@ -11,10 +24,32 @@ contract DeployScript is Script {
         address treasury = vm.envAddress("TREASURY_ADDRESS");
 
         vm.startBroadcast();
-        RawlBetting betting = new RawlBetting(admin, oracle, treasury);
+        RawlBettingModule[] memory modules = new RawlBettingModule[](12);
+        modules[0] = new MatchesModule();
+        modules[1] = new SchedulingModule();
+        modules[2] = new LifecycleModule();
+        modules[3] = new ResolutionModule();
+        modules[4] = new OraclesModule();
+        modules[5] = new RandomnessModule();
+        modules[6] = new DisputesModule();
+        modules[7] = new PromosModule();
+        modules[8] = new TreasuryModule();
+        modules[9] = new AdminModule();
+        modules[10] = new AccountsModule();
+        modules[11] = new ViewsModule();
+        RawlBetting betting = new RawlBetting(admin, oracle, treasury, modules);
+        SeasonFutures futures = new SeasonFutures(admin, oracle, treasury);
+        BetPositions positions = new BetPositions(address(betting));
         vm.stopBroadcast();
 
         console.log("RawlBetting deployed to:", address(betting));
+        for (uint256 i; i < modules.length; ++i) {
+            console.log("  Module:", address(modules[i]));
+        }
+        console.log("SeasonFutures deployed to:", address(futures));
+        console.log("BetPositions deployed to:", address(positions));
+        console.log("  Enable with setBetPositions from the admin account");
+        console.log("Publish the build with setVersions(programVersion, minClientVersion)");
         console.log("  Admin:", admin);
         console.log("  Oracle:", oracle);
         console.log("  Treasury:", treasury);
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@openzeppelin/contracts/token/ERC721/ERC721.sol";
+
+/// @title BetPositions — transferable ERC-721 receipts for RawlBetting positions
+/// @notice Minted when a bet is placed via placeBetAsPosition and burned when it is redeemed.
+///         Whoever holds the token owns the bet, so positions can be sold or escrowed
+contract BetPositions is ERC721 {
+    address public immutable betting;
+    uint256 public nextTokenId = 1; // 0 is reserved as "not a position"
+
+    error OnlyBetting();
+
+    modifier onlyBetting() {
+        if (msg.sender != betting) revert OnlyBetting();
+        _;
+    }
+
+    constructor(address _betting) ERC721("Rawl Bet Position", "RAWLBET") {
+        betting = _betting;
+    }
+
+    /// @dev _mint rather than _safeMint — no receiver callback runs mid-bet
+    function mint(address to) external onlyBetting returns (uint256 tokenId) {
+        tokenId = nextTokenId++;
+        _mint(to, tokenId);
+    }
+
+    function burn(uint256 tokenId) external onlyBetting {
+        _burn(tokenId);
+    }
+}
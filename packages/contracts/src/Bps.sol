@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @dev A share in basis points, 10_000 = 100%. A distinct type so a share can't be added to a
+///      wei amount, or a wei amount passed as a share, without an explicit conversion
+type Bps is uint16;
+
+/// @dev A wei amount at the packed pool width. Pool totals and the settlement snapshot hold
+///      this rather than a bare uint128, so they only move through WeiMath's checked add/sub
+///      and can't be confused with a share or a count
+type Wei is uint128;
+
+using BpsMath for Bps global;
+using WeiMath for Wei global;
+
+/// @title BpsMath — checked construction and application of basis-point shares
+/// @notice Shares go through here, so every `amount * bps / 10_000` in settlement is the same
+///         rounding, and a share above 100% reverts instead of paying out more than the amount
+///         it is taken from
+library BpsMath {
+    uint16 internal constant ONE = 10_000;
+
+    error BpsOutOfRange(uint256 value);
+
+    /// @notice Checked conversion from a raw value — reverts above 100%
+    function toBps(uint256 value) internal pure returns (Bps) {
+        if (value > ONE) revert BpsOutOfRange(value);
+        return Bps.wrap(uint16(value));
+    }
+
+    /// @notice `amount` × share, rounded down
+    function applyTo(Bps share, uint256 amount) internal pure returns (uint256) {
+        return (amount * Bps.unwrap(share)) / ONE;
+    }
+
+    /// @notice `amount` × share for a pool-width amount, rounded down. Never above `amount`,
+    ///         so the narrowing back to Wei can't truncate
+    function applyTo(Bps share, Wei amount) internal pure returns (Wei) {
+        return Wei.wrap(uint128(applyTo(share, amount.raw())));
+    }
+
+    /// @notice 100% less the share, e.g. the part of a stake left after the fee
+    function complement(Bps share) internal pure returns (Bps) {
+        return Bps.wrap(ONE - Bps.unwrap(share));
+    }
+}
+
+/// @title WeiMath — checked arithmetic on pool-width wei amounts
+/// @notice Narrowing a uint256 into Wei reverts instead of truncating, and add/sub keep the
+///         compiler's overflow checks. Products and quotients widen to uint256 with `raw`
+library WeiMath {
+    error WeiOverflow(uint256 value);
+
+    /// @notice Checked narrowing from a uint256 amount
+    function toWei(uint256 value) internal pure returns (Wei) {
+        if (value > type(uint128).max) revert WeiOverflow(value);
+        return Wei.wrap(uint128(value));
+    }
+
+    function add(Wei a, Wei b) internal pure returns (Wei) {
+        return Wei.wrap(Wei.unwrap(a) + Wei.unwrap(b));
+    }
+
+    function sub(Wei a, Wei b) internal pure returns (Wei) {
+        return Wei.wrap(Wei.unwrap(a) - Wei.unwrap(b));
+    }
+
+    /// @notice The amount widened to uint256, for products and comparisons
+    function raw(Wei a) internal pure returns (uint256) {
+        return Wei.unwrap(a);
+    }
+
+    function isZero(Wei a) internal pure returns (bool) {
+        return Wei.unwrap(a) == 0;
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @title EventLog — fixed-size ring buffer of significant match events
+/// @notice Lets clients that can't subscribe to logs rebuild recent match activity from a
+///         single eth_call instead of an indexer
+library EventLog {
+    uint256 internal constant SIZE = 16;
+
+    enum Kind { Bet, Locked, Resolved, Cancelled, CashedOut, Donated, Unlocked }
+
+    /// @dev Each entry packs kind (8) | side (8) | timestamp (64) | amount (128) = 208 bits
+    struct Buffer {
+        bytes32[SIZE] entries;
+        uint64 count; // total ever pushed — slot of the newest entry is (count - 1) % SIZE
+    }
+
+    function push(Buffer storage buffer, Kind kind, uint8 side, uint128 amount) internal {
+        buffer.entries[buffer.count % SIZE] = encode(kind, side, uint64(block.timestamp), amount);
+        unchecked { buffer.count++; }
+    }
+
+    function encode(Kind kind, uint8 side, uint64 timestamp, uint128 amount)
+        internal
+        pure
+        returns (bytes32)
+    {
+        return bytes32(
+            (uint256(kind) << 200) | (uint256(side) << 192) | (uint256(timestamp) << 128)
+                | uint256(amount)
+        );
+    }
+
+    function decode(bytes32 entry)
+        internal
+        pure
+        returns (Kind kind, uint8 side, uint64 timestamp, uint128 amount)
+    {
+        uint256 word = uint256(entry);
+        kind = Kind(uint8(word >> 200));
+        side = uint8(word >> 192);
+        timestamp = uint64(word >> 128);
+        amount = uint128(word);
+    }
+}
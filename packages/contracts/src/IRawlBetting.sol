@@ -0,0 +1,727 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingBase.sol";
+
+/// @title IRawlBetting — every function callable on a RawlBetting deployment
+/// @notice RawlBetting's own entry points plus those its modules serve through the fallback,
+///         so integrations and tests can call the whole surface through one type. Types,
+///         errors and events are RawlBettingBase's. `sync_abi.py --check` keeps this in step
+///         with the build
+interface IRawlBetting {
+    // RawlBetting
+    function moduleOf(bytes4) external view returns (address);
+    function placeBet(bytes32 matchId, uint8 side) external payable;
+    function placeBetWithReferrer(bytes32 matchId, uint8 side, address referrer) external payable;
+    function placeBetFor(bytes32 matchId, uint8 side, address bettor) external payable;
+    function claimPayout(bytes32 matchId) external;
+    function claimPayoutTo(bytes32 matchId, address recipient) external;
+    function claimAll(bytes32[] calldata matchIds) external returns (uint256 total);
+    function rollOver(bytes32 fromMatchId, bytes32 toMatchId, uint8 side) external;
+    function refundNoWinners(bytes32 matchId) external;
+    function refundBet(bytes32 matchId) external;
+    function pause() external;
+    function unpause() external;
+    function setUnpauseGracePeriod(uint64 newGracePeriod) external;
+
+    // Constants and inherited OpenZeppelin functions
+    function ADMIN_ROLE() external view returns (bytes32);
+    function AMEND_WINDOW() external view returns (uint64);
+    function ATTESTATION_TYPEHASH() external view returns (bytes32);
+    function CLAIM_WINDOW() external view returns (uint64);
+    function CONFIG_TIMELOCK() external view returns (uint64);
+    function DEFAULT_ADMIN_ROLE() external view returns (bytes32);
+    function DEFAULT_DEAD_MAN_PERIOD() external view returns (uint64);
+    function DEFAULT_EVENT_LOG_BET_THRESHOLD() external view returns (uint128);
+    function DEFAULT_MAX_MATCH_AGE() external view returns (uint64);
+    function DEFAULT_MIN_BET() external view returns (uint128);
+    function DEFAULT_TIMEOUT() external view returns (uint64);
+    function GAME_SERVER_ROLE() external view returns (bytes32);
+    function GUARDIAN_ROLE() external view returns (bytes32);
+    function LOCK_AUTHORITY_ROLE() external view returns (bytes32);
+    function MAX_CLAIM_BATCH() external view returns (uint8);
+    function MAX_CLAIM_COOLDOWN() external view returns (uint64);
+    function MAX_COMMITTEE_SIZE() external view returns (uint8);
+    function MAX_CREATE_BATCH() external view returns (uint8);
+    function MAX_FEE_BPS() external view returns (uint16);
+    function MAX_FEE_TIERS() external view returns (uint8);
+    function MAX_LOSS_FLOOR_BPS() external view returns (uint16);
+    function MAX_ORGANIZER_EXTENSION() external view returns (uint64);
+    function MAX_RESOLUTION_THRESHOLD() external view returns (uint8);
+    function MAX_SUBSCRIBERS() external view returns (uint256);
+    function MAX_TIMEOUT() external view returns (uint64);
+    function MAX_URI_LENGTH() external view returns (uint8);
+    function MIN_DEAD_MAN_PERIOD() external view returns (uint64);
+    function MIN_SCHEDULE_INTERVAL() external view returns (uint64);
+    function MIN_TIMEOUT() external view returns (uint64);
+    function MYSTERY_MATCH_NAME() external view returns (bytes32);
+    function ORACLE_ROLE() external view returns (bytes32);
+    function POOL_SHARDS() external view returns (uint8);
+    function PRICE_PUBLISH_TOLERANCE() external view returns (uint64);
+    function RESOLVE_AUTHORITY_ROLE() external view returns (bytes32);
+    function RESULT_TYPEHASH() external view returns (bytes32);
+    function UNLOCK_WINDOW() external view returns (uint64);
+    function VOLUME_WINDOW() external view returns (uint64);
+    function VRF_CONFIRMATIONS() external view returns (uint16);
+    function eip712Domain()
+        external
+        view
+        returns (
+            bytes1 fields,
+            string memory name,
+            string memory version,
+            uint256 chainId,
+            address verifyingContract,
+            bytes32 salt,
+            uint256[] memory extensions
+        );
+    function getRoleAdmin(bytes32 role) external view returns (bytes32);
+    function grantRole(bytes32 role, address account) external;
+    function hasRole(bytes32 role, address account) external view returns (bool);
+    function paused() external view returns (bool);
+    function renounceRole(bytes32 role, address callerConfirmation) external;
+    function revokeRole(bytes32 role, address account) external;
+    function supportsInterface(bytes4 interfaceId) external view returns (bool);
+
+    // MatchesModule
+    function createMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable;
+    function createMatches(
+        RawlBettingBase.MatchSpec[] calldata specs,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable;
+    function createMatchInCategory(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        RawlBettingBase.MatchCategory category
+    ) external payable;
+    function createMatchWithSettlement(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        Settlement.Strategy strategy
+    ) external payable;
+    function createMatchWithLossFloor(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint16 lossFloorBps
+    ) external payable;
+    function createMatchWithMetadata(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        bytes32 name,
+        string calldata uri,
+        bytes32 metadataHash
+    ) external payable;
+    function createMatchWithDerivedId(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint64 startTime,
+        uint64 nonce,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable;
+    function deriveMatchId(address fighterA, address fighterB, uint64 startTime, uint64 nonce)
+        external
+        pure
+        returns (bytes32);
+    function createScheduledMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 opensAt,
+        uint64 scheduledLockAt
+    ) external payable;
+    function settleCreatorBond(bytes32 matchId) external;
+    function createCommunityMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable;
+    function vetoMatch(bytes32 matchId) external;
+    function setVetoParams(uint64 newWindow, uint128 newBetCap) external;
+    function createMatchWithAdmin(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        address matchAdmin
+    ) external payable;
+    function updateMatchMetadata(
+        bytes32 matchId,
+        bytes32 name,
+        string calldata uri,
+        bytes32 metadataHash
+    ) external;
+    function extendBettingWindow(bytes32 matchId, uint64 extension) external;
+    function requestCancellation(bytes32 matchId) external;
+    function approveCancellation(bytes32 matchId) external;
+
+    // SchedulingModule
+    function createRecurringSchedule(
+        bytes32 scheduleId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint64 interval,
+        uint64 firstAt
+    ) external payable;
+    function fundRecurringSchedule(bytes32 scheduleId) external payable;
+    function stopRecurringSchedule(bytes32 scheduleId) external;
+    function crankSchedule(bytes32 scheduleId) external returns (bytes32 matchId);
+    function initMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint128 seedA,
+        uint128 seedB
+    ) external payable;
+    function depositCreatorBond(bytes32 matchId) external payable;
+    function fundPendingSeed(bytes32 matchId) external payable;
+    function activateMatch(bytes32 matchId) external;
+    function abortPendingMatch(bytes32 matchId) external;
+
+    // LifecycleModule
+    function lockMatch(bytes32 matchId) external;
+    function lockMatchWithCommitment(bytes32 matchId, bytes32 commitment) external;
+    function lockExpired(bytes32 matchId) external;
+    function unlockMatch(bytes32 matchId) external;
+    function cancelMatch(bytes32 matchId) external;
+    function emergencyCancel(bytes32 matchId) external;
+    function cancelMatchWithOpId(bytes32 matchId, bytes32 opId) external;
+    function timeoutMatch(bytes32 matchId) external;
+    function resolutionDeadlineOf(bytes32 matchId) external view returns (uint64);
+    function setMatchResolutionDeadline(bytes32 matchId, uint64 deadline) external;
+    function cancelUnbalanced(bytes32 matchId) external;
+    function forceCancelExpired(bytes32 matchId) external;
+
+    // ResolutionModule
+    function resolveMatch(bytes32 matchId, uint8 winner) external;
+    function resolveMatchWithOpId(bytes32 matchId, uint8 winner, bytes32 opId) external;
+    function resolveMatchWithResultHash(bytes32 matchId, uint8 winner, bytes32 resultHash) external;
+    function resolveMatchSigned(
+        bytes32 matchId,
+        uint8 winner,
+        uint64 timestamp,
+        bytes calldata signature
+    ) external;
+    function setResolutionThreshold(uint8 threshold) external;
+    function resultVotesOf(bytes32 matchId) external view returns (uint8 sideA, uint8 sideB);
+    function cosignResolution(bytes32 matchId, uint8 winner) external;
+    function setDualSignPoolSize(uint128 newSize) external;
+    function forfeitMatch(bytes32 matchId, uint8 winner) external;
+    function forfeitNoContest(bytes32 matchId) external;
+    function setMatchOracle(bytes32 matchId, address replaced, address oracle) external;
+    function createCorrelatedMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        bytes32 groupId
+    ) external payable;
+    function resolveCorrelated(
+        bytes32 groupId,
+        bytes32[] calldata matchIds,
+        uint8[] calldata winners,
+        bytes32 resultHash
+    ) external;
+    function clearCorrelationFlag(bytes32 matchId) external;
+
+    // OraclesModule
+    function bindResultFeed(bytes32 matchId, bytes32 feedId) external;
+    function resolveFromFeed(bytes32 matchId) external;
+    function setSwitchboard(address newSwitchboard) external;
+    function createPriceMarket(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 lockAt,
+        RawlBettingBase.PriceMarket calldata market
+    ) external payable;
+    function resolvePriceMarket(bytes32 matchId, bytes[] calldata priceUpdate) external payable;
+    function setPyth(address newPyth) external;
+    function revealResult(bytes32 matchId, uint8 winner, bytes32 salt) external;
+    function resultCommitment(bytes32 matchId, uint8 winner, bytes32 salt)
+        external
+        pure
+        returns (bytes32);
+    function setCommitRevealRequired(bool required) external;
+    function oracleHeartbeat() external;
+    function oracleSilent() external view returns (bool);
+    function currentMatchTimeout() external view returns (uint64);
+    function setOracleLiveness(uint64 silencePeriod, uint64 silentTimeout) external;
+    function attestRound(
+        bytes32 matchId,
+        uint16 round,
+        bytes32 stateHash,
+        uint8 leader,
+        bool finished,
+        bytes calldata signature
+    ) external;
+    function clearAttestation(bytes32 matchId) external;
+
+    // RandomnessModule
+    function requestMysteryMatch(bytes32 matchId, uint128 minBet, uint64 bettingWindow)
+        external
+        payable
+        returns (uint256 requestId);
+    function rawFulfillRandomWords(uint256 requestId, uint256[] calldata randomWords) external;
+    function createMysteryMatch(RawlBettingBase.MysteryRequest calldata request, uint256 word)
+        external;
+    function selectMysteryFighters(uint256 word)
+        external
+        view
+        returns (address fighterA, address fighterB);
+    function setVrfConfig(
+        address coordinator,
+        bytes32 keyHash,
+        uint256 subscriptionId,
+        uint32 callbackGasLimit
+    ) external;
+    function createChaosMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable;
+    function requestChaosResult(bytes32 matchId) external returns (uint256 requestId);
+    function chaosWinner(uint256 word) external pure returns (uint8);
+
+    // DisputesModule
+    function amendResolution(bytes32 matchId, uint8 newWinner) external;
+    function overturnResult(bytes32 matchId, uint8 newWinner) external payable;
+    function recordClawback(bytes32 matchId, address bettor) external;
+    function repayClawback(bytes32 matchId) external payable;
+    function challengeResult(bytes32 matchId) external payable;
+    function adjudicateDispute(bytes32 matchId, bool upheld) external;
+    function proposeResult(bytes32 matchId, uint8 winner) external payable;
+    function challengeProposal(bytes32 matchId) external payable;
+    function finalizeProposal(bytes32 matchId) external;
+    function arbitrateProposal(bytes32 matchId, uint8 winner) external;
+    function refundProposalBonds(bytes32 matchId) external;
+    function setDisputeParams(uint64 newWindow, uint128 newBond) external;
+    function setCommittee(address[] calldata members) external;
+    function committeeMembers() external view returns (address[] memory);
+    function castArbitrationVote(bytes32 matchId, uint8 winner) external;
+
+    // PromosModule
+    function fundPromo() external payable;
+    function withdrawPromo(uint256 amount) external;
+    function issueVoucher(bytes32 voucherId, address redeemer, uint128 amount, uint64 expiresAt)
+        external;
+    function placeBetWithVoucher(bytes32 matchId, uint8 side, bytes32 voucherId) external;
+    function reclaimVoucher(bytes32 voucherId) external;
+    function placeBetOrEscrow(bytes32 matchId, uint8 side) external payable;
+    function refundPendingBet(bytes32 matchId, address bettor) external;
+    function sponsorMatch(bytes32 matchId, uint128 capPerBet, uint16 matchBps) external payable;
+    function withdrawSponsorship(bytes32 matchId) external;
+    function setSeeder(address account, bool approved) external;
+    function seedMatch(bytes32 matchId, uint128 sideA, uint128 sideB) external payable;
+    function redeemSeed(bytes32 matchId) external returns (uint256 amount);
+    function setFirstBetInsuranceCap(uint128 newCap) external;
+    function claimFirstBetRefund() external;
+    function releaseFirstBetInsurance(address bettor) external;
+
+    // TreasuryModule
+    function cashOut(bytes32 matchId) external;
+    function fundCashOutReserve() external payable;
+    function withdrawCashOutReserve(uint256 amount) external;
+    function settleCashOutDesk(bytes32 matchId, uint8 side) external;
+    function withdrawCashOutDust() external;
+    function withdrawFees(bytes32 matchId) external;
+    function withdrawFeesWithOpId(bytes32 matchId, bytes32 opId) external;
+    function sweepUnclaimed(bytes32 matchId, address bettor) external;
+    function sweepCancelled(bytes32 matchId, address bettor) external;
+    function previewSweep(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint256 amount, address recipient);
+    function registerReferrer() external;
+    function claimReferralRewards(bytes32 matchId) external;
+    function setReferralShareBps(uint16 newShareBps) external;
+    function setFeeTiers(uint128[] calldata thresholds, uint16[] calldata discountsBps) external;
+    function rollingVolume(address bettor) external view returns (uint256);
+    function feeTierOf(address bettor) external view returns (uint8);
+    function getSolvency(bytes32 matchId)
+        external
+        view
+        returns (
+            uint256 vaultBalance,
+            uint256 outstandingLiability,
+            uint256 feeReserved,
+            int256 surplus
+        );
+    function proposeDonation(address recipient, uint16 bps) external;
+    function cancelDonationProposal() external;
+    function applyDonation() external;
+    function approveLargeDebit(bytes32 matchId, address account) external;
+    function setSpendLimit(uint16 newLimitBps) external;
+
+    // AdminModule
+    function updateConfig(uint16 newFeeBps, uint64 newTimeout, address newTreasury) external;
+    function timeoutBounds() external pure returns (uint64 min, uint64 max);
+    function setEventLogBetThreshold(uint128 newThreshold) external;
+    function setParticipationMinimums(uint32 newMinBetsPerSide, uint128 newMinTotalPool) external;
+    function setAntiSnipe(
+        uint16 newThresholdBps,
+        uint64 newWindow,
+        uint64 newExtension,
+        uint64 newMaxExtension
+    ) external;
+    function setCashOutDiscountBps(uint16 newDiscountBps) external;
+    function setCashOutMatchCap(uint128 newCap) external;
+    function setMaxOpenMatchesPerCreator(uint32 newMax) external;
+    function setCreatorBond(uint128 newBond) external;
+    function setMaxMatchAge(uint64 newMaxMatchAge) external;
+    function setClaimCooldown(uint64 newCooldown) external;
+    function setMinResolutionDelay(uint64 newDelay) external;
+    function setResolutionDeadline(uint64 newDeadline) external;
+    function setTreasuryHook(address newHook, uint16 newShareBps) external;
+    function setScreeningOracle(address newOracle) external;
+    function setTreasuryRouting(address fees, address sweeps, address dust) external;
+    function setVersions(uint32 newProgramVersion, uint32 newMinClientVersion) external;
+    function setMaxPoolSize(uint128 newMaxPoolSize) external;
+    function registerFighter(address fighter, bytes32 nameHash, address owner) external;
+    function linkFighterToken(address fighter, uint256 tokenId) external;
+    function fighterCount() external view returns (uint256);
+    function setFighterCollection(address newCollection) external;
+    function claimFighterRoyalty(bytes32 matchId) external;
+    function setRoyaltyShareBps(uint16 newShareBps) external;
+    function pingAuthority() external;
+    function setSuccessor(address newSuccessor, uint64 period) external;
+    function claimAuthority() external;
+
+    // AccountsModule
+    function getEventLog(bytes32 matchId) external view returns (EventLog.Buffer memory);
+    function placeBetAsPosition(bytes32 matchId, uint8 side)
+        external
+        payable
+        returns (uint256 tokenId);
+    function redeemPosition(uint256 tokenId) external;
+    function setBetPositions(address positions) external;
+    function setClaimDelegate(address delegate) external;
+    function claimPayoutFor(bytes32 matchId, address bettor) external;
+    function refundBetFor(bytes32 matchId, address bettor) external;
+    function refundNoWinnersFor(bytes32 matchId, address bettor) external;
+    function subscribe(bytes32 matchId) external;
+    function unsubscribe(bytes32 matchId) external;
+    function subscribersOf(bytes32 matchId) external view returns (address[] memory);
+    function pendingSummaries(bytes32 matchId) external view returns (uint256);
+    function pushSummaries(bytes32 matchId, uint256 max) external returns (uint256 pushed);
+
+    // ViewsModule
+    function treasury() external view returns (address);
+    function treasuryRouting() external view returns (address fees, address sweeps, address dust);
+    function feeBps() external view returns (uint16);
+    function matchTimeout() external view returns (uint64);
+    function resolutionDeadline() external view returns (uint64);
+    function claimWindow() external view returns (uint64);
+    function claimCooldown() external view returns (uint64);
+    function minResolutionDelay() external view returns (uint64);
+    function commitRevealRequired() external view returns (bool);
+    function referralShareBps() external view returns (uint16);
+    function royaltyShareBps() external view returns (uint16);
+    function maxPoolSize() external view returns (uint128);
+    function fighterCollection() external view returns (address);
+    function unpauseGracePeriod() external view returns (uint64);
+    function bettingResumesAt() external view returns (uint64);
+    function maxMatchAge() external view returns (uint64);
+    function eventLogBetThreshold() external view returns (uint128);
+    function minBetsPerSide() external view returns (uint32);
+    function minTotalPool() external view returns (uint128);
+    function snipeThresholdBps() external view returns (uint16);
+    function snipeWindow() external view returns (uint64);
+    function snipeExtension() external view returns (uint64);
+    function maxSnipeExtension() external view returns (uint64);
+    function cashOutDiscountBps() external view returns (uint16);
+    function cashOutDust() external view returns (uint256);
+    function cashOutReserve() external view returns (uint256);
+    function cashOutMatchCap() external view returns (uint128);
+    function cashOutSpent(bytes32 matchId) external view returns (uint128);
+    function screeningOracle() external view returns (address);
+    function treasuryHook() external view returns (address);
+    function treasuryHookBps() external view returns (uint16);
+    function authority() external view returns (address);
+    function successor() external view returns (address);
+    function lastAuthorityPing() external view returns (uint64);
+    function deadManPeriod() external view returns (uint64);
+    function oracleLastSeen() external view returns (uint64);
+    function oracleSilencePeriod() external view returns (uint64);
+    function silentOracleTimeout() external view returns (uint64);
+    function resolutionThreshold() external view returns (uint8);
+    function donationAddress() external view returns (address);
+    function donationBps() external view returns (uint16);
+    function pendingDonation() external view returns (address recipient, uint16 bps, uint64 eta);
+    function metrics()
+        external
+        view
+        returns (uint64 betsPlaced, uint64 claims, uint64 refunds, uint64 sweeps);
+    function totalDonated() external view returns (uint256);
+    function switchboard() external view returns (address);
+    function pyth() external view returns (address);
+    function disputeWindow() external view returns (uint64);
+    function disputeBond() external view returns (uint128);
+    function vrfCoordinator() external view returns (address);
+    function vrfKeyHash() external view returns (bytes32);
+    function vrfSubscriptionId() external view returns (uint256);
+    function vrfCallbackGasLimit() external view returns (uint32);
+    function vetoWindow() external view returns (uint64);
+    function vetoBetCap() external view returns (uint128);
+    function spendLimitBps() external view returns (uint16);
+    function dualSignPoolSize() external view returns (uint128);
+    function executedOps(bytes32 opId) external view returns (bool);
+    function matches(bytes32 matchId)
+        external
+        view
+        returns (
+            address fighterA,
+            address fighterB,
+            RawlBettingBase.MatchStatus status,
+            RawlBettingBase.MatchWinner winner,
+            uint32 sideABetCount,
+            uint32 sideBBetCount,
+            uint32 winningBetCount,
+            uint32 betCount,
+            uint16 poolFeeBps,
+            uint128 sideATotal,
+            uint128 sideBTotal,
+            uint64 createdAt,
+            uint64 lockTimestamp,
+            uint64 resolveTimestamp,
+            uint64 cancelTimestamp,
+            uint128 minBet,
+            uint64 bettingWindow,
+            bool feesWithdrawn
+        );
+    function poolShards(bytes32 matchId, uint256 shard)
+        external
+        view
+        returns (uint128 sideATotal, uint128 sideBTotal);
+    function bets(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint128 amount, uint8 side, bool claimed);
+    function betOdds(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint128 sideATotal, uint128 sideBTotal);
+    function schedules(bytes32 matchId)
+        external
+        view
+        returns (uint64 opensAt, uint64 scheduledLockAt);
+    function snipeExtensions(bytes32 matchId) external view returns (uint64);
+    function cancelReasons(bytes32 matchId) external view returns (RawlBettingBase.CancelReason);
+    function resultKinds(bytes32 matchId) external view returns (RawlBettingBase.ResultKind);
+    function fighters(address fighter)
+        external
+        view
+        returns (
+            bytes32 nameHash,
+            address owner,
+            uint32 wins,
+            uint32 losses,
+            uint32 draws,
+            uint128 totalVolume
+        );
+    function fighterList(uint256 index) external view returns (address);
+    function fighterTokens(address fighter) external view returns (uint256 tokenId, bool linked);
+    function settlementStrategies(bytes32 matchId) external view returns (Settlement.Strategy);
+    function lossFloors(bytes32 matchId) external view returns (uint16);
+    function matchMetadata(bytes32 matchId)
+        external
+        view
+        returns (bytes32 name, string memory uri, bytes32 metadataHash, bytes32 resultHash);
+    function matchCategories(bytes32 matchId) external view returns (RawlBettingBase.MatchCategory);
+    function oddsSnapshots(bytes32 matchId)
+        external
+        view
+        returns (
+            uint128 sideATotal,
+            uint128 sideBTotal,
+            uint16 impliedABps,
+            uint16 impliedBBps,
+            uint64 lockedAt
+        );
+    function recurringSchedules(bytes32 matchId)
+        external
+        view
+        returns (
+            address creator,
+            uint64 interval,
+            uint32 sequence,
+            address fighterA,
+            uint64 nextAt,
+            bool active,
+            address fighterB,
+            uint64 bettingWindow,
+            uint128 minBet,
+            uint128 bondBudget
+        );
+    function pendingFundings(bytes32 matchId)
+        external
+        view
+        returns (
+            uint128 requiredBond,
+            uint128 seedA,
+            uint128 seedB,
+            address seeder,
+            bool seedFunded
+        );
+    function donatedFees(bytes32 matchId) external view returns (uint256);
+    function summaryCursor(bytes32 matchId) external view returns (uint256);
+    function resultAttested(bytes32 matchId, address oracle) external view returns (uint8);
+    function resultFeeds(bytes32 matchId) external view returns (bytes32);
+    function priceMarkets(bytes32 matchId)
+        external
+        view
+        returns (bytes32 priceId, int64 strike, int32 expo, uint64 resolveAt);
+    function disputeDeadlines(bytes32 matchId) external view returns (uint64);
+    function disputes(bytes32 matchId)
+        external
+        view
+        returns (address challenger, uint64 raisedAt, bool adjudicated, uint128 bond);
+    function resultProposals(bytes32 matchId)
+        external
+        view
+        returns (
+            address proposer,
+            uint64 liveUntil,
+            uint8 winner,
+            address challenger,
+            bool settled,
+            uint128 bond
+        );
+    function mysteryRequests(uint256 requestId)
+        external
+        view
+        returns (
+            bytes32 matchId,
+            address creator,
+            uint64 bettingWindow,
+            uint128 minBet,
+            uint128 bond
+        );
+    function mysteryReserved(bytes32 matchId) external view returns (bool);
+    function mysterySeeds(bytes32 matchId) external view returns (uint256);
+    function chaosMatches(bytes32 matchId) external view returns (bool);
+    function chaosRequests(uint256 requestId) external view returns (bytes32);
+    function chaosRequestIds(bytes32 matchId) external view returns (uint256);
+    function chaosSeeds(bytes32 matchId) external view returns (uint256);
+    function vetoDeadlines(bytes32 matchId) external view returns (uint64);
+    function largeDebitApprovals(bytes32 matchId, address account) external view returns (bool);
+    function oracleResults(bytes32 matchId) external view returns (uint8);
+    function authorityCosigns(bytes32 matchId) external view returns (uint8);
+    function resultCommitments(bytes32 matchId) external view returns (bytes32);
+    function revealedResults(bytes32 matchId) external view returns (uint8);
+    function matchAdmins(bytes32 matchId) external view returns (address);
+    function organizerExtensions(bytes32 matchId) external view returns (uint64);
+    function cancellationRequests(bytes32 matchId) external view returns (bool);
+    function matchOracles(bytes32 matchId, address replaced) external view returns (address);
+    function correlatedGroups(bytes32 matchId) external view returns (bytes32);
+    function groupResultHashes(bytes32 groupId) external view returns (bytes32);
+    function correlationFlags(bytes32 matchId) external view returns (bool);
+    function committeeEpoch() external view returns (uint32);
+    function committeeSeats(address member) external view returns (uint32);
+    function arbitrationTallies(bytes32 matchId)
+        external
+        view
+        returns (uint32 epoch, uint8 sideAVotes, uint8 sideBVotes);
+    function arbitrationVotes(bytes32 matchId, address member) external view returns (uint32);
+    function attestations(bytes32 matchId)
+        external
+        view
+        returns (
+            bytes32 stateHash,
+            address server,
+            uint64 timestamp,
+            uint16 round,
+            uint8 leader,
+            bool finished
+        );
+    function resolutionDeadlines(bytes32 matchId) external view returns (uint64);
+    function royaltyPools(bytes32 matchId)
+        external
+        view
+        returns (uint16 shareBps, bool fighterAClaimed, bool fighterBClaimed);
+    function referrers(address referrer) external view returns (bool);
+    function referralPools(bytes32 matchId)
+        external
+        view
+        returns (uint128 referredVolume, uint16 shareBps);
+    function referralAccruals(bytes32 matchId, address referrer)
+        external
+        view
+        returns (uint128 volume, bool claimed);
+    function betReferrers(bytes32 matchId, address bettor) external view returns (address);
+    function claimDelegates(address bettor) external view returns (address);
+    function maxOpenMatchesPerCreator() external view returns (uint32);
+    function openMatchCounts(address creator) external view returns (uint32);
+    function matchCreators(bytes32 matchId) external view returns (address);
+    function creatorBond() external view returns (uint128);
+    function creatorBonds(bytes32 matchId) external view returns (uint128);
+    function settledStakes(bytes32 matchId, uint256 side) external view returns (uint128);
+    function overturnedWinners(bytes32 matchId) external view returns (RawlBettingBase.MatchWinner);
+    function clawbacks(bytes32 matchId, address account) external view returns (uint256);
+    function clawbackRecorded(bytes32 matchId, address account) external view returns (bool);
+    function clawbackDebt(address account) external view returns (uint256);
+    function firstBetInsuranceCap() external view returns (uint128);
+    function hasBet(address bettor) external view returns (bool);
+    function firstBets(address bettor)
+        external
+        view
+        returns (bytes32 matchId, uint128 insured, bool refunded);
+    function feeTierThresholds(uint256 tier) external view returns (uint128);
+    function feeTierDiscountBps(uint256 tier) external view returns (uint16);
+    function bettorProfiles(address bettor)
+        external
+        view
+        returns (uint128 currentVolume, uint128 previousVolume, uint64 windowStart, uint8 feeTier);
+    function feeDiscounts(bytes32 matchId) external view returns (uint256);
+    function programVersion() external view returns (uint32);
+    function minClientVersion() external view returns (uint32);
+    function promoBudget() external view returns (uint256);
+    function vouchers(bytes32 voucherId)
+        external
+        view
+        returns (address redeemer, uint64 expiresAt, bool redeemed, uint128 amount);
+    function voucherStakes(bytes32 matchId, address bettor) external view returns (uint128);
+    function sponsorships(bytes32 matchId)
+        external
+        view
+        returns (address sponsor, uint16 matchBps, uint128 budget, uint128 capPerBet);
+    function sponsorMatched(bytes32 matchId, address bettor) external view returns (uint128);
+    function approvedSeeders(address seeder) external view returns (bool);
+    function seedOwners(address seedKey) external view returns (address);
+    function pendingBets(bytes32 matchId, address bettor) external view returns (uint256);
+    function betPositions() external view returns (BetPositions);
+    function positionMatches(uint256 tokenId) external view returns (bytes32);
+    function positionTokenIds(address positionKey) external view returns (uint256);
+}
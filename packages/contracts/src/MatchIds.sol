@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @title MatchIds — canonical match id derivation
+/// @notice matchId = keccak256(abi.encode(fighterA, fighterB, startTime, nonce)). Off-chain
+///         callers derive the same value (rawl.evm.client.derive_match_id), so an id can be
+///         checked against the fight it claims to be before anyone bets on it
+library MatchIds {
+    function derive(address fighterA, address fighterB, uint64 startTime, uint64 nonce)
+        internal
+        pure
+        returns (bytes32)
+    {
+        return keccak256(abi.encode(fighterA, fighterB, startTime, nonce));
+    }
+}
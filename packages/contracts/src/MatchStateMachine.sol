@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {RawlBettingBase} from "./RawlBettingBase.sol";
+
+/// @title MatchStateMachine — the one table of legal match status transitions
+/// @notice Every RawlBetting entry point that moves a match checks canTransition first.
+///         An entry point may narrow a row further (timeoutMatch only fires from Locked,
+///         activateMatch only from Pending) but never allows a move the table refuses
+library MatchStateMachine {
+    /// @dev Who is driving the transition. Creator is the match's oracle side, Anyone a
+    ///      permissionless crank (lockExpired, timeoutMatch, forceCancelExpired, ...), Forfeit
+    ///      the resolver calling a match that never locked (forfeitMatch, forfeitNoContest)
+    enum Actor { Anyone, Creator, LockAuthority, ResolveAuthority, Admin, Forfeit }
+
+    /// @dev None → Open | Pending            Creator
+    ///      Pending → Open | Cancelled       Creator
+    ///      Open → Locked                    LockAuthority, Anyone
+    ///      Locked → Open                    LockAuthority (unlockMatch)
+    ///      Open | Locked → Cancelled        Admin, Anyone
+    ///      Open → Resolved | Cancelled      Forfeit
+    ///      Locked → Resolved                ResolveAuthority
+    ///      Resolved, Cancelled              final
+    function canTransition(
+        RawlBettingBase.MatchStatus from,
+        RawlBettingBase.MatchStatus to,
+        Actor actor
+    ) internal pure returns (bool) {
+        if (
+            from == RawlBettingBase.MatchStatus.None
+                || from == RawlBettingBase.MatchStatus.Pending
+        ) {
+            bool target = to == RawlBettingBase.MatchStatus.Open
+                || (from == RawlBettingBase.MatchStatus.None
+                    ? to == RawlBettingBase.MatchStatus.Pending
+                    : to == RawlBettingBase.MatchStatus.Cancelled);
+            return target && actor == Actor.Creator;
+        }
+        if (from == RawlBettingBase.MatchStatus.Open) {
+            if (to == RawlBettingBase.MatchStatus.Locked) {
+                return actor == Actor.LockAuthority || actor == Actor.Anyone;
+            }
+            if (to == RawlBettingBase.MatchStatus.Resolved) return actor == Actor.Forfeit;
+            return to == RawlBettingBase.MatchStatus.Cancelled
+                && (actor == Actor.Admin || actor == Actor.Anyone || actor == Actor.Forfeit);
+        }
+        if (from == RawlBettingBase.MatchStatus.Locked) {
+            if (to == RawlBettingBase.MatchStatus.Resolved) return actor == Actor.ResolveAuthority;
+            if (to == RawlBettingBase.MatchStatus.Open) return actor == Actor.LockAuthority;
+            return to == RawlBettingBase.MatchStatus.Cancelled
+                && (actor == Actor.Admin || actor == Actor.Anyone);
+        }
+        return false;
+    }
+}
@@ -0,0 +1,2159 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@openzeppelin/contracts/access/AccessControl.sol";
+import "@openzeppelin/contracts/token/ERC721/IERC721.sol";
+import "@openzeppelin/contracts/utils/cryptography/ECDSA.sol";
+import "@openzeppelin/contracts/utils/cryptography/EIP712.sol";
+import "@openzeppelin/contracts/utils/ReentrancyGuard.sol";
+import "@openzeppelin/contracts/utils/Pausable.sol";
+import "@openzeppelin/contracts/utils/math/SafeCast.sol";
+import "./BetPositions.sol";
+import "./Bps.sol";
+import "./EventLog.sol";
+import "./MatchIds.sol";
+import "./MatchStateMachine.sol";
+import "./Settlement.sol";
+
+/// @notice Sanctions list lookup, e.g. the Chainalysis on-chain oracle
+interface IScreeningOracle {
+    function isSanctioned(address addr) external view returns (bool);
+}
+
+/// @notice Receives a slice of every fee withdrawal, e.g. to swap it into stablecoins
+interface ITreasuryHook {
+    function onFeesWithdrawn(bytes32 matchId) external payable;
+}
+
+/// @notice Switchboard On-Demand aggregator — a feed's latest verified value
+interface ISwitchboard {
+    struct Update {
+        bytes32 oracleId;
+        int128 result;
+        uint256 timestamp;
+    }
+
+    function latestUpdate(bytes32 aggregatorId) external view returns (Update memory);
+}
+
+/// @notice Pyth pull oracle — verifies signed price updates fetched from Hermes
+interface IPyth {
+    struct Price {
+        int64 price;
+        uint64 conf;
+        int32 expo;
+        uint256 publishTime;
+    }
+
+    struct PriceFeed {
+        bytes32 id;
+        Price price;
+        Price emaPrice;
+    }
+
+    function getUpdateFee(bytes[] calldata updateData) external view returns (uint256);
+
+    function parsePriceFeedUpdates(
+        bytes[] calldata updateData,
+        bytes32[] calldata priceIds,
+        uint64 minPublishTime,
+        uint64 maxPublishTime
+    ) external payable returns (PriceFeed[] memory);
+}
+
+/// @notice Chainlink VRF v2.5 coordinator — the request struct mirrors VRFV2PlusClient's
+interface IVRFCoordinatorV2Plus {
+    struct RandomWordsRequest {
+        bytes32 keyHash;
+        uint256 subId;
+        uint16 requestConfirmations;
+        uint32 callbackGasLimit;
+        uint32 numWords;
+        bytes extraArgs;
+    }
+
+    function requestRandomWords(RandomWordsRequest calldata req)
+        external
+        returns (uint256 requestId);
+}
+
+/// @title RawlBettingBase — storage, types and shared internals of RawlBetting
+/// @notice RawlBetting and each of its modules inherit this, so every piece of the contract
+///         agrees on one storage layout. State is internal: ViewsModule carries the getters
+abstract contract RawlBettingBase is AccessControl, ReentrancyGuard, Pausable, EIP712 {
+    using EventLog for EventLog.Buffer;
+    using SafeCast for uint256;
+
+    // ──────────────────────────────────────────────
+    // Roles
+    // ──────────────────────────────────────────────
+    bytes32 public constant ORACLE_ROLE = keccak256("ORACLE_ROLE");
+    // Lock and resolve can be held by different accounts, e.g. the game server locks while a
+    // separate adjudicator resolves. Both start with the oracle; rotate with grantRole/revokeRole
+    bytes32 public constant LOCK_AUTHORITY_ROLE = keccak256("LOCK_AUTHORITY_ROLE");
+    bytes32 public constant RESOLVE_AUTHORITY_ROLE = keccak256("RESOLVE_AUTHORITY_ROLE");
+    bytes32 public constant ADMIN_ROLE = keccak256("ADMIN_ROLE");
+    // Vetoes community-created matches and co-signs debits above spendLimitBps; starts with
+    // the admin
+    bytes32 public constant GUARDIAN_ROLE = keccak256("GUARDIAN_ROLE");
+    bytes32 public constant RESULT_TYPEHASH =
+        keccak256("ResolutionResult(bytes32 matchId,uint8 winner,uint64 timestamp)");
+    // Registered game servers sign round-by-round state while a match runs, see attestRound.
+    // Granted by the admin per server key; nobody holds it at deploy
+    bytes32 public constant GAME_SERVER_ROLE = keccak256("GAME_SERVER_ROLE");
+    bytes32 public constant ATTESTATION_TYPEHASH = keccak256(
+        "RoundAttestation(bytes32 matchId,uint16 round,bytes32 stateHash,uint8 leader,"
+        "bool finished)"
+    );
+
+    // ──────────────────────────────────────────────
+    // Constants
+    // ──────────────────────────────────────────────
+    uint16 public constant MAX_FEE_BPS = 1000; // 10% hard ceiling
+    uint64 public constant DEFAULT_TIMEOUT = 1800; // 30 min
+    uint64 public constant MIN_TIMEOUT = 5 minutes; // oracle needs time to resolve
+    uint64 public constant MAX_TIMEOUT = 7 days; // bounds how long a stuck match holds funds
+    uint64 public constant CLAIM_WINDOW = 30 days; // 2,592,000 seconds
+    uint128 public constant DEFAULT_MIN_BET = 0.001 ether; // 1e15 wei
+    uint64 public constant DEFAULT_MAX_MATCH_AGE = 7 days;
+    uint64 public constant MIN_SCHEDULE_INTERVAL = 5 minutes;
+    uint64 public constant VOLUME_WINDOW = 30 days; // rolling window for fee tiers
+    uint64 public constant DEFAULT_DEAD_MAN_PERIOD = 90 days;
+    uint128 public constant DEFAULT_EVENT_LOG_BET_THRESHOLD = 1 ether; // keeps small bets out
+    uint8 public constant MAX_RESOLUTION_THRESHOLD = 15;
+    uint256 public constant MAX_SUBSCRIBERS = 256; // bounds the keeper's push work per match
+    uint8 public constant MAX_COMMITTEE_SIZE = 15;
+    uint64 public constant CONFIG_TIMELOCK = 2 days; // delay on timelocked config changes
+    uint64 public constant MIN_DEAD_MAN_PERIOD = 30 days; // no successor takeover on a short trip
+    uint8 public constant MAX_FEE_TIERS = 5;
+    uint8 public constant MAX_CLAIM_BATCH = 20; // keeps claimAll well under the block gas limit
+    uint8 public constant MAX_CREATE_BATCH = 32; // an event night's card in one transaction
+    uint16 public constant MAX_LOSS_FLOOR_BPS = 5000; // keeps rebates below the net pool at any fee
+    uint8 public constant MAX_URI_LENGTH = 128;
+    uint64 public constant PRICE_PUBLISH_TOLERANCE = 60; // seconds after resolveAt a price may land
+    uint16 public constant VRF_CONFIRMATIONS = 3;
+    bytes32 public constant MYSTERY_MATCH_NAME = "Mystery fight";
+    uint64 public constant AMEND_WINDOW = 10 minutes; // fat-finger grace after resolution
+    uint64 public constant UNLOCK_WINDOW = 5 minutes; // grace to undo a mistaken lock
+    uint64 public constant MAX_ORGANIZER_EXTENSION = 2 hours; // per match, on top of snipes
+    uint64 public constant MAX_CLAIM_COOLDOWN = 3 days; // well inside CLAIM_WINDOW
+    uint8 public constant POOL_SHARDS = 8; // slots an Open match's bets spread their stake over
+
+    // ──────────────────────────────────────────────
+    // Enums
+    // ──────────────────────────────────────────────
+    /// @dev Append only — Pending sits last so the original status codes stay stable
+    enum MatchStatus { None, Open, Locked, Resolved, Cancelled, Pending }
+    enum MatchWinner { None, SideA, SideB }
+    /// @dev Why a match was cancelled — append only, analytics keys off the numeric code
+    enum CancelReason {
+        None,
+        OperatorCancel,
+        Timeout,
+        EmptySide,
+        Expired,
+        Emergency,
+        Undersubscribed,
+        Vetoed,
+        NoShow,
+        OrganizerRequest
+    }
+
+    /// @dev How a resolved match was decided — append only
+    enum ResultKind { Decision, Walkover }
+
+    /// @dev Match category for off-chain policy (indexer, keeper) — append only
+    enum MatchCategory { Ranked, Challenge, Exhibition, Tournament, Community, PricePrediction }
+
+    /// @dev Stable numeric codes for ConfigUpdated — append only, never reorder
+    enum ConfigField {
+        FeeBps,
+        MatchTimeout,
+        Treasury,
+        ReferralShareBps,
+        MaxPoolSize,
+        RoyaltyShareBps,
+        FighterCollection,
+        UnpauseGracePeriod,
+        MaxMatchAge,
+        EventLogBetThreshold,
+        MinBetsPerSide,
+        MinTotalPool,
+        SnipeThresholdBps,
+        SnipeWindow,
+        SnipeExtension,
+        MaxSnipeExtension,
+        CashOutDiscountBps,
+        BetPositions,
+        ScreeningOracle,
+        ProgramVersion,
+        MinClientVersion,
+        MaxOpenMatchesPerCreator,
+        FirstBetInsuranceCap,
+        FeeTiers,
+        CreatorBond,
+        TreasuryHook,
+        ResolutionThreshold,
+        Donation,
+        Switchboard,
+        FeeTreasury,
+        SweepTreasury,
+        DustTreasury,
+        Pyth,
+        DisputeWindow,
+        DisputeBond,
+        VrfCoordinator,
+        VetoWindow,
+        VetoBetCap,
+        SpendLimit,
+        DualSignPoolSize,
+        ClaimCooldown,
+        MinResolutionDelay,
+        CommitRevealRequired,
+        OracleSilencePeriod,
+        SilentOracleTimeout,
+        ResolutionDeadline,
+        CashOutMatchCap
+    }
+
+    // ──────────────────────────────────────────────
+    // Structs (gas-optimized packed storage)
+    // ──────────────────────────────────────────────
+    struct MatchPool {
+        // Slot 1
+        address fighterA;
+        // Slot 2
+        address fighterB;
+        // Slot 3 (packed: 1+1+4+4+4+4+2 = 20 bytes)
+        MatchStatus status;
+        MatchWinner winner;
+        uint32 sideABetCount;
+        uint32 sideBBetCount;
+        uint32 winningBetCount;
+        uint32 betCount;
+        uint16 feeBps;
+        // Slot 4 (packed: 16+16 = 32 bytes)
+        Wei sideATotal;
+        Wei sideBTotal;
+        // Slot 5 (packed: 8+8+8+8 = 32 bytes)
+        uint64 createdAt;
+        uint64 lockTimestamp;
+        uint64 resolveTimestamp;
+        uint64 cancelTimestamp;
+        // Slot 6 (packed: 16+8+1 = 25 bytes)
+        uint128 minBet;
+        uint64 bettingWindow;
+        bool feesWithdrawn;
+    }
+
+    struct BetInfo {
+        // Single slot (16+1+1 = 18 bytes)
+        uint128 amount;
+        uint8 side; // 0=SideA, 1=SideB
+        bool claimed;
+    }
+
+    struct BetOdds {
+        // Single slot (16+16 = 32 bytes)
+        uint128 sideATotal; // pool totals just before the bet landed
+        uint128 sideBTotal;
+    }
+
+    /// @dev Stake bets have added to an Open match but not yet folded into its MatchPool
+    struct PoolShard {
+        // Single slot (16+16 = 32 bytes)
+        Wei sideATotal;
+        Wei sideBTotal;
+    }
+
+    /// @dev Final odds frozen at lock — an immutable on-chain reference for other protocols
+    struct OddsSnapshot {
+        // Slot 1 (packed: 16+16 = 32 bytes)
+        uint128 sideATotal;
+        uint128 sideBTotal;
+        // Slot 2 (packed: 2+2+8 = 12 bytes)
+        uint16 impliedABps; // side A's share of the pool = its implied win probability
+        uint16 impliedBBps;
+        uint64 lockedAt;
+    }
+
+    /// @dev Template for matches opened on a fixed cadence by the permissionless crank
+    struct RecurringSchedule {
+        // Slot 1 (packed: 20+8+4 = 32 bytes)
+        address creator; // oracle that set it up — owns the matches' slots and bonds
+        uint64 interval;
+        uint32 sequence; // matches created so far, part of each derived matchId
+        // Slot 2 (packed: 20+8+1 = 29 bytes)
+        address fighterA;
+        uint64 nextAt; // earliest time the next match may be cranked
+        bool active;
+        // Slot 3 (packed: 20+8 = 28 bytes)
+        address fighterB;
+        uint64 bettingWindow;
+        // Slot 4 (packed: 16+16 = 32 bytes)
+        uint128 minBet;
+        uint128 bondBudget; // prepaid creator bonds drawn by each crank
+    }
+
+    /// @dev Per-operation destinations, e.g. a hot ops wallet for fees and cold storage for
+    ///      sweeps. An unset route falls back to `treasury`
+    struct TreasuryRouting {
+        address fees; // withdrawFees
+        address sweeps; // sweepUnclaimed
+        address dust; // cash-out haircuts
+    }
+
+    /// @dev Price-prediction market: side A wins if the Pyth price at resolveAt is above the
+    ///      strike, side B if at or below. The fighters only label the two sides
+    struct PriceMarket {
+        // Slot 1
+        bytes32 priceId; // Pyth price feed id
+        // Slot 2 (packed: 8+4+8 = 20 bytes)
+        int64 strike; // in the feed's own units, price × 10^expo
+        int32 expo; // must match the feed's exponent at resolution
+        uint64 resolveAt;
+    }
+
+    /// @dev A challenge to a resolved result, open until the admin adjudicates it
+    struct Dispute {
+        // Slot 1 (packed: 20+8+1 = 29 bytes)
+        address challenger;
+        uint64 raisedAt;
+        bool adjudicated;
+        // Slot 2
+        uint128 bond; // returned if upheld, slashed to the treasury if rejected
+    }
+
+    /// @dev An optimistic result: final after the dispute window unless challenged, in which
+    ///      case a resolve authority arbitrates and the side it agrees with takes both bonds
+    struct ResultProposal {
+        // Slot 1 (packed: 20+8+1 = 29 bytes)
+        address proposer;
+        uint64 liveUntil; // challenges accepted before this
+        uint8 winner;
+        // Slot 2 (packed: 20+1 = 21 bytes)
+        address challenger;
+        bool settled; // bonds paid out
+        // Slot 3
+        uint128 bond; // posted by the proposer, matched by a challenger
+    }
+
+    /// @dev Committee votes on one disputed match, by winner. Votes cast under an earlier
+    ///      committee are dropped by the first vote of the current one
+    struct ArbitrationTally {
+        // Single slot (packed: 4+1+1 = 6 bytes)
+        uint32 epoch;
+        uint8 sideAVotes;
+        uint8 sideBVotes;
+    }
+
+    /// @dev Latest round a game server attested for a running match. `leader` is 0 (side A),
+    ///      1 (side B) or 2 (level); once `finished` it is the winner the oracle must match
+    struct Attestation {
+        // Slot 1
+        bytes32 stateHash; // hash of the server's full round state
+        // Slot 2 (packed: 20+8+2+1+1 = 32 bytes)
+        address server;
+        uint64 timestamp;
+        uint16 round;
+        uint8 leader;
+        bool finished;
+    }
+
+    /// @dev A mystery match waiting on its VRF word — the fighters are picked on fulfilment
+    struct MysteryRequest {
+        // Slot 1
+        bytes32 matchId;
+        // Slot 2 (packed: 20+8 = 28 bytes)
+        address creator;
+        uint64 bettingWindow;
+        // Slot 3 (packed: 16+16 = 32 bytes)
+        uint128 minBet;
+        uint128 bond; // creator bond posted with the request, refunded if creation fails
+    }
+
+    /// @dev Donation routing queued behind CONFIG_TIMELOCK
+    struct PendingDonation {
+        // Single slot (20+2+8 = 30 bytes)
+        address recipient;
+        uint16 bps;
+        uint64 eta; // applyDonation succeeds from here, 0 = nothing queued
+    }
+
+    /// @dev Deposits a Pending match must hold before activateMatch will open it
+    struct PendingFunding {
+        // Slot 1 (packed: 16+16 = 32 bytes)
+        uint128 requiredBond; // creatorBond snapshot at initMatch
+        uint128 seedA; // house seed required per side, zero if unseeded
+        // Slot 2 (packed: 16 bytes)
+        uint128 seedB;
+        // Slot 3 (packed: 20+1 = 21 bytes)
+        address seeder; // set once the seed has been escrowed
+        bool seedFunded;
+    }
+
+    /// @dev One entry of a createMatches batch
+    struct MatchSpec {
+        bytes32 matchId;
+        address fighterA;
+        address fighterB;
+    }
+
+    struct MatchSchedule {
+        // Single slot (8+8 = 16 bytes)
+        uint64 opensAt; // bets rejected before this, 0 = open at creation
+        uint64 scheduledLockAt; // anyone may lock from this time, 0 = oracle-only lock
+    }
+
+    /// @dev Display data for explorers and third-party frontends — never read by settlement
+    struct MatchMetadata {
+        bytes32 name; // UTF-8, zero-padded
+        string uri; // at most MAX_URI_LENGTH bytes
+        bytes32 metadataHash; // commitment to the off-chain match record, set at creation
+        bytes32 resultHash; // commitment to the result record, set at resolution
+    }
+
+    struct Voucher {
+        // Slot 1 (packed: 20+8+1 = 29 bytes)
+        address redeemer;
+        uint64 expiresAt;
+        bool redeemed;
+        // Slot 2
+        uint128 amount;
+    }
+
+    struct Sponsorship {
+        // Slot 1 (packed: 20+2 = 22 bytes)
+        address sponsor;
+        uint16 matchBps; // matched share of each bet, 10_000 = 1:1
+        // Slot 2 (packed: 16+16 = 32 bytes)
+        uint128 budget; // unmatched funds, refundable to the sponsor once betting closes
+        uint128 capPerBet;
+    }
+
+    struct BettorProfile {
+        // Slot 1 (packed: 16+16 = 32 bytes)
+        uint128 currentVolume; // staked since windowStart
+        uint128 previousVolume; // staked in the window before
+        // Slot 2 (packed: 8+1 = 9 bytes)
+        uint64 windowStart;
+        uint8 feeTier; // 0 = no discount, n = feeTierDiscountBps[n - 1]
+    }
+
+    struct FirstBet {
+        // Slot 1
+        bytes32 matchId;
+        // Slot 2 (packed: 16+1 = 17 bytes)
+        uint128 insured; // reserved from promoBudget, 0 = not insured or already settled
+        bool refunded;
+    }
+
+    struct FighterRecord {
+        // Slot 1
+        bytes32 nameHash;
+        // Slot 2 (packed: 20+4+4+4 = 32 bytes)
+        address owner;
+        uint32 wins;
+        uint32 losses;
+        uint32 draws;
+        // Slot 3
+        uint128 totalVolume;
+    }
+
+    struct FighterToken {
+        uint256 tokenId;
+        bool linked;
+    }
+
+    struct RoyaltyPool {
+        // Single slot (2+1+1 = 4 bytes)
+        uint16 shareBps; // snapshot of royaltyShareBps at creation
+        bool fighterAClaimed;
+        bool fighterBClaimed;
+    }
+
+    struct ReferralPool {
+        // Single slot (16+2 = 18 bytes)
+        uint128 referredVolume;
+        uint16 shareBps; // snapshot of referralShareBps at creation
+    }
+
+    struct ReferralAccrual {
+        // Single slot (16+1 = 17 bytes)
+        uint128 volume;
+        bool claimed;
+    }
+
+    struct UsageMetrics {
+        // Single slot (8+8+8+8 = 32 bytes) — one extra warm SSTORE per counted call
+        uint64 betsPlaced;
+        uint64 claims; // payouts claimed, rolled over or batch-claimed
+        uint64 refunds; // cancelled, no-winner and escrowed-bet refunds
+        uint64 sweeps; // sweepUnclaimed and sweepCancelled
+    }
+
+    // ──────────────────────────────────────────────
+    // State
+    // ──────────────────────────────────────────────
+    address internal _treasury;
+    TreasuryRouting internal _treasuryRouting;
+    uint16 internal _feeBps;
+    uint64 internal _matchTimeout; // expected fight length; the oracle deadline unless one is set
+    uint64 internal _resolutionDeadline; // lock to timeoutMatch, 0 = matchTimeout
+    uint64 internal _claimWindow;
+    uint64 internal _claimCooldown; // wait after resolution before the first payout, 0 = none
+    uint64 internal _minResolutionDelay; // wait after lock before an oracle result, 0 = none
+    bool internal _commitRevealRequired; // oracle results only by revealing a lock-time commitment
+    uint16 internal _referralShareBps; // slice of the fee routed to referrers
+    uint16 internal _royaltyShareBps; // slice of the fee routed to fighter owners
+    uint128 internal _maxPoolSize; // ETH risk limit per match, 0 = uncapped
+    address internal _fighterCollection; // ERC-721 gating fighters, 0 = ungated
+    uint64 internal _unpauseGracePeriod; // delay after unpause before bets are accepted
+    uint64 internal _bettingResumesAt; // set on unpause, placeBet reverts before this
+    uint64 internal _maxMatchAge; // unsettled matches older than this can be force-cancelled
+    uint128 internal _eventLogBetThreshold; // bets below this are not written to the event log
+    uint32 internal _minBetsPerSide; // participation required to lock, 0 = no minimum
+    uint128 internal _minTotalPool; // participation required to lock, 0 = no minimum
+    uint16 internal _snipeThresholdBps; // bet size vs pool that counts as a snipe, 0 = disabled
+    uint64 internal _snipeWindow; // seconds before betting closes in which snipes extend it
+    uint64 internal _snipeExtension; // seconds added per snipe
+    uint64 internal _maxSnipeExtension; // cap on total seconds added to one match
+    // Haircut on an exit's value kept by treasury, 0 = disabled
+    uint16 internal _cashOutDiscountBps;
+    uint256 internal _cashOutDust; // haircuts kept by cashOut, sent on by withdrawCashOutDust
+    uint256 internal _cashOutReserve; // house ETH that buys positions on Locked matches
+    uint128 internal _cashOutMatchCap; // most the reserve pays out per match, 0 = Open exits only
+    address internal _screeningOracle; // sanctions list checked on every bet, 0 = disabled
+    address internal _treasuryHook; // diversification hook for withdrawn fees, 0 = disabled
+    uint16 internal _treasuryHookBps; // share of each fee withdrawal routed through the hook
+    address internal _authority; // top-level admin watched by the dead-man switch
+    address internal _successor; // may claim authority once the authority stops pinging
+    uint64 internal _lastAuthorityPing;
+    uint64 internal _deadManPeriod; // silence after which the successor may claim
+    uint64 internal _oracleLastSeen; // last heartbeat or attestation from a resolver
+    // Quiet time after which the oracle counts as down, 0 = off
+    uint64 internal _oracleSilencePeriod;
+    uint64 internal _silentOracleTimeout; // matchTimeout while the oracle is down
+    uint8 internal _resolutionThreshold; // M of the RESOLVE_AUTHORITY_ROLE holders must agree
+    address internal _donationAddress; // public-goods recipient, set only through the timelock
+    uint16 internal _donationBps; // share of every fee withdrawal donated
+    PendingDonation internal _pendingDonation;
+    UsageMetrics internal _metrics; // lifetime call counters for capacity planning
+    uint256 internal _totalDonated;
+    address internal _switchboard; // result feeds for feed-resolved matches, 0 = disabled
+    address internal _pyth; // price oracle for price-prediction markets, 0 = disabled
+    uint64 internal _disputeWindow; // challenge period after resolution, 0 = disputes disabled
+    uint128 internal _disputeBond; // msg.value required to challenge a result
+    address internal _vrfCoordinator; // randomness for mystery matches, 0 = disabled
+    bytes32 internal _vrfKeyHash;
+    uint256 internal _vrfSubscriptionId;
+    uint32 internal _vrfCallbackGasLimit;
+    // Guardian veto period on community matches, 0 = no public creation
+    uint64 internal _vetoWindow;
+    uint128 internal _vetoBetCap; // pool size a community match may reach inside its veto window
+    uint16 internal _spendLimitBps; // share of a pool one debit may take unco-signed, 0 = no limit
+    uint128 internal _dualSignPoolSize; // pools this large also need the authority, 0 = disabled
+
+    mapping(bytes32 => bool) internal _executedOps; // operator idempotency keys
+
+    mapping(bytes32 => MatchPool) internal _matches;
+    mapping(bytes32 => mapping(address => BetInfo)) internal _bets;
+    mapping(bytes32 => mapping(address => BetOdds)) internal _betOdds;
+    mapping(bytes32 => PoolShard[POOL_SHARDS]) internal _poolShards; // empty once consolidated
+    mapping(bytes32 => uint128) internal _cashOutSpent; // reserve paid for positions per match
+    mapping(bytes32 => MatchSchedule) internal _schedules;
+    mapping(bytes32 => uint64) internal _snipeExtensions; // seconds added so far per match
+    mapping(bytes32 => CancelReason) internal _cancelReasons;
+    mapping(bytes32 => ResultKind) internal _resultKinds;
+    mapping(bytes32 => EventLog.Buffer) internal eventLogs;
+
+    mapping(address => FighterRecord) internal _fighters;
+    address[] internal _fighterList; // registration order, the pool mystery matches draw from
+    mapping(address => FighterToken) internal _fighterTokens;
+
+    mapping(bytes32 => Settlement.Strategy) internal _settlementStrategies;
+    mapping(bytes32 => uint16) internal _lossFloors; // capped-loss markets, 0 = standard
+    mapping(bytes32 => MatchMetadata) internal _matchMetadata;
+    mapping(bytes32 => MatchCategory) internal _matchCategories;
+    mapping(bytes32 => OddsSnapshot) internal _oddsSnapshots; // written at lock, cleared on unlock
+    mapping(bytes32 => RecurringSchedule) internal _recurringSchedules;
+    mapping(bytes32 => PendingFunding) internal _pendingFundings;
+    mapping(bytes32 => uint256) internal _donatedFees;
+    mapping(bytes32 => address[]) internal subscribers;
+    mapping(bytes32 => mapping(address => uint256)) internal subscriberSlots; // index + 1
+    mapping(bytes32 => uint256) internal _summaryCursor; // subscribers already pushed to
+    mapping(bytes32 => uint8[2]) internal resultVotes; // per-side attestations toward M
+    mapping(bytes32 => mapping(address => uint8)) internal _resultAttested; // winner + 1, 0 = none
+    mapping(bytes32 => bytes32) internal _resultFeeds; // Switchboard feed id, 0 = oracle-resolved
+    mapping(bytes32 => PriceMarket) internal _priceMarkets;
+    mapping(bytes32 => uint64) internal _disputeDeadlines; // set at resolution, claims wait for it
+    mapping(bytes32 => Dispute) internal _disputes;
+    mapping(bytes32 => ResultProposal) internal _resultProposals;
+    mapping(uint256 => MysteryRequest) internal _mysteryRequests; // VRF request id → request
+    mapping(bytes32 => bool) internal _mysteryReserved; // match ids awaiting randomness
+    mapping(bytes32 => uint256) internal _mysterySeeds; // VRF word that picked the fighters
+    mapping(bytes32 => bool) internal _chaosMatches; // exhibitions whose winner VRF draws
+    mapping(uint256 => bytes32) internal _chaosRequests; // VRF request id → chaos match
+    // Chaos match → pending request, 0 = none
+    mapping(bytes32 => uint256) internal _chaosRequestIds;
+    mapping(bytes32 => uint256) internal _chaosSeeds; // VRF word that drew the winner
+    mapping(bytes32 => uint64) internal _vetoDeadlines; // community match → end of its veto window
+    mapping(bytes32 => mapping(address => bool)) internal _largeDebitApprovals; // guardian co-signs
+    // Dual-signature results, stored as winner + 1 so 0 means not yet signed
+    mapping(bytes32 => uint8) internal _oracleResults;
+    mapping(bytes32 => uint8) internal _authorityCosigns;
+    mapping(bytes32 => bytes32) internal _resultCommitments; // made at lock, see resultCommitment
+    mapping(bytes32 => uint8) internal _revealedResults; // winner + 1 once the commitment opens
+    mapping(bytes32 => address) internal _matchAdmins; // event organizer managing the match
+    mapping(bytes32 => uint64) internal _organizerExtensions; // seconds its organizer added so far
+    mapping(bytes32 => bool) internal _cancellationRequests; // organizer asked, guardian to approve
+    // Oracle key rotated out of a match → the key that replaced it, see setMatchOracle
+    mapping(bytes32 => mapping(address => address)) internal _matchOracles;
+    mapping(bytes32 => bytes32) internal _correlatedGroups; // markets on one fight, 0 = standalone
+    mapping(bytes32 => bytes32) internal _groupResultHashes; // result record a group settled on
+    mapping(bytes32 => bool) internal _correlationFlags; // held for guardian review
+    address[] internal committee; // arbitration committee, see committeeMembers
+    uint32 internal _committeeEpoch; // bumped by every setCommittee
+    mapping(address => uint32) internal _committeeSeats; // epoch a member was seated in
+    mapping(bytes32 => ArbitrationTally) internal _arbitrationTallies;
+    mapping(bytes32 => mapping(address => uint32)) internal _arbitrationVotes; // epoch voted in
+    mapping(bytes32 => Attestation) internal _attestations; // latest game-server round per match
+    mapping(bytes32 => uint64) internal _resolutionDeadlines; // per-match override, 0 = global
+    mapping(bytes32 => RoyaltyPool) internal _royaltyPools;
+
+    mapping(address => bool) internal _referrers;
+    mapping(bytes32 => ReferralPool) internal _referralPools;
+    mapping(bytes32 => mapping(address => ReferralAccrual)) internal _referralAccruals;
+    mapping(bytes32 => mapping(address => address)) internal _betReferrers;
+
+    // Bettor → session key allowed to settle for them
+    mapping(address => address) internal _claimDelegates;
+
+    // Unsettled (Open or Locked) matches per creating oracle account, 0 max = unlimited
+    uint32 internal _maxOpenMatchesPerCreator;
+    mapping(address => uint32) internal _openMatchCounts;
+    mapping(bytes32 => address) internal _matchCreators;
+
+    // Creator bond: posted with createMatch, returned after cleanup, slashed if abandoned
+    uint128 internal _creatorBond; // required msg.value for new matches, 0 = no bond
+    mapping(bytes32 => uint128) internal _creatorBonds; // posted amount, snapshot per match
+
+    // Stake per side already paid, refunded or swept — the rest is what getSolvency owes
+    mapping(bytes32 => uint128[2]) internal _settledStakes;
+
+    // Clawback ledger: after a post-claim overturn, what each paid-out wallet owes back
+    mapping(bytes32 => MatchWinner) internal _overturnedWinners; // result replaced by an overturn
+    mapping(bytes32 => mapping(address => uint256)) internal _clawbacks; // outstanding per match
+    mapping(bytes32 => mapping(address => bool)) internal _clawbackRecorded;
+    mapping(bytes32 => mapping(address => bool)) internal sweptToTreasury; // never paid out
+    mapping(address => uint256) internal _clawbackDebt; // blocks payout claims while non-zero
+
+    // Risk-free first bet: a wallet's first own bet is insured up to the cap from promoBudget
+    uint128 internal _firstBetInsuranceCap; // 0 = promotion off
+    mapping(address => bool) internal _hasBet;
+    mapping(address => FirstBet) internal _firstBets;
+
+    // Volume fee tiers: ascending 30-day volume thresholds unlocking a claim-time discount
+    uint128[] internal _feeTierThresholds;
+    uint16[] internal _feeTierDiscountBps; // share of the bettor's cut of the platform fee
+    mapping(address => BettorProfile) internal _bettorProfiles;
+    mapping(bytes32 => uint256) internal _feeDiscounts; // discounts paid, deducted from fees
+
+    // Semver as major * 1e6 + minor * 1e3 + patch (1.4.2 = 1_004_002), 0 = unset
+    uint32 internal _programVersion; // build currently deployed, set by the admin after deploy
+    uint32 internal _minClientVersion; // clients below this must refuse to send transactions
+
+    uint256 internal _promoBudget; // ETH held for vouchers, not yet reserved by one
+    mapping(bytes32 => Voucher) internal _vouchers;
+    // Voucher principal still owed back to promoBudget, per bettor per match
+    mapping(bytes32 => mapping(address => uint128)) internal _voucherStakes;
+
+    mapping(bytes32 => Sponsorship) internal _sponsorships;
+    mapping(bytes32 => mapping(address => uint128)) internal _sponsorMatched; // part of each stake
+
+    // House / LP seeding: each seeded side is a bet under a synthetic key owned by the seeder
+    mapping(address => bool) internal _approvedSeeders;
+    mapping(address => address) internal _seedOwners; // seed bettor key → seeder
+
+    // Bets that arrived after betting closed, held for refund instead of reverting
+    mapping(bytes32 => mapping(address => uint256)) internal _pendingBets;
+
+    // Position NFT contract, unset = placeBetAsPosition disabled
+    BetPositions internal _betPositions;
+    mapping(uint256 => bytes32) internal _positionMatches; // tokenId → matchId
+    mapping(address => uint256) internal _positionTokenIds; // position bettor key → tokenId
+
+    // ──────────────────────────────────────────────
+    // Custom Errors
+    // ──────────────────────────────────────────────
+    error MatchAlreadyExists();
+    error MatchNotOpen();
+    error MatchNotLocked();
+    error MatchNotResolved();
+    error MatchNotCancelled();
+    error InvalidSide();
+    error ZeroBetAmount();
+    error BetBelowMinimum(uint128 min);
+    error BettingWindowClosed();
+    error AlreadyBet();
+    error NoBetFound();
+    error AlreadyClaimed();
+    error BetOnLosingSide();
+    error WinnersExist();
+    error TimeoutNotElapsed();
+    error ClaimWindowNotElapsed();
+    error FeesAlreadyWithdrawn();
+    error WinningBetsRemaining();
+    error TransferFailed();
+    error InvalidFeeBps();
+    error InvalidTimeout();
+    error ClaimCooldownActive(uint64 claimableAt);
+    error ResolutionTooEarly(uint64 earliest);
+    error CommitmentRequired();
+    error NoCommitment();
+    error CommitmentMismatch();
+    error NotMatchAdmin();
+    error InvalidMatchAdmin();
+    error ExtensionTooLong(uint64 remaining);
+    error NoBettingDeadline();
+    error NoCancellationRequest();
+    error NotMatchOracle();
+    error InvalidMatchOracle();
+    error InvalidCorrelatedGroup();
+    error CorrelationUnderReview();
+    error NotFlagged();
+    error InvalidCommittee();
+    error NotCommitteeMember();
+    error AlreadyVoted();
+    error InvalidAttestation();
+    error StaleAttestationRound();
+    error AttestationMismatch(uint8 attestedWinner);
+    error TimeoutOutOfBounds(uint64 min, uint64 max);
+    error InvalidMatchStatus();
+    error InvalidShareBps();
+    error ReferrerNotRegistered();
+    error SelfReferral();
+    error NoReferralRewards();
+    error FighterNotRegistered();
+    error InvalidFighters();
+    error MatchIdMismatch();
+    error FighterAlreadyRegistered();
+    error InvalidOwner();
+    error PoolCapExceeded(uint128 cap);
+    error NoRoyaltyDue();
+    error FighterTokenNotOwned(address fighter);
+    error InvalidSchedule();
+    error UriTooLong();
+    error BettingNotOpen(uint64 opensAt);
+    error LockNotDue();
+    error UnlockWindowClosed(uint64 closedAt);
+    error ResultPending();
+    error UnpauseGracePeriodActive(uint64 resumesAt);
+    error PoolBalanced();
+    error MatchNotExpired();
+    error ParticipationBelowMinimum();
+    error CashOutDisabled();
+    error NoCashOutDust();
+    error InsufficientCashOutReserve();
+    error CashOutLimitReached();
+    error InvalidLossFloor();
+    error PositionsDisabled();
+    error BetPositionsAlreadySet();
+    error NotPositionOwner();
+    error AddressSanctioned(address account);
+    error InvalidBettor();
+    error NotClaimDelegate();
+    error InvalidRecipient();
+    error VoucherExists();
+    error VoucherNotFound();
+    error VoucherExpired();
+    error VoucherNotExpired();
+    error VoucherAlreadyRedeemed();
+    error InsufficientPromoBudget();
+    error NoPendingBet();
+    error NotSponsor();
+    error NotSeeder();
+    error NoSeed();
+    error InvalidSeedAmount();
+    error InvalidBatchSize();
+    error TooManyOpenMatches(uint32 max);
+    error NoFirstBetInsurance();
+    error InvalidFeeTiers();
+    error InvalidCreatorBond();
+    error NoCreatorBond();
+    error MatchNotCleanedUp();
+    error CannotOverturn();
+    error ResultFinal();
+    error InvalidRecurringSchedule();
+    error ScheduleInactive();
+    error ScheduleNotDue(uint64 nextAt);
+    error InsufficientScheduleBudget();
+    error MatchNotPending();
+    error MatchUnderfunded();
+    error NotAuthority();
+    error NotSuccessor();
+    error InvalidDeadManPeriod();
+    error AuthorityStillActive(uint64 claimableAt);
+    error InvalidResolutionThreshold();
+    error AlreadyAttested();
+    error InvalidResultSignature();
+    error InvalidDonation();
+    error TimelockNotElapsed(uint64 eta);
+    error AlreadySubscribed();
+    error NotSubscribed();
+    error SubscriptionsFull();
+    error FeedAlreadyBound();
+    error NoResultFeed();
+    error StaleFeedResult(uint256 updatedAt);
+    error InvalidFeedResult(int128 result);
+    error ResolvedByFeed();
+    error WalkoverNeedsQuorum();
+    error InvalidPriceMarket();
+    error PriceNotDue(uint64 resolveAt);
+    error PriceExponentMismatch(int32 expo);
+    error InsufficientPythFee(uint256 fee);
+    error DisputeWindowActive(uint64 endsAt);
+    error DisputeWindowClosed();
+    error ResultDisputed();
+    error NoDispute();
+    error InvalidDisputeBond(uint128 required);
+    error ResultProposed();
+    error NoProposal();
+    error ProposalLive(uint64 liveUntil);
+    error VrfNotConfigured();
+    error UnauthorizedCallback();
+    error NotEnoughFighters();
+    error NotChaosMatch();
+    error ChaosResultPending(uint256 requestId);
+    error CommunityCreationDisabled();
+    error VetoWindowClosed();
+    error VetoWindowCapExceeded(uint128 cap);
+    error SpendLimitExceeded(uint256 limit);
+    error InvalidBackstop(uint256 required);
+    error NoClawback();
+    error OutstandingClawback();
+    error FirstBetNotLost();
+    error FirstBetNotSettled();
+    error NoSponsorBudget();
+
+    // ──────────────────────────────────────────────
+    // Events
+    // ──────────────────────────────────────────────
+    event MatchCreated(
+        bytes32 indexed matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint16 feeBps,
+        MatchCategory indexed category
+    );
+    event MatchScheduled(bytes32 indexed matchId, uint64 opensAt, uint64 scheduledLockAt);
+    event MatchMetadataSet(bytes32 indexed matchId, bytes32 name, string uri, bytes32 metadataHash);
+    event MatchResultCommitted(bytes32 indexed matchId, bytes32 resultHash);
+    event MatchLocked(bytes32 indexed matchId, uint64 timestamp);
+    event MatchUnlocked(bytes32 indexed matchId, address indexed by);
+    event BettingWindowExtended(bytes32 indexed matchId, uint64 closesAt);
+    event MatchResolved(
+        bytes32 indexed matchId,
+        uint8 winner,
+        uint128 sideATotal,
+        uint128 sideBTotal,
+        uint64 timestamp
+    );
+    event MatchCancelled(bytes32 indexed matchId, CancelReason indexed reason, uint64 timestamp);
+    event BetPlaced(
+        bytes32 indexed matchId,
+        address indexed bettor,
+        uint8 side,
+        uint256 amount,
+        uint128 sideATotal, // odds the bettor saw — totals before this bet
+        uint128 sideBTotal
+    );
+    event BetGifted(
+        bytes32 indexed matchId,
+        address indexed payer,
+        address indexed bettor,
+        uint256 amount
+    );
+    event PayoutClaimed(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event PayoutRedirected(bytes32 indexed matchId, address indexed bettor, address recipient);
+    event RolledOver(
+        bytes32 indexed fromMatchId,
+        bytes32 indexed toMatchId,
+        address indexed bettor,
+        uint256 amount
+    );
+    event BetRefunded(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event CashedOut(
+        bytes32 indexed matchId,
+        address indexed bettor,
+        uint256 amount,
+        uint256 retained
+    );
+    event CashOutDustWithdrawn(uint256 amount, address treasury);
+    event PositionSold(
+        bytes32 indexed matchId,
+        address indexed bettor,
+        uint8 side,
+        uint128 stake,
+        uint256 amount
+    );
+    event CashOutReserveFunded(address indexed funder, uint256 amount);
+    event CashOutReserveWithdrawn(uint256 amount, address treasury);
+    event CashOutDeskSettled(bytes32 indexed matchId, uint8 side, uint256 amount);
+    event PromoFunded(address indexed funder, uint256 amount);
+    event PromoWithdrawn(uint256 amount, address treasury);
+    event VoucherIssued(
+        bytes32 indexed voucherId,
+        address indexed redeemer,
+        uint128 amount,
+        uint64 expiresAt
+    );
+    event VoucherRedeemed(
+        bytes32 indexed voucherId, bytes32 indexed matchId, address indexed redeemer
+    );
+    event VoucherReclaimed(bytes32 indexed voucherId, uint128 amount);
+    event VoucherPrincipalReturned(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event BetEscrowed(bytes32 indexed matchId, address indexed bettor, uint8 side, uint256 amount);
+    event PendingBetRefunded(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event SponsorshipFunded(
+        bytes32 indexed matchId,
+        address indexed sponsor,
+        uint256 amount,
+        uint128 capPerBet,
+        uint16 matchBps
+    );
+    event BetMatched(bytes32 indexed matchId, address indexed bettor, uint256 matched);
+    event SponsorMatchReturned(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event SponsorshipWithdrawn(bytes32 indexed matchId, address indexed sponsor, uint256 amount);
+    event SeederApproved(address indexed account, bool approved);
+    event MatchSeeded(
+        bytes32 indexed matchId, address indexed seeder, uint256 sideA, uint256 sideB
+    );
+    event SeedRedeemed(bytes32 indexed matchId, address indexed seeder, uint256 amount);
+    event FirstBetInsured(bytes32 indexed matchId, address indexed bettor, uint128 insured);
+    event FirstBetRefunded(bytes32 indexed matchId, address indexed bettor, uint128 amount);
+    event FirstBetInsuranceReleased(
+        bytes32 indexed matchId, address indexed bettor, uint128 amount
+    );
+    event FeeTierChanged(address indexed bettor, uint8 oldTier, uint8 newTier);
+    event FeeDiscountApplied(bytes32 indexed matchId, address indexed bettor, uint256 discount);
+    event CreatorBondReturned(bytes32 indexed matchId, address indexed creator, uint128 amount);
+    event CreatorBondSlashed(bytes32 indexed matchId, address indexed creator, uint128 amount);
+    event ResultOverturned(
+        bytes32 indexed matchId, MatchWinner previousWinner, MatchWinner newWinner, uint256 backstop
+    );
+    event ResolutionAmended(
+        bytes32 indexed matchId, MatchWinner previousWinner, MatchWinner newWinner, address by
+    );
+    event ClawbackRecorded(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event ClawbackRepaid(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event ClaimDelegateSet(address indexed bettor, address indexed delegate);
+    event NoWinnersRefunded(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event FeesWithdrawn(bytes32 indexed matchId, uint256 amount, address treasury);
+    event RecurringScheduleCreated(
+        bytes32 indexed scheduleId, address indexed creator, uint64 interval, uint64 firstAt
+    );
+    event RecurringScheduleStopped(bytes32 indexed scheduleId, uint256 refunded);
+    event ScheduleCranked(
+        bytes32 indexed scheduleId, bytes32 indexed matchId, uint32 sequence, uint64 nextAt
+    );
+    event MatchInitialized(
+        bytes32 indexed matchId, uint128 requiredBond, uint128 seedA, uint128 seedB
+    );
+    event PendingSeedFunded(bytes32 indexed matchId, address indexed seeder);
+    event MatchActivated(bytes32 indexed matchId, uint64 openedAt);
+    event PendingMatchAborted(bytes32 indexed matchId, uint256 seedRefunded);
+    event AuthorityPinged(address indexed authority, uint64 at);
+    event OracleHeartbeat(address indexed oracle, uint64 at);
+    event SuccessorSet(address indexed successor, uint64 deadManPeriod);
+    event AuthorityClaimed(address indexed previous, address indexed successor);
+    event SignedResultRelayed(bytes32 indexed matchId, address indexed oracle, address relayer);
+    event ResultAttested(
+        bytes32 indexed matchId, address indexed oracle, uint8 winner, uint8 votes
+    );
+    event FeesDiversified(bytes32 indexed matchId, uint256 amount, address hook);
+    event FeesDonated(bytes32 indexed matchId, uint256 amount, address indexed recipient);
+    event DonationProposed(address indexed recipient, uint16 bps, uint64 eta);
+    event DonationProposalCancelled();
+    event Subscribed(bytes32 indexed matchId, address indexed subscriber);
+    event Unsubscribed(bytes32 indexed matchId, address indexed subscriber);
+    /// @dev Indexed by subscriber, so a wallet filters logs on its own address to see every
+    ///      match it followed end
+    event MatchSummary(
+        bytes32 indexed matchId,
+        address indexed subscriber,
+        MatchStatus status,
+        MatchWinner winner,
+        uint128 sideATotal,
+        uint128 sideBTotal
+    );
+    event ResultFeedBound(bytes32 indexed matchId, bytes32 indexed feedId);
+    event FeedResultApplied(
+        bytes32 indexed matchId, bytes32 indexed feedId, int128 result, uint256 updatedAt
+    );
+    event PriceMarketCreated(
+        bytes32 indexed matchId, bytes32 indexed priceId, int64 strike, int32 expo, uint64 resolveAt
+    );
+    event PriceMarketResolved(
+        bytes32 indexed matchId, bytes32 indexed priceId, int64 price, uint256 publishTime
+    );
+    event ResultChallenged(bytes32 indexed matchId, address indexed challenger, uint128 bond);
+    event DisputeAdjudicated(bytes32 indexed matchId, address indexed challenger, bool upheld);
+    event ProposalMade(
+        bytes32 indexed matchId, address indexed proposer, uint8 winner, uint64 liveUntil
+    );
+    event ProposalChallenged(bytes32 indexed matchId, address indexed challenger);
+    event ProposalSettled(bytes32 indexed matchId, address indexed bondRecipient, uint256 bonds);
+    event MatchVetoed(bytes32 indexed matchId, address indexed guardian);
+    event ResolutionAwaitingCosign(bytes32 indexed matchId, uint8 winner);
+    event ResolutionCosigned(bytes32 indexed matchId, address indexed authority, uint8 winner);
+    event MatchForfeited(bytes32 indexed matchId, ResultKind kind, uint8 winner, address oracle);
+    event LargeDebitApproved(bytes32 indexed matchId, address indexed account, address guardian);
+    event MysteryMatchRequested(bytes32 indexed matchId, uint256 indexed requestId);
+    event MysteryMatchCreated(
+        bytes32 indexed matchId, uint256 randomWord, address fighterA, address fighterB
+    );
+    event MysteryMatchFailed(bytes32 indexed matchId, uint256 randomWord, uint128 bondRefunded);
+    event ChaosResultRequested(bytes32 indexed matchId, uint256 indexed requestId);
+    event ChaosResultDrawn(bytes32 indexed matchId, uint256 randomWord, uint8 winner);
+    event ResultCommitmentMade(bytes32 indexed matchId, bytes32 commitment);
+    event ResultRevealed(bytes32 indexed matchId, address indexed oracle, uint8 winner);
+    event MatchAdminSet(bytes32 indexed matchId, address indexed admin);
+    event CancellationRequested(bytes32 indexed matchId, address indexed admin);
+    event CancellationApproved(bytes32 indexed matchId, address indexed guardian);
+    event MatchOracleSet(bytes32 indexed matchId, address replaced, address oracle);
+    event CorrelatedMatchCreated(bytes32 indexed matchId, bytes32 indexed groupId);
+    event CorrelationMismatch(
+        bytes32 indexed groupId, bytes32 indexed matchId, bytes32 expected, bytes32 actual
+    );
+    event CorrelationCleared(bytes32 indexed matchId, address indexed guardian);
+    event CommitteeSet(uint32 indexed epoch, address[] members);
+    event ArbitrationVoteCast(
+        bytes32 indexed matchId, address indexed member, uint8 winner, uint8 votes
+    );
+    event RoundAttested(
+        bytes32 indexed matchId,
+        address indexed server,
+        uint16 round,
+        bytes32 stateHash,
+        uint8 leader,
+        bool finished
+    );
+    event AttestationCleared(bytes32 indexed matchId, address indexed guardian);
+    event MatchResolutionDeadlineSet(bytes32 indexed matchId, uint64 deadline);
+    event UnclaimedSwept(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event CancelledSwept(bytes32 indexed matchId, address indexed bettor, uint256 amount);
+    event ConfigUpdated(ConfigField indexed field, uint256 value);
+    event OperationSkipped(bytes32 indexed opId);
+    event BettingResumeScheduled(uint64 resumesAt);
+    event FighterRegistered(address indexed fighter, address indexed owner, bytes32 nameHash);
+    event FighterTokenLinked(address indexed fighter, uint256 tokenId);
+    event FighterRecordUpdated(address indexed fighter, uint32 wins, uint32 losses, uint32 draws);
+    event FighterRoyaltyClaimed(bytes32 indexed matchId, address indexed owner, uint256 amount);
+    event ReferrerRegistered(address indexed referrer);
+    event BetReferred(
+        bytes32 indexed matchId,
+        address indexed bettor,
+        address indexed referrer,
+        uint256 amount
+    );
+    event ReferralRewardsClaimed(bytes32 indexed matchId, address indexed referrer, uint256 amount);
+    event PositionMinted(bytes32 indexed matchId, uint256 indexed tokenId, address indexed holder);
+    event PositionRedeemed(uint256 indexed tokenId, address indexed holder, uint256 amount);
+
+    // ──────────────────────────────────────────────
+    // Modifiers
+    // ──────────────────────────────────────────────
+    /// @dev A retried operator tx whose first attempt already landed returns early instead of
+    ///      reverting on the changed state (e.g. MatchNotLocked on a second resolve)
+    modifier idempotent(bytes32 opId) {
+        if (_executedOps[opId]) {
+            emit OperationSkipped(opId);
+            return;
+        }
+        _executedOps[opId] = true;
+        _;
+    }
+
+    /// @dev The match's organizer — platform roles grant nothing here
+    modifier onlyMatchAdmin(bytes32 matchId) {
+        if (msg.sender != _matchAdmins[matchId]) revert NotMatchAdmin();
+        _;
+    }
+
+    /// @dev Creation calls must carry exactly one creatorBond per match they open
+    modifier withCreatorBond(uint256 count) {
+        if (msg.value != _creatorBond * count) revert InvalidCreatorBond();
+        _;
+    }
+
+    // ──────────────────────────────────────────────
+    // Constructor
+    // ──────────────────────────────────────────────
+    /// @dev Roles and defaults are set by RawlBetting's constructor; modules only need
+    ///      the EIP-712 domain name, whose separator is rebuilt for RawlBetting's address
+    constructor() EIP712("RawlBetting", "1") {}
+
+    // ──────────────────────────────────────────────
+    // Internal: Operator actions
+    // ──────────────────────────────────────────────
+    function _lockMatch(bytes32 matchId, MatchStateMachine.Actor actor) internal {
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Locked, actor);
+
+        _consolidateShards(matchId, pool);
+        pool.status = MatchStatus.Locked;
+        pool.lockTimestamp = uint64(block.timestamp);
+        _snapshotOdds(matchId, pool);
+
+        eventLogs[matchId].push(EventLog.Kind.Locked, 0, Wei.unwrap(_poolTotal(pool)));
+
+        emit MatchLocked(matchId, uint64(block.timestamp));
+    }
+
+    function _snapshotOdds(bytes32 matchId, MatchPool storage pool) internal {
+        uint256 total = _poolTotal(pool).raw();
+        uint16 impliedA = total == 0 ? 0 : uint16((pool.sideATotal.raw() * 10_000) / total);
+        _oddsSnapshots[matchId] = OddsSnapshot({
+            sideATotal: Wei.unwrap(pool.sideATotal),
+            sideBTotal: Wei.unwrap(pool.sideBTotal),
+            impliedABps: impliedA,
+            impliedBBps: total == 0 ? 0 : 10_000 - impliedA,
+            lockedAt: uint64(block.timestamp)
+        });
+    }
+
+    /// @dev One vote per oracle per match, never changed. The match resolves once either
+    ///      side reaches resolutionThreshold; returns whether this call resolved it
+    function _attestResult(bytes32 matchId, uint8 winner, address oracle)
+        internal
+        returns (bool resolved)
+    {
+        if (_resolvedExternally(matchId)) revert ResolvedByFeed();
+        _requireMatchOracle(matchId, oracle);
+        _oracleLastSeen = uint64(block.timestamp);
+        if (_resultProposals[matchId].proposer != address(0)) revert ResultProposed();
+        if (_resultCommitments[matchId] != bytes32(0)) {
+            _requireRevealed(matchId, winner);
+        } else if (_commitRevealRequired) {
+            revert NoCommitment();
+        }
+        // A server that stopped mid-match (no finished round) doesn't hold the result up
+        Attestation storage attested = _attestations[matchId];
+        if (attested.finished && attested.leader != winner) {
+            revert AttestationMismatch(attested.leader);
+        }
+        // Not yet locked leaves earliest in the past; the transition check rejects it below
+        uint64 earliest = _matches[matchId].lockTimestamp + _minResolutionDelay;
+        if (block.timestamp < earliest) revert ResolutionTooEarly(earliest);
+        if (_resolutionThreshold == 1) {
+            return _finalizeResult(matchId, winner);
+        }
+        if (winner > 1) revert InvalidSide();
+        _requireTransition(
+            _matches[matchId], MatchStatus.Resolved, MatchStateMachine.Actor.ResolveAuthority
+        );
+        if (_resultAttested[matchId][oracle] != 0) revert AlreadyAttested();
+
+        _resultAttested[matchId][oracle] = winner + 1;
+        uint8 votes = ++resultVotes[matchId][winner];
+        emit ResultAttested(matchId, oracle, winner, votes);
+
+        if (votes < _resolutionThreshold) return false;
+        return _finalizeResult(matchId, winner);
+    }
+
+    /// @dev Resolve with the oracles' agreed winner, unless the pool needs the authority's
+    ///      co-sign and it hasn't signed that winner yet — then the result waits for it
+    function _finalizeResult(bytes32 matchId, uint8 winner) internal returns (bool resolved) {
+        if (!_needsCosign(matchId)) {
+            _resolveMatch(matchId, winner);
+            return true;
+        }
+        if (winner > 1) revert InvalidSide();
+        _requireTransition(
+            _matches[matchId], MatchStatus.Resolved, MatchStateMachine.Actor.ResolveAuthority
+        );
+        if (_authorityCosigns[matchId] == winner + 1) {
+            _resolveMatch(matchId, winner);
+            return true;
+        }
+        _oracleResults[matchId] = winner + 1;
+        emit ResolutionAwaitingCosign(matchId, winner);
+        return false;
+    }
+
+    /// @dev A key the authority rotated out of a match no longer touches its result
+    function _requireMatchOracle(bytes32 matchId, address oracle) internal view {
+        if (_matchOracles[matchId][oracle] != address(0)) revert NotMatchOracle();
+    }
+
+    /// @dev Live total: a forfeit asks this of a match that is still Open
+    function _needsCosign(bytes32 matchId) internal view returns (bool) {
+        return _dualSignPoolSize > 0 && _livePoolTotal(matchId).raw() >= _dualSignPoolSize;
+    }
+
+    function _resolveMatch(bytes32 matchId, uint8 winner) internal {
+        _resolveMatchAs(matchId, winner, MatchStateMachine.Actor.ResolveAuthority);
+    }
+
+    function _resolveMatchAs(bytes32 matchId, uint8 winner, MatchStateMachine.Actor actor)
+        internal
+    {
+        if (winner > 1) revert InvalidSide();
+
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Resolved, actor);
+
+        // Forfeits resolve straight from Open
+        if (pool.status == MatchStatus.Open) _consolidateShards(matchId, pool);
+        pool.status = MatchStatus.Resolved;
+        _releaseCreatorSlot(matchId);
+        pool.winner = winner == 0 ? MatchWinner.SideA : MatchWinner.SideB;
+        pool.resolveTimestamp = uint64(block.timestamp);
+        pool.winningBetCount = winner == 0 ? pool.sideABetCount : pool.sideBBetCount;
+        if (_disputeWindow > 0) {
+            _disputeDeadlines[matchId] = uint64(block.timestamp) + _disputeWindow;
+        }
+
+        _recordResult(pool, winner);
+        bytes32 group = _correlatedGroups[matchId];
+        if (group != bytes32(0)) _checkCorrelation(group, matchId);
+        eventLogs[matchId].push(EventLog.Kind.Resolved, winner, Wei.unwrap(_poolTotal(pool)));
+
+        emit MatchResolved(
+            matchId,
+            winner,
+            Wei.unwrap(pool.sideATotal),
+            Wei.unwrap(pool.sideBTotal),
+            uint64(block.timestamp)
+        );
+    }
+
+    function _cancelMatch(bytes32 matchId, CancelReason reason) internal {
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Cancelled, MatchStateMachine.Actor.Admin);
+
+        _setCancelled(matchId, pool, reason);
+    }
+
+    /// @dev The result hash is staged before attesting so the correlation check at resolution
+    ///      sees it, and kept only by the attestation that finalizes the result
+    function _attestWithResultHash(bytes32 matchId, uint8 winner, bytes32 resultHash)
+        internal
+    {
+        MatchMetadata storage metadata = _matchMetadata[matchId];
+        metadata.resultHash = resultHash;
+        if (!_attestResult(matchId, winner, msg.sender)) {
+            delete metadata.resultHash;
+            return;
+        }
+
+        emit MatchResultCommitted(matchId, resultHash);
+    }
+
+    /// @dev Every market in a group settles on one result record. The first member resolved
+    ///      with a hash sets it; a member resolved without one, or with a different one, is
+    ///      held from settlement until a guardian clears it
+    function _checkCorrelation(bytes32 groupId, bytes32 matchId) internal {
+        bytes32 actual = _matchMetadata[matchId].resultHash;
+        bytes32 expected = _groupResultHashes[groupId];
+        if (actual != bytes32(0) && expected == bytes32(0)) {
+            _groupResultHashes[groupId] = actual;
+            return;
+        }
+        if (actual != bytes32(0) && actual == expected) return;
+
+        _correlationFlags[matchId] = true;
+        emit CorrelationMismatch(groupId, matchId, expected, actual);
+    }
+
+    function _isFinal(MatchStatus status) internal pure returns (bool) {
+        return status == MatchStatus.Resolved || status == MatchStatus.Cancelled;
+    }
+
+    /// @dev Gate for every status change. A refusal reverts with the error each entry point
+    ///      used before the table existed, so clients decoding them are unaffected
+    function _requireTransition(
+        MatchPool storage pool,
+        MatchStatus to,
+        MatchStateMachine.Actor actor
+    ) internal view {
+        if (MatchStateMachine.canTransition(pool.status, to, actor)) return;
+        if (to == MatchStatus.Locked || actor == MatchStateMachine.Actor.Forfeit) {
+            revert MatchNotOpen();
+        }
+        if (to == MatchStatus.Resolved) revert MatchNotLocked();
+        if (actor == MatchStateMachine.Actor.Creator) revert MatchNotPending();
+        revert InvalidMatchStatus();
+    }
+
+    function _withdrawFees(bytes32 matchId) internal {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+        if (pool.feesWithdrawn) revert FeesAlreadyWithdrawn();
+        if (pool.winningBetCount > 0) revert WinningBetsRemaining();
+        if (block.timestamp < pool.resolveTimestamp + _claimWindow) revert ClaimWindowNotElapsed();
+
+        // Volume-tier discounts were already paid out of the platform's share at claim time
+        uint256 fee = _platformFee(matchId, pool) - _feeDiscounts[matchId];
+        // Use min(fee, balance) to handle rounding dust
+        uint256 amount = fee < address(this).balance ? fee : address(this).balance;
+
+        pool.feesWithdrawn = true;
+
+        uint256 donated;
+        if (_donationBps > 0) {
+            donated = BpsMath.toBps(_donationBps).applyTo(amount);
+            _donatedFees[matchId] = donated;
+            _totalDonated += donated;
+            eventLogs[matchId].push(EventLog.Kind.Donated, 0, donated.toUint128());
+
+            (bool sent,) = payable(_donationAddress).call{value: donated}("");
+            if (!sent) revert TransferFailed();
+            emit FeesDonated(matchId, donated, _donationAddress);
+        }
+
+        // The hook and treasury split what is left after the donation
+        amount -= donated;
+        uint256 diverted;
+        if (_treasuryHookBps > 0) {
+            diverted = BpsMath.toBps(_treasuryHookBps).applyTo(amount);
+            try ITreasuryHook(_treasuryHook).onFeesWithdrawn{value: diverted}(matchId) {
+                emit FeesDiversified(matchId, diverted, _treasuryHook);
+            } catch {
+                diverted = 0; // a failing swap never blocks a withdrawal — all of it goes below
+            }
+        }
+
+        address destination = _routed(_treasuryRouting.fees);
+        (bool success,) = payable(destination).call{value: amount - diverted}("");
+        if (!success) revert TransferFailed();
+
+        emit FeesWithdrawn(matchId, amount - diverted, destination);
+    }
+
+    /// @dev Resolve from a proposal and pay its bonds to `bondRecipient`. No second dispute
+    ///      window — the proposal's own window or the arbitration stands in for it
+    function _settleProposal(
+        bytes32 matchId,
+        ResultProposal storage proposal,
+        uint8 winner,
+        address bondRecipient
+    ) internal {
+        proposal.settled = true;
+        _resolveMatch(matchId, winner);
+        delete _disputeDeadlines[matchId];
+
+        uint256 bonds = proposal.bond;
+        if (proposal.challenger != address(0)) bonds *= 2;
+        (bool success,) = payable(bondRecipient).call{value: bonds}("");
+        if (!success) revert TransferFailed();
+
+        emit ProposalSettled(matchId, bondRecipient, bonds);
+    }
+
+    /// @dev Claims and no-winner refunds wait out claimCooldown after resolution
+    function _requireCooledDown(MatchPool storage pool) internal view {
+        uint64 claimableAt = pool.resolveTimestamp + _claimCooldown;
+        if (block.timestamp < claimableAt) revert ClaimCooldownActive(claimableAt);
+    }
+
+    /// @dev Resolved payouts wait out the dispute window and any open challenge
+    function _requireUndisputed(bytes32 matchId) internal view {
+        uint64 deadline = _disputeDeadlines[matchId];
+        if (block.timestamp < deadline) revert DisputeWindowActive(deadline);
+        Dispute storage dispute = _disputes[matchId];
+        if (dispute.challenger != address(0) && !dispute.adjudicated) revert ResultDisputed();
+        if (_correlationFlags[matchId]) revert CorrelationUnderReview();
+    }
+
+    /// @dev Matches settled by something other than an oracle's word: a Switchboard feed, a
+    ///      Pyth price market or a VRF draw
+    function _resolvedExternally(bytes32 matchId) internal view returns (bool) {
+        return _resultFeeds[matchId] != bytes32(0) || _priceMarkets[matchId].priceId != bytes32(0)
+            || _chaosMatches[matchId];
+    }
+
+    function _requestRandomWord() internal returns (uint256) {
+        return IVRFCoordinatorV2Plus(_vrfCoordinator).requestRandomWords(
+            IVRFCoordinatorV2Plus.RandomWordsRequest({
+                keyHash: _vrfKeyHash,
+                subId: _vrfSubscriptionId,
+                requestConfirmations: VRF_CONFIRMATIONS,
+                callbackGasLimit: _vrfCallbackGasLimit,
+                numWords: 1,
+                // VRFV2PlusClient ExtraArgsV1, paid from the subscription's LINK balance
+                extraArgs: abi.encodeWithSelector(bytes4(keccak256("VRF ExtraArgsV1")), false)
+            })
+        );
+    }
+
+    /// @dev Committed matches take only the revealed winner — until the reveal, nothing
+    function _requireRevealed(bytes32 matchId, uint8 winner) internal view {
+        if (uint256(_revealedResults[matchId]) != uint256(winner) + 1) revert CommitmentMismatch();
+    }
+
+    /// @dev Where an operation's proceeds go: its configured route, else the treasury
+    function _routed(address route) internal view returns (address) {
+        return route == address(0) ? _treasury : route;
+    }
+
+    /// @dev A cancelled bet's refund goes to its position holder or seeder, else the bettor
+    function _cancelledSweepRecipient(address bettor) internal view returns (address) {
+        uint256 tokenId = _positionTokenIds[bettor];
+        if (tokenId != 0) return _betPositions.ownerOf(tokenId);
+        if (_seedOwners[bettor] != address(0)) return _seedOwners[bettor];
+        return bettor;
+    }
+
+    /// @dev What's left of `amount` once up to `returned` has gone back to a budget
+    function _afterReturn(uint256 amount, uint256 returned) internal pure returns (uint256) {
+        return amount < returned ? 0 : amount - returned;
+    }
+
+    function _meetsParticipation(bytes32 matchId) internal view returns (bool) {
+        MatchPool storage pool = _matches[matchId];
+        return pool.sideABetCount >= _minBetsPerSide && pool.sideBBetCount >= _minBetsPerSide
+            && _livePoolTotal(matchId).raw() >= _minTotalPool;
+    }
+
+    function _setCancelled(bytes32 matchId, MatchPool storage pool, CancelReason reason)
+        internal
+    {
+        if (pool.status == MatchStatus.Open) _consolidateShards(matchId, pool);
+        pool.status = MatchStatus.Cancelled;
+        _releaseCreatorSlot(matchId);
+        pool.cancelTimestamp = uint64(block.timestamp);
+        _cancelReasons[matchId] = reason;
+        // The side byte carries the reason code for Cancelled entries
+        eventLogs[matchId].push(
+            EventLog.Kind.Cancelled, uint8(reason), Wei.unwrap(_poolTotal(pool))
+        );
+
+        emit MatchCancelled(matchId, reason, uint64(block.timestamp));
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Oracle liveness
+    // ──────────────────────────────────────────────
+    function _globalResolutionDeadline() internal view returns (uint64) {
+        return _resolutionDeadline == 0 ? _matchTimeout : _resolutionDeadline;
+    }
+
+    function _oracleSilent() internal view returns (bool) {
+        return _oracleSilencePeriod > 0
+            && block.timestamp >= _oracleLastSeen + _oracleSilencePeriod;
+    }
+
+    function _silentOracleCap(uint64 deadline) internal view returns (uint64) {
+        if (_oracleSilent() && _silentOracleTimeout < deadline) return _silentOracleTimeout;
+        return deadline;
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Bettor settlement
+    // ──────────────────────────────────────────────
+    /// @dev `bettor` keys the bet, `recipient` receives the ETH — the same address unless
+    ///      the bet is a tokenized position
+    function _claimPayout(bytes32 matchId, address bettor, address recipient)
+        internal
+        returns (uint256 payout)
+    {
+        // CEI: effects before interaction
+        payout = _returnVoucherPrincipal(matchId, bettor, _markPayoutClaimed(matchId, bettor));
+
+        (bool success,) = payable(recipient).call{value: payout}("");
+        if (!success) revert TransferFailed();
+
+        emit PayoutClaimed(matchId, bettor, payout);
+    }
+
+    /// @dev Effects half of a claim — marks the bet settled and returns what it is owed,
+    ///      leaving the caller to pay it out or restake it
+    function _markPayoutClaimed(bytes32 matchId, address bettor)
+        internal
+        returns (uint256 payout)
+    {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+        _requireUndisputed(matchId);
+        _requireCooledDown(pool);
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+        if (_clawbackDebt[bettor] > 0) revert OutstandingClawback();
+
+        payout = _claimableAmount(matchId, pool, bet);
+        if (_isWinningSide(pool, bet.side)) {
+            payout += _feeDiscount(matchId, pool, bettor, bet.amount);
+        }
+        _enforceSpendLimit(matchId, pool, bettor, payout);
+
+        _markSettled(matchId, bet);
+        unchecked {
+            pool.betCount--;
+            _metrics.claims++;
+        }
+    }
+
+    function _refundNoWinners(bytes32 matchId, address bettor, address recipient)
+        internal
+        returns (uint256 refundAmount)
+    {
+        refundAmount = _returnVoucherPrincipal(
+            matchId, bettor, _markNoWinnersRefunded(matchId, bettor)
+        );
+        refundAmount = _returnSponsorMatch(matchId, bettor, refundAmount);
+
+        (bool success,) = payable(recipient).call{value: refundAmount}("");
+        if (!success) revert TransferFailed();
+
+        emit NoWinnersRefunded(matchId, bettor, refundAmount);
+    }
+
+    /// @dev Effects half of a no-winner refund, like _markPayoutClaimed
+    function _markNoWinnersRefunded(bytes32 matchId, address bettor)
+        internal
+        returns (uint256 refundAmount)
+    {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+        _requireUndisputed(matchId);
+        _requireCooledDown(pool);
+        if (pool.winningBetCount > 0) revert WinnersExist();
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+
+        // Fee IS deducted even on no-winner refunds
+        refundAmount = Settlement.noWinnerRefund(_settlementPool(matchId, pool), bet.amount);
+        _enforceSpendLimit(matchId, pool, bettor, refundAmount);
+
+        _markSettled(matchId, bet);
+        unchecked {
+            pool.betCount--;
+            _metrics.refunds++;
+        }
+    }
+
+    function _refundBet(bytes32 matchId, address bettor, address recipient)
+        internal
+        returns (uint256 refundAmount)
+    {
+        refundAmount = _returnVoucherPrincipal(matchId, bettor, _markRefunded(matchId, bettor));
+        refundAmount = _returnSponsorMatch(matchId, bettor, refundAmount);
+
+        (bool success,) = payable(recipient).call{value: refundAmount}("");
+        if (!success) revert TransferFailed();
+
+        emit BetRefunded(matchId, bettor, refundAmount);
+    }
+
+    /// @dev Effects half of a cancelled-match refund, like _markPayoutClaimed
+    function _markRefunded(bytes32 matchId, address bettor) internal returns (uint256) {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Cancelled) revert MatchNotCancelled();
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+
+        _markSettled(matchId, bet);
+        unchecked {
+            pool.betCount--;
+            _metrics.refunds++;
+        }
+        return bet.amount;
+    }
+
+    /// @dev Every settlement of a voucher-funded bet routes up to the voucher principal back
+    ///      to promoBudget and returns what is left for the bettor (or treasury on sweeps)
+    function _returnVoucherPrincipal(bytes32 matchId, address bettor, uint256 amount)
+        internal
+        returns (uint256)
+    {
+        uint128 principal = _voucherStakes[matchId][bettor];
+        if (principal == 0) return amount;
+
+        delete _voucherStakes[matchId][bettor];
+        uint256 returned = amount < principal ? amount : principal;
+        _promoBudget += returned;
+
+        emit VoucherPrincipalReturned(matchId, bettor, returned);
+        return amount - returned;
+    }
+
+    /// @dev Adds the sponsor's match to a bettor's own stake, drawing it from the budget
+    function _withSponsorMatch(bytes32 matchId, address bettor, uint256 amount)
+        internal
+        returns (uint256)
+    {
+        Sponsorship storage sponsorship = _sponsorships[matchId];
+        if (sponsorship.budget == 0) return amount;
+
+        uint256 matched = BpsMath.toBps(sponsorship.matchBps).applyTo(amount);
+        if (matched > sponsorship.capPerBet) matched = sponsorship.capPerBet;
+        if (matched > sponsorship.budget) matched = sponsorship.budget;
+        if (matched == 0) return amount;
+
+        sponsorship.budget -= matched.toUint128();
+        _sponsorMatched[matchId][bettor] = matched.toUint128();
+
+        emit BetMatched(matchId, bettor, matched);
+        return amount + matched;
+    }
+
+    /// @dev Stake refunds (cancellation, no winners, cash-out) give the matched part back
+    ///      to the sponsor's budget first; winnings on a matched bet belong to the bettor
+    function _returnSponsorMatch(bytes32 matchId, address bettor, uint256 amount)
+        internal
+        returns (uint256)
+    {
+        uint128 matched = _sponsorMatched[matchId][bettor];
+        if (matched == 0) return amount;
+
+        delete _sponsorMatched[matchId][bettor];
+        uint256 returned = amount < matched ? amount : matched;
+        _sponsorships[matchId].budget += returned.toUint128();
+
+        emit SponsorMatchReturned(matchId, bettor, returned);
+        return amount - returned;
+    }
+
+    function _seedKey(address seeder, uint8 side) internal pure returns (address) {
+        return address(uint160(uint256(keccak256(abi.encode("rawl.seed", seeder, side)))));
+    }
+
+    /// @dev Bettor address holding the positions the cash-out reserve bought on `side`
+    function _cashOutDesk(uint8 side) internal pure returns (address) {
+        return address(uint160(uint256(keccak256(abi.encode("rawl.cashout", side)))));
+    }
+
+    function _isWinningSide(MatchPool storage pool, uint8 side) internal view returns (bool) {
+        return pool.winner == (side == 0 ? MatchWinner.SideA : MatchWinner.SideB);
+    }
+
+    function _recordFirstBet(bytes32 matchId, address bettor, uint256 amount) internal {
+        _hasBet[bettor] = true;
+        // Free bets are already promo-funded, so they are not insured on top
+        if (_firstBetInsuranceCap == 0 || _voucherStakes[matchId][bettor] > 0) return;
+
+        uint128 insured = amount < _firstBetInsuranceCap ? uint128(amount) : _firstBetInsuranceCap;
+        if (insured > _promoBudget) return; // budget exhausted — promotion quietly ends
+
+        _promoBudget -= insured;
+        _firstBets[bettor] = FirstBet({matchId: matchId, insured: insured, refunded: false});
+
+        emit FirstBetInsured(matchId, bettor, insured);
+    }
+
+    function _releaseFirstBet(address bettor) internal {
+        FirstBet storage first = _firstBets[bettor];
+        uint128 insured = first.insured;
+        if (insured == 0) return;
+
+        first.insured = 0;
+        _promoBudget += insured;
+
+        emit FirstBetInsuranceReleased(first.matchId, bettor, insured);
+    }
+
+    /// @dev Lost = resolved with winners on the other side. A no-winners match refunds the
+    ///      stake anyway, so it does not count
+    function _firstBetLost(bytes32 matchId, address bettor) internal view returns (bool) {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) return false;
+
+        uint8 side = _bets[matchId][bettor].side;
+        return !_isWinningSide(pool, side) && !_winningSideTotal(pool).isZero();
+    }
+
+    /// @dev Sliding-window estimate of the bettor's stake over the last VOLUME_WINDOW
+    function _rollingVolume(address bettor) internal view returns (uint256) {
+        BettorProfile memory profile = _bettorProfiles[bettor];
+        uint256 elapsed = block.timestamp - profile.windowStart;
+        if (elapsed >= 2 * VOLUME_WINDOW) return 0;
+        if (elapsed >= VOLUME_WINDOW) {
+            // currentVolume has become the previous window
+            return (uint256(profile.currentVolume) * (2 * VOLUME_WINDOW - elapsed)) / VOLUME_WINDOW;
+        }
+        return profile.currentVolume
+            + (uint256(profile.previousVolume) * (VOLUME_WINDOW - elapsed)) / VOLUME_WINDOW;
+    }
+
+    function _feeTierOf(address bettor) internal view returns (uint8 tier) {
+        uint256 volume = _rollingVolume(bettor);
+        while (tier < _feeTierThresholds.length && volume >= _feeTierThresholds[tier]) {
+            ++tier;
+        }
+    }
+
+    function _recordVolume(address bettor, uint256 amount) internal {
+        BettorProfile storage profile = _bettorProfiles[bettor];
+        uint256 elapsed = block.timestamp - profile.windowStart;
+        if (elapsed >= 2 * VOLUME_WINDOW) {
+            profile.previousVolume = 0;
+            profile.currentVolume = 0;
+            profile.windowStart = uint64(block.timestamp);
+        } else if (elapsed >= VOLUME_WINDOW) {
+            profile.previousVolume = profile.currentVolume;
+            profile.currentVolume = 0;
+            profile.windowStart += VOLUME_WINDOW;
+        }
+        profile.currentVolume += amount.toUint128();
+        _refreshFeeTier(bettor);
+    }
+
+    function _refreshFeeTier(address bettor) internal returns (uint8 tier) {
+        tier = _feeTierOf(bettor);
+        uint8 oldTier = _bettorProfiles[bettor].feeTier;
+        if (tier != oldTier) {
+            _bettorProfiles[bettor].feeTier = tier;
+            emit FeeTierChanged(bettor, oldTier, tier);
+        }
+    }
+
+    /// @dev The bettor's pro-rata slice of the platform fee, scaled by their tier's discount
+    function _feeDiscount(bytes32 matchId, MatchPool storage pool, address bettor, uint128 stake)
+        internal
+        returns (uint256 discount)
+    {
+        // Fees are only withdrawn once every winner has claimed, so the share is still here —
+        // unless the result was overturned, where the old winners' discounts already used it
+        uint8 tier = _refreshFeeTier(bettor);
+        if (tier == 0 || _overturnedWinners[matchId] != MatchWinner.None) return 0;
+
+        discount = BpsMath.toBps(_feeTierDiscountBps[tier - 1])
+            .applyTo(_platformFee(matchId, pool) * stake / _winningSideTotal(pool).raw());
+        if (discount == 0) return 0;
+
+        _feeDiscounts[matchId] += discount;
+        emit FeeDiscountApplied(matchId, bettor, discount);
+    }
+
+    /// @dev Match fee less the referral and royalty slices reserved for their claimants
+    function _platformFee(bytes32 matchId, MatchPool storage pool) internal view returns (uint256) {
+        uint256 fee = Settlement.fee(_settlementPool(matchId, pool));
+        // Referral slice stays in the contract for claimReferralRewards
+        ReferralPool storage referral = _referralPools[matchId];
+        fee -= _referralReward(referral.referredVolume, pool.feeBps, referral.shareBps);
+        // Both owners' royalty halves are reserved for claimFighterRoyalty
+        return fee - 2 * _royaltyShare(pool, _royaltyPools[matchId].shareBps);
+    }
+
+    /// @dev A debit of more than spendLimitBps of the match's pool needs a guardian approval
+    ///      for that account, used up by the debit
+    function _enforceSpendLimit(
+        bytes32 matchId,
+        MatchPool storage pool,
+        address account,
+        uint256 amount
+    ) internal {
+        if (_spendLimitBps == 0) return;
+        uint256 limit = BpsMath.toBps(_spendLimitBps).applyTo(_poolTotal(pool).raw());
+        if (amount <= limit) return;
+        if (!_largeDebitApprovals[matchId][account]) revert SpendLimitExceeded(limit);
+        delete _largeDebitApprovals[matchId][account];
+    }
+
+    function _markSettled(bytes32 matchId, BetInfo storage bet) internal {
+        bet.claimed = true;
+        _settledStakes[matchId][bet.side] += bet.amount;
+    }
+
+    function _checkDelegate(address bettor) internal view {
+        if (msg.sender != bettor && _claimDelegates[bettor] != msg.sender) {
+            revert NotClaimDelegate();
+        }
+    }
+
+    /// @dev Bettor address standing in for a position token — no key can sign for it, so
+    ///      the bet is only reachable through redeemPosition or sweeps
+    function _positionKey(uint256 tokenId) internal pure returns (address) {
+        return address(uint160(uint256(keccak256(abi.encode("rawl.position", tokenId)))));
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Match creation
+    // ──────────────────────────────────────────────
+    function _createMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        MatchCategory category
+    ) internal {
+        _createMatchFor(
+            msg.sender,
+            matchId,
+            fighterA,
+            fighterB,
+            minBet,
+            bettingWindow,
+            category,
+            MatchStatus.Open
+        );
+    }
+
+    /// @dev `creator` owns the open-match slot and the bond — the caller, or a schedule's owner
+    function _createMatchFor(
+        address creator,
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        MatchCategory category,
+        MatchStatus initial
+    ) internal {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.None) revert MatchAlreadyExists();
+        _requireTransition(pool, initial, MatchStateMachine.Actor.Creator);
+        // A fighter can't face itself, and address(0) is never a real fighter
+        if (fighterA == fighterB || fighterA == address(0) || fighterB == address(0)) {
+            revert InvalidFighters();
+        }
+        if (_fighters[fighterA].owner == address(0) || _fighters[fighterB].owner == address(0)) {
+            revert FighterNotRegistered();
+        }
+        if (_fighterCollection != address(0)) {
+            _verifyFighterToken(fighterA);
+            _verifyFighterToken(fighterB);
+        }
+
+        uint32 open = _openMatchCounts[creator];
+        if (_maxOpenMatchesPerCreator > 0 && open >= _maxOpenMatchesPerCreator) {
+            revert TooManyOpenMatches(_maxOpenMatchesPerCreator);
+        }
+        _openMatchCounts[creator] = open + 1;
+        _matchCreators[matchId] = creator;
+
+        if (_creatorBond > 0) _creatorBonds[matchId] = _creatorBond;
+
+        pool.fighterA = fighterA;
+        pool.fighterB = fighterB;
+        pool.status = initial;
+        pool.feeBps = _feeBps; // snapshot from global config
+        pool.createdAt = uint64(block.timestamp);
+        pool.minBet = minBet;
+        pool.bettingWindow = bettingWindow;
+        _referralPools[matchId].shareBps = _referralShareBps; // snapshot like feeBps
+        _royaltyPools[matchId].shareBps = _royaltyShareBps;
+        _matchCategories[matchId] = category;
+
+        emit MatchCreated(matchId, fighterA, fighterB, minBet, bettingWindow, _feeBps, category);
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Bet placement
+    // ──────────────────────────────────────────────
+    function _placeBet(bytes32 matchId, uint8 side, address bettor, uint256 amount) internal {
+        if (side > 1) revert InvalidSide();
+        if (amount == 0) revert ZeroBetAmount();
+        if (block.timestamp < _bettingResumesAt) revert UnpauseGracePeriodActive(_bettingResumesAt);
+        // The funding wallet is screened — position bets are keyed by a token, not a person
+        _screen(msg.sender);
+
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Open) revert MatchNotOpen();
+
+        if (pool.minBet > 0 && amount < pool.minBet) {
+            revert BetBelowMinimum(pool.minBet);
+        }
+        if (pool.bettingWindow > 0 && block.timestamp > pool.createdAt + pool.bettingWindow) {
+            revert BettingWindowClosed();
+        }
+
+        MatchSchedule storage schedule = _schedules[matchId];
+        if (block.timestamp < schedule.opensAt) revert BettingNotOpen(schedule.opensAt);
+        // Closed from scheduledLockAt even if nobody has called lockExpired yet
+        if (schedule.scheduledLockAt != 0 && block.timestamp >= schedule.scheduledLockAt) {
+            revert BettingWindowClosed();
+        }
+
+        (Wei sideATotal, Wei sideBTotal) = _sideTotals(matchId);
+        uint256 poolBefore = sideATotal.raw() + sideBTotal.raw();
+        if (_maxPoolSize > 0 && poolBefore + amount > _maxPoolSize) {
+            revert PoolCapExceeded(_maxPoolSize);
+        }
+        if (block.timestamp < _vetoDeadlines[matchId] && poolBefore + amount > _vetoBetCap) {
+            revert VetoWindowCapExceeded(_vetoBetCap);
+        }
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount > 0) revert AlreadyBet();
+
+        if (_snipeThresholdBps > 0) _extendIfSniped(matchId, pool, amount, poolBefore);
+        // Only a wallet's own bets count — positions, seeds and gifts are keyed elsewhere
+        if (bettor == msg.sender) {
+            if (!_hasBet[bettor]) _recordFirstBet(matchId, bettor, amount);
+            _recordVolume(bettor, amount);
+        }
+
+        bet.amount = amount.toUint128();
+        bet.side = side;
+        BetOdds memory odds =
+            BetOdds({sideATotal: Wei.unwrap(sideATotal), sideBTotal: Wei.unwrap(sideBTotal)});
+        _betOdds[matchId][bettor] = odds;
+
+        // Stake goes to the shards round-robin rather than into MatchPool, so consecutive
+        // bets don't all rewrite the same totals slot; _consolidateShards folds them back in
+        // when the match leaves Open
+        PoolShard storage shard = _poolShards[matchId][pool.betCount % POOL_SHARDS];
+        if (side == 0) {
+            shard.sideATotal = shard.sideATotal.add(WeiMath.toWei(amount));
+            unchecked { pool.sideABetCount++; }
+        } else {
+            shard.sideBTotal = shard.sideBTotal.add(WeiMath.toWei(amount));
+            unchecked { pool.sideBBetCount++; }
+        }
+        unchecked {
+            pool.betCount++;
+            _metrics.betsPlaced++;
+        }
+
+        if (amount >= _eventLogBetThreshold) {
+            eventLogs[matchId].push(EventLog.Kind.Bet, side, amount.toUint128());
+        }
+
+        emit BetPlaced(
+            matchId,
+            bettor,
+            side,
+            amount,
+            odds.sideATotal,
+            odds.sideBTotal
+        );
+    }
+
+    /// @dev True once a created match stops taking bets, whether or not lockExpired has run
+    function _bettingClosed(bytes32 matchId) internal view returns (bool) {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status == MatchStatus.None) return false;
+        if (pool.status != MatchStatus.Open) return true;
+
+        uint64 lockAt = _schedules[matchId].scheduledLockAt;
+        return (pool.bettingWindow > 0 && block.timestamp > pool.createdAt + pool.bettingWindow)
+            || (lockAt != 0 && block.timestamp >= lockAt);
+    }
+
+    function _boundedTimeout(uint64 timeout) internal pure returns (uint64) {
+        if (timeout < MIN_TIMEOUT || timeout > MAX_TIMEOUT) {
+            revert TimeoutOutOfBounds(MIN_TIMEOUT, MAX_TIMEOUT);
+        }
+        return timeout;
+    }
+
+    /// @dev Called once per match as it leaves Open/Locked for Resolved or Cancelled
+    function _releaseCreatorSlot(bytes32 matchId) internal {
+        unchecked { _openMatchCounts[_matchCreators[matchId]]--; }
+    }
+
+    function _screen(address account) internal view {
+        if (
+            _screeningOracle != address(0)
+                && IScreeningOracle(_screeningOracle).isSanctioned(account)
+        ) {
+            revert AddressSanctioned(account);
+        }
+    }
+
+    /// @dev Measured against the pool before this bet lands. Extends whichever deadline the
+    ///      match uses — bettingWindow or scheduledLockAt — up to maxSnipeExtension in total
+    function _extendIfSniped(
+        bytes32 matchId,
+        MatchPool storage pool,
+        uint256 amount,
+        uint256 poolBefore
+    ) internal {
+        MatchSchedule storage schedule = _schedules[matchId];
+        uint256 closesAt;
+        if (pool.bettingWindow > 0) {
+            closesAt = uint256(pool.createdAt) + pool.bettingWindow;
+        } else if (schedule.scheduledLockAt != 0) {
+            closesAt = schedule.scheduledLockAt;
+        } else {
+            return; // no deadline to snipe
+        }
+        if (block.timestamp + _snipeWindow < closesAt) return;
+
+        if (amount * 10_000 < poolBefore * _snipeThresholdBps) return;
+
+        uint64 used = _snipeExtensions[matchId];
+        // setAntiSnipe may have lowered the cap below what this match already used
+        if (used >= _maxSnipeExtension) return;
+        uint64 extension = _snipeExtension;
+        if (used + extension > _maxSnipeExtension) extension = _maxSnipeExtension - used;
+        if (extension == 0) return;
+
+        _snipeExtensions[matchId] = used + extension;
+        if (pool.bettingWindow > 0) {
+            pool.bettingWindow += extension;
+        } else {
+            schedule.scheduledLockAt += extension;
+        }
+
+        emit BettingWindowExtended(matchId, uint64(closesAt) + extension);
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Fighter records
+    // ──────────────────────────────────────────────
+    /// @dev Ownership is checked at creation time — the token may have moved since linking
+    function _verifyFighterToken(address fighter) internal view {
+        FighterToken storage token = _fighterTokens[fighter];
+        if (!token.linked) revert FighterTokenNotOwned(fighter);
+
+        try IERC721(_fighterCollection).ownerOf(token.tokenId) returns (address holder) {
+            if (holder != _fighters[fighter].owner) revert FighterTokenNotOwned(fighter);
+        } catch {
+            revert FighterTokenNotOwned(fighter);
+        }
+    }
+
+    function _recordResult(MatchPool storage pool, uint8 winner) internal {
+        FighterRecord storage a = _fighters[pool.fighterA];
+        FighterRecord storage b = _fighters[pool.fighterB];
+        uint128 volume = Wei.unwrap(_poolTotal(pool));
+
+        if (winner == 0) {
+            unchecked { a.wins++; b.losses++; }
+        } else {
+            unchecked { b.wins++; a.losses++; }
+        }
+        a.totalVolume += volume;
+        b.totalVolume += volume;
+
+        emit FighterRecordUpdated(pool.fighterA, a.wins, a.losses, a.draws);
+        emit FighterRecordUpdated(pool.fighterB, b.wins, b.losses, b.draws);
+    }
+
+    /// @dev Move one win and one loss between the fighters when a result is overturned
+    function _reverseResult(MatchPool storage pool, uint8 newWinner) internal {
+        FighterRecord storage a = _fighters[pool.fighterA];
+        FighterRecord storage b = _fighters[pool.fighterB];
+
+        if (newWinner == 0) {
+            a.wins++;
+            b.losses++;
+            b.wins--;
+            a.losses--;
+        } else {
+            b.wins++;
+            a.losses++;
+            a.wins--;
+            b.losses--;
+        }
+
+        emit FighterRecordUpdated(pool.fighterA, a.wins, a.losses, a.draws);
+        emit FighterRecordUpdated(pool.fighterB, b.wins, b.losses, b.draws);
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal: Payout math
+    // ──────────────────────────────────────────────
+    function _calculatePayout(bytes32 matchId, MatchPool storage pool, uint128 betAmount)
+        internal
+        view
+        returns (uint256)
+    {
+        return Settlement.payout(_settlementPool(matchId, pool), betAmount);
+    }
+
+    /// @dev Winning payout, or the loss-floor rebate for a losing bet. Decrements
+    ///      winningBetCount for winners, so callers must mark the bet claimed
+    function _claimableAmount(bytes32 matchId, MatchPool storage pool, BetInfo storage bet)
+        internal
+        returns (uint256)
+    {
+        uint8 winningSide = pool.winner == MatchWinner.SideA ? 0 : 1;
+        if (bet.side == winningSide) {
+            unchecked { pool.winningBetCount--; }
+            return _calculatePayout(matchId, pool, bet.amount);
+        }
+
+        // With no winners the whole side is refunded via refundNoWinners instead
+        Wei winningSideTotal = winningSide == 0 ? pool.sideATotal : pool.sideBTotal;
+        uint256 rebate = Settlement.lossRebate(_settlementPool(matchId, pool), bet.amount);
+        if (rebate == 0 || winningSideTotal.isZero()) revert BetOnLosingSide();
+        return rebate;
+    }
+
+    function _settlementPool(bytes32 matchId, MatchPool storage pool)
+        internal
+        view
+        returns (Settlement.Pool memory)
+    {
+        return Settlement.Pool({
+            strategy: _settlementStrategies[matchId],
+            sideATotal: pool.sideATotal,
+            sideBTotal: pool.sideBTotal,
+            feeBps: BpsMath.toBps(pool.feeBps),
+            winningSide: pool.winner == MatchWinner.SideB ? 1 : 0,
+            lossFloorBps: BpsMath.toBps(_lossFloors[matchId])
+        });
+    }
+
+    /// @dev Per-referrer floors sum to at most the pool-wide figure, so the reserve always
+    ///      covers claims
+    function _referralReward(uint128 volume, uint16 poolFeeBps, uint16 shareBps)
+        internal
+        pure
+        returns (uint256)
+    {
+        return (uint256(volume) * poolFeeBps * shareBps) / (10_000 * 10_000);
+    }
+
+    /// @dev Royalty owed to ONE fighter owner — the fee slice is split evenly between both sides
+    function _royaltyShare(MatchPool storage pool, uint16 shareBps)
+        internal
+        view
+        returns (uint256)
+    {
+        uint256 totalPool = _poolTotal(pool).raw();
+        return (totalPool * pool.feeBps * shareBps) / (10_000 * 10_000 * 2);
+    }
+
+    /// @dev Both sides' stake together. Only MatchPool's fields, so for a match that has left
+    ///      Open; use _livePoolTotal while bets may still sit in the shards
+    function _poolTotal(MatchPool storage pool) internal view returns (Wei) {
+        return pool.sideATotal.add(pool.sideBTotal);
+    }
+
+    function _livePoolTotal(bytes32 matchId) internal view returns (Wei) {
+        (Wei sideATotal, Wei sideBTotal) = _sideTotals(matchId);
+        return sideATotal.add(sideBTotal);
+    }
+
+    /// @dev MatchPool's side totals plus whatever is still in the shards — the whole pool at
+    ///      any status. Past Open the shards are empty and this is MatchPool alone
+    function _sideTotals(bytes32 matchId) internal view returns (Wei sideATotal, Wei sideBTotal) {
+        MatchPool storage pool = _matches[matchId];
+        sideATotal = pool.sideATotal;
+        sideBTotal = pool.sideBTotal;
+        PoolShard[POOL_SHARDS] storage shards = _poolShards[matchId];
+        for (uint256 i; i < POOL_SHARDS; ++i) {
+            sideATotal = sideATotal.add(shards[i].sideATotal);
+            sideBTotal = sideBTotal.add(shards[i].sideBTotal);
+        }
+    }
+
+    /// @dev Folds the shards into MatchPool's totals and clears them. Runs whenever a match
+    ///      leaves Open (and before an Open cash-out takes its stake back out), so everything
+    ///      from lock on reads MatchPool alone
+    function _consolidateShards(bytes32 matchId, MatchPool storage pool) internal {
+        (pool.sideATotal, pool.sideBTotal) = _sideTotals(matchId);
+        delete _poolShards[matchId];
+    }
+
+    /// @dev Stake on the side that won, zero when nobody backed it
+    function _winningSideTotal(MatchPool storage pool) internal view returns (Wei) {
+        return pool.winner == MatchWinner.SideA ? pool.sideATotal : pool.sideBTotal;
+    }
+}
@@ -0,0 +1,518 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@openzeppelin/contracts/access/AccessControl.sol";
+import "@openzeppelin/contracts/utils/ReentrancyGuard.sol";
+import "@openzeppelin/contracts/utils/Pausable.sol";
+import "@openzeppelin/contracts/utils/math/SafeCast.sol";
+
+/// @title SeasonFutures — season-long multi-outcome pools (e.g. "which stable wins the season")
+/// @notice Markets stay open for weeks, outcomes are knocked out at checkpoints as the season
+///         progresses, and the final standings settle the pool parimutuel-style. Kept apart from
+///         RawlBetting because futures have no lock/timeout cycle and more than two sides
+contract SeasonFutures is AccessControl, ReentrancyGuard, Pausable {
+    // ──────────────────────────────────────────────
+    // Roles
+    // ──────────────────────────────────────────────
+    bytes32 public constant ORACLE_ROLE = keccak256("ORACLE_ROLE");
+    bytes32 public constant ADMIN_ROLE = keccak256("ADMIN_ROLE");
+
+    // ──────────────────────────────────────────────
+    // Constants
+    // ──────────────────────────────────────────────
+    uint16 public constant MAX_FEE_BPS = 1000; // 10% hard ceiling
+    uint8 public constant MAX_OUTCOMES = 32; // fits the eliminated bitmask
+    uint64 public constant SETTLEMENT_GRACE = 14 days; // after seasonEndsAt, then cancellable
+    uint8 public constant MAX_TIERS = 3; // 1st / 2nd / 3rd place
+    uint64 public constant CLAIM_WINDOW = 180 days; // after settlement, then sweepable
+
+    // ──────────────────────────────────────────────
+    // Enums
+    // ──────────────────────────────────────────────
+    enum MarketStatus { None, Open, Settled, Cancelled }
+    enum MarketKind { Season, MethodOfVictory }
+    /// @dev Outcome index of a method-of-victory market
+    enum VictoryMethod { KO, Submission, Decision }
+
+    // ──────────────────────────────────────────────
+    // Structs (gas-optimized packed storage)
+    // ──────────────────────────────────────────────
+    struct Market {
+        // Slot 1 (packed: 8+8+8+1+1+1+2+1+1 = 31 bytes)
+        uint64 seasonId; // backend season (quarterly ELO reset period)
+        uint64 closesAt; // last moment bets are accepted
+        uint64 seasonEndsAt; // settlement allowed from here
+        uint8 outcomeCount;
+        uint8 winningOutcome;
+        MarketStatus status;
+        uint16 feeBps; // snapshot at creation
+        bool feesWithdrawn;
+        bool swept; // unclaimed remainder sent to treasury, claims closed
+        // Slot 2 (packed: 16+4+8 = 28 bytes)
+        uint128 totalPool;
+        uint32 eliminated; // bitmask of outcomes knocked out at checkpoints
+        uint64 settledAt; // CLAIM_WINDOW runs from here
+    }
+
+    // ──────────────────────────────────────────────
+    // State
+    // ──────────────────────────────────────────────
+    address public treasury;
+    uint16 public feeBps;
+
+    mapping(bytes32 => Market) public markets;
+    mapping(bytes32 => mapping(uint8 => uint128)) public outcomeTotals;
+    mapping(bytes32 => mapping(address => mapping(uint8 => uint128))) public stakes;
+    mapping(bytes32 => mapping(address => uint128)) public totalStaked;
+    mapping(bytes32 => mapping(address => bool)) public claimed;
+    mapping(bytes32 => uint128) public paidOut; // claims paid per settled market
+
+    mapping(bytes32 => MarketKind) public marketKinds;
+    mapping(bytes32 => bytes32) public propMatches; // method market → RawlBetting matchId
+
+    // Tiered markets (battle royale): net pool split across placings by weight
+    mapping(bytes32 => uint16[]) internal tierWeights; // bps per place, sums to 10_000
+    mapping(bytes32 => uint8[]) internal placings; // oracle's ordered result, 1st first
+
+    // ──────────────────────────────────────────────
+    // Custom Errors
+    // ──────────────────────────────────────────────
+    error MarketAlreadyExists();
+    error MarketNotOpen();
+    error MarketNotSettled();
+    error MarketNotCancelled();
+    error InvalidOutcome();
+    error InvalidOutcomeCount();
+    error InvalidSchedule();
+    error OutcomeEliminated();
+    error ZeroBetAmount();
+    error BettingClosed();
+    error SeasonNotEnded();
+    error SettlementGraceNotElapsed();
+    error NoStakeFound();
+    error AlreadyClaimed();
+    error NothingToClaim();
+    error FeesAlreadyWithdrawn();
+    error InvalidFeeBps();
+    error TransferFailed();
+    error InvalidTierWeights();
+    error RankingRequired();
+    error NotTieredMarket();
+    error InvalidRanking();
+    error WrongMarketKind();
+    error ClaimWindowNotElapsed();
+    error AlreadySwept();
+
+    // ──────────────────────────────────────────────
+    // Events
+    // ──────────────────────────────────────────────
+    event MarketCreated(
+        bytes32 indexed marketId,
+        uint64 indexed seasonId,
+        uint8 outcomeCount,
+        uint64 closesAt,
+        uint64 seasonEndsAt,
+        uint16 feeBps
+    );
+    event FutureBetPlaced(
+        bytes32 indexed marketId, address indexed bettor, uint8 outcome, uint256 amount
+    );
+    event OutcomesEliminated(bytes32 indexed marketId, uint32 eliminated);
+    event MarketSettled(bytes32 indexed marketId, uint8 winningOutcome, uint128 totalPool);
+    event MethodMarketCreated(bytes32 indexed marketId, bytes32 indexed matchId, uint64 closesAt);
+    event MethodResolved(bytes32 indexed marketId, VictoryMethod method, uint128 totalPool);
+    event TiersConfigured(bytes32 indexed marketId, uint16[] weightsBps);
+    event MarketSettledRanked(bytes32 indexed marketId, uint8[] ranking, uint128 totalPool);
+    event MarketCancelled(bytes32 indexed marketId);
+    event FuturePayoutClaimed(bytes32 indexed marketId, address indexed bettor, uint256 amount);
+    event FutureRefunded(bytes32 indexed marketId, address indexed bettor, uint256 amount);
+    event FeesWithdrawn(bytes32 indexed marketId, uint256 amount, address treasury);
+    event UnclaimedSwept(bytes32 indexed marketId, uint256 amount, address treasury);
+    event ConfigUpdated(uint16 feeBps, address treasury);
+
+    // ──────────────────────────────────────────────
+    // Constructor
+    // ──────────────────────────────────────────────
+    constructor(address admin, address oracle, address _treasury) {
+        _grantRole(DEFAULT_ADMIN_ROLE, admin);
+        _grantRole(ADMIN_ROLE, admin);
+        _grantRole(ORACLE_ROLE, oracle);
+        treasury = _treasury;
+        feeBps = 300; // 3%
+    }
+
+    // ──────────────────────────────────────────────
+    // 1. createMarket
+    // ──────────────────────────────────────────────
+    function createMarket(
+        bytes32 marketId,
+        uint64 seasonId,
+        uint8 outcomeCount,
+        uint64 closesAt,
+        uint64 seasonEndsAt
+    ) external whenNotPaused onlyRole(ORACLE_ROLE) {
+        _createMarket(marketId, seasonId, outcomeCount, closesAt, seasonEndsAt);
+    }
+
+    /// @notice Market paid out across the top `weightsBps.length` finishers, e.g.
+    ///         [6000, 3000, 1000] splits the net pool 60/30/10 between 1st, 2nd and 3rd
+    function createTieredMarket(
+        bytes32 marketId,
+        uint64 seasonId,
+        uint8 outcomeCount,
+        uint64 closesAt,
+        uint64 seasonEndsAt,
+        uint16[] calldata weightsBps
+    ) external whenNotPaused onlyRole(ORACLE_ROLE) {
+        if (weightsBps.length == 0 || weightsBps.length > MAX_TIERS) revert InvalidTierWeights();
+        if (weightsBps.length >= outcomeCount) revert InvalidTierWeights();
+        uint256 sum;
+        for (uint256 i; i < weightsBps.length; ++i) {
+            if (weightsBps[i] == 0) revert InvalidTierWeights();
+            sum += weightsBps[i];
+        }
+        if (sum != 10_000) revert InvalidTierWeights();
+
+        _createMarket(marketId, seasonId, outcomeCount, closesAt, seasonEndsAt);
+        tierWeights[marketId] = weightsBps;
+
+        emit TiersConfigured(marketId, weightsBps);
+    }
+
+    /// @notice Prop market on how a single match ends — KO, Submission or Decision. Bets
+    ///         close at `closesAt` (fight start) and it settles via resolveMethod only
+    function createMethodMarket(bytes32 marketId, bytes32 matchId, uint64 closesAt)
+        external
+        whenNotPaused
+        onlyRole(ORACLE_ROLE)
+    {
+        _createMarket(marketId, 0, uint8(type(VictoryMethod).max) + 1, closesAt, closesAt);
+        marketKinds[marketId] = MarketKind.MethodOfVictory;
+        propMatches[marketId] = matchId;
+
+        emit MethodMarketCreated(marketId, matchId, closesAt);
+    }
+
+    // ──────────────────────────────────────────────
+    // 2. placeBet (repeatable, any live outcome)
+    // ──────────────────────────────────────────────
+    function placeBet(bytes32 marketId, uint8 outcome)
+        external
+        payable
+        whenNotPaused
+        nonReentrant
+    {
+        if (msg.value == 0) revert ZeroBetAmount();
+
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+        if (block.timestamp >= market.closesAt) revert BettingClosed();
+        if (outcome >= market.outcomeCount) revert InvalidOutcome();
+        if (market.eliminated & (uint32(1) << outcome) != 0) revert OutcomeEliminated();
+
+        uint128 amount = SafeCast.toUint128(msg.value);
+        stakes[marketId][msg.sender][outcome] += amount;
+        totalStaked[marketId][msg.sender] += amount;
+        outcomeTotals[marketId][outcome] += amount;
+        market.totalPool += amount;
+
+        emit FutureBetPlaced(marketId, msg.sender, outcome, msg.value);
+    }
+
+    // ──────────────────────────────────────────────
+    // 3. checkpoint (incremental resolution)
+    // ──────────────────────────────────────────────
+    /// @notice Knock out outcomes that can no longer win. Monotonic — eliminations are never
+    ///         undone, and at least one outcome must survive to settle on
+    function checkpoint(bytes32 marketId, uint32 newlyEliminated) external onlyRole(ORACLE_ROLE) {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+        if (marketKinds[marketId] != MarketKind.Season) revert WrongMarketKind();
+        if (newlyEliminated >> market.outcomeCount != 0) revert InvalidOutcome();
+
+        uint32 eliminated = market.eliminated | newlyEliminated;
+        uint32 all = uint32((uint256(1) << market.outcomeCount) - 1);
+        if (eliminated == all) revert InvalidOutcome();
+
+        market.eliminated = eliminated;
+
+        emit OutcomesEliminated(marketId, eliminated);
+    }
+
+    // ──────────────────────────────────────────────
+    // 4. settle (final standings)
+    // ──────────────────────────────────────────────
+    function settle(bytes32 marketId, uint8 winningOutcome) external onlyRole(ORACLE_ROLE) {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+        if (block.timestamp < market.seasonEndsAt) revert SeasonNotEnded();
+        if (winningOutcome >= market.outcomeCount) revert InvalidOutcome();
+        if (market.eliminated & (uint32(1) << winningOutcome) != 0) revert OutcomeEliminated();
+        if (tierWeights[marketId].length > 0) revert RankingRequired();
+        if (marketKinds[marketId] != MarketKind.Season) revert WrongMarketKind();
+
+        market.status = MarketStatus.Settled;
+        market.settledAt = uint64(block.timestamp);
+        market.winningOutcome = winningOutcome;
+
+        emit MarketSettled(marketId, winningOutcome, market.totalPool);
+    }
+
+    /// @notice Settle a tiered market with its finishers in order, one per payout tier
+    function settleRanked(bytes32 marketId, uint8[] calldata ranking)
+        external
+        onlyRole(ORACLE_ROLE)
+    {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+        if (block.timestamp < market.seasonEndsAt) revert SeasonNotEnded();
+        if (tierWeights[marketId].length == 0) revert NotTieredMarket();
+        if (ranking.length != tierWeights[marketId].length) revert InvalidRanking();
+
+        uint32 seen;
+        for (uint256 i; i < ranking.length; ++i) {
+            uint8 outcome = ranking[i];
+            if (outcome >= market.outcomeCount) revert InvalidOutcome();
+            if (market.eliminated & (uint32(1) << outcome) != 0) revert OutcomeEliminated();
+            if (seen & (uint32(1) << outcome) != 0) revert InvalidRanking();
+            seen |= uint32(1) << outcome;
+        }
+
+        market.status = MarketStatus.Settled;
+        market.settledAt = uint64(block.timestamp);
+        market.winningOutcome = ranking[0];
+        placings[marketId] = ranking;
+
+        emit MarketSettledRanked(marketId, ranking, market.totalPool);
+    }
+
+    /// @notice Settle a method-of-victory market; the pool is split over the three buckets
+    ///         exactly like a single-winner season market
+    function resolveMethod(bytes32 marketId, VictoryMethod method)
+        external
+        onlyRole(ORACLE_ROLE)
+    {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+        if (marketKinds[marketId] != MarketKind.MethodOfVictory) revert WrongMarketKind();
+        if (block.timestamp < market.seasonEndsAt) revert SeasonNotEnded();
+
+        market.status = MarketStatus.Settled;
+        market.settledAt = uint64(block.timestamp);
+        market.winningOutcome = uint8(method);
+
+        emit MethodResolved(marketId, method, market.totalPool);
+    }
+
+    function getTiers(bytes32 marketId)
+        external
+        view
+        returns (uint16[] memory weightsBps, uint8[] memory ranking)
+    {
+        return (tierWeights[marketId], placings[marketId]);
+    }
+
+    // ──────────────────────────────────────────────
+    // 5. claim
+    // ──────────────────────────────────────────────
+    /// @notice Winning stake's share of the net pool, or every stake minus fee if nobody
+    ///         backed the winner (mirrors RawlBetting.refundNoWinners)
+    function claim(bytes32 marketId) external nonReentrant {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Settled) revert MarketNotSettled();
+        if (market.swept) revert AlreadySwept();
+        if (totalStaked[marketId][msg.sender] == 0) revert NoStakeFound();
+        if (claimed[marketId][msg.sender]) revert AlreadyClaimed();
+
+        uint256 amount = placings[marketId].length > 0
+            ? _tieredPayout(marketId, market, msg.sender)
+            : _winnerPayout(marketId, market, msg.sender);
+        if (amount == 0) revert NothingToClaim();
+
+        claimed[marketId][msg.sender] = true;
+        paidOut[marketId] += SafeCast.toUint128(amount);
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit FuturePayoutClaimed(marketId, msg.sender, amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // 6. cancel / refund
+    // ──────────────────────────────────────────────
+    function cancelMarket(bytes32 marketId) external onlyRole(ADMIN_ROLE) {
+        _cancel(marketId);
+    }
+
+    /// @notice Permissionless cancel once the season is SETTLEMENT_GRACE past its end unsettled
+    function cancelExpired(bytes32 marketId) external {
+        if (block.timestamp < markets[marketId].seasonEndsAt + SETTLEMENT_GRACE) {
+            revert SettlementGraceNotElapsed();
+        }
+        _cancel(marketId);
+    }
+
+    /// @notice Cancelled markets refund every stake in full, no fee
+    function refund(bytes32 marketId) external nonReentrant {
+        if (markets[marketId].status != MarketStatus.Cancelled) revert MarketNotCancelled();
+
+        uint256 amount = totalStaked[marketId][msg.sender];
+        if (amount == 0) revert NoStakeFound();
+        if (claimed[marketId][msg.sender]) revert AlreadyClaimed();
+
+        claimed[marketId][msg.sender] = true;
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit FutureRefunded(marketId, msg.sender, amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // 7. withdrawFees
+    // ──────────────────────────────────────────────
+    function withdrawFees(bytes32 marketId) external onlyRole(ADMIN_ROLE) nonReentrant {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Settled) revert MarketNotSettled();
+        if (market.feesWithdrawn) revert FeesAlreadyWithdrawn();
+        if (market.swept) revert AlreadySwept();
+
+        uint256 amount = _fee(market);
+        market.feesWithdrawn = true;
+
+        (bool success,) = payable(treasury).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit FeesWithdrawn(marketId, amount, treasury);
+    }
+
+    // ──────────────────────────────────────────────
+    // 8. sweepUnclaimed
+    // ──────────────────────────────────────────────
+    /// @notice Once CLAIM_WINDOW has passed since settlement, send whatever the market still
+    ///         holds (unclaimed payouts, rounding dust, and the fee if not yet withdrawn) to
+    ///         the treasury and close claims for good
+    function sweepUnclaimed(bytes32 marketId) external onlyRole(ADMIN_ROLE) nonReentrant {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Settled) revert MarketNotSettled();
+        if (market.swept) revert AlreadySwept();
+        if (block.timestamp < market.settledAt + CLAIM_WINDOW) revert ClaimWindowNotElapsed();
+
+        uint256 amount = market.totalPool - paidOut[marketId];
+        if (market.feesWithdrawn) amount -= _fee(market);
+        market.swept = true;
+        market.feesWithdrawn = true;
+
+        (bool success,) = payable(treasury).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit UnclaimedSwept(marketId, amount, treasury);
+    }
+
+    // ──────────────────────────────────────────────
+    // 9. Config / pause
+    // ──────────────────────────────────────────────
+    function updateConfig(uint16 newFeeBps, address newTreasury) external onlyRole(ADMIN_ROLE) {
+        if (newFeeBps == 0 || newFeeBps > MAX_FEE_BPS) revert InvalidFeeBps();
+        feeBps = newFeeBps;
+        if (newTreasury != address(0)) treasury = newTreasury;
+
+        emit ConfigUpdated(newFeeBps, treasury);
+    }
+
+    function pause() external onlyRole(ADMIN_ROLE) {
+        _pause();
+    }
+
+    function unpause() external onlyRole(ADMIN_ROLE) {
+        _unpause();
+    }
+
+    // ──────────────────────────────────────────────
+    // Internal
+    // ──────────────────────────────────────────────
+    function _createMarket(
+        bytes32 marketId,
+        uint64 seasonId,
+        uint8 outcomeCount,
+        uint64 closesAt,
+        uint64 seasonEndsAt
+    ) internal {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.None) revert MarketAlreadyExists();
+        if (outcomeCount < 2 || outcomeCount > MAX_OUTCOMES) revert InvalidOutcomeCount();
+        if (closesAt <= block.timestamp || seasonEndsAt < closesAt) revert InvalidSchedule();
+
+        market.seasonId = seasonId;
+        market.closesAt = closesAt;
+        market.seasonEndsAt = seasonEndsAt;
+        market.outcomeCount = outcomeCount;
+        market.status = MarketStatus.Open;
+        market.feeBps = feeBps;
+
+        emit MarketCreated(marketId, seasonId, outcomeCount, closesAt, seasonEndsAt, feeBps);
+    }
+
+    function _cancel(bytes32 marketId) internal {
+        Market storage market = markets[marketId];
+        if (market.status != MarketStatus.Open) revert MarketNotOpen();
+
+        market.status = MarketStatus.Cancelled;
+
+        emit MarketCancelled(marketId);
+    }
+
+    function _winnerPayout(bytes32 marketId, Market storage market, address bettor)
+        internal
+        view
+        returns (uint256)
+    {
+        uint128 winningTotal = outcomeTotals[marketId][market.winningOutcome];
+        if (winningTotal == 0) return _noWinnerRefund(marketId, market, bettor);
+
+        uint256 netPool = market.totalPool - _fee(market);
+        uint256 stake = stakes[marketId][bettor][market.winningOutcome];
+        return (netPool * stake) / winningTotal;
+    }
+
+    /// @dev Each placing's weight of the net pool goes pro rata to stakes on that finisher.
+    ///      A placing nobody backed has its weight shared among the backed ones; if none was
+    ///      backed, everyone gets their stake back minus fee as with a single winner
+    function _tieredPayout(bytes32 marketId, Market storage market, address bettor)
+        internal
+        view
+        returns (uint256 amount)
+    {
+        uint8[] storage ranking = placings[marketId];
+        uint16[] storage weights = tierWeights[marketId];
+
+        uint256 backedWeight;
+        for (uint256 i; i < ranking.length; ++i) {
+            if (outcomeTotals[marketId][ranking[i]] > 0) backedWeight += weights[i];
+        }
+        if (backedWeight == 0) return _noWinnerRefund(marketId, market, bettor);
+
+        uint256 netPool = market.totalPool - _fee(market);
+        for (uint256 i; i < ranking.length; ++i) {
+            uint256 stake = stakes[marketId][bettor][ranking[i]];
+            if (stake == 0) continue;
+            amount += (netPool * weights[i] * stake)
+                / (backedWeight * outcomeTotals[marketId][ranking[i]]);
+        }
+    }
+
+    function _noWinnerRefund(bytes32 marketId, Market storage market, address bettor)
+        internal
+        view
+        returns (uint256)
+    {
+        return (uint256(totalStaked[marketId][bettor]) * (10_000 - market.feeBps)) / 10_000;
+    }
+
+    function _fee(Market storage market) internal view returns (uint256) {
+        return (uint256(market.totalPool) * market.feeBps) / 10_000;
+    }
+
+    // No receive() or fallback() — ETH only enters via placeBet
+}
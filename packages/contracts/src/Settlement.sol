@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./Bps.sol";
+
+/// @title Settlement — payout strategies for RawlBetting match pools
+/// @notice Every strategy answers the same questions (fee, winner payout, loss rebate,
+///         no-winner refund, exit and fair value) so handlers never branch on payout mode
+///         themselves. Parimutuel is the only one implemented; the others are reserved and
+///         rejected at match creation
+library Settlement {
+    enum Strategy { Parimutuel, FixedOdds, MerkleRoot, HouseBacked }
+
+    /// @dev Snapshot of the pool fields settlement math depends on
+    struct Pool {
+        Strategy strategy;
+        Wei sideATotal;
+        Wei sideBTotal;
+        Bps feeBps;
+        uint8 winningSide; // 0=SideA, 1=SideB
+        Bps lossFloorBps; // share of a losing stake returned, funded from winner payouts
+    }
+
+    error UnsupportedStrategy(Strategy strategy);
+
+    /// @notice Strategies that can currently be selected at match creation
+    function isSupported(Strategy strategy) internal pure returns (bool) {
+        return strategy == Strategy.Parimutuel;
+    }
+
+    /// @notice Platform fee taken from the pool at resolution
+    function fee(Pool memory pool) internal pure returns (uint256) {
+        if (pool.strategy == Strategy.Parimutuel) {
+            return pool.feeBps.applyTo(pool.sideATotal.add(pool.sideBTotal)).raw();
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+
+    /// @notice Amount owed to a winning stake
+    function payout(Pool memory pool, uint256 stake) internal pure returns (uint256) {
+        if (pool.strategy == Strategy.Parimutuel) {
+            Wei winningSideTotal = pool.winningSide == 0 ? pool.sideATotal : pool.sideBTotal;
+            Wei losingSideTotal = pool.winningSide == 0 ? pool.sideBTotal : pool.sideATotal;
+            Wei total = winningSideTotal.add(losingSideTotal);
+            // The fee and loss-floor rebates come out of the winners' share
+            Wei netPool = total.sub(pool.feeBps.applyTo(total))
+                .sub(pool.lossFloorBps.applyTo(losingSideTotal));
+            return (netPool.raw() * stake) / winningSideTotal.raw();
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+
+    /// @notice Value of a stake leaving an Open pool. Nothing is known about the result yet,
+    ///         so its expected share of the net pool is the stake less the fee whatever the odds
+    function exitValue(Pool memory pool, uint256 stake) internal pure returns (uint256) {
+        if (pool.strategy == Strategy.Parimutuel) {
+            return pool.feeBps.complement().applyTo(stake);
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+
+    /// @notice Value of an unsettled stake on `side` at the pool's implied odds, with P(side
+    ///         wins) taken as the side's share of the pool: P × payout if it wins plus
+    ///         (1 − P) × its loss-floor rebate
+    function fairValue(Pool memory pool, uint8 side, uint256 stake)
+        internal
+        pure
+        returns (uint256)
+    {
+        if (pool.strategy == Strategy.Parimutuel) {
+            uint256 total = pool.sideATotal.raw() + pool.sideBTotal.raw();
+            uint256 sideTotal = (side == 0 ? pool.sideATotal : pool.sideBTotal).raw();
+            pool.winningSide = side;
+            return (payout(pool, stake) * sideTotal
+                + lossRebate(pool, stake) * (total - sideTotal)) / total;
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+
+    /// @notice Amount returned to a losing stake under the pool's loss floor
+    function lossRebate(Pool memory pool, uint256 stake) internal pure returns (uint256) {
+        if (pool.strategy == Strategy.Parimutuel) {
+            return pool.lossFloorBps.applyTo(stake);
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+
+    /// @notice Amount returned to a stake when nobody backed the winning side (fee still applies)
+    function noWinnerRefund(Pool memory pool, uint256 stake) internal pure returns (uint256) {
+        if (pool.strategy == Strategy.Parimutuel) {
+            return pool.feeBps.complement().applyTo(stake);
+        }
+        revert UnsupportedStrategy(pool.strategy);
+    }
+}
@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@openzeppelin/contracts/access/Ownable.sol";
+import {ITreasuryHook} from "./RawlBettingBase.sol";
+
+/// @notice Uniswap V3 SwapRouter02 exact-input swap — ETH is wrapped when tokenIn is WETH9
+interface ISwapRouter02 {
+    struct ExactInputSingleParams {
+        address tokenIn;
+        address tokenOut;
+        uint24 fee;
+        address recipient;
+        uint256 amountIn;
+        uint256 amountOutMinimum;
+        uint160 sqrtPriceLimitX96;
+    }
+
+    function exactInputSingle(ExactInputSingleParams calldata params)
+        external
+        payable
+        returns (uint256 amountOut);
+}
+
+/// @title TreasuryDiversifier — RawlBetting treasury hook that swaps fee ETH into USDC
+/// @notice Installed with RawlBetting.setTreasuryHook. Every fee withdrawal forwards its hook
+///         share here, which is swapped on a Uniswap V3 pool and paid straight to usdcTreasury.
+///         A swap below the configured price floor reverts, and RawlBetting then sends the
+///         whole withdrawal to the ETH treasury instead
+contract TreasuryDiversifier is ITreasuryHook, Ownable {
+    address public immutable betting;
+    ISwapRouter02 public immutable router;
+    address public immutable weth;
+    address public immutable usdc;
+
+    address public usdcTreasury;
+    uint24 public poolFee = 500; // 0.05% WETH/USDC pool
+    uint256 public minUsdcPerEth; // price floor in USDC base units per 1 ETH, guards slippage
+
+    error OnlyBetting();
+
+    event Diversified(bytes32 indexed matchId, uint256 ethIn, uint256 usdcOut);
+    event SwapConfigUpdated(address usdcTreasury, uint24 poolFee, uint256 minUsdcPerEth);
+
+    constructor(
+        address _betting,
+        ISwapRouter02 _router,
+        address _weth,
+        address _usdc,
+        address _usdcTreasury,
+        uint256 _minUsdcPerEth,
+        address owner
+    ) Ownable(owner) {
+        betting = _betting;
+        router = _router;
+        weth = _weth;
+        usdc = _usdc;
+        usdcTreasury = _usdcTreasury;
+        minUsdcPerEth = _minUsdcPerEth;
+    }
+
+    function onFeesWithdrawn(bytes32 matchId) external payable {
+        if (msg.sender != betting) revert OnlyBetting();
+
+        uint256 usdcOut = router.exactInputSingle{value: msg.value}(
+            ISwapRouter02.ExactInputSingleParams({
+                tokenIn: weth,
+                tokenOut: usdc,
+                fee: poolFee,
+                recipient: usdcTreasury,
+                amountIn: msg.value,
+                amountOutMinimum: (msg.value * minUsdcPerEth) / 1 ether,
+                sqrtPriceLimitX96: 0
+            })
+        );
+
+        emit Diversified(matchId, msg.value, usdcOut);
+    }
+
+    function setSwapConfig(address newUsdcTreasury, uint24 newPoolFee, uint256 newMinUsdcPerEth)
+        external
+        onlyOwner
+    {
+        usdcTreasury = newUsdcTreasury;
+        poolFee = newPoolFee;
+        minUsdcPerEth = newMinUsdcPerEth;
+        emit SwapConfigUpdated(newUsdcTreasury, newPoolFee, newMinUsdcPerEth);
+    }
+}
@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title AccountsModule — bettor-side extras: event log reads, position NFTs, claim delegates,
+///       subscriptions
+contract AccountsModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](13);
+        s[0] = AccountsModule.getEventLog.selector;
+        s[1] = AccountsModule.placeBetAsPosition.selector;
+        s[2] = AccountsModule.redeemPosition.selector;
+        s[3] = AccountsModule.setBetPositions.selector;
+        s[4] = AccountsModule.setClaimDelegate.selector;
+        s[5] = AccountsModule.claimPayoutFor.selector;
+        s[6] = AccountsModule.refundBetFor.selector;
+        s[7] = AccountsModule.refundNoWinnersFor.selector;
+        s[8] = AccountsModule.subscribe.selector;
+        s[9] = AccountsModule.unsubscribe.selector;
+        s[10] = AccountsModule.subscribersOf.selector;
+        s[11] = AccountsModule.pendingSummaries.selector;
+        s[12] = AccountsModule.pushSummaries.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Event log
+    // ──────────────────────────────────────────────
+    /// @notice Last EventLog.SIZE significant events of a match, oldest entry at count % SIZE
+    ///         once the buffer has wrapped
+    function getEventLog(bytes32 matchId) external view returns (EventLog.Buffer memory) {
+        return eventLogs[matchId];
+    }
+
+    // ──────────────────────────────────────────────
+    // Bet positions (optional NFT receipts)
+    // ──────────────────────────────────────────────
+    /// @notice Place a bet whose claim rights live in a BetPositions token minted to the
+    ///         caller. The bet is keyed by the token, not the wallet, so it can only be
+    ///         settled through redeemPosition and does not count as the caller's own bet
+    function placeBetAsPosition(bytes32 matchId, uint8 side)
+        external
+        payable
+        whenNotPaused
+        nonReentrant
+        returns (uint256 tokenId)
+    {
+        if (address(_betPositions) == address(0)) revert PositionsDisabled();
+
+        tokenId = _betPositions.mint(msg.sender);
+        address key = _positionKey(tokenId);
+        _positionMatches[tokenId] = matchId;
+        _positionTokenIds[key] = tokenId;
+
+        _placeBet(matchId, side, key, msg.value);
+
+        emit PositionMinted(matchId, tokenId, msg.sender);
+    }
+
+    /// @notice Burn a position token and settle its bet to the holder — payout or loss
+    ///         rebate once resolved, fee-deducted refund with no winners, full refund if cancelled
+    function redeemPosition(uint256 tokenId) external nonReentrant {
+        if (_betPositions.ownerOf(tokenId) != msg.sender) revert NotPositionOwner();
+
+        bytes32 matchId = _positionMatches[tokenId];
+        address key = _positionKey(tokenId);
+        _betPositions.burn(tokenId);
+
+        MatchPool storage pool = _matches[matchId];
+        uint256 amount;
+        if (pool.status == MatchStatus.Cancelled) {
+            amount = _refundBet(matchId, key, msg.sender);
+        } else if (pool.status == MatchStatus.Resolved && _winningSideTotal(pool).isZero()) {
+            amount = _refundNoWinners(matchId, key, msg.sender);
+        } else {
+            amount = _claimPayout(matchId, key, msg.sender);
+        }
+
+        emit PositionRedeemed(tokenId, msg.sender, amount);
+    }
+
+    /// @notice One-time wiring of the position NFT — keys derive from token ids, so the
+    ///         contract can't be swapped once positions exist
+    function setBetPositions(address positions) external onlyRole(ADMIN_ROLE) {
+        if (address(_betPositions) != address(0)) revert BetPositionsAlreadySet();
+        _betPositions = BetPositions(positions);
+        emit ConfigUpdated(ConfigField.BetPositions, uint256(uint160(positions)));
+    }
+
+    // ──────────────────────────────────────────────
+    // Claim delegates
+    // ──────────────────────────────────────────────
+    /// @notice Let `delegate` (e.g. a mobile session key) trigger claims and refunds for the
+    ///         caller. Proceeds always go to the caller; address(0) revokes
+    function setClaimDelegate(address delegate) external {
+        _claimDelegates[msg.sender] = delegate;
+        emit ClaimDelegateSet(msg.sender, delegate);
+    }
+
+    function claimPayoutFor(bytes32 matchId, address bettor) external nonReentrant {
+        _checkDelegate(bettor);
+        _claimPayout(matchId, bettor, bettor);
+    }
+
+    function refundBetFor(bytes32 matchId, address bettor) external nonReentrant {
+        _checkDelegate(bettor);
+        _refundBet(matchId, bettor, bettor);
+    }
+
+    function refundNoWinnersFor(bytes32 matchId, address bettor) external nonReentrant {
+        _checkDelegate(bettor);
+        _refundNoWinners(matchId, bettor, bettor);
+    }
+
+    // ──────────────────────────────────────────────
+    // Observer subscriptions (end-of-match push)
+    // ──────────────────────────────────────────────
+    /// @notice Follow a match without betting on it. Once it settles, the keeper pushes a
+    ///         MatchSummary log addressed to every subscriber
+    function subscribe(bytes32 matchId) external {
+        MatchStatus status = _matches[matchId].status;
+        if (status == MatchStatus.None || _isFinal(status)) revert InvalidMatchStatus();
+        if (subscriberSlots[matchId][msg.sender] != 0) revert AlreadySubscribed();
+        address[] storage list = subscribers[matchId];
+        if (list.length >= MAX_SUBSCRIBERS) revert SubscriptionsFull();
+
+        list.push(msg.sender);
+        subscriberSlots[matchId][msg.sender] = list.length;
+
+        emit Subscribed(matchId, msg.sender);
+    }
+
+    /// @notice Stop following a match. Closed once it settles so the push cursor stays valid
+    function unsubscribe(bytes32 matchId) external {
+        if (_isFinal(_matches[matchId].status)) revert InvalidMatchStatus();
+        uint256 slot = subscriberSlots[matchId][msg.sender];
+        if (slot == 0) revert NotSubscribed();
+
+        // Swap-and-pop: the last subscriber takes the leaving one's slot
+        address[] storage list = subscribers[matchId];
+        address last = list[list.length - 1];
+        list[slot - 1] = last;
+        subscriberSlots[matchId][last] = slot;
+        list.pop();
+        delete subscriberSlots[matchId][msg.sender];
+
+        emit Unsubscribed(matchId, msg.sender);
+    }
+
+    function subscribersOf(bytes32 matchId) external view returns (address[] memory) {
+        return subscribers[matchId];
+    }
+
+    /// @notice Subscribers of a settled match still waiting for their summary
+    function pendingSummaries(bytes32 matchId) external view returns (uint256) {
+        if (!_isFinal(_matches[matchId].status)) return 0;
+        return subscribers[matchId].length - _summaryCursor[matchId];
+    }
+
+    /// @notice Push the end-of-match summary to up to `max` waiting subscribers. Permissionless
+    ///         and resumable, so the keeper can split a long list across transactions
+    function pushSummaries(bytes32 matchId, uint256 max) external returns (uint256 pushed) {
+        MatchPool storage pool = _matches[matchId];
+        if (!_isFinal(pool.status)) revert InvalidMatchStatus();
+
+        address[] storage list = subscribers[matchId];
+        uint256 cursor = _summaryCursor[matchId];
+        uint256 end = cursor + max < list.length ? cursor + max : list.length;
+        for (uint256 i = cursor; i < end; ++i) {
+            emit MatchSummary(
+                matchId,
+                list[i],
+                pool.status,
+                pool.winner,
+                Wei.unwrap(pool.sideATotal),
+                Wei.unwrap(pool.sideBTotal)
+            );
+        }
+        _summaryCursor[matchId] = end;
+        return end - cursor;
+    }
+}
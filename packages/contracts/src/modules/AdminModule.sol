@@ -0,0 +1,317 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title AdminModule — configuration, the fighter registry and the dead-man switch
+contract AdminModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](27);
+        s[0] = AdminModule.updateConfig.selector;
+        s[1] = AdminModule.timeoutBounds.selector;
+        s[2] = AdminModule.setEventLogBetThreshold.selector;
+        s[3] = AdminModule.setParticipationMinimums.selector;
+        s[4] = AdminModule.setAntiSnipe.selector;
+        s[5] = AdminModule.setCashOutDiscountBps.selector;
+        s[6] = AdminModule.setMaxOpenMatchesPerCreator.selector;
+        s[7] = AdminModule.setCreatorBond.selector;
+        s[8] = AdminModule.setMaxMatchAge.selector;
+        s[9] = AdminModule.setClaimCooldown.selector;
+        s[10] = AdminModule.setMinResolutionDelay.selector;
+        s[11] = AdminModule.setResolutionDeadline.selector;
+        s[12] = AdminModule.setTreasuryHook.selector;
+        s[13] = AdminModule.setScreeningOracle.selector;
+        s[14] = AdminModule.setTreasuryRouting.selector;
+        s[15] = AdminModule.setVersions.selector;
+        s[16] = AdminModule.setMaxPoolSize.selector;
+        s[17] = AdminModule.registerFighter.selector;
+        s[18] = AdminModule.linkFighterToken.selector;
+        s[19] = AdminModule.fighterCount.selector;
+        s[20] = AdminModule.setFighterCollection.selector;
+        s[21] = AdminModule.claimFighterRoyalty.selector;
+        s[22] = AdminModule.setRoyaltyShareBps.selector;
+        s[23] = AdminModule.pingAuthority.selector;
+        s[24] = AdminModule.setSuccessor.selector;
+        s[25] = AdminModule.claimAuthority.selector;
+        s[26] = AdminModule.setCashOutMatchCap.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Configuration
+    // ──────────────────────────────────────────────
+    function updateConfig(uint16 newFeeBps, uint64 newTimeout, address newTreasury)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        if (newFeeBps > 0) {
+            if (newFeeBps > MAX_FEE_BPS) revert InvalidFeeBps();
+            _feeBps = newFeeBps;
+            emit ConfigUpdated(ConfigField.FeeBps, uint256(newFeeBps));
+        }
+        if (newTimeout > 0) {
+            _matchTimeout = _boundedTimeout(newTimeout);
+            emit ConfigUpdated(ConfigField.MatchTimeout, uint256(newTimeout));
+        }
+        if (newTreasury != address(0)) {
+            _treasury = newTreasury;
+            emit ConfigUpdated(ConfigField.Treasury, uint256(uint160(newTreasury)));
+        }
+    }
+
+    /// @notice Range updateConfig accepts for matchTimeout
+    function timeoutBounds() external pure returns (uint64 min, uint64 max) {
+        return (MIN_TIMEOUT, MAX_TIMEOUT);
+    }
+
+    function setEventLogBetThreshold(uint128 newThreshold) external onlyRole(ADMIN_ROLE) {
+        _eventLogBetThreshold = newThreshold;
+        emit ConfigUpdated(ConfigField.EventLogBetThreshold, uint256(newThreshold));
+    }
+
+    function setParticipationMinimums(uint32 newMinBetsPerSide, uint128 newMinTotalPool)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        _minBetsPerSide = newMinBetsPerSide;
+        _minTotalPool = newMinTotalPool;
+        emit ConfigUpdated(ConfigField.MinBetsPerSide, uint256(newMinBetsPerSide));
+        emit ConfigUpdated(ConfigField.MinTotalPool, uint256(newMinTotalPool));
+    }
+
+    function setAntiSnipe(
+        uint16 newThresholdBps,
+        uint64 newWindow,
+        uint64 newExtension,
+        uint64 newMaxExtension
+    ) external onlyRole(ADMIN_ROLE) {
+        if (newThresholdBps > 10_000) revert InvalidShareBps();
+        _snipeThresholdBps = newThresholdBps;
+        _snipeWindow = newWindow;
+        _snipeExtension = newExtension;
+        _maxSnipeExtension = newMaxExtension;
+        emit ConfigUpdated(ConfigField.SnipeThresholdBps, uint256(newThresholdBps));
+        emit ConfigUpdated(ConfigField.SnipeWindow, uint256(newWindow));
+        emit ConfigUpdated(ConfigField.SnipeExtension, uint256(newExtension));
+        emit ConfigUpdated(ConfigField.MaxSnipeExtension, uint256(newMaxExtension));
+    }
+
+    function setCashOutDiscountBps(uint16 newDiscountBps) external onlyRole(ADMIN_ROLE) {
+        if (newDiscountBps > 10_000) revert InvalidShareBps();
+        _cashOutDiscountBps = newDiscountBps;
+        emit ConfigUpdated(ConfigField.CashOutDiscountBps, uint256(newDiscountBps));
+    }
+
+    /// @notice Most the cash-out reserve may pay for positions on any one Locked match.
+    ///         0 leaves cash-out to Open matches
+    function setCashOutMatchCap(uint128 newCap) external onlyRole(ADMIN_ROLE) {
+        _cashOutMatchCap = newCap;
+        emit ConfigUpdated(ConfigField.CashOutMatchCap, uint256(newCap));
+    }
+
+    function setMaxOpenMatchesPerCreator(uint32 newMax) external onlyRole(ADMIN_ROLE) {
+        _maxOpenMatchesPerCreator = newMax;
+        emit ConfigUpdated(ConfigField.MaxOpenMatchesPerCreator, uint256(newMax));
+    }
+
+    function setCreatorBond(uint128 newBond) external onlyRole(ADMIN_ROLE) {
+        _creatorBond = newBond;
+        emit ConfigUpdated(ConfigField.CreatorBond, uint256(newBond));
+    }
+
+    /// @notice Never below MAX_TIMEOUT, so a match can always run out its longest resolution
+    ///         deadline before anyone may force-cancel it
+    function setMaxMatchAge(uint64 newMaxMatchAge) external onlyRole(ADMIN_ROLE) {
+        if (newMaxMatchAge < MAX_TIMEOUT) revert InvalidTimeout();
+        _maxMatchAge = newMaxMatchAge;
+        emit ConfigUpdated(ConfigField.MaxMatchAge, uint256(newMaxMatchAge));
+    }
+
+    /// @notice Delay between resolution and the first claim or no-winner refund, giving
+    ///         monitoring and disputes time to react before funds leave the vault
+    function setClaimCooldown(uint64 newCooldown) external onlyRole(ADMIN_ROLE) {
+        if (newCooldown > MAX_CLAIM_COOLDOWN) revert InvalidTimeout();
+        _claimCooldown = newCooldown;
+        emit ConfigUpdated(ConfigField.ClaimCooldown, uint256(newCooldown));
+    }
+
+    /// @notice Time an oracle must wait after lock before attesting a result, so observers
+    ///         can see the fight happen. Kept below the resolution deadline or every match
+    ///         would time out; per-match overrides only ever lengthen that deadline
+    function setMinResolutionDelay(uint64 newDelay) external onlyRole(ADMIN_ROLE) {
+        if (newDelay >= _matchTimeout) revert InvalidTimeout();
+        if (_resolutionDeadline != 0 && newDelay >= _resolutionDeadline) revert InvalidTimeout();
+        _minResolutionDelay = newDelay;
+        emit ConfigUpdated(ConfigField.MinResolutionDelay, uint256(newDelay));
+    }
+
+    /// @notice How long a locked match may wait for its result before timeoutMatch refunds
+    ///         it, separate from matchTimeout (the expected fight length). 0 = matchTimeout
+    function setResolutionDeadline(uint64 newDeadline) external onlyRole(ADMIN_ROLE) {
+        if (newDeadline != 0) {
+            _boundedTimeout(newDeadline);
+            if (newDeadline <= _minResolutionDelay) revert InvalidTimeout();
+        }
+        _resolutionDeadline = newDeadline;
+        emit ConfigUpdated(ConfigField.ResolutionDeadline, uint256(newDeadline));
+    }
+
+    function setTreasuryHook(address newHook, uint16 newShareBps) external onlyRole(ADMIN_ROLE) {
+        if (newShareBps > 10_000) revert InvalidShareBps();
+        _treasuryHook = newHook;
+        _treasuryHookBps = newHook == address(0) ? 0 : newShareBps;
+        emit ConfigUpdated(ConfigField.TreasuryHook, uint256(uint160(newHook)));
+    }
+
+    /// @notice Point bet screening at a sanctions oracle, or address(0) to turn it off
+    function setScreeningOracle(address newOracle) external onlyRole(ADMIN_ROLE) {
+        _screeningOracle = newOracle;
+        emit ConfigUpdated(ConfigField.ScreeningOracle, uint256(uint160(newOracle)));
+    }
+
+    /// @notice Send fee withdrawals, unclaimed sweeps and cash-out haircuts to separate
+    ///         wallets. address(0) routes that operation to `treasury`
+    function setTreasuryRouting(address fees, address sweeps, address dust)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        _treasuryRouting = TreasuryRouting({fees: fees, sweeps: sweeps, dust: dust});
+        emit ConfigUpdated(ConfigField.FeeTreasury, uint256(uint160(fees)));
+        emit ConfigUpdated(ConfigField.SweepTreasury, uint256(uint160(sweeps)));
+        emit ConfigUpdated(ConfigField.DustTreasury, uint256(uint160(dust)));
+    }
+
+    /// @notice Publish the deployed build's version and the oldest client that supports it
+    function setVersions(uint32 newProgramVersion, uint32 newMinClientVersion)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        _programVersion = newProgramVersion;
+        _minClientVersion = newMinClientVersion;
+        emit ConfigUpdated(ConfigField.ProgramVersion, uint256(newProgramVersion));
+        emit ConfigUpdated(ConfigField.MinClientVersion, uint256(newMinClientVersion));
+    }
+
+    function setMaxPoolSize(uint128 newMaxPoolSize) external onlyRole(ADMIN_ROLE) {
+        _maxPoolSize = newMaxPoolSize;
+        emit ConfigUpdated(ConfigField.MaxPoolSize, uint256(newMaxPoolSize));
+    }
+
+    // ──────────────────────────────────────────────
+    // Fighter registry
+    // ──────────────────────────────────────────────
+    function registerFighter(address fighter, bytes32 nameHash, address owner)
+        external
+        whenNotPaused
+        onlyRole(ORACLE_ROLE)
+    {
+        if (owner == address(0)) revert InvalidOwner();
+        if (fighter == address(0)) revert InvalidFighters();
+        FighterRecord storage record = _fighters[fighter];
+        if (record.owner != address(0)) revert FighterAlreadyRegistered();
+
+        record.nameHash = nameHash;
+        record.owner = owner;
+        _fighterList.push(fighter);
+
+        emit FighterRegistered(fighter, owner, nameHash);
+    }
+
+    /// @notice Link a registered fighter to its token in the gating collection
+    function linkFighterToken(address fighter, uint256 tokenId)
+        external
+        whenNotPaused
+        onlyRole(ORACLE_ROLE)
+    {
+        if (_fighters[fighter].owner == address(0)) revert FighterNotRegistered();
+
+        _fighterTokens[fighter] = FighterToken({tokenId: tokenId, linked: true});
+
+        emit FighterTokenLinked(fighter, tokenId);
+    }
+
+    function fighterCount() external view returns (uint256) {
+        return _fighterList.length;
+    }
+
+    function setFighterCollection(address newCollection) external onlyRole(ADMIN_ROLE) {
+        _fighterCollection = newCollection;
+        emit ConfigUpdated(ConfigField.FighterCollection, uint256(uint160(newCollection)));
+    }
+
+    /// @notice Claim the owner royalty for each side of a resolved match owned by the caller
+    function claimFighterRoyalty(bytes32 matchId) external nonReentrant {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+
+        RoyaltyPool storage royalty = _royaltyPools[matchId];
+        uint256 share = _royaltyShare(pool, royalty.shareBps);
+        uint256 amount;
+
+        if (_fighters[pool.fighterA].owner == msg.sender && !royalty.fighterAClaimed) {
+            royalty.fighterAClaimed = true;
+            amount += share;
+        }
+        if (_fighters[pool.fighterB].owner == msg.sender && !royalty.fighterBClaimed) {
+            royalty.fighterBClaimed = true;
+            amount += share;
+        }
+        if (amount == 0) revert NoRoyaltyDue();
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit FighterRoyaltyClaimed(matchId, msg.sender, amount);
+    }
+
+    function setRoyaltyShareBps(uint16 newShareBps) external onlyRole(ADMIN_ROLE) {
+        if (uint256(newShareBps) + _referralShareBps > 10_000) revert InvalidShareBps();
+        _royaltyShareBps = newShareBps;
+        emit ConfigUpdated(ConfigField.RoyaltyShareBps, uint256(newShareBps));
+    }
+
+    // ──────────────────────────────────────────────
+    // Dead-man switch (authority succession)
+    // ──────────────────────────────────────────────
+    /// @notice Heartbeat from the authority. Any call here or to setSuccessor restarts the
+    ///         dead-man period
+    function pingAuthority() external {
+        if (msg.sender != _authority) revert NotAuthority();
+        _lastAuthorityPing = uint64(block.timestamp);
+        emit AuthorityPinged(msg.sender, uint64(block.timestamp));
+    }
+
+    /// @notice Name the key that inherits authority after `period` without a ping.
+    ///         address(0) removes the successor
+    function setSuccessor(address newSuccessor, uint64 period) external {
+        if (msg.sender != _authority) revert NotAuthority();
+        if (period < MIN_DEAD_MAN_PERIOD) revert InvalidDeadManPeriod();
+
+        _successor = newSuccessor;
+        _deadManPeriod = period;
+        _lastAuthorityPing = uint64(block.timestamp);
+
+        emit SuccessorSet(newSuccessor, period);
+        emit AuthorityPinged(msg.sender, uint64(block.timestamp));
+    }
+
+    /// @notice Take over a silent authority: the successor receives DEFAULT_ADMIN_ROLE and
+    ///         ADMIN_ROLE and the old key loses both, so a lost key can't orphan the treasury
+    ///         settings or be used later if it resurfaces in the wrong hands
+    function claimAuthority() external {
+        if (_successor == address(0) || msg.sender != _successor) revert NotSuccessor();
+        uint64 claimableAt = _lastAuthorityPing + _deadManPeriod;
+        if (block.timestamp < claimableAt) revert AuthorityStillActive(claimableAt);
+
+        address previous = _authority;
+        _revokeRole(ADMIN_ROLE, previous);
+        _revokeRole(DEFAULT_ADMIN_ROLE, previous);
+        _grantRole(DEFAULT_ADMIN_ROLE, msg.sender);
+        _grantRole(ADMIN_ROLE, msg.sender);
+
+        _authority = msg.sender;
+        _successor = address(0);
+        _lastAuthorityPing = uint64(block.timestamp);
+
+        emit AuthorityClaimed(previous, msg.sender);
+    }
+}
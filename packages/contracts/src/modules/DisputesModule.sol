@@ -0,0 +1,351 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title DisputesModule — result disputes: challenge bonds, optimistic proposals, the arbitration
+///       committee and post-claim corrections
+contract DisputesModule is RawlBettingModule {
+    using SafeCast for uint256;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](15);
+        s[0] = DisputesModule.amendResolution.selector;
+        s[1] = DisputesModule.overturnResult.selector;
+        s[2] = DisputesModule.recordClawback.selector;
+        s[3] = DisputesModule.repayClawback.selector;
+        s[4] = DisputesModule.challengeResult.selector;
+        s[5] = DisputesModule.adjudicateDispute.selector;
+        s[6] = DisputesModule.proposeResult.selector;
+        s[7] = DisputesModule.challengeProposal.selector;
+        s[8] = DisputesModule.finalizeProposal.selector;
+        s[9] = DisputesModule.arbitrateProposal.selector;
+        s[10] = DisputesModule.refundProposalBonds.selector;
+        s[11] = DisputesModule.setDisputeParams.selector;
+        s[12] = DisputesModule.setCommittee.selector;
+        s[13] = DisputesModule.committeeMembers.selector;
+        s[14] = DisputesModule.castArbitrationVote.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Result correction (clawback ledger)
+    // ──────────────────────────────────────────────
+    /// @notice Correct a mistyped winner within AMEND_WINDOW of resolution, as long as nothing
+    ///         has been paid out at the old result. After that the result only moves through
+    ///         a dispute or overturnResult. Results the oracle didn't enter — feeds, price
+    ///         markets, proposals — can't be amended
+    function amendResolution(bytes32 matchId, uint8 newWinner)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+    {
+        if (newWinner > 1) revert InvalidSide();
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+        uint128[2] storage settled = _settledStakes[matchId];
+        if (
+            block.timestamp >= pool.resolveTimestamp + AMEND_WINDOW || settled[0] > 0
+                || settled[1] > 0 || _overturnedWinners[matchId] != MatchWinner.None
+        ) revert ResultFinal();
+        if (_resolvedExternally(matchId)) revert ResolvedByFeed();
+        _requireMatchOracle(matchId, msg.sender);
+        if (_resultCommitments[matchId] != bytes32(0)) _requireRevealed(matchId, newWinner);
+        if (_resultProposals[matchId].proposer != address(0)) revert ResultProposed();
+        if (_disputes[matchId].challenger != address(0)) revert ResultDisputed();
+        // The game server's final round binds a correction as it bound the original result
+        Attestation storage attested = _attestations[matchId];
+        if (attested.finished && attested.leader != newWinner) {
+            revert AttestationMismatch(attested.leader);
+        }
+        // One resolver can't undo what a quorum or the authority's co-sign agreed on
+        if (_resolutionThreshold > 1 || _needsCosign(matchId)) revert ResultFinal();
+
+        MatchWinner previous = pool.winner;
+        MatchWinner next = newWinner == 0 ? MatchWinner.SideA : MatchWinner.SideB;
+        if (previous == next) revert CannotOverturn();
+
+        pool.winner = next;
+        pool.winningBetCount = newWinner == 0 ? pool.sideABetCount : pool.sideBBetCount;
+        // The corrected result gets a challenge period of its own
+        if (_disputeWindow > 0) {
+            _disputeDeadlines[matchId] = uint64(block.timestamp) + _disputeWindow;
+        }
+        _reverseResult(pool, newWinner);
+
+        emit ResolutionAmended(matchId, previous, next, msg.sender);
+    }
+
+    /// @notice Worst-case tooling: flip a resolved result after payouts were already made. The
+    ///         admin fronts exactly what the old winners were paid so the new winners are fully
+    ///         funded; clawbacks recorded against the old winners repay that backstop. Once per
+    ///         match, and not for loss-floor markets whose rebates were paid to the new winners
+    function overturnResult(bytes32 matchId, uint8 newWinner)
+        external
+        payable
+        onlyRole(ADMIN_ROLE)
+        nonReentrant
+    {
+        if (newWinner > 1) revert InvalidSide();
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+
+        MatchWinner previous = pool.winner;
+        MatchWinner next = newWinner == 0 ? MatchWinner.SideA : MatchWinner.SideB;
+        if (
+            previous == next || _overturnedWinners[matchId] != MatchWinner.None
+                || _lossFloors[matchId] > 0 || pool.sideATotal.isZero() || pool.sideBTotal.isZero()
+        ) revert CannotOverturn();
+
+        // Claimed and swept stakes on the old winning side were paid at the old result
+        uint8 previousSide = previous == MatchWinner.SideA ? 0 : 1;
+        uint256 paid = _calculatePayout(matchId, pool, _settledStakes[matchId][previousSide]);
+        if (msg.value != paid) revert InvalidBackstop(paid);
+
+        _overturnedWinners[matchId] = previous;
+        pool.winner = next;
+        pool.winningBetCount = newWinner == 0 ? pool.sideABetCount : pool.sideBBetCount;
+        pool.resolveTimestamp = uint64(block.timestamp); // new winners get a full claim window
+        _reverseResult(pool, newWinner);
+
+        emit ResultOverturned(matchId, previous, next, paid);
+    }
+
+    /// @notice Record what one old winner was paid before an overturn. Permissionless — the
+    ///         amount is derived from the bet and the replaced result
+    function recordClawback(bytes32 matchId, address bettor) external {
+        MatchWinner previous = _overturnedWinners[matchId];
+        if (previous == MatchWinner.None) revert CannotOverturn();
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        bool paidAtOldResult = bet.claimed && !sweptToTreasury[matchId][bettor]
+            && bet.side == (previous == MatchWinner.SideA ? 0 : 1);
+        if (!paidAtOldResult || _clawbackRecorded[matchId][bettor]) revert NoClawback();
+
+        Settlement.Pool memory snapshot = _settlementPool(matchId, _matches[matchId]);
+        snapshot.winningSide = bet.side;
+        uint256 owed = Settlement.payout(snapshot, bet.amount);
+
+        _clawbackRecorded[matchId][bettor] = true;
+        _clawbacks[matchId][bettor] = owed;
+        _clawbackDebt[bettor] += owed;
+
+        emit ClawbackRecorded(matchId, bettor, owed);
+    }
+
+    /// @notice Voluntarily repay (part of) a clawback. Repayments reimburse the treasury's
+    ///         backstop; payout claims unblock once the wallet's total debt reaches zero
+    function repayClawback(bytes32 matchId) external payable nonReentrant {
+        uint256 owed = _clawbacks[matchId][msg.sender];
+        if (owed == 0 || msg.value == 0 || msg.value > owed) revert NoClawback();
+
+        _clawbacks[matchId][msg.sender] = owed - msg.value;
+        _clawbackDebt[msg.sender] -= msg.value;
+
+        (bool success,) = payable(_treasury).call{value: msg.value}("");
+        if (!success) revert TransferFailed();
+
+        emit ClawbackRepaid(matchId, msg.sender, msg.value);
+    }
+
+    // ──────────────────────────────────────────────
+    // Dispute window (challenge bonds)
+    // ──────────────────────────────────────────────
+    /// @notice Challenge a result during its dispute window by posting disputeBond. One
+    ///         challenge per match; claims, refunds and sweeps stay blocked until adjudicated
+    function challengeResult(bytes32 matchId) external payable nonReentrant {
+        if (_matches[matchId].status != MatchStatus.Resolved) revert MatchNotResolved();
+        if (block.timestamp >= _disputeDeadlines[matchId]) revert DisputeWindowClosed();
+        if (_disputes[matchId].challenger != address(0)) revert ResultDisputed();
+        if (msg.value != _disputeBond) revert InvalidDisputeBond(_disputeBond);
+
+        _disputes[matchId] = Dispute({
+            challenger: msg.sender,
+            raisedAt: uint64(block.timestamp),
+            adjudicated: false,
+            bond: msg.value.toUint128()
+        });
+
+        emit ResultChallenged(matchId, msg.sender, msg.value.toUint128());
+    }
+
+    /// @notice Settle a challenge. Upheld flips the result — nothing was paid out while it
+    ///         was open — and returns the bond; rejected slashes the bond to the treasury.
+    ///         Either way the adjudicated result is final and claims open straight away
+    function adjudicateDispute(bytes32 matchId, bool upheld)
+        external
+        onlyRole(ADMIN_ROLE)
+        nonReentrant
+    {
+        _adjudicateDispute(matchId, upheld);
+    }
+
+    function _adjudicateDispute(bytes32 matchId, bool upheld) internal {
+        Dispute storage dispute = _disputes[matchId];
+        if (dispute.challenger == address(0) || dispute.adjudicated) revert NoDispute();
+
+        dispute.adjudicated = true;
+        delete _disputeDeadlines[matchId];
+
+        if (upheld) {
+            MatchPool storage pool = _matches[matchId];
+            MatchWinner previous = pool.winner;
+            uint8 newWinner = previous == MatchWinner.SideA ? 1 : 0;
+            pool.winner = newWinner == 0 ? MatchWinner.SideA : MatchWinner.SideB;
+            pool.winningBetCount = newWinner == 0 ? pool.sideABetCount : pool.sideBBetCount;
+            _reverseResult(pool, newWinner);
+            emit ResultOverturned(matchId, previous, pool.winner, 0);
+        }
+
+        (bool success,) =
+            payable(upheld ? dispute.challenger : _treasury).call{value: dispute.bond}("");
+        if (!success) revert TransferFailed();
+
+        emit DisputeAdjudicated(matchId, dispute.challenger, upheld);
+    }
+
+    // ──────────────────────────────────────────────
+    // Optimistic resolution (proposer bond)
+    // ──────────────────────────────────────────────
+    /// @notice Propose the result of a locked match by posting disputeBond, so a match can
+    ///         settle without the oracle. Final after disputeWindow unless challenged; the
+    ///         oracle stops resolving the match directly while a proposal is pending
+    function proposeResult(bytes32 matchId, uint8 winner) external payable nonReentrant {
+        if (winner > 1) revert InvalidSide();
+        if (_matches[matchId].status != MatchStatus.Locked) revert MatchNotLocked();
+        if (_resolvedExternally(matchId)) revert ResolvedByFeed();
+        if (_resultCommitments[matchId] != bytes32(0)) _requireRevealed(matchId, winner);
+        if (_disputeWindow == 0) revert DisputeWindowClosed();
+        if (_resultProposals[matchId].proposer != address(0)) revert ResultProposed();
+        if (msg.value != _disputeBond) revert InvalidDisputeBond(_disputeBond);
+
+        uint64 liveUntil = uint64(block.timestamp) + _disputeWindow;
+        _resultProposals[matchId] = ResultProposal({
+            proposer: msg.sender,
+            liveUntil: liveUntil,
+            winner: winner,
+            challenger: address(0),
+            settled: false,
+            bond: msg.value.toUint128()
+        });
+
+        emit ProposalMade(matchId, msg.sender, winner, liveUntil);
+    }
+
+    /// @notice Dispute a live proposal by matching its bond, handing it to arbitration
+    function challengeProposal(bytes32 matchId) external payable nonReentrant {
+        ResultProposal storage proposal = _resultProposals[matchId];
+        if (proposal.proposer == address(0) || proposal.settled) revert NoProposal();
+        if (proposal.challenger != address(0)) revert ResultDisputed();
+        if (block.timestamp >= proposal.liveUntil) revert DisputeWindowClosed();
+        if (msg.value != proposal.bond) revert InvalidDisputeBond(proposal.bond);
+
+        proposal.challenger = msg.sender;
+        emit ProposalChallenged(matchId, msg.sender);
+    }
+
+    /// @notice Resolve with an unchallenged proposal once its window has passed and return
+    ///         the proposer's bond. Permissionless. The window already served as the
+    ///         result's dispute period, so claims open immediately
+    function finalizeProposal(bytes32 matchId) external nonReentrant {
+        ResultProposal storage proposal = _resultProposals[matchId];
+        if (proposal.proposer == address(0) || proposal.settled) revert NoProposal();
+        if (proposal.challenger != address(0)) revert ResultDisputed();
+        if (block.timestamp < proposal.liveUntil) revert ProposalLive(proposal.liveUntil);
+
+        _settleProposal(matchId, proposal, proposal.winner, proposal.proposer);
+    }
+
+    /// @notice Rule on a challenged proposal. The match resolves to `winner` and whichever
+    ///         of proposer and challenger it agrees with receives both bonds
+    function arbitrateProposal(bytes32 matchId, uint8 winner)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+        nonReentrant
+    {
+        ResultProposal storage proposal = _resultProposals[matchId];
+        if (proposal.challenger == address(0) || proposal.settled) revert NoProposal();
+
+        address recipient = winner == proposal.winner ? proposal.proposer : proposal.challenger;
+        _settleProposal(matchId, proposal, winner, recipient);
+    }
+
+    /// @notice Return the bonds of a proposal whose match was cancelled before it settled
+    function refundProposalBonds(bytes32 matchId) external nonReentrant {
+        ResultProposal storage proposal = _resultProposals[matchId];
+        if (proposal.proposer == address(0) || proposal.settled) revert NoProposal();
+        if (_matches[matchId].status != MatchStatus.Cancelled) revert MatchNotCancelled();
+
+        proposal.settled = true;
+        (bool success,) = payable(proposal.proposer).call{value: proposal.bond}("");
+        if (!success) revert TransferFailed();
+        if (proposal.challenger != address(0)) {
+            (success,) = payable(proposal.challenger).call{value: proposal.bond}("");
+            if (!success) revert TransferFailed();
+        }
+    }
+
+    /// @notice Length of the challenge period opened by each resolution and the bond a
+    ///         challenge must post. A zero window turns disputes off
+    function setDisputeParams(uint64 newWindow, uint128 newBond) external onlyRole(ADMIN_ROLE) {
+        if (newWindow > 0 && newBond == 0) revert InvalidDisputeBond(0);
+        _disputeWindow = newWindow;
+        _disputeBond = newBond;
+        emit ConfigUpdated(ConfigField.DisputeWindow, uint256(newWindow));
+        emit ConfigUpdated(ConfigField.DisputeBond, uint256(newBond));
+    }
+
+    // ──────────────────────────────────────────────
+    // Arbitration committee
+    // ──────────────────────────────────────────────
+    /// @notice Seat a new committee. Replacing it voids every vote the old one cast on matches
+    ///         still in arbitration; an empty list disbands it
+    function setCommittee(address[] calldata members) external onlyRole(ADMIN_ROLE) {
+        if (members.length > MAX_COMMITTEE_SIZE) revert InvalidCommittee();
+        uint32 epoch = ++_committeeEpoch;
+        for (uint256 i; i < members.length; ++i) {
+            address member = members[i];
+            if (member == address(0) || _committeeSeats[member] == epoch) revert InvalidCommittee();
+            _committeeSeats[member] = epoch;
+        }
+        committee = members;
+        emit CommitteeSet(epoch, members);
+    }
+
+    function committeeMembers() external view returns (address[] memory) {
+        return committee;
+    }
+
+    /// @notice Vote on the winner of a challenged result or challenged proposal. The first
+    ///         winner backed by a majority of the committee settles it — as adjudicateDispute
+    ///         would (upheld when it differs from the resolved winner) or as arbitrateProposal
+    ///         would — above the word of any single oracle
+    function castArbitrationVote(bytes32 matchId, uint8 winner) external nonReentrant {
+        if (winner > 1) revert InvalidSide();
+        uint32 epoch = _committeeEpoch;
+        if (epoch == 0 || _committeeSeats[msg.sender] != epoch) revert NotCommitteeMember();
+        Dispute storage dispute = _disputes[matchId];
+        ResultProposal storage proposal = _resultProposals[matchId];
+        bool disputed = dispute.challenger != address(0) && !dispute.adjudicated;
+        if (!disputed && (proposal.challenger == address(0) || proposal.settled)) {
+            revert NoDispute();
+        }
+        if (_arbitrationVotes[matchId][msg.sender] == epoch) revert AlreadyVoted();
+        _arbitrationVotes[matchId][msg.sender] = epoch;
+
+        ArbitrationTally storage tally = _arbitrationTallies[matchId];
+        if (tally.epoch != epoch) {
+            _arbitrationTallies[matchId] =
+                ArbitrationTally({epoch: epoch, sideAVotes: 0, sideBVotes: 0});
+        }
+        uint8 votes = winner == 0 ? ++tally.sideAVotes : ++tally.sideBVotes;
+        emit ArbitrationVoteCast(matchId, msg.sender, winner, votes);
+        if (votes <= committee.length / 2) return;
+
+        delete _arbitrationTallies[matchId];
+        if (disputed) {
+            _adjudicateDispute(matchId, winner + 1 != uint8(_matches[matchId].winner));
+        } else {
+            address recipient = winner == proposal.winner ? proposal.proposer : proposal.challenger;
+            _settleProposal(matchId, proposal, winner, recipient);
+        }
+    }
+}
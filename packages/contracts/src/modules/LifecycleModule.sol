@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title LifecycleModule — locking, unlocking, cancellation and the permissionless timeouts
+contract LifecycleModule is RawlBettingModule {
+    using EventLog for EventLog.Buffer;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](12);
+        s[0] = LifecycleModule.lockMatch.selector;
+        s[1] = LifecycleModule.lockMatchWithCommitment.selector;
+        s[2] = LifecycleModule.lockExpired.selector;
+        s[3] = LifecycleModule.unlockMatch.selector;
+        s[4] = LifecycleModule.cancelMatch.selector;
+        s[5] = LifecycleModule.emergencyCancel.selector;
+        s[6] = LifecycleModule.cancelMatchWithOpId.selector;
+        s[7] = LifecycleModule.timeoutMatch.selector;
+        s[8] = LifecycleModule.resolutionDeadlineOf.selector;
+        s[9] = LifecycleModule.setMatchResolutionDeadline.selector;
+        s[10] = LifecycleModule.cancelUnbalanced.selector;
+        s[11] = LifecycleModule.forceCancelExpired.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // lockMatch
+    // ──────────────────────────────────────────────
+    function lockMatch(bytes32 matchId) external onlyRole(LOCK_AUTHORITY_ROLE) {
+        if (_commitRevealRequired) revert CommitmentRequired();
+        if (!_meetsParticipation(matchId)) revert ParticipationBelowMinimum();
+        _lockMatch(matchId, MatchStateMachine.Actor.LockAuthority);
+    }
+
+    /// @notice lockMatch that also seals the result: `commitment` is resultCommitment(matchId,
+    ///         winner, salt), and the match can then only resolve to the winner revealResult opens
+    function lockMatchWithCommitment(bytes32 matchId, bytes32 commitment)
+        external
+        onlyRole(LOCK_AUTHORITY_ROLE)
+    {
+        if (commitment == bytes32(0)) revert NoCommitment();
+        if (!_meetsParticipation(matchId)) revert ParticipationBelowMinimum();
+        _lockMatch(matchId, MatchStateMachine.Actor.LockAuthority);
+        _resultCommitments[matchId] = commitment;
+        emit ResultCommitmentMade(matchId, commitment);
+    }
+
+    /// @notice Permissionless lock once betting has closed — bettingWindow elapsed or
+    ///         scheduledLockAt reached — so a late oracle can't leave the match bettable.
+    ///         Matches below the participation minimums are cancelled instead
+    function lockExpired(bytes32 matchId) external {
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Locked, MatchStateMachine.Actor.Anyone);
+        uint64 lockAt = _schedules[matchId].scheduledLockAt;
+
+        bool windowElapsed =
+            pool.bettingWindow > 0 && block.timestamp > pool.createdAt + pool.bettingWindow;
+        bool scheduleReached = lockAt != 0 && block.timestamp >= lockAt;
+        if (!windowElapsed && !scheduleReached) revert LockNotDue();
+
+        if (!_meetsParticipation(matchId)) {
+            _setCancelled(matchId, pool, CancelReason.Undersubscribed);
+            return;
+        }
+        _lockMatch(matchId, MatchStateMachine.Actor.Anyone);
+    }
+
+    /// @notice Reopen a match locked by mistake, within UNLOCK_WINDOW of the lock and before
+    ///         any result has been attested, proposed or signed. Betting resumes on the same
+    ///         pool; a match whose window has already closed can be re-locked by anyone
+    function unlockMatch(bytes32 matchId) external onlyRole(LOCK_AUTHORITY_ROLE) {
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Open, MatchStateMachine.Actor.LockAuthority);
+        uint64 closedAt = pool.lockTimestamp + UNLOCK_WINDOW;
+        if (block.timestamp >= closedAt) revert UnlockWindowClosed(closedAt);
+        uint8[2] storage votes = resultVotes[matchId];
+        if (votes[0] > 0 || votes[1] > 0 || _oracleResults[matchId] != 0) revert ResultPending();
+        if (_resultProposals[matchId].proposer != address(0)) revert ResultProposed();
+
+        pool.status = MatchStatus.Open;
+        pool.lockTimestamp = 0;
+        delete _oddsSnapshots[matchId];
+        delete _resultCommitments[matchId]; // the next lock commits afresh
+
+        eventLogs[matchId].push(EventLog.Kind.Unlocked, 0, Wei.unwrap(_poolTotal(pool)));
+
+        emit MatchUnlocked(matchId, msg.sender);
+    }
+
+    // ──────────────────────────────────────────────
+    // cancelMatch
+    // ──────────────────────────────────────────────
+    function cancelMatch(bytes32 matchId) external onlyRole(ADMIN_ROLE) {
+        _cancelMatch(matchId, CancelReason.OperatorCancel);
+    }
+
+    /// @notice cancelMatch recorded as an emergency (exploit, oracle compromise, ...) so
+    ///         incident cancels are kept apart from routine ones in SLA reporting
+    function emergencyCancel(bytes32 matchId) external onlyRole(ADMIN_ROLE) {
+        _cancelMatch(matchId, CancelReason.Emergency);
+    }
+
+    /// @notice cancelMatch that no-ops when retried with an already-executed opId
+    function cancelMatchWithOpId(bytes32 matchId, bytes32 opId)
+        external
+        onlyRole(ADMIN_ROLE)
+        idempotent(opId)
+    {
+        _cancelMatch(matchId, CancelReason.OperatorCancel);
+    }
+
+    // ──────────────────────────────────────────────
+    // Timeouts, resolution deadlines and permissionless cancels
+    // ──────────────────────────────────────────────
+    function timeoutMatch(bytes32 matchId) external {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Locked) revert MatchNotLocked();
+        if (block.timestamp < pool.lockTimestamp + resolutionDeadlineOf(matchId)) {
+            revert TimeoutNotElapsed();
+        }
+        // A pending proposal is the match making progress without the oracle
+        if (_resultProposals[matchId].proposer != address(0)) revert ResultProposed();
+
+        _setCancelled(matchId, pool, CancelReason.Timeout);
+    }
+
+    /// @notice Seconds after lock before timeoutMatch can cancel this match: the global
+    ///         deadline, or its own override while that is longer — shortened while the oracle
+    ///         is down. Never below the global, so setMinResolutionDelay's check covers it
+    function resolutionDeadlineOf(bytes32 matchId) public view returns (uint64) {
+        uint64 global = _globalResolutionDeadline();
+        uint64 deadline = _resolutionDeadlines[matchId];
+        return _silentOracleCap(deadline > global ? deadline : global);
+    }
+
+    /// @notice Give one match (a long tournament bracket, say) a longer resolution deadline
+    ///         than the global one, 0 to fall back to it. Open or Locked matches only
+    function setMatchResolutionDeadline(bytes32 matchId, uint64 deadline)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        MatchStatus status = _matches[matchId].status;
+        if (status != MatchStatus.Open && status != MatchStatus.Locked) {
+            revert InvalidMatchStatus();
+        }
+        if (deadline != 0) {
+            _boundedTimeout(deadline);
+            if (deadline <= _globalResolutionDeadline()) revert InvalidTimeout();
+        }
+        _resolutionDeadlines[matchId] = deadline;
+        emit MatchResolutionDeadlineSet(matchId, deadline);
+    }
+
+    /// @notice Permissionless cancel of a locked match with no bets on one side — there is
+    ///         no counterparty pool to pay winners from, so every bettor gets a full refund
+    function cancelUnbalanced(bytes32 matchId) external {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Locked) revert MatchNotLocked();
+        if (!pool.sideATotal.isZero() && !pool.sideBTotal.isZero()) revert PoolBalanced();
+
+        _setCancelled(matchId, pool, CancelReason.EmptySide);
+    }
+
+    /// @notice Permissionless cancel of any Open or Locked match older than maxMatchAge, so
+    ///         funds are refundable even if both the oracle and admin disappear. A locked match
+    ///         still gets its full resolution deadline, however late it locked
+    function forceCancelExpired(bytes32 matchId) external {
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Cancelled, MatchStateMachine.Actor.Anyone);
+        if (block.timestamp < pool.createdAt + _maxMatchAge) revert MatchNotExpired();
+        if (
+            pool.status == MatchStatus.Locked
+                && block.timestamp < pool.lockTimestamp + resolutionDeadlineOf(matchId)
+        ) revert MatchNotExpired();
+
+        _setCancelled(matchId, pool, CancelReason.Expired);
+    }
+}
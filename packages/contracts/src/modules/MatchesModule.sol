@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title MatchesModule — match creation: the createMatch family, community and organizer-run
+///       matches, creator bonds
+contract MatchesModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](18);
+        s[0] = MatchesModule.createMatch.selector;
+        s[1] = MatchesModule.createMatches.selector;
+        s[2] = MatchesModule.createMatchInCategory.selector;
+        s[3] = MatchesModule.createMatchWithLossFloor.selector;
+        s[4] = MatchesModule.createMatchWithMetadata.selector;
+        s[5] = MatchesModule.createMatchWithDerivedId.selector;
+        s[6] = MatchesModule.deriveMatchId.selector;
+        s[7] = MatchesModule.createScheduledMatch.selector;
+        s[8] = MatchesModule.settleCreatorBond.selector;
+        s[9] = MatchesModule.createCommunityMatch.selector;
+        s[10] = MatchesModule.vetoMatch.selector;
+        s[11] = MatchesModule.setVetoParams.selector;
+        s[12] = MatchesModule.createMatchWithAdmin.selector;
+        s[13] = MatchesModule.updateMatchMetadata.selector;
+        s[14] = MatchesModule.extendBettingWindow.selector;
+        s[15] = MatchesModule.requestCancellation.selector;
+        s[16] = MatchesModule.approveCancellation.selector;
+        s[17] = MatchesModule.createMatchWithSettlement.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // createMatch
+    // ──────────────────────────────────────────────
+    function createMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+    }
+
+    /// @notice Open up to MAX_CREATE_BATCH Ranked matches sharing minBet and bettingWindow in
+    ///         one transaction. All-or-nothing: any invalid entry reverts the whole batch
+    function createMatches(MatchSpec[] calldata specs, uint128 minBet, uint64 bettingWindow)
+        external
+        payable
+        whenNotPaused
+        onlyRole(ORACLE_ROLE)
+        withCreatorBond(specs.length)
+    {
+        if (specs.length == 0 || specs.length > MAX_CREATE_BATCH) revert InvalidBatchSize();
+        for (uint256 i; i < specs.length; ++i) {
+            MatchSpec calldata spec = specs[i];
+            _createMatch(
+                spec.matchId, spec.fighterA, spec.fighterB, minBet, bettingWindow,
+                MatchCategory.Ranked
+            );
+        }
+    }
+
+    /// @notice Create a match outside the default Ranked category (challenge, exhibition, ...)
+    function createMatchInCategory(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        MatchCategory category
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, category);
+    }
+
+    /// @notice Create a match settled by a non-default payout strategy. Only strategies
+    ///         Settlement.isSupported accepts can be chosen
+    function createMatchWithSettlement(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        Settlement.Strategy strategy
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (!Settlement.isSupported(strategy)) revert Settlement.UnsupportedStrategy(strategy);
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+        _settlementStrategies[matchId] = strategy;
+    }
+
+    /// @notice Create a capped-loss market where losing bettors recover lossFloorBps of their stake
+    function createMatchWithLossFloor(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint16 lossFloorBps
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (lossFloorBps == 0 || lossFloorBps > MAX_LOSS_FLOOR_BPS) revert InvalidLossFloor();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+        _lossFloors[matchId] = lossFloorBps;
+    }
+
+    /// @notice Create a match carrying its own display name and metadata URI, plus a hash
+    ///         of the full off-chain record that disputes can point back to
+    function createMatchWithMetadata(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        bytes32 name,
+        string calldata uri,
+        bytes32 metadataHash
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (bytes(uri).length > MAX_URI_LENGTH) revert UriTooLong();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+        _matchMetadata[matchId] =
+            MatchMetadata({name: name, uri: uri, metadataHash: metadataHash, resultHash: 0});
+
+        emit MatchMetadataSet(matchId, name, uri, metadataHash);
+    }
+
+    /// @notice Create a match whose id must equal MatchIds.derive over its fighters, start
+    ///         time and nonce, so a duplicate or made-up id can't shadow the real fight
+    function createMatchWithDerivedId(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint64 startTime,
+        uint64 nonce,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (matchId != MatchIds.derive(fighterA, fighterB, startTime, nonce)) {
+            revert MatchIdMismatch();
+        }
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+    }
+
+    function deriveMatchId(address fighterA, address fighterB, uint64 startTime, uint64 nonce)
+        external
+        pure
+        returns (bytes32)
+    {
+        return MatchIds.derive(fighterA, fighterB, startTime, nonce);
+    }
+
+    /// @notice Create a match ahead of time whose betting opens and locks on a schedule
+    function createScheduledMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 opensAt,
+        uint64 scheduledLockAt
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (scheduledLockAt != 0 && scheduledLockAt <= opensAt) revert InvalidSchedule();
+        if (scheduledLockAt != 0 && scheduledLockAt <= block.timestamp) revert InvalidSchedule();
+        // A schedule past maxMatchAge could be force-cancelled before it ever locks
+        if (scheduledLockAt >= block.timestamp + _maxMatchAge) revert InvalidSchedule();
+
+        _createMatch(matchId, fighterA, fighterB, minBet, 0, MatchCategory.Ranked);
+        _schedules[matchId] = MatchSchedule({opensAt: opensAt, scheduledLockAt: scheduledLockAt});
+
+        emit MatchScheduled(matchId, opensAt, scheduledLockAt);
+    }
+
+    // ──────────────────────────────────────────────
+    // Creator bond
+    // ──────────────────────────────────────────────
+    /// @notice Settle a match's creator bond. Permissionless. A match cancelled by Timeout or
+    ///         Expired was abandoned by its creator, and a Vetoed one was abusive, so the bond
+    ///         goes to the treasury straight away; otherwise it returns to the creator once
+    ///         every bet has been settled
+    function settleCreatorBond(bytes32 matchId) external nonReentrant {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved && pool.status != MatchStatus.Cancelled) {
+            revert InvalidMatchStatus();
+        }
+        uint128 bond = _creatorBonds[matchId];
+        if (bond == 0) revert NoCreatorBond();
+
+        CancelReason reason = _cancelReasons[matchId];
+        bool forfeited = reason == CancelReason.Timeout || reason == CancelReason.Expired
+            || reason == CancelReason.Vetoed;
+        if (!forfeited && pool.betCount > 0) revert MatchNotCleanedUp();
+
+        delete _creatorBonds[matchId];
+        address creator = _matchCreators[matchId];
+        (bool success,) = payable(forfeited ? _treasury : creator).call{value: bond}("");
+        if (!success) revert TransferFailed();
+
+        if (forfeited) emit CreatorBondSlashed(matchId, creator, bond);
+        else emit CreatorBondReturned(matchId, creator, bond);
+    }
+
+    // ──────────────────────────────────────────────
+    // Community matches (veto window)
+    // ──────────────────────────────────────────────
+    /// @notice Open a Community match without ORACLE_ROLE. Bets are capped at vetoBetCap in
+    ///         total until the veto window ends, so a guardian can pull an abusive match
+    ///         before it holds real money
+    function createCommunityMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable whenNotPaused withCreatorBond(1) {
+        if (_vetoWindow == 0) revert CommunityCreationDisabled();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Community);
+        _vetoDeadlines[matchId] = uint64(block.timestamp) + _vetoWindow;
+    }
+
+    /// @notice Cancel a community match inside its veto window. Bettors get full refunds and
+    ///         the creator bond is forfeited to the treasury
+    function vetoMatch(bytes32 matchId) external onlyRole(GUARDIAN_ROLE) {
+        if (block.timestamp >= _vetoDeadlines[matchId]) revert VetoWindowClosed();
+        _cancelMatch(matchId, CancelReason.Vetoed);
+        emit MatchVetoed(matchId, msg.sender);
+    }
+
+    /// @notice A zero window closes match creation to the public again; matches already
+    ///         created keep the deadline they were given
+    function setVetoParams(uint64 newWindow, uint128 newBetCap) external onlyRole(ADMIN_ROLE) {
+        _vetoWindow = newWindow;
+        _vetoBetCap = newBetCap;
+        emit ConfigUpdated(ConfigField.VetoWindow, uint256(newWindow));
+        emit ConfigUpdated(ConfigField.VetoBetCap, uint256(newBetCap));
+    }
+
+    // ──────────────────────────────────────────────
+    // Delegated match administration
+    // ──────────────────────────────────────────────
+    /// @notice Create a match run by an event organizer, who can edit its metadata, push back
+    ///         its betting close by up to MAX_ORGANIZER_EXTENSION and ask a guardian to cancel
+    ///         it — nothing that touches results or funds
+    function createMatchWithAdmin(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        address matchAdmin
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (matchAdmin == address(0)) revert InvalidMatchAdmin();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+        _matchAdmins[matchId] = matchAdmin;
+        emit MatchAdminSet(matchId, matchAdmin);
+    }
+
+    /// @notice Replace the card's display name, URI and record hash while betting is open.
+    ///         A committed result hash is never touched
+    function updateMatchMetadata(
+        bytes32 matchId,
+        bytes32 name,
+        string calldata uri,
+        bytes32 metadataHash
+    ) external onlyMatchAdmin(matchId) {
+        if (_matches[matchId].status != MatchStatus.Open) revert MatchNotOpen();
+        if (bytes(uri).length > MAX_URI_LENGTH) revert UriTooLong();
+
+        MatchMetadata storage metadata = _matchMetadata[matchId];
+        metadata.name = name;
+        metadata.uri = uri;
+        metadata.metadataHash = metadataHash;
+        emit MatchMetadataSet(matchId, name, uri, metadataHash);
+    }
+
+    /// @notice Keep betting open longer, e.g. when the card runs late. Only before betting
+    ///         closes, and never so far that the match could age out before it locks
+    function extendBettingWindow(bytes32 matchId, uint64 extension)
+        external
+        onlyMatchAdmin(matchId)
+    {
+        MatchPool storage pool = _matches[matchId];
+        if (_bettingClosed(matchId)) revert BettingWindowClosed();
+        if (pool.bettingWindow == 0) revert NoBettingDeadline();
+        uint64 used = _organizerExtensions[matchId];
+        if (used + extension > MAX_ORGANIZER_EXTENSION) {
+            revert ExtensionTooLong(MAX_ORGANIZER_EXTENSION - used);
+        }
+        uint64 closesAt = pool.createdAt + pool.bettingWindow + extension;
+        if (closesAt >= pool.createdAt + _maxMatchAge) revert InvalidSchedule();
+
+        _organizerExtensions[matchId] = used + extension;
+        pool.bettingWindow += extension;
+        emit BettingWindowExtended(matchId, closesAt);
+    }
+
+    /// @notice Ask for the match to be called off. Nothing changes until a guardian approves
+    function requestCancellation(bytes32 matchId) external onlyMatchAdmin(matchId) {
+        if (_isFinal(_matches[matchId].status)) revert InvalidMatchStatus();
+        _cancellationRequests[matchId] = true;
+        emit CancellationRequested(matchId, msg.sender);
+    }
+
+    /// @notice Cancel a match its organizer asked to call off. Bettors refund as with any
+    ///         cancel and the creator bond is returned
+    function approveCancellation(bytes32 matchId) external onlyRole(GUARDIAN_ROLE) {
+        if (!_cancellationRequests[matchId]) revert NoCancellationRequest();
+        delete _cancellationRequests[matchId];
+        _cancelMatch(matchId, CancelReason.OrganizerRequest);
+        emit CancellationApproved(matchId, msg.sender);
+    }
+}
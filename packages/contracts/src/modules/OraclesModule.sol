@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title OraclesModule — oracle plumbing: Switchboard result feeds, Pyth price markets,
+///       commit–reveal results, liveness and game-server attestations
+contract OraclesModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](15);
+        s[0] = OraclesModule.bindResultFeed.selector;
+        s[1] = OraclesModule.resolveFromFeed.selector;
+        s[2] = OraclesModule.setSwitchboard.selector;
+        s[3] = OraclesModule.createPriceMarket.selector;
+        s[4] = OraclesModule.resolvePriceMarket.selector;
+        s[5] = OraclesModule.setPyth.selector;
+        s[6] = OraclesModule.revealResult.selector;
+        s[7] = OraclesModule.resultCommitment.selector;
+        s[8] = OraclesModule.setCommitRevealRequired.selector;
+        s[9] = OraclesModule.oracleHeartbeat.selector;
+        s[10] = OraclesModule.oracleSilent.selector;
+        s[11] = OraclesModule.currentMatchTimeout.selector;
+        s[12] = OraclesModule.setOracleLiveness.selector;
+        s[13] = OraclesModule.attestRound.selector;
+        s[14] = OraclesModule.clearAttestation.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Result feeds (Switchboard-resolved matches)
+    // ──────────────────────────────────────────────
+    /// @notice Hand a match's result to a Switchboard feed whose value encodes the winner
+    ///         (1 = side A, 2 = side B). Once bound, only resolveFromFeed can resolve it and
+    ///         the binding can't change. Creator or admin, before the match locks
+    function bindResultFeed(bytes32 matchId, bytes32 feedId) external {
+        if (msg.sender != _matchCreators[matchId] && !hasRole(ADMIN_ROLE, msg.sender)) {
+            revert AccessControlUnauthorizedAccount(msg.sender, ADMIN_ROLE);
+        }
+        MatchStatus status = _matches[matchId].status;
+        if (status != MatchStatus.Open && status != MatchStatus.Pending) revert MatchNotOpen();
+        if (feedId == bytes32(0)) revert NoResultFeed();
+        if (_resolvedExternally(matchId)) revert FeedAlreadyBound();
+
+        _resultFeeds[matchId] = feedId;
+        emit ResultFeedBound(matchId, feedId);
+    }
+
+    /// @notice Resolve a locked, feed-bound match from the feed's latest update. The update
+    ///         must postdate the lock, so a value left over from before the fight can't settle
+    ///         it. Permissionless — the feed is the authority, not the caller
+    function resolveFromFeed(bytes32 matchId) external {
+        bytes32 feedId = _resultFeeds[matchId];
+        if (feedId == bytes32(0) || _switchboard == address(0)) revert NoResultFeed();
+
+        ISwitchboard.Update memory update = ISwitchboard(_switchboard).latestUpdate(feedId);
+        if (update.timestamp < _matches[matchId].lockTimestamp) {
+            revert StaleFeedResult(update.timestamp);
+        }
+        if (update.result != 1 && update.result != 2) revert InvalidFeedResult(update.result);
+
+        emit FeedResultApplied(matchId, feedId, update.result, update.timestamp);
+        _resolveMatch(matchId, uint8(uint128(update.result)) - 1);
+    }
+
+    function setSwitchboard(address newSwitchboard) external onlyRole(ADMIN_ROLE) {
+        _switchboard = newSwitchboard;
+        emit ConfigUpdated(ConfigField.Switchboard, uint256(uint160(newSwitchboard)));
+    }
+
+    // ──────────────────────────────────────────────
+    // Price-prediction markets (Pyth)
+    // ──────────────────────────────────────────────
+    /// @notice Open an above/below market on a Pyth price at resolveAt. Betting closes at
+    ///         lockAt, when anyone may lock it; after that it bets, claims and refunds like
+    ///         any match, but only resolvePriceMarket can resolve it
+    function createPriceMarket(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 lockAt,
+        PriceMarket calldata market
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (_pyth == address(0) || market.priceId == bytes32(0)) revert InvalidPriceMarket();
+        if (lockAt <= block.timestamp || lockAt > market.resolveAt) revert InvalidSchedule();
+        // Same bound as createScheduledMatch, or it could be force-cancelled before locking
+        if (lockAt >= block.timestamp + _maxMatchAge) revert InvalidSchedule();
+
+        _createMatch(matchId, fighterA, fighterB, minBet, 0, MatchCategory.PricePrediction);
+        _schedules[matchId] = MatchSchedule({opensAt: 0, scheduledLockAt: lockAt});
+        _priceMarkets[matchId] = market;
+
+        emit MatchScheduled(matchId, 0, lockAt);
+        emit PriceMarketCreated(
+            matchId, market.priceId, market.strike, market.expo, market.resolveAt
+        );
+    }
+
+    /// @notice Resolve a locked price market from a Pyth update published within
+    ///         PRICE_PUBLISH_TOLERANCE of resolveAt. Permissionless: the caller fetches the
+    ///         update from Hermes and pays Pyth's fee, any excess is returned. A market with
+    ///         no update in the window times out and refunds like any stuck match
+    function resolvePriceMarket(bytes32 matchId, bytes[] calldata priceUpdate)
+        external
+        payable
+        nonReentrant
+    {
+        PriceMarket memory market = _priceMarkets[matchId];
+        if (market.priceId == bytes32(0)) revert InvalidPriceMarket();
+        if (_matches[matchId].status != MatchStatus.Locked) revert MatchNotLocked();
+        if (block.timestamp < market.resolveAt) revert PriceNotDue(market.resolveAt);
+
+        uint256 fee = IPyth(_pyth).getUpdateFee(priceUpdate);
+        if (msg.value < fee) revert InsufficientPythFee(fee);
+        bytes32[] memory ids = new bytes32[](1);
+        ids[0] = market.priceId;
+        IPyth.PriceFeed[] memory feeds = IPyth(_pyth).parsePriceFeedUpdates{value: fee}(
+            priceUpdate, ids, market.resolveAt, market.resolveAt + PRICE_PUBLISH_TOLERANCE
+        );
+        IPyth.Price memory price = feeds[0].price;
+        if (price.expo != market.expo) revert PriceExponentMismatch(price.expo);
+
+        emit PriceMarketResolved(matchId, market.priceId, price.price, price.publishTime);
+        _resolveMatch(matchId, price.price > market.strike ? 0 : 1);
+
+        if (msg.value > fee) {
+            (bool success,) = payable(msg.sender).call{value: msg.value - fee}("");
+            if (!success) revert TransferFailed();
+        }
+    }
+
+    function setPyth(address newPyth) external onlyRole(ADMIN_ROLE) {
+        _pyth = newPyth;
+        emit ConfigUpdated(ConfigField.Pyth, uint256(uint160(newPyth)));
+    }
+
+    // ──────────────────────────────────────────────
+    // Commit–reveal resolution
+    // ──────────────────────────────────────────────
+    /// @notice Open the commitment made at lock and attest the committed winner. Any other
+    ///         oracle result, proposal or amendment for the match must then agree with it
+    function revealResult(bytes32 matchId, uint8 winner, bytes32 salt)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+    {
+        bytes32 commitment = _resultCommitments[matchId];
+        if (commitment == bytes32(0)) revert NoCommitment();
+        if (winner > 1) revert InvalidSide();
+        if (resultCommitment(matchId, winner, salt) != commitment) revert CommitmentMismatch();
+
+        _revealedResults[matchId] = winner + 1;
+        emit ResultRevealed(matchId, msg.sender, winner);
+        _attestResult(matchId, winner, msg.sender);
+    }
+
+    /// @notice What lockMatchWithCommitment expects. The match id is bound in, so a commitment
+    ///         can't be replayed onto another match
+    function resultCommitment(bytes32 matchId, uint8 winner, bytes32 salt)
+        public
+        pure
+        returns (bytes32)
+    {
+        return keccak256(abi.encode(matchId, winner, salt));
+    }
+
+    /// @notice With this on, plain lockMatch is refused and a match the oracle didn't commit
+    ///         on (locked by lockExpired) can't take an oracle result — it times out to refunds
+    function setCommitRevealRequired(bool required) external onlyRole(ADMIN_ROLE) {
+        _commitRevealRequired = required;
+        emit ConfigUpdated(ConfigField.CommitRevealRequired, required ? 1 : 0);
+    }
+
+    // ──────────────────────────────────────────────
+    // Oracle liveness (heartbeat)
+    // ──────────────────────────────────────────────
+    /// @notice Cheap proof of life from a resolver. Attestations count too, so a busy oracle
+    ///         needn't send these
+    function oracleHeartbeat() external onlyRole(RESOLVE_AUTHORITY_ROLE) {
+        _oracleLastSeen = uint64(block.timestamp);
+        emit OracleHeartbeat(msg.sender, uint64(block.timestamp));
+    }
+
+    /// @notice True once no resolver has been heard from for oracleSilencePeriod
+    function oracleSilent() external view returns (bool) {
+        return _oracleSilent();
+    }
+
+    /// @notice Timeout timeoutMatch applies right now to a match without its own deadline:
+    ///         silentOracleTimeout while the oracle is down, so stuck matches refund sooner,
+    ///         else resolutionDeadline (matchTimeout when unset)
+    function currentMatchTimeout() public view returns (uint64) {
+        return _silentOracleCap(_globalResolutionDeadline());
+    }
+
+    /// @notice A zero period turns the shorter timeout off. The silent timeout stays above
+    ///         minResolutionDelay, so an oracle coming back can still resolve in time
+    function setOracleLiveness(uint64 silencePeriod, uint64 silentTimeout)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        if (silencePeriod > 0) {
+            if (silentTimeout < MIN_TIMEOUT || silentTimeout >= _matchTimeout) {
+                revert InvalidTimeout();
+            }
+            if (silentTimeout <= _minResolutionDelay) revert InvalidTimeout();
+        }
+        _oracleSilencePeriod = silencePeriod;
+        _silentOracleTimeout = silentTimeout;
+        emit ConfigUpdated(ConfigField.OracleSilencePeriod, uint256(silencePeriod));
+        emit ConfigUpdated(ConfigField.SilentOracleTimeout, uint256(silentTimeout));
+    }
+
+    // ──────────────────────────────────────────────
+    // Game-server attestations
+    // ──────────────────────────────────────────────
+    /// @notice Record a round of a locked match as signed by a GAME_SERVER_ROLE key
+    ///         (EIP-712 RoundAttestation), relayed by anyone. Rounds only move forward, and a
+    ///         `finished` attestation closes the stream: the oracle can then only resolve
+    ///         the match for the winner it names
+    function attestRound(
+        bytes32 matchId,
+        uint16 round,
+        bytes32 stateHash,
+        uint8 leader,
+        bool finished,
+        bytes calldata signature
+    ) external {
+        if (_matches[matchId].status != MatchStatus.Locked) revert MatchNotLocked();
+        if (leader > 2 || (finished && leader > 1)) revert InvalidAttestation();
+        bytes32 digest = _hashTypedDataV4(
+            keccak256(
+                abi.encode(ATTESTATION_TYPEHASH, matchId, round, stateHash, leader, finished)
+            )
+        );
+        (address server, ECDSA.RecoverError error,) = ECDSA.tryRecover(digest, signature);
+        if (error != ECDSA.RecoverError.NoError || !hasRole(GAME_SERVER_ROLE, server)) {
+            revert InvalidAttestation();
+        }
+
+        Attestation storage last = _attestations[matchId];
+        if (last.finished || (last.server != address(0) && round <= last.round)) {
+            revert StaleAttestationRound();
+        }
+        _attestations[matchId] = Attestation({
+            stateHash: stateHash,
+            server: server,
+            timestamp: uint64(block.timestamp),
+            round: round,
+            leader: leader,
+            finished: finished
+        });
+        emit RoundAttested(matchId, server, round, stateHash, leader, finished);
+    }
+
+    /// @notice Drop a match's attestations, e.g. a server that signed the wrong final round,
+    ///         so the oracle's result can land
+    function clearAttestation(bytes32 matchId) external onlyRole(GUARDIAN_ROLE) {
+        if (_attestations[matchId].server == address(0)) revert InvalidAttestation();
+        delete _attestations[matchId];
+        emit AttestationCleared(matchId, msg.sender);
+    }
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title PromosModule — promotions: vouchers, sponsor-matched bets, house seeding and the
+///       risk-free first bet
+contract PromosModule is RawlBettingModule {
+    using SafeCast for uint256;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](15);
+        s[0] = PromosModule.fundPromo.selector;
+        s[1] = PromosModule.withdrawPromo.selector;
+        s[2] = PromosModule.issueVoucher.selector;
+        s[3] = PromosModule.placeBetWithVoucher.selector;
+        s[4] = PromosModule.reclaimVoucher.selector;
+        s[5] = PromosModule.placeBetOrEscrow.selector;
+        s[6] = PromosModule.refundPendingBet.selector;
+        s[7] = PromosModule.sponsorMatch.selector;
+        s[8] = PromosModule.withdrawSponsorship.selector;
+        s[9] = PromosModule.setSeeder.selector;
+        s[10] = PromosModule.seedMatch.selector;
+        s[11] = PromosModule.redeemSeed.selector;
+        s[12] = PromosModule.setFirstBetInsuranceCap.selector;
+        s[13] = PromosModule.claimFirstBetRefund.selector;
+        s[14] = PromosModule.releaseFirstBetInsurance.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Vouchers (free bets funded from the promo budget)
+    // ──────────────────────────────────────────────
+    function fundPromo() external payable onlyRole(ADMIN_ROLE) {
+        _promoBudget += msg.value;
+        emit PromoFunded(msg.sender, msg.value);
+    }
+
+    function withdrawPromo(uint256 amount) external onlyRole(ADMIN_ROLE) nonReentrant {
+        if (amount > _promoBudget) revert InsufficientPromoBudget();
+        _promoBudget -= amount;
+
+        (bool success,) = payable(_treasury).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit PromoWithdrawn(amount, _treasury);
+    }
+
+    /// @notice Reserve `amount` of the promo budget as a one-time free bet for `redeemer`
+    function issueVoucher(bytes32 voucherId, address redeemer, uint128 amount, uint64 expiresAt)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        if (_vouchers[voucherId].redeemer != address(0)) revert VoucherExists();
+        if (redeemer == address(0)) revert InvalidRecipient();
+        if (amount == 0) revert ZeroBetAmount();
+        if (expiresAt <= block.timestamp) revert VoucherExpired();
+        if (amount > _promoBudget) revert InsufficientPromoBudget();
+
+        _promoBudget -= amount;
+        _vouchers[voucherId] = Voucher({
+            redeemer: redeemer,
+            expiresAt: expiresAt,
+            redeemed: false,
+            amount: amount
+        });
+
+        emit VoucherIssued(voucherId, redeemer, amount, expiresAt);
+    }
+
+    /// @notice Bet the voucher amount as the stake. Winnings are paid as usual, but the
+    ///         principal (or whatever comes back of it) returns to the promo budget
+    function placeBetWithVoucher(bytes32 matchId, uint8 side, bytes32 voucherId)
+        external
+        whenNotPaused
+        nonReentrant
+    {
+        Voucher storage voucher = _vouchers[voucherId];
+        if (voucher.redeemer != msg.sender) revert VoucherNotFound();
+        if (voucher.redeemed) revert VoucherAlreadyRedeemed();
+        if (block.timestamp >= voucher.expiresAt) revert VoucherExpired();
+
+        voucher.redeemed = true;
+        _voucherStakes[matchId][msg.sender] = voucher.amount;
+        _placeBet(matchId, side, msg.sender, voucher.amount);
+
+        emit VoucherRedeemed(voucherId, matchId, msg.sender);
+    }
+
+    /// @notice Return an expired, unused voucher's reservation to the promo budget
+    function reclaimVoucher(bytes32 voucherId) external onlyRole(ADMIN_ROLE) {
+        Voucher storage voucher = _vouchers[voucherId];
+        if (voucher.redeemer == address(0)) revert VoucherNotFound();
+        if (voucher.redeemed) revert VoucherAlreadyRedeemed();
+        if (block.timestamp < voucher.expiresAt) revert VoucherNotExpired();
+
+        voucher.redeemed = true;
+        _promoBudget += voucher.amount;
+
+        emit VoucherReclaimed(voucherId, voucher.amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // Pending bets (bets that race the lock)
+    // ──────────────────────────────────────────────
+    /// @notice Same as placeBet, except a bet that lands after betting closed — in the block
+    ///         the match locked, or after the window or scheduled lock passed — is escrowed
+    ///         for refund instead of reverting
+    function placeBetOrEscrow(bytes32 matchId, uint8 side)
+        external
+        payable
+        whenNotPaused
+        nonReentrant
+    {
+        if (!_bettingClosed(matchId)) {
+            _placeBet(matchId, side, msg.sender, msg.value);
+            return;
+        }
+        if (side > 1) revert InvalidSide();
+        if (msg.value == 0) revert ZeroBetAmount();
+        _screen(msg.sender);
+
+        _pendingBets[matchId][msg.sender] += msg.value;
+        emit BetEscrowed(matchId, msg.sender, side, msg.value);
+    }
+
+    /// @notice Return an escrowed late bet to its bettor. Permissionless so a crank can
+    ///         clear them; funds only ever go to the bettor
+    function refundPendingBet(bytes32 matchId, address bettor) external nonReentrant {
+        uint256 amount = _pendingBets[matchId][bettor];
+        if (amount == 0) revert NoPendingBet();
+
+        delete _pendingBets[matchId][bettor];
+        unchecked { _metrics.refunds++; }
+
+        (bool success,) = payable(bettor).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit PendingBetRefunded(matchId, bettor, amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // Sponsor-matched bets
+    // ──────────────────────────────────────────────
+    /// @notice Deposit a budget that tops up each placeBet on an open match by `matchBps` of
+    ///         the stake, capped at `capPerBet`. One sponsor per match; they may add to it
+    function sponsorMatch(bytes32 matchId, uint128 capPerBet, uint16 matchBps)
+        external
+        payable
+        whenNotPaused
+    {
+        if (_matches[matchId].status != MatchStatus.Open) revert MatchNotOpen();
+        if (msg.value == 0) revert ZeroBetAmount();
+        if (matchBps == 0 || matchBps > 10_000) revert InvalidShareBps();
+
+        Sponsorship storage sponsorship = _sponsorships[matchId];
+        if (sponsorship.sponsor == address(0)) {
+            sponsorship.sponsor = msg.sender;
+        } else if (sponsorship.sponsor != msg.sender) {
+            revert NotSponsor();
+        }
+        sponsorship.matchBps = matchBps;
+        sponsorship.capPerBet = capPerBet;
+        sponsorship.budget += msg.value.toUint128();
+
+        emit SponsorshipFunded(matchId, msg.sender, msg.value, capPerBet, matchBps);
+    }
+
+    /// @notice Return the unmatched budget once the match stops taking bets. Matched funds
+    ///         that come back through refunds land in the budget and can be withdrawn too
+    function withdrawSponsorship(bytes32 matchId) external nonReentrant {
+        Sponsorship storage sponsorship = _sponsorships[matchId];
+        if (sponsorship.sponsor != msg.sender) revert NotSponsor();
+        if (_matches[matchId].status == MatchStatus.Open) revert InvalidMatchStatus();
+
+        uint256 amount = sponsorship.budget;
+        if (amount == 0) revert NoSponsorBudget();
+        sponsorship.budget = 0;
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit SponsorshipWithdrawn(matchId, msg.sender, amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // House seeding (LP liquidity on both sides)
+    // ──────────────────────────────────────────────
+    function setSeeder(address account, bool approved) external onlyRole(ADMIN_ROLE) {
+        _approvedSeeders[account] = approved;
+        emit SeederApproved(account, approved);
+    }
+
+    /// @notice Seed both sides of an open match to bootstrap odds. msg.value must equal
+    ///         sideA + sideB. Each side is held as its own bet, so the seed shares the pool
+    ///         pro rata like any stake, but is redeemed as one position via redeemSeed
+    function seedMatch(bytes32 matchId, uint128 sideA, uint128 sideB)
+        external
+        payable
+        whenNotPaused
+        nonReentrant
+    {
+        if (!_approvedSeeders[msg.sender] && !hasRole(ADMIN_ROLE, msg.sender)) revert NotSeeder();
+        if (sideA == 0 || sideB == 0) revert ZeroBetAmount();
+        if (msg.value != uint256(sideA) + sideB) revert InvalidSeedAmount();
+
+        for (uint8 side; side < 2; ++side) {
+            address key = _seedKey(msg.sender, side);
+            _seedOwners[key] = msg.sender;
+            _placeBet(matchId, side, key, side == 0 ? sideA : sideB);
+        }
+
+        emit MatchSeeded(matchId, msg.sender, sideA, sideB);
+    }
+
+    /// @notice Return a seed once the match settles: full principal if cancelled, otherwise
+    ///         the winning side's pro-rata share of the net pool (principal ± P&L)
+    function redeemSeed(bytes32 matchId) external nonReentrant returns (uint256 amount) {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved && pool.status != MatchStatus.Cancelled) {
+            revert InvalidMatchStatus();
+        }
+
+        // Seeds cover both sides and cannot be cashed out, so a resolved seeded match
+        // always has winners — no refundNoWinners branch needed
+        for (uint8 side; side < 2; ++side) {
+            address key = _seedKey(msg.sender, side);
+            BetInfo storage bet = _bets[matchId][key];
+            if (bet.amount == 0 || bet.claimed) continue;
+
+            if (pool.status == MatchStatus.Cancelled) {
+                amount += _refundBet(matchId, key, msg.sender);
+            } else if (_isWinningSide(pool, side)) {
+                amount += _claimPayout(matchId, key, msg.sender);
+            }
+        }
+        if (amount == 0) revert NoSeed();
+
+        emit SeedRedeemed(matchId, msg.sender, amount);
+    }
+
+    // ──────────────────────────────────────────────
+    // Risk-free first bet
+    // ──────────────────────────────────────────────
+    function setFirstBetInsuranceCap(uint128 newCap) external onlyRole(ADMIN_ROLE) {
+        _firstBetInsuranceCap = newCap;
+        emit ConfigUpdated(ConfigField.FirstBetInsuranceCap, uint256(newCap));
+    }
+
+    /// @notice Refund the insured part of a losing first bet from the promo budget
+    function claimFirstBetRefund() external nonReentrant {
+        FirstBet storage first = _firstBets[msg.sender];
+        uint128 insured = first.insured;
+        if (insured == 0) revert NoFirstBetInsurance();
+        if (!_firstBetLost(first.matchId, msg.sender)) revert FirstBetNotLost();
+
+        first.insured = 0;
+        first.refunded = true;
+
+        (bool success,) = payable(msg.sender).call{value: insured}("");
+        if (!success) revert TransferFailed();
+
+        emit FirstBetRefunded(first.matchId, msg.sender, insured);
+    }
+
+    /// @notice Return an unused reservation to the promo budget once the first bet won,
+    ///         refunded with no winners, or its match was cancelled. Permissionless
+    function releaseFirstBetInsurance(address bettor) external {
+        FirstBet storage first = _firstBets[bettor];
+        if (first.insured == 0) revert NoFirstBetInsurance();
+
+        MatchStatus status = _matches[first.matchId].status;
+        if (status != MatchStatus.Resolved && status != MatchStatus.Cancelled) {
+            revert FirstBetNotSettled();
+        }
+        if (_firstBetLost(first.matchId, bettor)) revert FirstBetNotSettled();
+
+        _releaseFirstBet(bettor);
+    }
+}
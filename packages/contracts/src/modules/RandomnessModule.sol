@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title RandomnessModule — Chainlink VRF: mystery matchmaking and chaos exhibitions
+contract RandomnessModule is RawlBettingModule {
+    using SafeCast for uint256;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](8);
+        s[0] = RandomnessModule.requestMysteryMatch.selector;
+        s[1] = RandomnessModule.rawFulfillRandomWords.selector;
+        s[2] = RandomnessModule.createMysteryMatch.selector;
+        s[3] = RandomnessModule.selectMysteryFighters.selector;
+        s[4] = RandomnessModule.setVrfConfig.selector;
+        s[5] = RandomnessModule.createChaosMatch.selector;
+        s[6] = RandomnessModule.requestChaosResult.selector;
+        s[7] = RandomnessModule.chaosWinner.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Mystery matches (VRF matchmaking)
+    // ──────────────────────────────────────────────
+    /// @notice Reserve `matchId` for an Exhibition between two registered fighters picked by
+    ///         Chainlink VRF, so a mystery-fight card can't be rigged by the operator's choice
+    function requestMysteryMatch(bytes32 matchId, uint128 minBet, uint64 bettingWindow)
+        external
+        payable
+        whenNotPaused
+        onlyRole(ORACLE_ROLE)
+        withCreatorBond(1)
+        returns (uint256 requestId)
+    {
+        if (_vrfCoordinator == address(0)) revert VrfNotConfigured();
+        if (_fighterList.length < 2) revert NotEnoughFighters();
+        if (_matches[matchId].status != MatchStatus.None || _mysteryReserved[matchId]) {
+            revert MatchAlreadyExists();
+        }
+
+        _mysteryReserved[matchId] = true;
+        requestId = _requestRandomWord();
+        _mysteryRequests[requestId] = MysteryRequest({
+            matchId: matchId,
+            creator: msg.sender,
+            bettingWindow: bettingWindow,
+            minBet: minBet,
+            bond: msg.value.toUint128()
+        });
+
+        emit MysteryMatchRequested(matchId, requestId);
+    }
+
+    /// @notice VRF callback. Never reverts on a bad draw: if the match can't be created (the
+    ///         creator hit its open-match limit, a fighter lost its token, ...) the bond is
+    ///         refunded and the reservation released. Chaos-match draws are routed to
+    ///         _drawChaosResult
+    function rawFulfillRandomWords(uint256 requestId, uint256[] calldata randomWords)
+        external
+        nonReentrant
+    {
+        if (msg.sender != _vrfCoordinator) revert UnauthorizedCallback();
+        bytes32 chaosMatch = _chaosRequests[requestId];
+        if (chaosMatch != bytes32(0)) {
+            delete _chaosRequests[requestId];
+            delete _chaosRequestIds[chaosMatch];
+            _drawChaosResult(chaosMatch, randomWords[0]);
+            return;
+        }
+        MysteryRequest memory request = _mysteryRequests[requestId];
+        if (request.creator == address(0)) return;
+        delete _mysteryRequests[requestId];
+        delete _mysteryReserved[request.matchId];
+
+        uint256 word = randomWords[0];
+        try this.createMysteryMatch(request, word) {
+            emit MysteryMatchCreated(
+                request.matchId, word, _matches[request.matchId].fighterA,
+                _matches[request.matchId].fighterB
+            );
+        } catch {
+            (bool success,) = payable(request.creator).call{value: request.bond}("");
+            if (!success) revert TransferFailed();
+            emit MysteryMatchFailed(request.matchId, word, request.bond);
+        }
+    }
+
+    /// @dev External only so rawFulfillRandomWords can try/catch it — callable by this
+    ///      contract alone
+    function createMysteryMatch(MysteryRequest calldata request, uint256 word) external {
+        if (msg.sender != address(this)) revert UnauthorizedCallback();
+        (address fighterA, address fighterB) = selectMysteryFighters(word);
+
+        _createMatchFor(
+            request.creator,
+            request.matchId,
+            fighterA,
+            fighterB,
+            request.minBet,
+            request.bettingWindow,
+            MatchCategory.Exhibition,
+            MatchStatus.Open
+        );
+        // The bond was posted at request time, creatorBond may have changed since
+        if (request.bond > 0) _creatorBonds[request.matchId] = request.bond;
+        else delete _creatorBonds[request.matchId];
+
+        _mysterySeeds[request.matchId] = word;
+        bytes32 seedHash = keccak256(abi.encode(word));
+        _matchMetadata[request.matchId] = MatchMetadata({
+            name: MYSTERY_MATCH_NAME, uri: "", metadataHash: seedHash, resultHash: 0
+        });
+        emit MatchMetadataSet(request.matchId, MYSTERY_MATCH_NAME, "", seedHash);
+    }
+
+    /// @notice The two distinct fighters a VRF word draws from the registry, uniformly.
+    ///         Lets anyone re-derive a mystery match's pairing from its recorded seed —
+    ///         against the registry as it stood at fulfilment, which only ever grows
+    function selectMysteryFighters(uint256 word)
+        public
+        view
+        returns (address fighterA, address fighterB)
+    {
+        uint256 count = _fighterList.length;
+        if (count < 2) revert NotEnoughFighters();
+        uint256 first = word % count;
+        uint256 second = (first + 1 + (uint256(keccak256(abi.encode(word))) % (count - 1))) % count;
+        return (_fighterList[first], _fighterList[second]);
+    }
+
+    function setVrfConfig(
+        address coordinator,
+        bytes32 keyHash,
+        uint256 subscriptionId,
+        uint32 callbackGasLimit
+    ) external onlyRole(ADMIN_ROLE) {
+        _vrfCoordinator = coordinator;
+        _vrfKeyHash = keyHash;
+        _vrfSubscriptionId = subscriptionId;
+        _vrfCallbackGasLimit = callbackGasLimit;
+        emit ConfigUpdated(ConfigField.VrfCoordinator, uint256(uint160(coordinator)));
+    }
+
+    // ──────────────────────────────────────────────
+    // Chaos exhibitions (VRF outcomes)
+    // ──────────────────────────────────────────────
+    /// @notice Open an Exhibition whose winner is drawn by Chainlink VRF once it locks, not
+    ///         reported by the oracle. Oracle, proposal and feed results are refused for it
+    function createChaosMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (_vrfCoordinator == address(0)) revert VrfNotConfigured();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Exhibition);
+        _chaosMatches[matchId] = true;
+    }
+
+    /// @notice Ask VRF for a locked chaos match's winner. Permissionless, so the draw doesn't
+    ///         wait on the operator; one request in flight per match. A request that is never
+    ///         fulfilled leaves the match to timeoutMatch and full refunds
+    function requestChaosResult(bytes32 matchId) external returns (uint256 requestId) {
+        if (!_chaosMatches[matchId]) revert NotChaosMatch();
+        if (_vrfCoordinator == address(0)) revert VrfNotConfigured();
+        if (_matches[matchId].status != MatchStatus.Locked) revert MatchNotLocked();
+        uint256 pending = _chaosRequestIds[matchId];
+        if (pending != 0) revert ChaosResultPending(pending);
+
+        requestId = _requestRandomWord();
+        _chaosRequests[requestId] = matchId;
+        _chaosRequestIds[matchId] = requestId;
+        emit ChaosResultRequested(matchId, requestId);
+    }
+
+    /// @notice The side a VRF word draws — lets anyone re-derive a chaos result from its seed
+    function chaosWinner(uint256 word) public pure returns (uint8) {
+        return uint8(word % 2);
+    }
+
+    /// @dev A draw that lands after the match was cancelled (timeout, emergency) is dropped
+    function _drawChaosResult(bytes32 matchId, uint256 word) internal {
+        if (_matches[matchId].status != MatchStatus.Locked) return;
+        _chaosSeeds[matchId] = word;
+        uint8 winner = chaosWinner(word);
+        _resolveMatch(matchId, winner);
+        emit ChaosResultDrawn(matchId, word, winner);
+    }
+}
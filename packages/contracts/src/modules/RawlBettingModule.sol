@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "../RawlBettingBase.sol";
+
+/// @title RawlBettingModule — a slice of RawlBetting's entry points
+/// @notice RawlBetting registers each module's selectors at deploy and delegatecalls them from
+///         its fallback, so module code runs against RawlBetting's storage, balance and address.
+///         Modules get the shared layout from RawlBettingBase and must not declare state of
+///         their own
+abstract contract RawlBettingModule is RawlBettingBase {
+    /// @dev A module deployed on its own has empty storage of its own. Pausing that copy keeps
+    ///      direct calls, rather than calls through RawlBetting, off every whenNotPaused path
+    constructor() {
+        _pause();
+    }
+
+    /// @notice The entry points RawlBetting routes to this module
+    function selectors() external pure virtual returns (bytes4[] memory);
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title ResolutionModule — oracle resolution: M-of-N attestation, signed results, dual-signature
+///       co-signs, forfeits, per-match oracle rotation and correlated markets
+contract ResolutionModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](14);
+        s[0] = ResolutionModule.resolveMatch.selector;
+        s[1] = ResolutionModule.resolveMatchWithOpId.selector;
+        s[2] = ResolutionModule.resolveMatchWithResultHash.selector;
+        s[3] = ResolutionModule.resolveMatchSigned.selector;
+        s[4] = ResolutionModule.setResolutionThreshold.selector;
+        s[5] = ResolutionModule.resultVotesOf.selector;
+        s[6] = ResolutionModule.cosignResolution.selector;
+        s[7] = ResolutionModule.setDualSignPoolSize.selector;
+        s[8] = ResolutionModule.forfeitMatch.selector;
+        s[9] = ResolutionModule.forfeitNoContest.selector;
+        s[10] = ResolutionModule.setMatchOracle.selector;
+        s[11] = ResolutionModule.createCorrelatedMatch.selector;
+        s[12] = ResolutionModule.resolveCorrelated.selector;
+        s[13] = ResolutionModule.clearCorrelationFlag.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // resolveMatch
+    // ──────────────────────────────────────────────
+    function resolveMatch(bytes32 matchId, uint8 winner)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+    {
+        _attestResult(matchId, winner, msg.sender);
+    }
+
+    /// @notice resolveMatch that no-ops when retried with an already-executed opId
+    function resolveMatchWithOpId(bytes32 matchId, uint8 winner, bytes32 opId)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+        idempotent(opId)
+    {
+        _attestResult(matchId, winner, msg.sender);
+    }
+
+    /// @notice resolveMatch that also commits the hash of the result record (replay, judges'
+    ///         card, ...) so a later dispute references an immutable on-chain commitment
+    function resolveMatchWithResultHash(bytes32 matchId, uint8 winner, bytes32 resultHash)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+    {
+        _attestWithResultHash(matchId, winner, resultHash);
+    }
+
+    /// @notice Land a result an oracle signed offline (EIP-712 ResolutionResult), so any
+    ///         relayer can submit it and the oracle key never sits on a hot server. The
+    ///         signature counts as that oracle's attestation; it must postdate the lock
+    function resolveMatchSigned(
+        bytes32 matchId,
+        uint8 winner,
+        uint64 timestamp,
+        bytes calldata signature
+    ) external {
+        bytes32 digest =
+            _hashTypedDataV4(keccak256(abi.encode(RESULT_TYPEHASH, matchId, winner, timestamp)));
+        (address oracle, ECDSA.RecoverError error,) = ECDSA.tryRecover(digest, signature);
+        if (error != ECDSA.RecoverError.NoError || !hasRole(RESOLVE_AUTHORITY_ROLE, oracle)) {
+            revert InvalidResultSignature();
+        }
+        if (timestamp < _matches[matchId].lockTimestamp || timestamp > block.timestamp) {
+            revert InvalidResultSignature();
+        }
+
+        emit SignedResultRelayed(matchId, oracle, msg.sender);
+        _attestResult(matchId, winner, oracle);
+    }
+
+    /// @notice Number of RESOLVE_AUTHORITY_ROLE attestations a result needs. 1 resolves on
+    ///         the first call as before; the admin keeps it at or below the role's holders
+    function setResolutionThreshold(uint8 threshold) external onlyRole(ADMIN_ROLE) {
+        if (threshold == 0 || threshold > MAX_RESOLUTION_THRESHOLD) {
+            revert InvalidResolutionThreshold();
+        }
+        _resolutionThreshold = threshold;
+        emit ConfigUpdated(ConfigField.ResolutionThreshold, uint256(threshold));
+    }
+
+    /// @notice Attestations so far for each side of a locked match
+    function resultVotesOf(bytes32 matchId) external view returns (uint8 sideA, uint8 sideB) {
+        uint8[2] storage votes = resultVotes[matchId];
+        return (votes[0], votes[1]);
+    }
+
+    // ──────────────────────────────────────────────
+    // Dual-signature resolution (authority co-sign)
+    // ──────────────────────────────────────────────
+    /// @notice The authority's half of a result on a pool of at least dualSignPoolSize.
+    ///         Either side may sign first; the match resolves once the oracles' result and
+    ///         this co-sign name the same winner. Counts as an authority ping
+    function cosignResolution(bytes32 matchId, uint8 winner) external {
+        if (msg.sender != _authority) revert NotAuthority();
+        if (winner > 1) revert InvalidSide();
+        _requireTransition(
+            _matches[matchId], MatchStatus.Resolved, MatchStateMachine.Actor.ResolveAuthority
+        );
+
+        _authorityCosigns[matchId] = winner + 1;
+        _lastAuthorityPing = uint64(block.timestamp);
+        emit ResolutionCosigned(matchId, msg.sender, winner);
+
+        if (_oracleResults[matchId] == winner + 1) _resolveMatch(matchId, winner);
+    }
+
+    /// @notice Pool size from which a result needs the authority's co-sign on top of the
+    ///         oracles'. Applies to matches still awaiting their result
+    function setDualSignPoolSize(uint128 newSize) external onlyRole(ADMIN_ROLE) {
+        _dualSignPoolSize = newSize;
+        emit ConfigUpdated(ConfigField.DualSignPoolSize, uint256(newSize));
+    }
+
+    // ──────────────────────────────────────────────
+    // Forfeits (resolution from Open)
+    // ──────────────────────────────────────────────
+    /// @notice Award a match that never locked to the fighter who showed up. Recorded as a
+    ///         Walkover in resultKinds and settles like any resolved match. A single oracle
+    ///         can't walk over a pool that needs a quorum or the authority's co-sign — those
+    ///         go through forfeitNoContest instead — nor a match a feed or VRF draw resolves
+    function forfeitMatch(bytes32 matchId, uint8 winner)
+        external
+        onlyRole(RESOLVE_AUTHORITY_ROLE)
+    {
+        if (_resolvedExternally(matchId)) revert ResolvedByFeed();
+        _requireMatchOracle(matchId, msg.sender);
+        if (_resolutionThreshold > 1 || _needsCosign(matchId)) revert WalkoverNeedsQuorum();
+
+        _resultKinds[matchId] = ResultKind.Walkover;
+        _resolveMatchAs(matchId, winner, MatchStateMachine.Actor.Forfeit);
+        emit MatchForfeited(matchId, ResultKind.Walkover, winner, msg.sender);
+    }
+
+    /// @notice Call off a match that never locked because neither fighter can go on. Bettors
+    ///         refund as with any cancel and the creator bond is returned. Feed-bound and chaos
+    ///         matches are left to their feed or VRF draw, like forfeitMatch
+    function forfeitNoContest(bytes32 matchId) external onlyRole(RESOLVE_AUTHORITY_ROLE) {
+        if (_resolvedExternally(matchId)) revert ResolvedByFeed();
+        _requireMatchOracle(matchId, msg.sender);
+        MatchPool storage pool = _matches[matchId];
+        _requireTransition(pool, MatchStatus.Cancelled, MatchStateMachine.Actor.Forfeit);
+        _setCancelled(matchId, pool, CancelReason.NoShow);
+    }
+
+    // ──────────────────────────────────────────────
+    // Per-match oracle (rotation)
+    // ──────────────────────────────────────────────
+    /// @notice Swap a key compromised mid-match for `oracle` on one unresolved match. The
+    ///         replaced key can no longer attest, amend or forfeit it and its vote is struck, so
+    ///         `oracle` takes its seat in the resolutionThreshold quorum — the other votes stand.
+    ///         A result awaiting the authority's co-sign survives only if the remaining votes
+    ///         still reach the threshold. Counts as an authority ping
+    function setMatchOracle(bytes32 matchId, address replaced, address oracle) external {
+        if (msg.sender != _authority) revert NotAuthority();
+        MatchStatus status = _matches[matchId].status;
+        if (status != MatchStatus.Open && status != MatchStatus.Locked) {
+            revert InvalidMatchStatus();
+        }
+        if (
+            oracle == replaced || !hasRole(RESOLVE_AUTHORITY_ROLE, oracle)
+                || _matchOracles[matchId][oracle] != address(0)
+        ) revert InvalidMatchOracle();
+
+        _matchOracles[matchId][replaced] = oracle;
+        uint8 vote = _resultAttested[matchId][replaced];
+        if (vote != 0) {
+            delete _resultAttested[matchId][replaced];
+            resultVotes[matchId][vote - 1]--;
+        }
+        // A single-oracle result isn't recorded per key, so it can't be told apart from the
+        // replaced key's and is dropped; the oracle resubmits
+        uint8 pending = _oracleResults[matchId];
+        if (pending != 0) {
+            bool backed = _resolutionThreshold > 1
+                && resultVotes[matchId][pending - 1] >= _resolutionThreshold;
+            if (!backed) delete _oracleResults[matchId];
+        }
+        _lastAuthorityPing = uint64(block.timestamp);
+        emit MatchOracleSet(matchId, replaced, oracle);
+    }
+
+    // ──────────────────────────────────────────────
+    // Correlated markets (result consistency)
+    // ──────────────────────────────────────────────
+    /// @notice Create a market tied to others on the same fight (winner, method, ...) under
+    ///         `groupId`. Every member must resolve on the same result record — see
+    ///         resolveCorrelated — or it is held for guardian review
+    function createCorrelatedMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        bytes32 groupId
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) withCreatorBond(1) {
+        if (groupId == bytes32(0)) revert InvalidCorrelatedGroup();
+        _createMatch(matchId, fighterA, fighterB, minBet, bettingWindow, MatchCategory.Ranked);
+        _correlatedGroups[matchId] = groupId;
+        emit CorrelatedMatchCreated(matchId, groupId);
+    }
+
+    /// @notice Resolve several markets of one group in one transaction against one result
+    ///         record. All-or-nothing like createMatches
+    function resolveCorrelated(
+        bytes32 groupId,
+        bytes32[] calldata matchIds,
+        uint8[] calldata winners,
+        bytes32 resultHash
+    ) external onlyRole(RESOLVE_AUTHORITY_ROLE) {
+        if (matchIds.length == 0 || matchIds.length != winners.length) revert InvalidBatchSize();
+        for (uint256 i; i < matchIds.length; ++i) {
+            if (_correlatedGroups[matchIds[i]] != groupId) revert InvalidCorrelatedGroup();
+            _attestWithResultHash(matchIds[i], winners[i], resultHash);
+        }
+    }
+
+    /// @notice Release a flagged market for settlement once a guardian has reviewed it — after
+    ///         an overturnResult if its result was the wrong one
+    function clearCorrelationFlag(bytes32 matchId) external onlyRole(GUARDIAN_ROLE) {
+        if (!_correlationFlags[matchId]) revert NotFlagged();
+        delete _correlationFlags[matchId];
+        emit CorrelationCleared(matchId, msg.sender);
+    }
+}
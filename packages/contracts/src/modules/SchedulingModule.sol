@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title SchedulingModule — recurring schedules and two-phase (funding-confirmed) creation
+contract SchedulingModule is RawlBettingModule {
+    using SafeCast for uint256;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](9);
+        s[0] = SchedulingModule.createRecurringSchedule.selector;
+        s[1] = SchedulingModule.fundRecurringSchedule.selector;
+        s[2] = SchedulingModule.stopRecurringSchedule.selector;
+        s[3] = SchedulingModule.crankSchedule.selector;
+        s[4] = SchedulingModule.initMatch.selector;
+        s[5] = SchedulingModule.depositCreatorBond.selector;
+        s[6] = SchedulingModule.fundPendingSeed.selector;
+        s[7] = SchedulingModule.activateMatch.selector;
+        s[8] = SchedulingModule.abortPendingMatch.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // Recurring schedules (permissionless crank)
+    // ──────────────────────────────────────────────
+    /// @notice Register a template that anyone can crank into a new match every `interval`,
+    ///         starting at `firstAt`. msg.value prefunds creator bonds for the cranked matches
+    function createRecurringSchedule(
+        bytes32 scheduleId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint64 interval,
+        uint64 firstAt
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) {
+        if (_recurringSchedules[scheduleId].creator != address(0)) {
+            revert InvalidRecurringSchedule();
+        }
+        if (interval < MIN_SCHEDULE_INTERVAL) revert InvalidRecurringSchedule();
+
+        _recurringSchedules[scheduleId] = RecurringSchedule({
+            creator: msg.sender,
+            interval: interval,
+            sequence: 0,
+            fighterA: fighterA,
+            nextAt: firstAt,
+            active: true,
+            fighterB: fighterB,
+            bettingWindow: bettingWindow,
+            minBet: minBet,
+            bondBudget: msg.value.toUint128()
+        });
+
+        emit RecurringScheduleCreated(scheduleId, msg.sender, interval, firstAt);
+    }
+
+    function fundRecurringSchedule(bytes32 scheduleId) external payable {
+        RecurringSchedule storage schedule = _recurringSchedules[scheduleId];
+        if (!schedule.active) revert ScheduleInactive();
+        schedule.bondBudget += msg.value.toUint128();
+    }
+
+    /// @notice Stop a schedule and refund its unused bond budget. Creator or admin
+    function stopRecurringSchedule(bytes32 scheduleId) external nonReentrant {
+        RecurringSchedule storage schedule = _recurringSchedules[scheduleId];
+        if (!schedule.active) revert ScheduleInactive();
+        if (msg.sender != schedule.creator && !hasRole(ADMIN_ROLE, msg.sender)) {
+            revert AccessControlUnauthorizedAccount(msg.sender, ADMIN_ROLE);
+        }
+
+        uint256 refund = schedule.bondBudget;
+        schedule.active = false;
+        schedule.bondBudget = 0;
+
+        (bool success,) = payable(schedule.creator).call{value: refund}("");
+        if (!success) revert TransferFailed();
+
+        emit RecurringScheduleStopped(scheduleId, refund);
+    }
+
+    /// @notice Open the schedule's next match once it is due. Permissionless, so no off-chain
+    ///         cron has to be trusted with the match parameters. Missed slots are skipped
+    ///         rather than created in a burst
+    function crankSchedule(bytes32 scheduleId) external whenNotPaused returns (bytes32 matchId) {
+        RecurringSchedule storage schedule = _recurringSchedules[scheduleId];
+        if (!schedule.active) revert ScheduleInactive();
+        if (block.timestamp < schedule.nextAt) revert ScheduleNotDue(schedule.nextAt);
+        // A revoked oracle's schedules stop with it
+        if (!hasRole(ORACLE_ROLE, schedule.creator)) revert ScheduleInactive();
+
+        if (schedule.bondBudget < _creatorBond) revert InsufficientScheduleBudget();
+        schedule.bondBudget -= _creatorBond;
+
+        uint32 sequence = schedule.sequence++;
+        uint64 missed = (uint64(block.timestamp) - schedule.nextAt) / schedule.interval;
+        schedule.nextAt += (missed + 1) * schedule.interval;
+
+        matchId = keccak256(abi.encode(scheduleId, sequence));
+        _createMatchFor(
+            schedule.creator,
+            matchId,
+            schedule.fighterA,
+            schedule.fighterB,
+            schedule.minBet,
+            schedule.bettingWindow,
+            MatchCategory.Ranked,
+            MatchStatus.Open
+        );
+
+        emit ScheduleCranked(scheduleId, matchId, sequence, schedule.nextAt);
+    }
+
+    // ──────────────────────────────────────────────
+    // Two-phase creation (funding confirmation)
+    // ──────────────────────────────────────────────
+    /// @notice Create a match in Pending status. It takes no bets until activateMatch confirms
+    ///         the creator bond and any house seed have arrived, so a setup that fails halfway
+    ///         never leaves an Open, under-collateralized pool. msg.value is an initial bond
+    ///         deposit and may be partial — depositCreatorBond tops it up
+    function initMatch(
+        bytes32 matchId,
+        address fighterA,
+        address fighterB,
+        uint128 minBet,
+        uint64 bettingWindow,
+        uint128 seedA,
+        uint128 seedB
+    ) external payable whenNotPaused onlyRole(ORACLE_ROLE) {
+        if (msg.value > _creatorBond) revert InvalidCreatorBond();
+        // Seeds cover both sides or neither, like seedMatch
+        if ((seedA == 0) != (seedB == 0)) revert InvalidSeedAmount();
+
+        _createMatchFor(
+            msg.sender,
+            matchId,
+            fighterA,
+            fighterB,
+            minBet,
+            bettingWindow,
+            MatchCategory.Ranked,
+            MatchStatus.Pending
+        );
+        _creatorBonds[matchId] = msg.value.toUint128();
+        _pendingFundings[matchId] = PendingFunding({
+            requiredBond: _creatorBond,
+            seedA: seedA,
+            seedB: seedB,
+            seeder: address(0),
+            seedFunded: false
+        });
+
+        emit MatchInitialized(matchId, _creatorBond, seedA, seedB);
+    }
+
+    function depositCreatorBond(bytes32 matchId) external payable {
+        if (_matches[matchId].status != MatchStatus.Pending) revert MatchNotPending();
+        uint128 deposited = _creatorBonds[matchId] + msg.value.toUint128();
+        if (deposited > _pendingFundings[matchId].requiredBond) revert InvalidCreatorBond();
+        _creatorBonds[matchId] = deposited;
+    }
+
+    /// @notice Escrow the house seed for a Pending match. msg.value must equal seedA + seedB;
+    ///         the seed is placed as the seeder's bets when the match activates
+    function fundPendingSeed(bytes32 matchId) external payable whenNotPaused {
+        if (!_approvedSeeders[msg.sender] && !hasRole(ADMIN_ROLE, msg.sender)) revert NotSeeder();
+        if (_matches[matchId].status != MatchStatus.Pending) revert MatchNotPending();
+        _screen(msg.sender);
+
+        PendingFunding storage funding = _pendingFundings[matchId];
+        if (funding.seedA == 0 || funding.seedFunded) revert InvalidSeedAmount();
+        if (msg.value != uint256(funding.seedA) + funding.seedB) revert InvalidSeedAmount();
+
+        funding.seeder = msg.sender;
+        funding.seedFunded = true;
+
+        emit PendingSeedFunded(matchId, msg.sender);
+    }
+
+    /// @notice Open a Pending match once every required deposit is held. The betting window
+    ///         runs from activation, not from initMatch
+    function activateMatch(bytes32 matchId) external whenNotPaused onlyRole(ORACLE_ROLE) {
+        MatchPool storage pool = _matches[matchId];
+        // None → Open is creation, not activation
+        if (pool.status != MatchStatus.Pending) revert MatchNotPending();
+        _requireTransition(pool, MatchStatus.Open, MatchStateMachine.Actor.Creator);
+
+        PendingFunding storage funding = _pendingFundings[matchId];
+        if (_creatorBonds[matchId] < funding.requiredBond) revert MatchUnderfunded();
+        if (funding.seedA > 0 && !funding.seedFunded) revert MatchUnderfunded();
+
+        pool.status = MatchStatus.Open;
+        pool.createdAt = uint64(block.timestamp);
+
+        if (funding.seedFunded) {
+            address seeder = funding.seeder;
+            for (uint8 side; side < 2; ++side) {
+                address key = _seedKey(seeder, side);
+                _seedOwners[key] = seeder;
+                _placeBet(matchId, side, key, side == 0 ? funding.seedA : funding.seedB);
+            }
+            emit MatchSeeded(matchId, seeder, funding.seedA, funding.seedB);
+        }
+
+        emit MatchActivated(matchId, uint64(block.timestamp));
+    }
+
+    /// @notice Cancel a match that never activated and refund its escrowed seed. Any partial
+    ///         bond is returned through settleCreatorBond. Creator or admin
+    function abortPendingMatch(bytes32 matchId) external nonReentrant {
+        MatchPool storage pool = _matches[matchId];
+        // An admin aborting acts for the creator — only Creator may cancel out of Pending,
+        // so cancelMatch can't strand an escrowed seed
+        _requireTransition(pool, MatchStatus.Cancelled, MatchStateMachine.Actor.Creator);
+        if (msg.sender != _matchCreators[matchId] && !hasRole(ADMIN_ROLE, msg.sender)) {
+            revert AccessControlUnauthorizedAccount(msg.sender, ADMIN_ROLE);
+        }
+
+        PendingFunding storage funding = _pendingFundings[matchId];
+        uint256 refund = funding.seedFunded ? uint256(funding.seedA) + funding.seedB : 0;
+        address seeder = funding.seeder;
+        funding.seedFunded = false;
+
+        _setCancelled(matchId, pool, CancelReason.OperatorCancel);
+
+        if (refund > 0) {
+            (bool success,) = payable(seeder).call{value: refund}("");
+            if (!success) revert TransferFailed();
+        }
+
+        emit PendingMatchAborted(matchId, refund);
+    }
+}
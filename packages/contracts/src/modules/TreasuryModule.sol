@@ -0,0 +1,493 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title TreasuryModule — fee withdrawal, sweeps, cash-outs, solvency, referrals, fee tiers,
+///       donations and spending limits
+contract TreasuryModule is RawlBettingModule {
+    using EventLog for EventLog.Buffer;
+    using SafeCast for uint256;
+
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](22);
+        s[0] = TreasuryModule.cashOut.selector;
+        s[1] = TreasuryModule.withdrawCashOutDust.selector;
+        s[2] = TreasuryModule.withdrawFees.selector;
+        s[3] = TreasuryModule.withdrawFeesWithOpId.selector;
+        s[4] = TreasuryModule.sweepUnclaimed.selector;
+        s[5] = TreasuryModule.sweepCancelled.selector;
+        s[6] = TreasuryModule.previewSweep.selector;
+        s[7] = TreasuryModule.registerReferrer.selector;
+        s[8] = TreasuryModule.claimReferralRewards.selector;
+        s[9] = TreasuryModule.setReferralShareBps.selector;
+        s[10] = TreasuryModule.setFeeTiers.selector;
+        s[11] = TreasuryModule.rollingVolume.selector;
+        s[12] = TreasuryModule.feeTierOf.selector;
+        s[13] = TreasuryModule.getSolvency.selector;
+        s[14] = TreasuryModule.proposeDonation.selector;
+        s[15] = TreasuryModule.cancelDonationProposal.selector;
+        s[16] = TreasuryModule.applyDonation.selector;
+        s[17] = TreasuryModule.approveLargeDebit.selector;
+        s[18] = TreasuryModule.setSpendLimit.selector;
+        s[19] = TreasuryModule.fundCashOutReserve.selector;
+        s[20] = TreasuryModule.withdrawCashOutReserve.selector;
+        s[21] = TreasuryModule.settleCashOutDesk.selector;
+    }
+
+    // ──────────────────────────────────────────────
+    // cashOut (Open exits, Locked sales to the cash-out reserve)
+    // ──────────────────────────────────────────────
+    /// @notice Exit a position early at its value at the pool's implied odds less
+    ///         cashOutDiscountBps. While the match is Open the stake leaves the pool, so
+    ///         remaining bettors settle as if it was never placed. Once it is Locked the
+    ///         cash-out reserve buys the position instead (see _sellToReserve)
+    function cashOut(bytes32 matchId) external nonReentrant {
+        if (_cashOutDiscountBps == 0) revert CashOutDisabled();
+
+        MatchPool storage pool = _matches[matchId];
+        bool live = pool.status == MatchStatus.Locked;
+        if (!live && pool.status != MatchStatus.Open) revert InvalidMatchStatus();
+
+        BetInfo storage bet = _bets[matchId][msg.sender];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+        // Cashing out closes the position early, so the first-bet insurance lapses
+        if (_firstBets[msg.sender].matchId == matchId) _releaseFirstBet(msg.sender);
+        if (live) {
+            _sellToReserve(matchId, pool, bet);
+            return;
+        }
+
+        uint128 stake = bet.amount;
+        uint256 value = Settlement.exitValue(_settlementPool(matchId, pool), stake);
+        uint256 amount = BpsMath.toBps(_cashOutDiscountBps).complement().applyTo(value);
+        uint256 retained = stake - amount;
+
+        // CEI: remove the stake from every pool-derived total before paying out. Its stake may
+        // sit in any shard, so fold them into MatchPool first and take it out of there
+        bet.claimed = true;
+        _cashOutDust += retained;
+        _consolidateShards(matchId, pool);
+        if (bet.side == 0) {
+            pool.sideATotal = pool.sideATotal.sub(Wei.wrap(stake));
+            unchecked { pool.sideABetCount--; }
+        } else {
+            pool.sideBTotal = pool.sideBTotal.sub(Wei.wrap(stake));
+            unchecked { pool.sideBBetCount--; }
+        }
+        unchecked { pool.betCount--; }
+
+        address referrer = _betReferrers[matchId][msg.sender];
+        if (referrer != address(0)) {
+            _referralPools[matchId].referredVolume -= stake;
+            _referralAccruals[matchId][referrer].volume -= stake;
+        }
+        eventLogs[matchId].push(EventLog.Kind.CashedOut, bet.side, stake);
+        amount = _returnVoucherPrincipal(matchId, msg.sender, amount);
+        amount = _returnSponsorMatch(matchId, msg.sender, amount);
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit CashedOut(matchId, msg.sender, amount, retained);
+    }
+
+    /// @dev A stake can't leave a live pool without winners paying for it, so the reserve
+    ///      takes the position over: the stake stays in the pool, other bettors' payouts
+    ///      don't move whichever side wins, and settleCashOutDesk later collects whatever the
+    ///      position is owed. The reserve carries the risk, so that is what is bounded — the
+    ///      price never beats the implied odds frozen at lock, each match draws at most
+    ///      cashOutMatchCap, and sales close once a result is in sight (an oracle vote or
+    ///      proposal, or a game server's finished round), when only losers would still sell
+    function _sellToReserve(bytes32 matchId, MatchPool storage pool, BetInfo storage bet)
+        internal
+    {
+        if (
+            _oracleResults[matchId] != 0 || resultVotes[matchId][0] != 0
+                || resultVotes[matchId][1] != 0 || _attestations[matchId].finished
+                || _resultProposals[matchId].proposer != address(0)
+        ) revert ResultPending();
+
+        uint128 stake = bet.amount;
+        uint8 side = bet.side;
+        uint256 value = Settlement.fairValue(_settlementPool(matchId, pool), side, stake);
+        uint256 price = BpsMath.toBps(_cashOutDiscountBps).complement().applyTo(value);
+        uint256 spent = _cashOutSpent[matchId] + price;
+        if (spent > _cashOutMatchCap) revert CashOutLimitReached();
+        if (price > _cashOutReserve) revert InsufficientCashOutReserve();
+
+        // CEI: hand the position to the reserve's desk before paying out
+        _cashOutReserve -= price;
+        _cashOutSpent[matchId] = spent.toUint128();
+        bet.claimed = true;
+        BetInfo storage desk = _bets[matchId][_cashOutDesk(side)];
+        if (desk.amount == 0) {
+            desk.side = side;
+        } else {
+            // The seller's bet folds into the desk's, so one fewer bet is left to settle
+            unchecked {
+                pool.betCount--;
+                if (side == 0) pool.sideABetCount--;
+                else pool.sideBBetCount--;
+            }
+        }
+        desk.amount += stake;
+        uint256 amount = _returnVoucherPrincipal(matchId, msg.sender, price);
+        amount = _returnSponsorMatch(matchId, msg.sender, amount);
+
+        (bool success,) = payable(msg.sender).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit PositionSold(matchId, msg.sender, side, stake, amount);
+    }
+
+    /// @notice Add ETH the reserve can spend buying positions on Locked matches
+    function fundCashOutReserve() external payable onlyRole(ADMIN_ROLE) {
+        _cashOutReserve += msg.value;
+        emit CashOutReserveFunded(msg.sender, msg.value);
+    }
+
+    function withdrawCashOutReserve(uint256 amount) external onlyRole(ADMIN_ROLE) nonReentrant {
+        if (amount > _cashOutReserve) revert InsufficientCashOutReserve();
+        _cashOutReserve -= amount;
+
+        (bool success,) = payable(_treasury).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit CashOutReserveWithdrawn(amount, _treasury);
+    }
+
+    /// @notice Return what the reserve's positions on `side` are owed — winnings, a loss-floor
+    ///         rebate or a refund — to the reserve once the match settles. Anyone may call it;
+    ///         the ETH never leaves the contract
+    function settleCashOutDesk(bytes32 matchId, uint8 side) external nonReentrant {
+        if (side > 1) revert InvalidSide();
+        address desk = _cashOutDesk(side);
+        MatchPool storage pool = _matches[matchId];
+
+        uint256 amount;
+        if (pool.status == MatchStatus.Cancelled) {
+            amount = _markRefunded(matchId, desk);
+        } else if (_winningSideTotal(pool).isZero()) {
+            amount = _markNoWinnersRefunded(matchId, desk);
+        } else {
+            amount = _markPayoutClaimed(matchId, desk);
+        }
+        _cashOutReserve += amount;
+
+        emit CashOutDeskSettled(matchId, side, amount);
+    }
+
+    /// @notice Send the haircuts cashOut has kept to the dust treasury. Kept out of the exit
+    ///         itself so a reverting treasury can't block bettors from leaving
+    function withdrawCashOutDust() external onlyRole(ADMIN_ROLE) nonReentrant {
+        uint256 amount = _cashOutDust;
+        if (amount == 0) revert NoCashOutDust();
+        _cashOutDust = 0;
+
+        address destination = _routed(_treasuryRouting.dust);
+        (bool success,) = payable(destination).call{value: amount}("");
+        if (!success) revert TransferFailed();
+
+        emit CashOutDustWithdrawn(amount, destination);
+    }
+
+    // ──────────────────────────────────────────────
+    // withdrawFees
+    // ──────────────────────────────────────────────
+    function withdrawFees(bytes32 matchId) external onlyRole(ADMIN_ROLE) nonReentrant {
+        _withdrawFees(matchId);
+    }
+
+    /// @notice withdrawFees that no-ops when retried with an already-executed opId
+    function withdrawFeesWithOpId(bytes32 matchId, bytes32 opId)
+        external
+        onlyRole(ADMIN_ROLE)
+        nonReentrant
+        idempotent(opId)
+    {
+        _withdrawFees(matchId);
+    }
+
+    // ──────────────────────────────────────────────
+    // sweepUnclaimed (unclaimed winning bets and loss rebates → treasury)
+    // ──────────────────────────────────────────────
+    function sweepUnclaimed(bytes32 matchId, address bettor)
+        external
+        onlyRole(ADMIN_ROLE)
+        nonReentrant
+    {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+        if (block.timestamp < pool.resolveTimestamp + _claimWindow) revert ClaimWindowNotElapsed();
+        _requireUndisputed(matchId);
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+
+        uint256 payout = _claimableAmount(matchId, pool, bet);
+        _enforceSpendLimit(matchId, pool, bettor, payout);
+
+        _markSettled(matchId, bet);
+        sweptToTreasury[matchId][bettor] = true;
+        unchecked {
+            pool.betCount--;
+            _metrics.sweeps++;
+        }
+        payout = _returnVoucherPrincipal(matchId, bettor, payout);
+
+        (bool success,) = payable(_routed(_treasuryRouting.sweeps)).call{value: payout}("");
+        if (!success) revert TransferFailed();
+
+        emit UnclaimedSwept(matchId, bettor, payout);
+    }
+
+    // ──────────────────────────────────────────────
+    // sweepCancelled (uncollected refunds → original bettor)
+    // ──────────────────────────────────────────────
+    function sweepCancelled(bytes32 matchId, address bettor) external nonReentrant {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Cancelled) revert MatchNotCancelled();
+        if (block.timestamp < pool.cancelTimestamp + _claimWindow) revert ClaimWindowNotElapsed();
+
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0) revert NoBetFound();
+        if (bet.claimed) revert AlreadyClaimed();
+
+        _markSettled(matchId, bet);
+        unchecked {
+            pool.betCount--;
+            _metrics.sweeps++;
+        }
+        uint256 refundAmount = _returnVoucherPrincipal(matchId, bettor, bet.amount);
+        refundAmount = _returnSponsorMatch(matchId, bettor, refundAmount);
+
+        // Returns to original bettor (NOT treasury) — matches Solana behavior.
+        // A tokenized position goes to whoever holds it now, and the token is retired
+        address recipient = _cancelledSweepRecipient(bettor);
+        uint256 tokenId = _positionTokenIds[bettor];
+        if (tokenId != 0) _betPositions.burn(tokenId);
+        (bool success,) = payable(recipient).call{value: refundAmount}("");
+        if (!success) revert TransferFailed();
+
+        emit CancelledSwept(matchId, bettor, refundAmount);
+    }
+
+    /// @notice What sweepUnclaimed (resolved) or sweepCancelled (cancelled) would send for
+    ///         this bet and to whom, for operators to review a sweep before running it. Ignores
+    ///         the claim window, disputes and spend limits; zero for a bet that is missing,
+    ///         already settled or lost without a rebate
+    function previewSweep(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint256 amount, address recipient)
+    {
+        MatchPool storage pool = _matches[matchId];
+        BetInfo storage bet = _bets[matchId][bettor];
+        if (bet.amount == 0 || bet.claimed) return (0, address(0));
+
+        if (pool.status == MatchStatus.Cancelled) {
+            amount = _afterReturn(bet.amount, _voucherStakes[matchId][bettor]);
+            amount = _afterReturn(amount, _sponsorMatched[matchId][bettor]);
+            return (amount, _cancelledSweepRecipient(bettor));
+        }
+        if (pool.status != MatchStatus.Resolved) return (0, address(0));
+
+        if (_isWinningSide(pool, bet.side)) {
+            amount = _calculatePayout(matchId, pool, bet.amount);
+        } else if (!_winningSideTotal(pool).isZero()) {
+            amount = Settlement.lossRebate(_settlementPool(matchId, pool), bet.amount);
+        }
+        if (amount == 0) return (0, address(0));
+        return (
+            _afterReturn(amount, _voucherStakes[matchId][bettor]), _routed(_treasuryRouting.sweeps)
+        );
+    }
+
+    // ──────────────────────────────────────────────
+    // Referrals
+    // ──────────────────────────────────────────────
+    function registerReferrer() external whenNotPaused {
+        _referrers[msg.sender] = true;
+        emit ReferrerRegistered(msg.sender);
+    }
+
+    /// @notice Claim the referral slice of fees from bets referred on a resolved match
+    function claimReferralRewards(bytes32 matchId) external nonReentrant {
+        MatchPool storage pool = _matches[matchId];
+        if (pool.status != MatchStatus.Resolved) revert MatchNotResolved();
+
+        ReferralAccrual storage accrual = _referralAccruals[matchId][msg.sender];
+        if (accrual.volume == 0) revert NoReferralRewards();
+        if (accrual.claimed) revert AlreadyClaimed();
+
+        uint256 reward =
+            _referralReward(accrual.volume, pool.feeBps, _referralPools[matchId].shareBps);
+
+        accrual.claimed = true;
+
+        (bool success,) = payable(msg.sender).call{value: reward}("");
+        if (!success) revert TransferFailed();
+
+        emit ReferralRewardsClaimed(matchId, msg.sender, reward);
+    }
+
+    function setReferralShareBps(uint16 newShareBps) external onlyRole(ADMIN_ROLE) {
+        if (uint256(newShareBps) + _royaltyShareBps > 10_000) revert InvalidShareBps();
+        _referralShareBps = newShareBps;
+        emit ConfigUpdated(ConfigField.ReferralShareBps, uint256(newShareBps));
+    }
+
+    // ──────────────────────────────────────────────
+    // Volume fee tiers
+    // ──────────────────────────────────────────────
+    /// @notice Replace the tier table. Thresholds are 30-day staked volume in wei, strictly
+    ///         ascending; discounts are bps of the bettor's pro-rata platform fee, non-decreasing
+    function setFeeTiers(uint128[] calldata thresholds, uint16[] calldata discountsBps)
+        external
+        onlyRole(ADMIN_ROLE)
+    {
+        if (thresholds.length != discountsBps.length || thresholds.length > MAX_FEE_TIERS) {
+            revert InvalidFeeTiers();
+        }
+        for (uint256 i; i < thresholds.length; ++i) {
+            if (discountsBps[i] > 10_000) revert InvalidFeeTiers();
+            if (
+                i > 0
+                    && (thresholds[i] <= thresholds[i - 1] || discountsBps[i] < discountsBps[i - 1])
+            ) revert InvalidFeeTiers();
+        }
+        _feeTierThresholds = thresholds;
+        _feeTierDiscountBps = discountsBps;
+        emit ConfigUpdated(ConfigField.FeeTiers, thresholds.length);
+    }
+
+    /// @notice Sliding-window estimate of the bettor's stake over the last VOLUME_WINDOW
+    function rollingVolume(address bettor) external view returns (uint256) {
+        return _rollingVolume(bettor);
+    }
+
+    function feeTierOf(address bettor) external view returns (uint8) {
+        return _feeTierOf(bettor);
+    }
+
+    // ──────────────────────────────────────────────
+    // Solvency
+    // ──────────────────────────────────────────────
+    /// @notice Solvency of one match from on-chain fields alone — the single definition the
+    ///         reserves feed, watchtower and explorers share. All matches draw on one vault, so
+    ///         vaultBalance is the contract balance and surplus is this match's headroom in it
+    /// @return vaultBalance Contract ETH balance
+    /// @return outstandingLiability Still owed to unsettled bets (payouts, rebates, refunds)
+    /// @return feeReserved Unwithdrawn fee, net of volume-tier discounts already paid
+    /// @return surplus vaultBalance - outstandingLiability - feeReserved
+    function getSolvency(bytes32 matchId)
+        external
+        view
+        returns (
+            uint256 vaultBalance,
+            uint256 outstandingLiability,
+            uint256 feeReserved,
+            int256 surplus
+        )
+    {
+        MatchPool storage pool = _matches[matchId];
+        uint128[2] storage settled = _settledStakes[matchId];
+        (Wei sideATotal, Wei sideBTotal) = _sideTotals(matchId);
+        uint256 unsettledA = sideATotal.sub(Wei.wrap(settled[0])).raw();
+        uint256 unsettledB = sideBTotal.sub(Wei.wrap(settled[1])).raw();
+
+        if (pool.status == MatchStatus.Pending) {
+            // An escrowed seed is owed back to the seeder until activation places it
+            PendingFunding storage funding = _pendingFundings[matchId];
+            if (funding.seedFunded) outstandingLiability = uint256(funding.seedA) + funding.seedB;
+        } else if (pool.status != MatchStatus.Resolved) {
+            outstandingLiability = unsettledA + unsettledB;
+        } else {
+            Settlement.Pool memory snapshot = _settlementPool(matchId, pool);
+            bool sideAWon = pool.winner == MatchWinner.SideA;
+            uint256 unsettledWinners = sideAWon ? unsettledA : unsettledB;
+            uint256 unsettledLosers = sideAWon ? unsettledB : unsettledA;
+
+            if (_winningSideTotal(pool).isZero()) {
+                outstandingLiability = Settlement.noWinnerRefund(snapshot, unsettledLosers);
+            } else {
+                outstandingLiability = Settlement.payout(snapshot, unsettledWinners)
+                    + Settlement.lossRebate(snapshot, unsettledLosers);
+            }
+            if (!pool.feesWithdrawn) {
+                feeReserved = Settlement.fee(snapshot) - _feeDiscounts[matchId];
+            }
+        }
+
+        // A challenge bond is owed to the challenger or treasury until adjudicated
+        Dispute storage dispute = _disputes[matchId];
+        if (dispute.challenger != address(0) && !dispute.adjudicated) {
+            outstandingLiability += dispute.bond;
+        }
+        ResultProposal storage proposal = _resultProposals[matchId];
+        if (proposal.proposer != address(0) && !proposal.settled) {
+            uint256 bond = proposal.bond;
+            outstandingLiability += proposal.challenger == address(0) ? bond : 2 * bond;
+        }
+
+        vaultBalance = address(this).balance;
+        surplus = int256(vaultBalance) - int256(outstandingLiability + feeReserved);
+    }
+
+    // ──────────────────────────────────────────────
+    // Public goods mode (timelocked donation routing)
+    // ──────────────────────────────────────────────
+    /// @notice Queue a donation share of every fee withdrawal. Takes effect through
+    ///         applyDonation after CONFIG_TIMELOCK, so bettors see any change — including
+    ///         switching it off with bps = 0 — coming. A new proposal replaces a queued one
+    function proposeDonation(address recipient, uint16 bps) external onlyRole(ADMIN_ROLE) {
+        if (bps > 10_000 || (bps > 0 && recipient == address(0))) revert InvalidDonation();
+
+        uint64 eta = uint64(block.timestamp) + CONFIG_TIMELOCK;
+        _pendingDonation = PendingDonation({recipient: recipient, bps: bps, eta: eta});
+
+        emit DonationProposed(recipient, bps, eta);
+    }
+
+    function cancelDonationProposal() external onlyRole(ADMIN_ROLE) {
+        if (_pendingDonation.eta == 0) revert InvalidDonation();
+        delete _pendingDonation;
+        emit DonationProposalCancelled();
+    }
+
+    /// @notice Apply the queued donation config once its timelock has elapsed. Permissionless
+    function applyDonation() external {
+        PendingDonation memory pending = _pendingDonation;
+        if (pending.eta == 0) revert InvalidDonation();
+        if (block.timestamp < pending.eta) revert TimelockNotElapsed(pending.eta);
+
+        delete _pendingDonation;
+        _donationAddress = pending.recipient;
+        _donationBps = pending.bps;
+
+        emit ConfigUpdated(ConfigField.Donation, uint256(pending.bps));
+    }
+
+    // ──────────────────────────────────────────────
+    // Spending limits (guardian co-sign)
+    // ──────────────────────────────────────────────
+    /// @notice Co-sign one payout, no-winner refund or sweep for `account` on `matchId` that
+    ///         exceeds spendLimitBps of the pool. A compromised resolver or admin alone can't
+    ///         drain a pool through a single outsized debit
+    function approveLargeDebit(bytes32 matchId, address account)
+        external
+        onlyRole(GUARDIAN_ROLE)
+    {
+        _largeDebitApprovals[matchId][account] = true;
+        emit LargeDebitApproved(matchId, account, msg.sender);
+    }
+
+    function setSpendLimit(uint16 newLimitBps) external onlyRole(ADMIN_ROLE) {
+        if (newLimitBps > 10_000) revert InvalidShareBps();
+        _spendLimitBps = newLimitBps;
+        emit ConfigUpdated(ConfigField.SpendLimit, uint256(newLimitBps));
+    }
+}
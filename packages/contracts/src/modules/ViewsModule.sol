@@ -0,0 +1,953 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "./RawlBettingModule.sol";
+
+/// @title ViewsModule — read access to RawlBetting's storage
+/// @notice State lives in RawlBettingBase as internal variables so these getters are
+///         compiled once, here, rather than into every module. Each returns what the public
+///         variable it replaces did
+contract ViewsModule is RawlBettingModule {
+    function selectors() external pure override returns (bytes4[] memory s) {
+        s = new bytes4[](145);
+        s[0] = ViewsModule.treasury.selector;
+        s[1] = ViewsModule.treasuryRouting.selector;
+        s[2] = ViewsModule.feeBps.selector;
+        s[3] = ViewsModule.matchTimeout.selector;
+        s[4] = ViewsModule.resolutionDeadline.selector;
+        s[5] = ViewsModule.claimWindow.selector;
+        s[6] = ViewsModule.claimCooldown.selector;
+        s[7] = ViewsModule.minResolutionDelay.selector;
+        s[8] = ViewsModule.commitRevealRequired.selector;
+        s[9] = ViewsModule.referralShareBps.selector;
+        s[10] = ViewsModule.royaltyShareBps.selector;
+        s[11] = ViewsModule.maxPoolSize.selector;
+        s[12] = ViewsModule.fighterCollection.selector;
+        s[13] = ViewsModule.unpauseGracePeriod.selector;
+        s[14] = ViewsModule.bettingResumesAt.selector;
+        s[15] = ViewsModule.maxMatchAge.selector;
+        s[16] = ViewsModule.eventLogBetThreshold.selector;
+        s[17] = ViewsModule.minBetsPerSide.selector;
+        s[18] = ViewsModule.minTotalPool.selector;
+        s[19] = ViewsModule.snipeThresholdBps.selector;
+        s[20] = ViewsModule.snipeWindow.selector;
+        s[21] = ViewsModule.snipeExtension.selector;
+        s[22] = ViewsModule.maxSnipeExtension.selector;
+        s[23] = ViewsModule.cashOutDiscountBps.selector;
+        s[24] = ViewsModule.cashOutDust.selector;
+        s[25] = ViewsModule.screeningOracle.selector;
+        s[26] = ViewsModule.treasuryHook.selector;
+        s[27] = ViewsModule.treasuryHookBps.selector;
+        s[28] = ViewsModule.authority.selector;
+        s[29] = ViewsModule.successor.selector;
+        s[30] = ViewsModule.lastAuthorityPing.selector;
+        s[31] = ViewsModule.deadManPeriod.selector;
+        s[32] = ViewsModule.oracleLastSeen.selector;
+        s[33] = ViewsModule.oracleSilencePeriod.selector;
+        s[34] = ViewsModule.silentOracleTimeout.selector;
+        s[35] = ViewsModule.resolutionThreshold.selector;
+        s[36] = ViewsModule.donationAddress.selector;
+        s[37] = ViewsModule.donationBps.selector;
+        s[38] = ViewsModule.pendingDonation.selector;
+        s[39] = ViewsModule.metrics.selector;
+        s[40] = ViewsModule.totalDonated.selector;
+        s[41] = ViewsModule.switchboard.selector;
+        s[42] = ViewsModule.pyth.selector;
+        s[43] = ViewsModule.disputeWindow.selector;
+        s[44] = ViewsModule.disputeBond.selector;
+        s[45] = ViewsModule.vrfCoordinator.selector;
+        s[46] = ViewsModule.vrfKeyHash.selector;
+        s[47] = ViewsModule.vrfSubscriptionId.selector;
+        s[48] = ViewsModule.vrfCallbackGasLimit.selector;
+        s[49] = ViewsModule.vetoWindow.selector;
+        s[50] = ViewsModule.vetoBetCap.selector;
+        s[51] = ViewsModule.spendLimitBps.selector;
+        s[52] = ViewsModule.dualSignPoolSize.selector;
+        s[53] = ViewsModule.executedOps.selector;
+        s[54] = ViewsModule.matches.selector;
+        s[55] = ViewsModule.bets.selector;
+        s[56] = ViewsModule.betOdds.selector;
+        s[57] = ViewsModule.schedules.selector;
+        s[58] = ViewsModule.snipeExtensions.selector;
+        s[59] = ViewsModule.cancelReasons.selector;
+        s[60] = ViewsModule.resultKinds.selector;
+        s[61] = ViewsModule.fighters.selector;
+        s[62] = ViewsModule.fighterList.selector;
+        s[63] = ViewsModule.fighterTokens.selector;
+        s[64] = ViewsModule.lossFloors.selector;
+        s[65] = ViewsModule.matchMetadata.selector;
+        s[66] = ViewsModule.matchCategories.selector;
+        s[67] = ViewsModule.oddsSnapshots.selector;
+        s[68] = ViewsModule.recurringSchedules.selector;
+        s[69] = ViewsModule.pendingFundings.selector;
+        s[70] = ViewsModule.donatedFees.selector;
+        s[71] = ViewsModule.summaryCursor.selector;
+        s[72] = ViewsModule.resultAttested.selector;
+        s[73] = ViewsModule.resultFeeds.selector;
+        s[74] = ViewsModule.priceMarkets.selector;
+        s[75] = ViewsModule.disputeDeadlines.selector;
+        s[76] = ViewsModule.disputes.selector;
+        s[77] = ViewsModule.resultProposals.selector;
+        s[78] = ViewsModule.mysteryRequests.selector;
+        s[79] = ViewsModule.mysteryReserved.selector;
+        s[80] = ViewsModule.mysterySeeds.selector;
+        s[81] = ViewsModule.chaosMatches.selector;
+        s[82] = ViewsModule.chaosRequests.selector;
+        s[83] = ViewsModule.chaosRequestIds.selector;
+        s[84] = ViewsModule.chaosSeeds.selector;
+        s[85] = ViewsModule.vetoDeadlines.selector;
+        s[86] = ViewsModule.largeDebitApprovals.selector;
+        s[87] = ViewsModule.oracleResults.selector;
+        s[88] = ViewsModule.authorityCosigns.selector;
+        s[89] = ViewsModule.resultCommitments.selector;
+        s[90] = ViewsModule.revealedResults.selector;
+        s[91] = ViewsModule.matchAdmins.selector;
+        s[92] = ViewsModule.organizerExtensions.selector;
+        s[93] = ViewsModule.cancellationRequests.selector;
+        s[94] = ViewsModule.matchOracles.selector;
+        s[95] = ViewsModule.correlatedGroups.selector;
+        s[96] = ViewsModule.groupResultHashes.selector;
+        s[97] = ViewsModule.correlationFlags.selector;
+        s[98] = ViewsModule.committeeEpoch.selector;
+        s[99] = ViewsModule.committeeSeats.selector;
+        s[100] = ViewsModule.arbitrationTallies.selector;
+        s[101] = ViewsModule.arbitrationVotes.selector;
+        s[102] = ViewsModule.attestations.selector;
+        s[103] = ViewsModule.resolutionDeadlines.selector;
+        s[104] = ViewsModule.royaltyPools.selector;
+        s[105] = ViewsModule.referrers.selector;
+        s[106] = ViewsModule.referralPools.selector;
+        s[107] = ViewsModule.referralAccruals.selector;
+        s[108] = ViewsModule.betReferrers.selector;
+        s[109] = ViewsModule.claimDelegates.selector;
+        s[110] = ViewsModule.maxOpenMatchesPerCreator.selector;
+        s[111] = ViewsModule.openMatchCounts.selector;
+        s[112] = ViewsModule.matchCreators.selector;
+        s[113] = ViewsModule.creatorBond.selector;
+        s[114] = ViewsModule.creatorBonds.selector;
+        s[115] = ViewsModule.settledStakes.selector;
+        s[116] = ViewsModule.overturnedWinners.selector;
+        s[117] = ViewsModule.clawbacks.selector;
+        s[118] = ViewsModule.clawbackRecorded.selector;
+        s[119] = ViewsModule.clawbackDebt.selector;
+        s[120] = ViewsModule.firstBetInsuranceCap.selector;
+        s[121] = ViewsModule.hasBet.selector;
+        s[122] = ViewsModule.firstBets.selector;
+        s[123] = ViewsModule.feeTierThresholds.selector;
+        s[124] = ViewsModule.feeTierDiscountBps.selector;
+        s[125] = ViewsModule.bettorProfiles.selector;
+        s[126] = ViewsModule.feeDiscounts.selector;
+        s[127] = ViewsModule.programVersion.selector;
+        s[128] = ViewsModule.minClientVersion.selector;
+        s[129] = ViewsModule.promoBudget.selector;
+        s[130] = ViewsModule.vouchers.selector;
+        s[131] = ViewsModule.voucherStakes.selector;
+        s[132] = ViewsModule.sponsorships.selector;
+        s[133] = ViewsModule.sponsorMatched.selector;
+        s[134] = ViewsModule.approvedSeeders.selector;
+        s[135] = ViewsModule.seedOwners.selector;
+        s[136] = ViewsModule.pendingBets.selector;
+        s[137] = ViewsModule.betPositions.selector;
+        s[138] = ViewsModule.positionMatches.selector;
+        s[139] = ViewsModule.positionTokenIds.selector;
+        s[140] = ViewsModule.cashOutReserve.selector;
+        s[141] = ViewsModule.cashOutMatchCap.selector;
+        s[142] = ViewsModule.cashOutSpent.selector;
+        s[143] = ViewsModule.settlementStrategies.selector;
+        s[144] = ViewsModule.poolShards.selector;
+    }
+
+    function treasury() external view returns (address) {
+        return _treasury;
+    }
+
+    function treasuryRouting() external view returns (address fees, address sweeps, address dust) {
+        TreasuryRouting storage entry = _treasuryRouting;
+        return (entry.fees, entry.sweeps, entry.dust);
+    }
+
+    function feeBps() external view returns (uint16) {
+        return _feeBps;
+    }
+
+    function matchTimeout() external view returns (uint64) {
+        return _matchTimeout;
+    }
+
+    function resolutionDeadline() external view returns (uint64) {
+        return _resolutionDeadline;
+    }
+
+    function claimWindow() external view returns (uint64) {
+        return _claimWindow;
+    }
+
+    function claimCooldown() external view returns (uint64) {
+        return _claimCooldown;
+    }
+
+    function minResolutionDelay() external view returns (uint64) {
+        return _minResolutionDelay;
+    }
+
+    function commitRevealRequired() external view returns (bool) {
+        return _commitRevealRequired;
+    }
+
+    function referralShareBps() external view returns (uint16) {
+        return _referralShareBps;
+    }
+
+    function royaltyShareBps() external view returns (uint16) {
+        return _royaltyShareBps;
+    }
+
+    function maxPoolSize() external view returns (uint128) {
+        return _maxPoolSize;
+    }
+
+    function fighterCollection() external view returns (address) {
+        return _fighterCollection;
+    }
+
+    function unpauseGracePeriod() external view returns (uint64) {
+        return _unpauseGracePeriod;
+    }
+
+    function bettingResumesAt() external view returns (uint64) {
+        return _bettingResumesAt;
+    }
+
+    function maxMatchAge() external view returns (uint64) {
+        return _maxMatchAge;
+    }
+
+    function eventLogBetThreshold() external view returns (uint128) {
+        return _eventLogBetThreshold;
+    }
+
+    function minBetsPerSide() external view returns (uint32) {
+        return _minBetsPerSide;
+    }
+
+    function minTotalPool() external view returns (uint128) {
+        return _minTotalPool;
+    }
+
+    function snipeThresholdBps() external view returns (uint16) {
+        return _snipeThresholdBps;
+    }
+
+    function snipeWindow() external view returns (uint64) {
+        return _snipeWindow;
+    }
+
+    function snipeExtension() external view returns (uint64) {
+        return _snipeExtension;
+    }
+
+    function maxSnipeExtension() external view returns (uint64) {
+        return _maxSnipeExtension;
+    }
+
+    function cashOutDiscountBps() external view returns (uint16) {
+        return _cashOutDiscountBps;
+    }
+
+    function cashOutDust() external view returns (uint256) {
+        return _cashOutDust;
+    }
+
+    function cashOutReserve() external view returns (uint256) {
+        return _cashOutReserve;
+    }
+
+    function cashOutMatchCap() external view returns (uint128) {
+        return _cashOutMatchCap;
+    }
+
+    function cashOutSpent(bytes32 matchId) external view returns (uint128) {
+        return _cashOutSpent[matchId];
+    }
+
+    function screeningOracle() external view returns (address) {
+        return _screeningOracle;
+    }
+
+    function treasuryHook() external view returns (address) {
+        return _treasuryHook;
+    }
+
+    function treasuryHookBps() external view returns (uint16) {
+        return _treasuryHookBps;
+    }
+
+    function authority() external view returns (address) {
+        return _authority;
+    }
+
+    function successor() external view returns (address) {
+        return _successor;
+    }
+
+    function lastAuthorityPing() external view returns (uint64) {
+        return _lastAuthorityPing;
+    }
+
+    function deadManPeriod() external view returns (uint64) {
+        return _deadManPeriod;
+    }
+
+    function oracleLastSeen() external view returns (uint64) {
+        return _oracleLastSeen;
+    }
+
+    function oracleSilencePeriod() external view returns (uint64) {
+        return _oracleSilencePeriod;
+    }
+
+    function silentOracleTimeout() external view returns (uint64) {
+        return _silentOracleTimeout;
+    }
+
+    function resolutionThreshold() external view returns (uint8) {
+        return _resolutionThreshold;
+    }
+
+    function donationAddress() external view returns (address) {
+        return _donationAddress;
+    }
+
+    function donationBps() external view returns (uint16) {
+        return _donationBps;
+    }
+
+    function pendingDonation() external view returns (address recipient, uint16 bps, uint64 eta) {
+        PendingDonation storage entry = _pendingDonation;
+        return (entry.recipient, entry.bps, entry.eta);
+    }
+
+    function metrics()
+        external
+        view
+        returns (uint64 betsPlaced, uint64 claims, uint64 refunds, uint64 sweeps)
+    {
+        UsageMetrics storage entry = _metrics;
+        return (entry.betsPlaced, entry.claims, entry.refunds, entry.sweeps);
+    }
+
+    function totalDonated() external view returns (uint256) {
+        return _totalDonated;
+    }
+
+    function switchboard() external view returns (address) {
+        return _switchboard;
+    }
+
+    function pyth() external view returns (address) {
+        return _pyth;
+    }
+
+    function disputeWindow() external view returns (uint64) {
+        return _disputeWindow;
+    }
+
+    function disputeBond() external view returns (uint128) {
+        return _disputeBond;
+    }
+
+    function vrfCoordinator() external view returns (address) {
+        return _vrfCoordinator;
+    }
+
+    function vrfKeyHash() external view returns (bytes32) {
+        return _vrfKeyHash;
+    }
+
+    function vrfSubscriptionId() external view returns (uint256) {
+        return _vrfSubscriptionId;
+    }
+
+    function vrfCallbackGasLimit() external view returns (uint32) {
+        return _vrfCallbackGasLimit;
+    }
+
+    function vetoWindow() external view returns (uint64) {
+        return _vetoWindow;
+    }
+
+    function vetoBetCap() external view returns (uint128) {
+        return _vetoBetCap;
+    }
+
+    function spendLimitBps() external view returns (uint16) {
+        return _spendLimitBps;
+    }
+
+    function dualSignPoolSize() external view returns (uint128) {
+        return _dualSignPoolSize;
+    }
+
+    function executedOps(bytes32 opId) external view returns (bool) {
+        return _executedOps[opId];
+    }
+
+    function matches(bytes32 matchId)
+        external
+        view
+        returns (
+            address fighterA,
+            address fighterB,
+            MatchStatus status,
+            MatchWinner winner,
+            uint32 sideABetCount,
+            uint32 sideBBetCount,
+            uint32 winningBetCount,
+            uint32 betCount,
+            uint16 poolFeeBps,
+            uint128 sideATotal,
+            uint128 sideBTotal,
+            uint64 createdAt,
+            uint64 lockTimestamp,
+            uint64 resolveTimestamp,
+            uint64 cancelTimestamp,
+            uint128 minBet,
+            uint64 bettingWindow,
+            bool feesWithdrawn
+        )
+    {
+        MatchPool storage entry = _matches[matchId];
+        // Side totals include stake an Open match still holds in its shards
+        (Wei liveA, Wei liveB) = _sideTotals(matchId);
+        return (
+            entry.fighterA, entry.fighterB, entry.status, entry.winner, entry.sideABetCount,
+            entry.sideBBetCount, entry.winningBetCount, entry.betCount, entry.feeBps,
+            Wei.unwrap(liveA), Wei.unwrap(liveB), entry.createdAt, entry.lockTimestamp,
+            entry.resolveTimestamp, entry.cancelTimestamp, entry.minBet, entry.bettingWindow,
+            entry.feesWithdrawn
+        );
+    }
+
+    function poolShards(bytes32 matchId, uint256 shard)
+        external
+        view
+        returns (uint128 sideATotal, uint128 sideBTotal)
+    {
+        PoolShard storage entry = _poolShards[matchId][shard];
+        return (Wei.unwrap(entry.sideATotal), Wei.unwrap(entry.sideBTotal));
+    }
+
+    function bets(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint128 amount, uint8 side, bool claimed)
+    {
+        BetInfo storage entry = _bets[matchId][bettor];
+        return (entry.amount, entry.side, entry.claimed);
+    }
+
+    function betOdds(bytes32 matchId, address bettor)
+        external
+        view
+        returns (uint128 sideATotal, uint128 sideBTotal)
+    {
+        BetOdds storage entry = _betOdds[matchId][bettor];
+        return (entry.sideATotal, entry.sideBTotal);
+    }
+
+    function schedules(bytes32 matchId)
+        external
+        view
+        returns (uint64 opensAt, uint64 scheduledLockAt)
+    {
+        MatchSchedule storage entry = _schedules[matchId];
+        return (entry.opensAt, entry.scheduledLockAt);
+    }
+
+    function snipeExtensions(bytes32 matchId) external view returns (uint64) {
+        return _snipeExtensions[matchId];
+    }
+
+    function cancelReasons(bytes32 matchId) external view returns (CancelReason) {
+        return _cancelReasons[matchId];
+    }
+
+    function resultKinds(bytes32 matchId) external view returns (ResultKind) {
+        return _resultKinds[matchId];
+    }
+
+    function fighters(address fighter)
+        external
+        view
+        returns (
+            bytes32 nameHash,
+            address owner,
+            uint32 wins,
+            uint32 losses,
+            uint32 draws,
+            uint128 totalVolume
+        )
+    {
+        FighterRecord storage entry = _fighters[fighter];
+        return (
+            entry.nameHash, entry.owner, entry.wins, entry.losses, entry.draws, entry.totalVolume
+        );
+    }
+
+    function fighterList(uint256 index) external view returns (address) {
+        return _fighterList[index];
+    }
+
+    function fighterTokens(address fighter) external view returns (uint256 tokenId, bool linked) {
+        FighterToken storage entry = _fighterTokens[fighter];
+        return (entry.tokenId, entry.linked);
+    }
+
+    function settlementStrategies(bytes32 matchId) external view returns (Settlement.Strategy) {
+        return _settlementStrategies[matchId];
+    }
+
+    function lossFloors(bytes32 matchId) external view returns (uint16) {
+        return _lossFloors[matchId];
+    }
+
+    function matchMetadata(bytes32 matchId)
+        external
+        view
+        returns (bytes32 name, string memory uri, bytes32 metadataHash, bytes32 resultHash)
+    {
+        MatchMetadata storage entry = _matchMetadata[matchId];
+        return (entry.name, entry.uri, entry.metadataHash, entry.resultHash);
+    }
+
+    function matchCategories(bytes32 matchId) external view returns (MatchCategory) {
+        return _matchCategories[matchId];
+    }
+
+    function oddsSnapshots(bytes32 matchId)
+        external
+        view
+        returns (
+            uint128 sideATotal,
+            uint128 sideBTotal,
+            uint16 impliedABps,
+            uint16 impliedBBps,
+            uint64 lockedAt
+        )
+    {
+        OddsSnapshot storage entry = _oddsSnapshots[matchId];
+        return (
+            entry.sideATotal, entry.sideBTotal, entry.impliedABps, entry.impliedBBps,
+            entry.lockedAt
+        );
+    }
+
+    function recurringSchedules(bytes32 matchId)
+        external
+        view
+        returns (
+            address creator,
+            uint64 interval,
+            uint32 sequence,
+            address fighterA,
+            uint64 nextAt,
+            bool active,
+            address fighterB,
+            uint64 bettingWindow,
+            uint128 minBet,
+            uint128 bondBudget
+        )
+    {
+        RecurringSchedule storage entry = _recurringSchedules[matchId];
+        return (
+            entry.creator, entry.interval, entry.sequence, entry.fighterA, entry.nextAt,
+            entry.active, entry.fighterB, entry.bettingWindow, entry.minBet, entry.bondBudget
+        );
+    }
+
+    function pendingFundings(bytes32 matchId)
+        external
+        view
+        returns (
+            uint128 requiredBond,
+            uint128 seedA,
+            uint128 seedB,
+            address seeder,
+            bool seedFunded
+        )
+    {
+        PendingFunding storage entry = _pendingFundings[matchId];
+        return (entry.requiredBond, entry.seedA, entry.seedB, entry.seeder, entry.seedFunded);
+    }
+
+    function donatedFees(bytes32 matchId) external view returns (uint256) {
+        return _donatedFees[matchId];
+    }
+
+    function summaryCursor(bytes32 matchId) external view returns (uint256) {
+        return _summaryCursor[matchId];
+    }
+
+    function resultAttested(bytes32 matchId, address oracle) external view returns (uint8) {
+        return _resultAttested[matchId][oracle];
+    }
+
+    function resultFeeds(bytes32 matchId) external view returns (bytes32) {
+        return _resultFeeds[matchId];
+    }
+
+    function priceMarkets(bytes32 matchId)
+        external
+        view
+        returns (bytes32 priceId, int64 strike, int32 expo, uint64 resolveAt)
+    {
+        PriceMarket storage entry = _priceMarkets[matchId];
+        return (entry.priceId, entry.strike, entry.expo, entry.resolveAt);
+    }
+
+    function disputeDeadlines(bytes32 matchId) external view returns (uint64) {
+        return _disputeDeadlines[matchId];
+    }
+
+    function disputes(bytes32 matchId)
+        external
+        view
+        returns (address challenger, uint64 raisedAt, bool adjudicated, uint128 bond)
+    {
+        Dispute storage entry = _disputes[matchId];
+        return (entry.challenger, entry.raisedAt, entry.adjudicated, entry.bond);
+    }
+
+    function resultProposals(bytes32 matchId)
+        external
+        view
+        returns (
+            address proposer,
+            uint64 liveUntil,
+            uint8 winner,
+            address challenger,
+            bool settled,
+            uint128 bond
+        )
+    {
+        ResultProposal storage entry = _resultProposals[matchId];
+        return (
+            entry.proposer, entry.liveUntil, entry.winner, entry.challenger, entry.settled,
+            entry.bond
+        );
+    }
+
+    function mysteryRequests(uint256 requestId)
+        external
+        view
+        returns (
+            bytes32 matchId,
+            address creator,
+            uint64 bettingWindow,
+            uint128 minBet,
+            uint128 bond
+        )
+    {
+        MysteryRequest storage entry = _mysteryRequests[requestId];
+        return (entry.matchId, entry.creator, entry.bettingWindow, entry.minBet, entry.bond);
+    }
+
+    function mysteryReserved(bytes32 matchId) external view returns (bool) {
+        return _mysteryReserved[matchId];
+    }
+
+    function mysterySeeds(bytes32 matchId) external view returns (uint256) {
+        return _mysterySeeds[matchId];
+    }
+
+    function chaosMatches(bytes32 matchId) external view returns (bool) {
+        return _chaosMatches[matchId];
+    }
+
+    function chaosRequests(uint256 requestId) external view returns (bytes32) {
+        return _chaosRequests[requestId];
+    }
+
+    function chaosRequestIds(bytes32 matchId) external view returns (uint256) {
+        return _chaosRequestIds[matchId];
+    }
+
+    function chaosSeeds(bytes32 matchId) external view returns (uint256) {
+        return _chaosSeeds[matchId];
+    }
+
+    function vetoDeadlines(bytes32 matchId) external view returns (uint64) {
+        return _vetoDeadlines[matchId];
+    }
+
+    function largeDebitApprovals(bytes32 matchId, address account) external view returns (bool) {
+        return _largeDebitApprovals[matchId][account];
+    }
+
+    function oracleResults(bytes32 matchId) external view returns (uint8) {
+        return _oracleResults[matchId];
+    }
+
+    function authorityCosigns(bytes32 matchId) external view returns (uint8) {
+        return _authorityCosigns[matchId];
+    }
+
+    function resultCommitments(bytes32 matchId) external view returns (bytes32) {
+        return _resultCommitments[matchId];
+    }
+
+    function revealedResults(bytes32 matchId) external view returns (uint8) {
+        return _revealedResults[matchId];
+    }
+
+    function matchAdmins(bytes32 matchId) external view returns (address) {
+        return _matchAdmins[matchId];
+    }
+
+    function organizerExtensions(bytes32 matchId) external view returns (uint64) {
+        return _organizerExtensions[matchId];
+    }
+
+    function cancellationRequests(bytes32 matchId) external view returns (bool) {
+        return _cancellationRequests[matchId];
+    }
+
+    function matchOracles(bytes32 matchId, address replaced) external view returns (address) {
+        return _matchOracles[matchId][replaced];
+    }
+
+    function correlatedGroups(bytes32 matchId) external view returns (bytes32) {
+        return _correlatedGroups[matchId];
+    }
+
+    function groupResultHashes(bytes32 groupId) external view returns (bytes32) {
+        return _groupResultHashes[groupId];
+    }
+
+    function correlationFlags(bytes32 matchId) external view returns (bool) {
+        return _correlationFlags[matchId];
+    }
+
+    function committeeEpoch() external view returns (uint32) {
+        return _committeeEpoch;
+    }
+
+    function committeeSeats(address member) external view returns (uint32) {
+        return _committeeSeats[member];
+    }
+
+    function arbitrationTallies(bytes32 matchId)
+        external
+        view
+        returns (uint32 epoch, uint8 sideAVotes, uint8 sideBVotes)
+    {
+        ArbitrationTally storage entry = _arbitrationTallies[matchId];
+        return (entry.epoch, entry.sideAVotes, entry.sideBVotes);
+    }
+
+    function arbitrationVotes(bytes32 matchId, address member) external view returns (uint32) {
+        return _arbitrationVotes[matchId][member];
+    }
+
+    function attestations(bytes32 matchId)
+        external
+        view
+        returns (
+            bytes32 stateHash,
+            address server,
+            uint64 timestamp,
+            uint16 round,
+            uint8 leader,
+            bool finished
+        )
+    {
+        Attestation storage entry = _attestations[matchId];
+        return (
+            entry.stateHash, entry.server, entry.timestamp, entry.round, entry.leader,
+            entry.finished
+        );
+    }
+
+    function resolutionDeadlines(bytes32 matchId) external view returns (uint64) {
+        return _resolutionDeadlines[matchId];
+    }
+
+    function royaltyPools(bytes32 matchId)
+        external
+        view
+        returns (uint16 shareBps, bool fighterAClaimed, bool fighterBClaimed)
+    {
+        RoyaltyPool storage entry = _royaltyPools[matchId];
+        return (entry.shareBps, entry.fighterAClaimed, entry.fighterBClaimed);
+    }
+
+    function referrers(address referrer) external view returns (bool) {
+        return _referrers[referrer];
+    }
+
+    function referralPools(bytes32 matchId)
+        external
+        view
+        returns (uint128 referredVolume, uint16 shareBps)
+    {
+        ReferralPool storage entry = _referralPools[matchId];
+        return (entry.referredVolume, entry.shareBps);
+    }
+
+    function referralAccruals(bytes32 matchId, address referrer)
+        external
+        view
+        returns (uint128 volume, bool claimed)
+    {
+        ReferralAccrual storage entry = _referralAccruals[matchId][referrer];
+        return (entry.volume, entry.claimed);
+    }
+
+    function betReferrers(bytes32 matchId, address bettor) external view returns (address) {
+        return _betReferrers[matchId][bettor];
+    }
+
+    function claimDelegates(address bettor) external view returns (address) {
+        return _claimDelegates[bettor];
+    }
+
+    function maxOpenMatchesPerCreator() external view returns (uint32) {
+        return _maxOpenMatchesPerCreator;
+    }
+
+    function openMatchCounts(address creator) external view returns (uint32) {
+        return _openMatchCounts[creator];
+    }
+
+    function matchCreators(bytes32 matchId) external view returns (address) {
+        return _matchCreators[matchId];
+    }
+
+    function creatorBond() external view returns (uint128) {
+        return _creatorBond;
+    }
+
+    function creatorBonds(bytes32 matchId) external view returns (uint128) {
+        return _creatorBonds[matchId];
+    }
+
+    function settledStakes(bytes32 matchId, uint256 side) external view returns (uint128) {
+        return _settledStakes[matchId][side];
+    }
+
+    function overturnedWinners(bytes32 matchId) external view returns (MatchWinner) {
+        return _overturnedWinners[matchId];
+    }
+
+    function clawbacks(bytes32 matchId, address account) external view returns (uint256) {
+        return _clawbacks[matchId][account];
+    }
+
+    function clawbackRecorded(bytes32 matchId, address account) external view returns (bool) {
+        return _clawbackRecorded[matchId][account];
+    }
+
+    function clawbackDebt(address account) external view returns (uint256) {
+        return _clawbackDebt[account];
+    }
+
+    function firstBetInsuranceCap() external view returns (uint128) {
+        return _firstBetInsuranceCap;
+    }
+
+    function hasBet(address bettor) external view returns (bool) {
+        return _hasBet[bettor];
+    }
+
+    function firstBets(address bettor)
+        external
+        view
+        returns (bytes32 matchId, uint128 insured, bool refunded)
+    {
+        FirstBet storage entry = _firstBets[bettor];
+        return (entry.matchId, entry.insured, entry.refunded);
+    }
+
+    function feeTierThresholds(uint256 tier) external view returns (uint128) {
+        return _feeTierThresholds[tier];
+    }
+
+    function feeTierDiscountBps(uint256 tier) external view returns (uint16) {
+        return _feeTierDiscountBps[tier];
+    }
+
+    function bettorProfiles(address bettor)
+        external
+        view
+        returns (uint128 currentVolume, uint128 previousVolume, uint64 windowStart, uint8 feeTier)
+    {
+        BettorProfile storage entry = _bettorProfiles[bettor];
+        return (entry.currentVolume, entry.previousVolume, entry.windowStart, entry.feeTier);
+    }
+
+    function feeDiscounts(bytes32 matchId) external view returns (uint256) {
+        return _feeDiscounts[matchId];
+    }
+
+    function programVersion() external view returns (uint32) {
+        return _programVersion;
+    }
+
+    function minClientVersion() external view returns (uint32) {
+        return _minClientVersion;
+    }
+
+    function promoBudget() external view returns (uint256) {
+        return _promoBudget;
+    }
+
+    function vouchers(bytes32 voucherId)
+        external
+        view
+        returns (address redeemer, uint64 expiresAt, bool redeemed, uint128 amount)
+    {
+        Voucher storage entry = _vouchers[voucherId];
+        return (entry.redeemer, entry.expiresAt, entry.redeemed, entry.amount);
+    }
+
+    function voucherStakes(bytes32 matchId, address bettor) external view returns (uint128) {
+        return _voucherStakes[matchId][bettor];
+    }
+
+    function sponsorships(bytes32 matchId)
+        external
+        view
+        returns (address sponsor, uint16 matchBps, uint128 budget, uint128 capPerBet)
+    {
+        Sponsorship storage entry = _sponsorships[matchId];
+        return (entry.sponsor, entry.matchBps, entry.budget, entry.capPerBet);
+    }
+
+    function sponsorMatched(bytes32 matchId, address bettor) external view returns (uint128) {
+        return _sponsorMatched[matchId][bettor];
+    }
+
+    function approvedSeeders(address seeder) external view returns (bool) {
+        return _approvedSeeders[seeder];
+    }
+
+    function seedOwners(address seedKey) external view returns (address) {
+        return _seedOwners[seedKey];
+    }
+
+    function pendingBets(bytes32 matchId, address bettor) external view returns (uint256) {
+        return _pendingBets[matchId][bettor];
+    }
+
+    function betPositions() external view returns (BetPositions) {
+        return _betPositions;
+    }
+
+    function positionMatches(uint256 tokenId) external view returns (bytes32) {
+        return _positionMatches[tokenId];
+    }
+
+    function positionTokenIds(address positionKey) external view returns (uint256) {
+        return _positionTokenIds[positionKey];
+    }
+}
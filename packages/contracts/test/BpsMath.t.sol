@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "../src/Bps.sol";
+
+contract BpsMathTest is Test {
+    function testFuzz_ApplyToMatchesRawMath(uint256 amount, uint16 raw) public pure {
+        amount = bound(amount, 0, type(uint128).max);
+        raw = uint16(bound(raw, 0, BpsMath.ONE));
+        Bps share = BpsMath.toBps(raw);
+
+        assertEq(share.applyTo(amount), (amount * raw) / 10_000);
+        assertEq(share.complement().applyTo(amount), (amount * (10_000 - raw)) / 10_000);
+        // Rounding never lets a share and its complement add up to more than the amount
+        assertLe(share.applyTo(amount) + share.complement().applyTo(amount), amount);
+    }
+
+    function test_ToBpsRejectsShareAboveWhole() public {
+        vm.expectRevert(abi.encodeWithSelector(BpsMath.BpsOutOfRange.selector, 10_001));
+        this.toBps(10_001);
+    }
+
+    function test_ComplementOfUncheckedWrapReverts() public {
+        vm.expectRevert(stdError.arithmeticError);
+        this.complement(10_001);
+    }
+
+    function testFuzz_WeiShareMatchesWideShare(uint128 amount, uint16 raw) public pure {
+        raw = uint16(bound(raw, 0, BpsMath.ONE));
+        Bps share = BpsMath.toBps(raw);
+
+        assertEq(share.applyTo(Wei.wrap(amount)).raw(), share.applyTo(uint256(amount)));
+    }
+
+    function test_ToWeiRejectsAmountAbovePoolWidth() public {
+        uint256 tooWide = uint256(type(uint128).max) + 1;
+        vm.expectRevert(abi.encodeWithSelector(WeiMath.WeiOverflow.selector, tooWide));
+        this.toWei(tooWide);
+    }
+
+    function test_WeiArithmeticIsChecked() public {
+        vm.expectRevert(stdError.arithmeticError);
+        this.addWei(type(uint128).max, 1);
+
+        vm.expectRevert(stdError.arithmeticError);
+        this.subWei(1 ether, 1 ether + 1);
+    }
+
+    function toBps(uint256 value) external pure returns (Bps) {
+        return BpsMath.toBps(value);
+    }
+
+    function complement(uint16 raw) external pure returns (Bps) {
+        return Bps.wrap(raw).complement();
+    }
+
+    function toWei(uint256 value) external pure returns (Wei) {
+        return WeiMath.toWei(value);
+    }
+
+    function addWei(uint128 a, uint128 b) external pure returns (Wei) {
+        return Wei.wrap(a).add(Wei.wrap(b));
+    }
+
+    function subWei(uint128 a, uint128 b) external pure returns (Wei) {
+        return Wei.wrap(a).sub(Wei.wrap(b));
+    }
+}
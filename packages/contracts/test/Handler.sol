@@ -2,22 +2,31 @@
 pragma solidity ^0.8.24;
 
 import "forge-std/Test.sol";
-import "../src/RawlBetting.sol";
+import "../src/IRawlBetting.sol";
 
-/// @notice Handler for invariant testing — simulates random user actions
+/// @notice Handler for invariant testing — the fuzzer calls these in random sequences with
+///         random matches, accounts and amounts. Ghost state mirrors what the contract should
+///         hold so the invariant suite can check vault solvency, counter consistency and that
+///         no match ever moves backwards through its lifecycle
 contract Handler is Test {
-    RawlBetting public betting;
+    IRawlBetting public betting;
     address public admin;
     address public oracle;
 
     uint256 public ghost_totalDeposited;
     uint256 public ghost_totalWithdrawn;
+    uint256 public ghost_statusRegressions;
+    uint256 public ghost_unauthorizedCalls;
+
+    mapping(bytes32 => uint256[2]) public ghost_sideTotals;
+    mapping(bytes32 => uint256[2]) public ghost_sideBetCounts;
+    mapping(bytes32 => RawlBettingBase.MatchStatus) private lastStatus;
 
     uint256 private matchCount;
     bytes32[] private activeMatches;
     address[] private bettors;
 
-    constructor(RawlBetting _betting, address _admin, address _oracle) {
+    constructor(IRawlBetting _betting, address _admin, address _oracle) {
         betting = _betting;
         admin = _admin;
         oracle = _oracle;
@@ -28,9 +37,45 @@ contract Handler is Test {
             vm.deal(bettor, 1000 ether);
             bettors.push(bettor);
         }
+
+        vm.startPrank(_oracle);
+        _betting.registerFighter(makeAddr("fA"), keccak256("fA"), makeAddr("ownerA"));
+        _betting.registerFighter(makeAddr("fB"), keccak256("fB"), makeAddr("ownerB"));
+        vm.stopPrank();
+    }
+
+    /// @dev Every action runs under this: ETH in and out of the vault feeds the ghost totals,
+    ///      and each tracked match's status is compared against its last observed value
+    modifier observed() {
+        uint256 balBefore = address(betting).balance;
+        _;
+        uint256 balAfter = address(betting).balance;
+        if (balAfter > balBefore) ghost_totalDeposited += balAfter - balBefore;
+        else ghost_totalWithdrawn += balBefore - balAfter;
+        _observeStatuses();
     }
 
-    function createMatch(uint256 seed) external {
+    // ─── Views for the invariant suite ───
+
+    function matchCountTracked() external view returns (uint256) {
+        return activeMatches.length;
+    }
+
+    function matchAt(uint256 i) external view returns (bytes32) {
+        return activeMatches[i];
+    }
+
+    function bettorCount() external view returns (uint256) {
+        return bettors.length;
+    }
+
+    function bettorAt(uint256 i) external view returns (address) {
+        return bettors[i];
+    }
+
+    // ─── Match lifecycle ───
+
+    function createMatch(uint256) external observed {
         matchCount++;
         bytes32 mId = keccak256(abi.encodePacked("invariant-match", matchCount));
         activeMatches.push(mId);
@@ -39,7 +84,29 @@ contract Handler is Test {
         betting.createMatch(mId, makeAddr("fA"), makeAddr("fB"), 0.001 ether, 0);
     }
 
-    function placeBet(uint256 matchSeed, uint256 bettorSeed, uint128 amount) external {
+    /// @notice Two-phase creation, activated straight away or left Pending for a later call
+    function initMatch(bool activate) external observed {
+        matchCount++;
+        bytes32 mId = keccak256(abi.encodePacked("invariant-match", matchCount));
+        activeMatches.push(mId);
+
+        vm.prank(oracle);
+        betting.initMatch(mId, makeAddr("fA"), makeAddr("fB"), 0.001 ether, 0, 0, 0);
+        if (activate) {
+            vm.prank(oracle);
+            betting.activateMatch(mId);
+        }
+    }
+
+    function activateMatch(uint256 matchSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(oracle);
+        try betting.activateMatch(mId) {} catch {}
+    }
+
+    function placeBet(uint256 matchSeed, uint256 bettorSeed, uint128 amount) external observed {
         if (activeMatches.length == 0) return;
 
         bytes32 mId = activeMatches[matchSeed % activeMatches.length];
@@ -48,26 +115,27 @@ contract Handler is Test {
         uint8 side = uint8(bettorSeed % 2);
 
         // Check match is open and bettor hasn't bet yet
-        (,,RawlBetting.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
-        if (status != RawlBetting.MatchStatus.Open) return;
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
+        if (status != RawlBettingBase.MatchStatus.Open) return;
 
         (uint128 existingAmount,,) = betting.bets(mId, bettor);
         if (existingAmount > 0) return;
 
         vm.prank(bettor);
         try betting.placeBet{value: amount}(mId, side) {
-            ghost_totalDeposited += amount;
+            ghost_sideTotals[mId][side] += amount;
+            ghost_sideBetCounts[mId][side]++;
         } catch {}
     }
 
-    function lockAndResolve(uint256 matchSeed, uint8 winner) external {
+    function lockAndResolve(uint256 matchSeed, uint8 winner) external observed {
         if (activeMatches.length == 0) return;
 
         bytes32 mId = activeMatches[matchSeed % activeMatches.length];
         winner = winner % 2;
 
-        (,,RawlBetting.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
-        if (status != RawlBetting.MatchStatus.Open) return;
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
+        if (status != RawlBettingBase.MatchStatus.Open) return;
 
         vm.prank(oracle);
         try betting.lockMatch(mId) {} catch { return; }
@@ -76,35 +144,190 @@ contract Handler is Test {
         try betting.resolveMatch(mId, winner) {} catch {}
     }
 
-    function claimPayout(uint256 matchSeed, uint256 bettorSeed) external {
+    /// @notice Lock without resolving, leaving the match for timeoutMatch
+    function lockOnly(uint256 matchSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(oracle);
+        try betting.lockMatch(mId) {} catch {}
+    }
+
+    function claimPayout(uint256 matchSeed, uint256 bettorSeed) external observed {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address bettor = bettors[bettorSeed % bettors.length];
+
+        vm.prank(bettor);
+        try betting.claimPayout(mId) {} catch {}
+    }
+
+    function refundNoWinners(uint256 matchSeed, uint256 bettorSeed) external observed {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address bettor = bettors[bettorSeed % bettors.length];
+
+        vm.prank(bettor);
+        try betting.refundNoWinners(mId) {} catch {}
+    }
+
+    function cashOut(uint256 matchSeed, uint256 bettorSeed) external observed {
         if (activeMatches.length == 0) return;
 
         bytes32 mId = activeMatches[matchSeed % activeMatches.length];
         address bettor = bettors[bettorSeed % bettors.length];
+        (uint128 stake, uint8 side,) = betting.bets(mId, bettor);
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
 
-        uint256 balBefore = bettor.balance;
+        // The suite leaves cashOutMatchCap at 0, so only Open exits go through; those take
+        // the stake out of the pool
         vm.prank(bettor);
-        try betting.claimPayout(mId) {
-            ghost_totalWithdrawn += bettor.balance - balBefore;
+        try betting.cashOut(mId) {
+            if (status == RawlBettingBase.MatchStatus.Open) {
+                ghost_sideTotals[mId][side] -= stake;
+                ghost_sideBetCounts[mId][side]--;
+            }
         } catch {}
     }
 
-    function cancelAndRefund(uint256 matchSeed, uint256 bettorSeed) external {
+    function cancelAndRefund(uint256 matchSeed, uint256 bettorSeed) external observed {
         if (activeMatches.length == 0) return;
 
         bytes32 mId = activeMatches[matchSeed % activeMatches.length];
         address bettor = bettors[bettorSeed % bettors.length];
 
-        (,,RawlBetting.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
-        if (status != RawlBetting.MatchStatus.Open && status != RawlBetting.MatchStatus.Locked) return;
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
+        if (
+            status != RawlBettingBase.MatchStatus.Open
+                && status != RawlBettingBase.MatchStatus.Locked
+        ) return;
 
         vm.prank(admin);
         try betting.cancelMatch(mId) {} catch { return; }
 
-        uint256 balBefore = bettor.balance;
         vm.prank(bettor);
-        try betting.refundBet(mId) {
-            ghost_totalWithdrawn += bettor.balance - balBefore;
-        } catch {}
+        try betting.refundBet(mId) {} catch {}
+    }
+
+    function refundBet(uint256 matchSeed, uint256 bettorSeed) external observed {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address bettor = bettors[bettorSeed % bettors.length];
+
+        vm.prank(bettor);
+        try betting.refundBet(mId) {} catch {}
+    }
+
+    function abortPendingMatch(uint256 matchSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(oracle);
+        try betting.abortPendingMatch(mId) {} catch {}
+    }
+
+    // ─── Permissionless cranks, called from a random bettor ───
+
+    function timeoutMatch(uint256 matchSeed, uint256 callerSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(bettors[callerSeed % bettors.length]);
+        try betting.timeoutMatch(mId) {} catch {}
+    }
+
+    function forceCancelExpired(uint256 matchSeed, uint256 callerSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(bettors[callerSeed % bettors.length]);
+        try betting.forceCancelExpired(mId) {} catch {}
+    }
+
+    function sweepCancelled(uint256 matchSeed, uint256 bettorSeed) external observed {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address bettor = bettors[bettorSeed % bettors.length];
+
+        vm.prank(bettors[(bettorSeed >> 8) % bettors.length]);
+        try betting.sweepCancelled(mId, bettor) {} catch {}
+    }
+
+    // ─── Admin actions ───
+
+    function withdrawFees(uint256 matchSeed) external observed {
+        if (activeMatches.length == 0) return;
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+
+        vm.prank(admin);
+        try betting.withdrawFees(mId) {} catch {}
+    }
+
+    function sweepUnclaimed(uint256 matchSeed, uint256 bettorSeed) external observed {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address bettor = bettors[bettorSeed % bettors.length];
+
+        vm.prank(admin);
+        try betting.sweepUnclaimed(mId, bettor) {} catch {}
+    }
+
+    /// @notice Admin-only calls from a random bettor must never get through
+    function unauthorizedAdminCall(uint256 matchSeed, uint256 callerSeed, uint8 op)
+        external
+        observed
+    {
+        if (activeMatches.length == 0) return;
+
+        bytes32 mId = activeMatches[matchSeed % activeMatches.length];
+        address caller = bettors[callerSeed % bettors.length];
+
+        vm.prank(caller);
+        if (op % 3 == 0) {
+            try betting.cancelMatch(mId) { ghost_unauthorizedCalls++; } catch {}
+        } else if (op % 3 == 1) {
+            try betting.withdrawFees(mId) { ghost_unauthorizedCalls++; } catch {}
+        } else {
+            try betting.resolveMatch(mId, op % 2) { ghost_unauthorizedCalls++; } catch {}
+        }
+    }
+
+    // ─── Time ───
+
+    /// @notice Move the clock so timeouts, expiry and claim windows become reachable
+    function warp(uint32 secondsForward) external observed {
+        vm.warp(block.timestamp + bound(secondsForward, 1, 45 days));
+    }
+
+    // ─── Internal ───
+
+    function _observeStatuses() internal {
+        for (uint256 i; i < activeMatches.length; ++i) {
+            bytes32 mId = activeMatches[i];
+            (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(mId);
+            RawlBettingBase.MatchStatus previous = lastStatus[mId];
+            if (status != previous && _rank(status) <= _rank(previous)) {
+                ghost_statusRegressions++;
+            }
+            lastStatus[mId] = status;
+        }
+    }
+
+    /// @dev Lifecycle order: None → Pending → Open → Locked → Resolved | Cancelled. unlockMatch's
+    ///      Locked → Open is the one sanctioned step back and isn't among the fuzzed actions
+    function _rank(RawlBettingBase.MatchStatus status) internal pure returns (uint8) {
+        if (status == RawlBettingBase.MatchStatus.Pending) return 1;
+        if (status == RawlBettingBase.MatchStatus.Open) return 2;
+        if (status == RawlBettingBase.MatchStatus.Locked) return 3;
+        if (
+            status == RawlBettingBase.MatchStatus.Resolved
+                || status == RawlBettingBase.MatchStatus.Cancelled
+        ) return 4;
+        return 0;
     }
 }
@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "../src/RawlBettingBase.sol";
+import "../src/MatchStateMachine.sol";
+
+contract MatchStateMachineTest is Test {
+    uint8 constant STATUSES = 6;
+    uint8 constant ACTORS = 6;
+
+    RawlBettingBase.MatchStatus constant NONE = RawlBettingBase.MatchStatus.None;
+    RawlBettingBase.MatchStatus constant PENDING = RawlBettingBase.MatchStatus.Pending;
+    RawlBettingBase.MatchStatus constant OPEN = RawlBettingBase.MatchStatus.Open;
+    RawlBettingBase.MatchStatus constant LOCKED = RawlBettingBase.MatchStatus.Locked;
+    RawlBettingBase.MatchStatus constant RESOLVED = RawlBettingBase.MatchStatus.Resolved;
+    RawlBettingBase.MatchStatus constant CANCELLED = RawlBettingBase.MatchStatus.Cancelled;
+
+    MatchStateMachine.Actor constant ANYONE = MatchStateMachine.Actor.Anyone;
+    MatchStateMachine.Actor constant CREATOR = MatchStateMachine.Actor.Creator;
+    MatchStateMachine.Actor constant LOCK = MatchStateMachine.Actor.LockAuthority;
+    MatchStateMachine.Actor constant RESOLVE = MatchStateMachine.Actor.ResolveAuthority;
+    MatchStateMachine.Actor constant ADMIN = MatchStateMachine.Actor.Admin;
+    MatchStateMachine.Actor constant FORFEIT = MatchStateMachine.Actor.Forfeit;
+
+    // Allowed (from, to, actor) triples, spelled out independently of the library's branches
+    mapping(bytes32 => bool) allowed;
+
+    function setUp() public {
+        _allow(NONE, OPEN, CREATOR);
+        _allow(NONE, PENDING, CREATOR);
+        _allow(PENDING, OPEN, CREATOR);
+        _allow(PENDING, CANCELLED, CREATOR);
+        _allow(OPEN, LOCKED, LOCK);
+        _allow(OPEN, LOCKED, ANYONE);
+        _allow(OPEN, CANCELLED, ADMIN);
+        _allow(OPEN, CANCELLED, ANYONE);
+        _allow(OPEN, RESOLVED, FORFEIT);
+        _allow(OPEN, CANCELLED, FORFEIT);
+        _allow(LOCKED, OPEN, LOCK);
+        _allow(LOCKED, RESOLVED, RESOLVE);
+        _allow(LOCKED, CANCELLED, ADMIN);
+        _allow(LOCKED, CANCELLED, ANYONE);
+    }
+
+    function _key(uint8 from, uint8 to, uint8 actor) internal pure returns (bytes32) {
+        return keccak256(abi.encode(from, to, actor));
+    }
+
+    function _allow(
+        RawlBettingBase.MatchStatus from,
+        RawlBettingBase.MatchStatus to,
+        MatchStateMachine.Actor actor
+    ) internal {
+        allowed[_key(uint8(from), uint8(to), uint8(actor))] = true;
+    }
+
+    /// @notice Every (from, to, actor) combination — 216 of them — matches the table
+    function test_TransitionTableIsExhaustive() public view {
+        uint256 legal;
+        for (uint8 from; from < STATUSES; ++from) {
+            for (uint8 to; to < STATUSES; ++to) {
+                for (uint8 actor; actor < ACTORS; ++actor) {
+                    bool expected = allowed[_key(from, to, actor)];
+                    bool actual = MatchStateMachine.canTransition(
+                        RawlBettingBase.MatchStatus(from),
+                        RawlBettingBase.MatchStatus(to),
+                        MatchStateMachine.Actor(actor)
+                    );
+                    assertEq(actual, expected);
+                    if (actual) legal++;
+                }
+            }
+        }
+        assertEq(legal, 14);
+    }
+
+    function test_FinalStatusesNeverMove() public pure {
+        for (uint8 to; to < STATUSES; ++to) {
+            for (uint8 actor; actor < ACTORS; ++actor) {
+                assertFalse(
+                    MatchStateMachine.canTransition(
+                        RESOLVED,
+                        RawlBettingBase.MatchStatus(to),
+                        MatchStateMachine.Actor(actor)
+                    )
+                );
+                assertFalse(
+                    MatchStateMachine.canTransition(
+                        CANCELLED,
+                        RawlBettingBase.MatchStatus(to),
+                        MatchStateMachine.Actor(actor)
+                    )
+                );
+            }
+        }
+    }
+
+    function test_NoSelfTransitions() public pure {
+        for (uint8 status; status < STATUSES; ++status) {
+            for (uint8 actor; actor < ACTORS; ++actor) {
+                assertFalse(
+                    MatchStateMachine.canTransition(
+                        RawlBettingBase.MatchStatus(status),
+                        RawlBettingBase.MatchStatus(status),
+                        MatchStateMachine.Actor(actor)
+                    )
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "@openzeppelin/contracts/token/ERC721/ERC721.sol";
+
+/// @notice Minimal fighter collection for NFT-gating tests
+contract MockFighterNFT is ERC721 {
+    constructor() ERC721("Rawl Fighters", "RAWLF") {}
+
+    function mint(address to, uint256 tokenId) external {
+        _mint(to, tokenId);
+    }
+}
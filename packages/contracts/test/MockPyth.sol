@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {IPyth} from "../src/RawlBettingBase.sol";
+
+/// @notice Pyth stand-in: each update is abi.encode(priceId, price, expo, publishTime), and
+///         parsing reverts like Pyth when none of them was published inside the window
+contract MockPyth is IPyth {
+    uint256 public immutable feePerUpdate;
+
+    error PriceFeedNotFoundWithinRange();
+
+    constructor(uint256 _feePerUpdate) {
+        feePerUpdate = _feePerUpdate;
+    }
+
+    function encodeUpdate(bytes32 id, int64 price, int32 expo, uint256 publishTime)
+        external
+        pure
+        returns (bytes memory)
+    {
+        return abi.encode(id, price, expo, publishTime);
+    }
+
+    function getUpdateFee(bytes[] calldata updateData) public view returns (uint256) {
+        return feePerUpdate * updateData.length;
+    }
+
+    function parsePriceFeedUpdates(
+        bytes[] calldata updateData,
+        bytes32[] calldata priceIds,
+        uint64 minPublishTime,
+        uint64 maxPublishTime
+    ) external payable returns (PriceFeed[] memory feeds) {
+        require(msg.value >= getUpdateFee(updateData), "insufficient fee");
+        feeds = new PriceFeed[](priceIds.length);
+        for (uint256 i; i < priceIds.length; ++i) {
+            bool found;
+            for (uint256 j; j < updateData.length && !found; ++j) {
+                (bytes32 id, int64 price, int32 expo, uint256 publishTime) =
+                    abi.decode(updateData[j], (bytes32, int64, int32, uint256));
+                if (id != priceIds[i]) continue;
+                if (publishTime < minPublishTime || publishTime > maxPublishTime) continue;
+                Price memory p =
+                    Price({price: price, conf: 0, expo: expo, publishTime: publishTime});
+                feeds[i] = PriceFeed({id: id, price: p, emaPrice: p});
+                found = true;
+            }
+            if (!found) revert PriceFeedNotFoundWithinRange();
+        }
+    }
+}
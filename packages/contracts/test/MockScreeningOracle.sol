@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Settable sanctions list for screening tests
+contract MockScreeningOracle {
+    mapping(address => bool) public isSanctioned;
+
+    function setSanctioned(address account, bool sanctioned) external {
+        isSanctioned[account] = sanctioned;
+    }
+}
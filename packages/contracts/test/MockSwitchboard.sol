@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {ISwitchboard} from "../src/RawlBettingBase.sol";
+
+/// @notice Switchboard aggregator whose latest update per feed is set directly by tests
+contract MockSwitchboard is ISwitchboard {
+    mapping(bytes32 => Update) internal updates;
+
+    function setUpdate(bytes32 feedId, int128 result, uint256 timestamp) external {
+        updates[feedId] = Update({oracleId: bytes32(0), result: result, timestamp: timestamp});
+    }
+
+    function latestUpdate(bytes32 feedId) external view returns (Update memory) {
+        return updates[feedId];
+    }
+}
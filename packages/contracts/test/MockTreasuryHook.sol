@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Treasury hook that keeps what it receives, or reverts to simulate a failed swap
+contract MockTreasuryHook {
+    bool public failing;
+    uint256 public received;
+
+    function setFailing(bool fail) external {
+        failing = fail;
+    }
+
+    function onFeesWithdrawn(bytes32) external payable {
+        require(!failing, "swap failed");
+        received += msg.value;
+    }
+}
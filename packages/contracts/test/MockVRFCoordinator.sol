@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import {IRawlBetting} from "../src/IRawlBetting.sol";
+import {IVRFCoordinatorV2Plus} from "../src/RawlBettingBase.sol";
+
+/// @notice VRF coordinator that hands out sequential request ids and fulfils them on demand
+contract MockVRFCoordinator is IVRFCoordinatorV2Plus {
+    uint256 public lastRequestId;
+    mapping(uint256 => address) public consumers;
+
+    function requestRandomWords(RandomWordsRequest calldata) external returns (uint256) {
+        lastRequestId++;
+        consumers[lastRequestId] = msg.sender;
+        return lastRequestId;
+    }
+
+    function fulfill(uint256 requestId, uint256 word) external {
+        uint256[] memory words = new uint256[](1);
+        words[0] = word;
+        IRawlBetting(consumers[requestId]).rawFulfillRandomWords(requestId, words);
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+/// @notice Minimal Safe-style vault for contract-bettor tests — the vault itself is msg.sender
+contract MockVault {
+    address public immutable owner;
+
+    constructor(address _owner) {
+        owner = _owner;
+    }
+
+    function execute(address target, uint256 value, bytes calldata data) external {
+        require(msg.sender == owner, "not owner");
+        (bool success, bytes memory ret) = target.call{value: value}(data);
+        if (!success) {
+            assembly { revert(add(ret, 32), mload(ret)) }
+        }
+    }
+
+    receive() external payable {}
+}
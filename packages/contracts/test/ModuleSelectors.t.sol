@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "./RawlBettingDeployer.sol";
+
+/// @notice Checks the router's selector table. The constructor already rejects a selector two
+///         modules both claim; these cover what it can't see: a module selector RawlBetting
+///         answers itself (the fallback would never reach it), and an IRawlBetting function
+///         nothing serves
+contract ModuleSelectorsTest is RawlBettingDeployer {
+    IRawlBetting betting;
+    RawlBettingModule[] modules;
+
+    function setUp() public {
+        modules = _deployModules();
+        betting = IRawlBetting(
+            address(new RawlBetting(address(this), address(this), address(this), modules))
+        );
+    }
+
+    /// @dev Selectors of every external function in `name`'s build artifact
+    function _artifactSelectors(string memory name) internal view returns (bytes4[] memory s) {
+        string memory json = vm.readFile(
+            string.concat(vm.projectRoot(), "/out/", name, ".sol/", name, ".json")
+        );
+        string[] memory signatures = vm.parseJsonKeys(json, ".methodIdentifiers");
+        s = new bytes4[](signatures.length);
+        for (uint256 i; i < signatures.length; ++i) {
+            s[i] = bytes4(keccak256(bytes(signatures[i])));
+        }
+    }
+
+    function _contains(bytes4[] memory list, bytes4 selector) internal pure returns (bool) {
+        for (uint256 i; i < list.length; ++i) {
+            if (list[i] == selector) return true;
+        }
+        return false;
+    }
+
+    function test_ModuleSelectorsDoNotShadowRouter() public view {
+        bytes4[] memory native = _artifactSelectors("RawlBetting");
+        for (uint256 i; i < modules.length; ++i) {
+            bytes4[] memory selectors = modules[i].selectors();
+            for (uint256 j; j < selectors.length; ++j) {
+                assertFalse(_contains(native, selectors[j]), vm.toString(selectors[j]));
+                assertEq(betting.moduleOf(selectors[j]), address(modules[i]));
+            }
+        }
+    }
+
+    function test_EveryInterfaceFunctionIsServed() public view {
+        bytes4[] memory native = _artifactSelectors("RawlBetting");
+        bytes4[] memory surface = _artifactSelectors("IRawlBetting");
+        for (uint256 i; i < surface.length; ++i) {
+            assertTrue(
+                _contains(native, surface[i]) || betting.moduleOf(surface[i]) != address(0),
+                vm.toString(surface[i])
+            );
+        }
+    }
+}
@@ -2,10 +2,17 @@
 pragma solidity ^0.8.24;
 
 import "forge-std/Test.sol";
-import "../src/RawlBetting.sol";
+import "./RawlBettingDeployer.sol";
+import "./MockFighterNFT.sol";
+import "./MockPyth.sol";
+import "./MockScreeningOracle.sol";
+import "./MockSwitchboard.sol";
+import "./MockTreasuryHook.sol";
+import "./MockVRFCoordinator.sol";
+import "./MockVault.sol";
 
-contract RawlBettingTest is Test {
-    RawlBetting public betting;
+contract RawlBettingTest is RawlBettingDeployer {
+    IRawlBetting public betting;
 
     address admin = makeAddr("admin");
     address oracle = makeAddr("oracle");
@@ -13,21 +20,28 @@ contract RawlBettingTest is Test {
     address alice = makeAddr("alice");
     address bob = makeAddr("bob");
     address charlie = makeAddr("charlie");
+    address fighterA = makeAddr("fighterA");
+    address fighterB = makeAddr("fighterB");
 
     bytes32 matchId = keccak256("match-1");
 
     function setUp() public {
-        betting = new RawlBetting(admin, oracle, treasury);
+        betting = _deployRawlBetting(admin, oracle, treasury);
         vm.deal(alice, 100 ether);
         vm.deal(bob, 100 ether);
         vm.deal(charlie, 100 ether);
+
+        vm.startPrank(oracle);
+        betting.registerFighter(fighterA, keccak256("FighterA"), alice);
+        betting.registerFighter(fighterB, keccak256("FighterB"), bob);
+        vm.stopPrank();
     }
 
     // ─── Helpers ───
 
     function _createMatch() internal {
         vm.prank(oracle);
-        betting.createMatch(matchId, makeAddr("fighterA"), makeAddr("fighterB"), 0.001 ether, 0);
+        betting.createMatch(matchId, fighterA, fighterB, 0.001 ether, 0);
     }
 
     function _createAndBetBothSides() internal {
@@ -43,8 +57,8 @@ contract RawlBettingTest is Test {
     function test_FullLifecycle() public {
         // Create
         _createMatch();
-        (,,RawlBetting.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
-        assertEq(uint8(status), uint8(RawlBetting.MatchStatus.Open));
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Open));
 
         // Bet
         vm.prank(alice);
@@ -106,7 +120,7 @@ contract RawlBettingTest is Test {
         betting.lockMatch(matchId);
 
         // Can't timeout yet
-        vm.expectRevert(RawlBetting.TimeoutNotElapsed.selector);
+        vm.expectRevert(RawlBettingBase.TimeoutNotElapsed.selector);
         betting.timeoutMatch(matchId);
 
         // Warp past timeout
@@ -186,7 +200,7 @@ contract RawlBettingTest is Test {
     function test_NonOracleCantCreate() public {
         vm.prank(alice);
         vm.expectRevert();
-        betting.createMatch(matchId, makeAddr("a"), makeAddr("b"), 0, 0);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
     }
 
     function test_NonOracleCantLock() public {
@@ -249,7 +263,7 @@ contract RawlBettingTest is Test {
         betting.placeBet{value: 1 ether}(matchId, 0);
 
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.AlreadyBet.selector);
+        vm.expectRevert(RawlBettingBase.AlreadyBet.selector);
         betting.placeBet{value: 1 ether}(matchId, 1);
     }
 
@@ -259,18 +273,18 @@ contract RawlBettingTest is Test {
         betting.lockMatch(matchId);
 
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.MatchNotOpen.selector);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
         betting.placeBet{value: 1 ether}(matchId, 0);
     }
 
     function test_BetAfterWindowReverts() public {
         vm.prank(oracle);
-        betting.createMatch(matchId, makeAddr("a"), makeAddr("b"), 0, 60); // 60s window
+        betting.createMatch(matchId, fighterA, fighterB, 0, 60); // 60s window
 
         vm.warp(block.timestamp + 61);
 
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.BettingWindowClosed.selector);
+        vm.expectRevert(RawlBettingBase.BettingWindowClosed.selector);
         betting.placeBet{value: 1 ether}(matchId, 0);
     }
 
@@ -282,7 +296,7 @@ contract RawlBettingTest is Test {
         betting.resolveMatch(matchId, 0); // Side A wins
 
         vm.prank(bob); // Bob bet on Side B
-        vm.expectRevert(RawlBetting.BetOnLosingSide.selector);
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
         betting.claimPayout(matchId);
     }
 
@@ -297,28 +311,30 @@ contract RawlBettingTest is Test {
         betting.claimPayout(matchId);
 
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.AlreadyClaimed.selector);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
         betting.claimPayout(matchId);
     }
 
     function test_ZeroBetReverts() public {
         _createMatch();
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.ZeroBetAmount.selector);
+        vm.expectRevert(RawlBettingBase.ZeroBetAmount.selector);
         betting.placeBet{value: 0}(matchId, 0);
     }
 
     function test_BetBelowMinReverts() public {
         _createMatch(); // minBet = 0.001 ether
         vm.prank(alice);
-        vm.expectRevert(abi.encodeWithSelector(RawlBetting.BetBelowMinimum.selector, 0.001 ether));
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.BetBelowMinimum.selector, 0.001 ether)
+        );
         betting.placeBet{value: 0.0001 ether}(matchId, 0);
     }
 
     function test_InvalidSideReverts() public {
         _createMatch();
         vm.prank(alice);
-        vm.expectRevert(RawlBetting.InvalidSide.selector);
+        vm.expectRevert(RawlBettingBase.InvalidSide.selector);
         betting.placeBet{value: 1 ether}(matchId, 2);
     }
 
@@ -333,12 +349,55 @@ contract RawlBettingTest is Test {
         assertEq(betting.treasury(), treasury); // unchanged
     }
 
+    function test_ConfigUpdatedEmitsFieldCode() public {
+        vm.prank(admin);
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.ConfigUpdated(RawlBettingBase.ConfigField.MatchTimeout, 3600);
+        betting.updateConfig(0, 3600, address(0));
+    }
+
     function test_UpdateConfigFeeTooHighReverts() public {
         vm.prank(admin);
-        vm.expectRevert(RawlBetting.InvalidFeeBps.selector);
+        vm.expectRevert(RawlBettingBase.InvalidFeeBps.selector);
         betting.updateConfig(1001, 0, address(0));
     }
 
+    function test_UpdateConfigTimeoutBounds() public {
+        (uint64 min, uint64 max) = betting.timeoutBounds();
+        assertEq(min, 5 minutes);
+        assertEq(max, 7 days);
+
+        vm.startPrank(admin);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.TimeoutOutOfBounds.selector, min, max)
+        );
+        betting.updateConfig(0, 10 * 365 days, address(0));
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.TimeoutOutOfBounds.selector, min, max)
+        );
+        betting.updateConfig(0, 60, address(0));
+
+        betting.updateConfig(0, max, address(0));
+        vm.stopPrank();
+        assertEq(betting.matchTimeout(), max);
+    }
+
+    function test_SetVersions() public {
+        vm.prank(admin);
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.ConfigUpdated(RawlBettingBase.ConfigField.ProgramVersion, 1_004_002);
+        betting.setVersions(1_004_002, 1_002_000);
+
+        assertEq(betting.programVersion(), 1_004_002);
+        assertEq(betting.minClientVersion(), 1_002_000);
+    }
+
+    function test_SetVersionsOnlyAdmin() public {
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.setVersions(1_000_000, 1_000_000);
+    }
+
     // ─── Pause ───
 
     function test_PauseBlocksCreateAndBet() public {
@@ -347,7 +406,7 @@ contract RawlBettingTest is Test {
 
         vm.prank(oracle);
         vm.expectRevert();
-        betting.createMatch(matchId, makeAddr("a"), makeAddr("b"), 0, 0);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
     }
 
     function test_PauseDoesNotBlockClaims() public {
@@ -433,7 +492,7 @@ contract RawlBettingTest is Test {
         betting.claimPayout(matchId);
 
         vm.prank(admin);
-        vm.expectRevert(RawlBetting.ClaimWindowNotElapsed.selector);
+        vm.expectRevert(RawlBettingBase.ClaimWindowNotElapsed.selector);
         betting.withdrawFees(matchId);
     }
 
@@ -448,7 +507,7 @@ contract RawlBettingTest is Test {
         vm.warp(block.timestamp + 30 days + 1);
 
         vm.prank(admin);
-        vm.expectRevert(RawlBetting.WinningBetsRemaining.selector);
+        vm.expectRevert(RawlBettingBase.WinningBetsRemaining.selector);
         betting.withdrawFees(matchId);
     }
 
@@ -457,7 +516,4510 @@ contract RawlBettingTest is Test {
     function test_CreateMatchTwiceReverts() public {
         _createMatch();
         vm.prank(oracle);
-        vm.expectRevert(RawlBetting.MatchAlreadyExists.selector);
-        betting.createMatch(matchId, makeAddr("a"), makeAddr("b"), 0, 0);
+        vm.expectRevert(RawlBettingBase.MatchAlreadyExists.selector);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
+    }
+
+    // ─── Referrals ───
+
+    function _enableReferrals(uint16 shareBps) internal {
+        vm.prank(admin);
+        betting.setReferralShareBps(shareBps);
+        vm.prank(charlie);
+        betting.registerReferrer();
+    }
+
+    function test_ReferralRewardsFlow() public {
+        _enableReferrals(2000); // 20% of the fee
+        _createMatch();
+
+        vm.prank(alice);
+        betting.placeBetWithReferrer{value: 1 ether}(matchId, 0, charlie);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        // Referral: 1 ETH * 3% fee * 20% share = 0.006 ETH
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.claimReferralRewards(matchId);
+        assertEq(charlie.balance - charlieBefore, 0.006 ether);
+
+        // Winner payout is unaffected by the referral split
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.94 ether);
+
+        // Treasury receives the fee minus the referral slice
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        assertEq(treasury.balance, 0.054 ether);
+    }
+
+    function test_UnregisteredReferrerReverts() public {
+        _createMatch();
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.ReferrerNotRegistered.selector);
+        betting.placeBetWithReferrer{value: 1 ether}(matchId, 0, charlie);
+    }
+
+    function test_SelfReferralReverts() public {
+        _enableReferrals(2000);
+        _createMatch();
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.SelfReferral.selector);
+        betting.placeBetWithReferrer{value: 1 ether}(matchId, 0, charlie);
+    }
+
+    function test_ReferralClaimTwiceReverts() public {
+        _enableReferrals(2000);
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBetWithReferrer{value: 1 ether}(matchId, 0, charlie);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(charlie);
+        betting.claimReferralRewards(matchId);
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
+        betting.claimReferralRewards(matchId);
+    }
+
+    function test_ReferralShareAboveMaxReverts() public {
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidShareBps.selector);
+        betting.setReferralShareBps(10_001);
+    }
+
+    // ─── Fighter Registry ───
+
+    function test_CreateWithUnregisteredFighterReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.FighterNotRegistered.selector);
+        betting.createMatch(matchId, fighterA, makeAddr("unknown"), 0, 0);
+    }
+
+    function test_CreateWithSameOrZeroFighterReverts() public {
+        vm.startPrank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidFighters.selector);
+        betting.createMatch(matchId, fighterA, fighterA, 0, 0);
+        vm.expectRevert(RawlBettingBase.InvalidFighters.selector);
+        betting.createMatch(matchId, address(0), fighterB, 0, 0);
+        vm.expectRevert(RawlBettingBase.InvalidFighters.selector);
+        betting.registerFighter(address(0), keccak256("Nobody"), alice);
+        vm.stopPrank();
+    }
+
+    function test_RegisterFighterTwiceReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.FighterAlreadyRegistered.selector);
+        betting.registerFighter(fighterA, keccak256("FighterA"), alice);
+    }
+
+    function test_NonOracleCantRegisterFighter() public {
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.registerFighter(makeAddr("newFighter"), keccak256("New"), alice);
+    }
+
+    function test_ResolveUpdatesFighterRecords() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1); // Side B wins
+
+        (, address ownerA, uint32 winsA, uint32 lossesA,, uint128 volumeA) = betting.fighters(fighterA);
+        (,, uint32 winsB, uint32 lossesB,, uint128 volumeB) = betting.fighters(fighterB);
+        assertEq(ownerA, alice);
+        assertEq(winsA, 0);
+        assertEq(lossesA, 1);
+        assertEq(winsB, 1);
+        assertEq(lossesB, 0);
+        assertEq(volumeA, 2 ether);
+        assertEq(volumeB, 2 ether);
+    }
+
+    // ─── Pool Shards ───
+
+    function test_BetsSpreadOverShardsUntilLock() public {
+        _createMatch();
+        for (uint256 i; i < 10; ++i) {
+            address bettor = makeAddr(string.concat("shard", vm.toString(i)));
+            vm.deal(bettor, 1 ether);
+            vm.prank(bettor);
+            betting.placeBet{value: 0.1 ether}(matchId, uint8(i % 2));
+        }
+
+        // Bet n lands in shard n % POOL_SHARDS: bets 0 and 8 backed A, 1 and 9 backed B
+        (uint128 shardA, uint128 shardB) = betting.poolShards(matchId, 0);
+        assertEq(shardA, 0.2 ether);
+        assertEq(shardB, 0);
+        (shardA, shardB) = betting.poolShards(matchId, 1);
+        assertEq(shardA, 0);
+        assertEq(shardB, 0.2 ether);
+        (shardA, shardB) = betting.poolShards(matchId, 2);
+        assertEq(shardA, 0.1 ether);
+
+        (,,,,,,,,, uint128 sideATotal, uint128 sideBTotal,,,,,,,) = betting.matches(matchId);
+        assertEq(sideATotal, 0.5 ether);
+        assertEq(sideBTotal, 0.5 ether);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        for (uint256 i; i < betting.POOL_SHARDS(); ++i) {
+            (shardA, shardB) = betting.poolShards(matchId, i);
+            assertEq(uint256(shardA) + shardB, 0);
+        }
+        (uint128 lockedA, uint128 lockedB,,,) = betting.oddsSnapshots(matchId);
+        assertEq(lockedA, 0.5 ether);
+        assertEq(lockedB, 0.5 ether);
+    }
+
+    function test_OpenCashOutConsolidatesShards() public {
+        vm.prank(admin);
+        betting.setCashOutDiscountBps(500);
+        _createAndBetBothSides();
+        vm.prank(charlie);
+        betting.placeBet{value: 2 ether}(matchId, 0);
+
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        (uint128 shardA, uint128 shardB) = betting.poolShards(matchId, 0);
+        assertEq(uint256(shardA) + shardB, 0);
+        (,,,,,,,,, uint128 sideATotal, uint128 sideBTotal,,,,,,,) = betting.matches(matchId);
+        assertEq(sideATotal, 2 ether);
+        assertEq(sideBTotal, 1 ether);
+
+        // Later bets go back to the shards on top of the consolidated totals
+        address late = makeAddr("late");
+        vm.deal(late, 1 ether);
+        vm.prank(late);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        (,,,,,,,,, sideATotal, sideBTotal,,,,,,,) = betting.matches(matchId);
+        assertEq(sideBTotal, 2 ether);
+    }
+
+    // ─── Pool Cap ───
+
+    function test_BetAbovePoolCapReverts() public {
+        vm.prank(admin);
+        betting.setMaxPoolSize(1.5 ether);
+        _createMatch();
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.prank(bob);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.PoolCapExceeded.selector, 1.5 ether)
+        );
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        // Filling exactly to the cap is allowed
+        vm.prank(bob);
+        betting.placeBet{value: 0.5 ether}(matchId, 1);
+    }
+
+    // ─── Fighter Royalties ───
+
+    function _resolveWithRoyalties(uint16 shareBps) internal {
+        vm.prank(admin);
+        betting.setRoyaltyShareBps(shareBps);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+    }
+
+    function test_FighterRoyaltyFlow() public {
+        _resolveWithRoyalties(1000); // 10% of the fee
+
+        // Pool 2 ETH, fee 0.06 ETH, royalty 0.006 ETH split between owners
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice); // owns fighterA
+        betting.claimFighterRoyalty(matchId);
+        assertEq(alice.balance - aliceBefore, 0.003 ether);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob); // owns fighterB
+        betting.claimFighterRoyalty(matchId);
+        assertEq(bob.balance - bobBefore, 0.003 ether);
+
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        assertEq(treasury.balance, 0.054 ether);
+    }
+
+    function test_FighterRoyaltyTwiceReverts() public {
+        _resolveWithRoyalties(1000);
+        vm.prank(alice);
+        betting.claimFighterRoyalty(matchId);
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NoRoyaltyDue.selector);
+        betting.claimFighterRoyalty(matchId);
+    }
+
+    function test_NonOwnerCantClaimRoyalty() public {
+        _resolveWithRoyalties(1000);
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.NoRoyaltyDue.selector);
+        betting.claimFighterRoyalty(matchId);
+    }
+
+    function test_RoyaltyPlusReferralAboveMaxReverts() public {
+        vm.prank(admin);
+        betting.setReferralShareBps(6000);
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidShareBps.selector);
+        betting.setRoyaltyShareBps(5000);
+    }
+
+    // ─── NFT-Gated Fighters ───
+
+    function _gateFighters() internal returns (MockFighterNFT nft) {
+        nft = new MockFighterNFT();
+        nft.mint(alice, 1);
+        nft.mint(bob, 2);
+        vm.prank(admin);
+        betting.setFighterCollection(address(nft));
+    }
+
+    function test_GatedCreateWithOwnedTokens() public {
+        _gateFighters();
+        vm.startPrank(oracle);
+        betting.linkFighterToken(fighterA, 1);
+        betting.linkFighterToken(fighterB, 2);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
+        vm.stopPrank();
+    }
+
+    function test_GatedCreateWithoutLinkReverts() public {
+        _gateFighters();
+        vm.startPrank(oracle);
+        betting.linkFighterToken(fighterA, 1);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.FighterTokenNotOwned.selector, fighterB)
+        );
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
+        vm.stopPrank();
+    }
+
+    function test_GatedCreateAfterTokenTransferReverts() public {
+        MockFighterNFT nft = _gateFighters();
+        vm.startPrank(oracle);
+        betting.linkFighterToken(fighterA, 1);
+        betting.linkFighterToken(fighterB, 2);
+        vm.stopPrank();
+
+        // Owner sells the fighter token — it no longer qualifies
+        vm.prank(alice);
+        nft.transferFrom(alice, charlie, 1);
+
+        vm.prank(oracle);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.FighterTokenNotOwned.selector, fighterA)
+        );
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
+    }
+
+    function test_GatedCreateWithMissingTokenReverts() public {
+        _gateFighters();
+        vm.startPrank(oracle);
+        betting.linkFighterToken(fighterA, 1);
+        betting.linkFighterToken(fighterB, 99); // never minted
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.FighterTokenNotOwned.selector, fighterB)
+        );
+        betting.createMatch(matchId, fighterA, fighterB, 0, 0);
+        vm.stopPrank();
+    }
+
+    // ─── Settlement Strategies ───
+
+    function test_CreateWithParimutuelStrategy() public {
+        vm.prank(oracle);
+        betting.createMatchWithSettlement(
+            matchId, fighterA, fighterB, 0, 0, Settlement.Strategy.Parimutuel
+        );
+        assertEq(
+            uint8(betting.settlementStrategies(matchId)), uint8(Settlement.Strategy.Parimutuel)
+        );
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 1.94 ether);
+    }
+
+    function test_CreateWithUnimplementedStrategyReverts() public {
+        for (uint8 i = 1; i <= uint8(Settlement.Strategy.HouseBacked); ++i) {
+            Settlement.Strategy strategy = Settlement.Strategy(i);
+            vm.prank(oracle);
+            vm.expectRevert(
+                abi.encodeWithSelector(Settlement.UnsupportedStrategy.selector, strategy)
+            );
+            betting.createMatchWithSettlement(matchId, fighterA, fighterB, 0, 0, strategy);
+        }
+    }
+
+    // ─── Idempotent Operator Actions ───
+
+    function test_ResolveWithSameOpIdIsNoOp() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        bytes32 opId = keccak256("resolve:match-1");
+        vm.prank(oracle);
+        betting.resolveMatchWithOpId(matchId, 0, opId);
+        assertTrue(betting.executedOps(opId));
+
+        // Retry lands after the first tx — no revert, no state change
+        vm.prank(oracle);
+        vm.expectEmit(true, false, false, false);
+        emit RawlBettingBase.OperationSkipped(opId);
+        betting.resolveMatchWithOpId(matchId, 1, opId);
+
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideA));
+    }
+
+    function test_ResolveWithNewOpIdStillReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatchWithOpId(matchId, 0, keccak256("op-1"));
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchNotLocked.selector);
+        betting.resolveMatchWithOpId(matchId, 0, keccak256("op-2"));
+    }
+
+    function test_CancelWithSameOpIdIsNoOp() public {
+        _createMatch();
+        bytes32 opId = keccak256("cancel:match-1");
+
+        vm.prank(admin);
+        betting.cancelMatchWithOpId(matchId, opId);
+        vm.prank(admin);
+        betting.cancelMatchWithOpId(matchId, opId);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Cancelled));
+    }
+
+    function test_WithdrawFeesWithSameOpIdIsNoOp() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        vm.warp(block.timestamp + 30 days + 1);
+
+        bytes32 opId = keccak256("withdraw:match-1");
+        vm.prank(admin);
+        betting.withdrawFeesWithOpId(matchId, opId);
+        uint256 treasuryAfter = treasury.balance;
+
+        vm.prank(admin);
+        betting.withdrawFeesWithOpId(matchId, opId);
+        assertEq(treasury.balance, treasuryAfter);
+    }
+
+    function test_NonOracleCantResolveWithOpId() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.resolveMatchWithOpId(matchId, 0, keccak256("op"));
+    }
+
+    // ─── Scheduled Matches ───
+
+    function _createScheduledMatch() internal returns (uint64 opensAt, uint64 lockAt) {
+        opensAt = uint64(block.timestamp + 1 hours);
+        lockAt = uint64(block.timestamp + 2 hours);
+        vm.prank(oracle);
+        betting.createScheduledMatch(matchId, fighterA, fighterB, 0, opensAt, lockAt);
+    }
+
+    function test_BetBeforeOpensAtReverts() public {
+        (uint64 opensAt,) = _createScheduledMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.BettingNotOpen.selector, opensAt));
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.warp(opensAt);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_BetAfterScheduledLockReverts() public {
+        (, uint64 lockAt) = _createScheduledMatch();
+        vm.warp(lockAt);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.BettingWindowClosed.selector);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_AnyoneCanLockAfterScheduledLockAt() public {
+        (, uint64 lockAt) = _createScheduledMatch();
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.LockNotDue.selector);
+        betting.lockExpired(matchId);
+
+        vm.warp(lockAt);
+        vm.prank(charlie);
+        betting.lockExpired(matchId);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Locked));
+    }
+
+    function test_MatchWithoutDeadlineCantBeLockedPermissionlessly() public {
+        _createMatch();
+        vm.warp(block.timestamp + 365 days);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.LockNotDue.selector);
+        betting.lockExpired(matchId);
+    }
+
+    function test_ScheduleLockBeforeOpenReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidSchedule.selector);
+        betting.createScheduledMatch(
+            matchId,
+            fighterA,
+            fighterB,
+            0,
+            uint64(block.timestamp + 2 hours),
+            uint64(block.timestamp + 1 hours)
+        );
+    }
+
+    // ─── Unpause Grace Period ───
+
+    function test_BetDuringUnpauseGraceReverts() public {
+        _createMatch();
+        vm.startPrank(admin);
+        betting.setUnpauseGracePeriod(10 minutes);
+        betting.pause();
+        betting.unpause();
+        vm.stopPrank();
+
+        uint64 resumesAt = uint64(block.timestamp + 10 minutes);
+        assertEq(betting.bettingResumesAt(), resumesAt);
+
+        vm.prank(alice);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.UnpauseGracePeriodActive.selector, resumesAt)
+        );
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.warp(resumesAt);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_UnpauseWithoutGraceReopensImmediately() public {
+        _createMatch();
+        vm.startPrank(admin);
+        betting.pause();
+        betting.unpause();
+        vm.stopPrank();
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    // ─── Permissionless Lock on Window Expiry ───
+
+    function test_AnyoneCanLockAfterBettingWindow() public {
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 1 hours);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.LockNotDue.selector);
+        betting.lockExpired(matchId);
+
+        vm.warp(block.timestamp + 1 hours + 1);
+        vm.prank(charlie);
+        betting.lockExpired(matchId);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Locked));
+    }
+
+    function test_LockExpiredOnLockedMatchReverts() public {
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 1 hours);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.warp(block.timestamp + 1 hours + 1);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
+        betting.lockExpired(matchId);
+    }
+
+    // ─── Unbalanced Pools ───
+
+    function test_CancelUnbalancedRefundsInFull() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.prank(charlie);
+        betting.cancelUnbalanced(matchId);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - aliceBefore, 1 ether);
+    }
+
+    function test_CancelUnbalancedWithBothSidesReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.expectRevert(RawlBettingBase.PoolBalanced.selector);
+        betting.cancelUnbalanced(matchId);
+    }
+
+    function test_CancelUnbalancedWhileOpenReverts() public {
+        _createMatch();
+        vm.expectRevert(RawlBettingBase.MatchNotLocked.selector);
+        betting.cancelUnbalanced(matchId);
+    }
+
+    // ─── Max Match Age ───
+
+    function test_ForceCancelExpiredOpenMatch() public {
+        _createAndBetBothSides();
+
+        vm.expectRevert(RawlBettingBase.MatchNotExpired.selector);
+        betting.forceCancelExpired(matchId);
+
+        vm.warp(block.timestamp + 7 days);
+        vm.prank(charlie);
+        betting.forceCancelExpired(matchId);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - aliceBefore, 1 ether);
+    }
+
+    function test_ForceCancelResolvedMatchReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.warp(block.timestamp + 7 days);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.forceCancelExpired(matchId);
+    }
+
+    function test_MaxMatchAgeBelowMaxTimeoutReverts() public {
+        uint64 floor = betting.MAX_TIMEOUT();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setMaxMatchAge(floor - 1);
+
+        vm.prank(admin);
+        betting.setMaxMatchAge(floor);
+        assertEq(betting.maxMatchAge(), floor);
+    }
+
+    function test_ScheduleBeyondMaxMatchAgeReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidSchedule.selector);
+        betting.createScheduledMatch(
+            matchId, fighterA, fighterB, 0, 0, uint64(block.timestamp + 8 days)
+        );
+    }
+
+    // ─── Event Log ───
+
+    function test_EventLogRecordsLifecycle() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+
+        EventLog.Buffer memory buffer = betting.getEventLog(matchId);
+        assertEq(buffer.count, 4);
+
+        (EventLog.Kind kind, uint8 side,, uint128 amount) = EventLog.decode(buffer.entries[1]);
+        assertEq(uint8(kind), uint8(EventLog.Kind.Bet));
+        assertEq(side, 1);
+        assertEq(amount, 1 ether);
+
+        (kind,,, amount) = EventLog.decode(buffer.entries[2]);
+        assertEq(uint8(kind), uint8(EventLog.Kind.Locked));
+        assertEq(amount, 2 ether);
+
+        uint64 timestamp;
+        (kind, side, timestamp,) = EventLog.decode(buffer.entries[3]);
+        assertEq(uint8(kind), uint8(EventLog.Kind.Resolved));
+        assertEq(side, 1);
+        assertEq(timestamp, uint64(block.timestamp));
+    }
+
+    function test_EventLogSkipsBetsBelowThreshold() public {
+        vm.prank(admin);
+        betting.setEventLogBetThreshold(2 ether);
+        _createAndBetBothSides();
+
+        assertEq(betting.getEventLog(matchId).count, 0);
+    }
+
+    function test_EventLogSkipsSmallBetsByDefault() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 0.5 ether}(matchId, 0);
+
+        assertEq(betting.getEventLog(matchId).count, 0);
+    }
+
+    function test_EventLogWrapsAround() public {
+        vm.prank(admin);
+        betting.setEventLogBetThreshold(0.01 ether);
+        _createMatch();
+        for (uint256 i = 0; i < EventLog.SIZE + 2; i++) {
+            address bettor = makeAddr(string(abi.encodePacked("bettor", vm.toString(i))));
+            vm.deal(bettor, 1 ether);
+            vm.prank(bettor);
+            betting.placeBet{value: 0.01 ether + i}(matchId, 0);
+        }
+
+        EventLog.Buffer memory buffer = betting.getEventLog(matchId);
+        assertEq(buffer.count, EventLog.SIZE + 2);
+
+        // Newest entry overwrote slot 1, oldest surviving entry sits at count % SIZE
+        (,,, uint128 newest) = EventLog.decode(buffer.entries[1]);
+        assertEq(newest, 0.01 ether + EventLog.SIZE + 1);
+        (,,, uint128 oldest) = EventLog.decode(buffer.entries[2]);
+        assertEq(oldest, 0.01 ether + 2);
+    }
+
+    function test_EventLogRecordsCancel() public {
+        _createMatch();
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        EventLog.Buffer memory buffer = betting.getEventLog(matchId);
+        assertEq(buffer.count, 1);
+        (EventLog.Kind kind, uint8 reason,,) = EventLog.decode(buffer.entries[0]);
+        assertEq(uint8(kind), uint8(EventLog.Kind.Cancelled));
+        assertEq(reason, uint8(RawlBettingBase.CancelReason.OperatorCancel));
+    }
+
+    // ─── Participation Minimums ───
+
+    function test_LockBelowMinBetsPerSideReverts() public {
+        vm.prank(admin);
+        betting.setParticipationMinimums(2, 0);
+        _createAndBetBothSides();
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ParticipationBelowMinimum.selector);
+        betting.lockMatch(matchId);
+
+        vm.prank(charlie);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.deal(address(0xBEEF), 1 ether);
+        vm.prank(address(0xBEEF));
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function test_LockBelowMinTotalPoolReverts() public {
+        vm.prank(admin);
+        betting.setParticipationMinimums(0, 5 ether);
+        _createAndBetBothSides();
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ParticipationBelowMinimum.selector);
+        betting.lockMatch(matchId);
+    }
+
+    function test_LockExpiredBelowMinimumCancels() public {
+        vm.prank(admin);
+        betting.setParticipationMinimums(0, 5 ether);
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 1 hours);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.warp(block.timestamp + 1 hours + 1);
+        betting.lockExpired(matchId);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Cancelled));
+    }
+
+    // ─── Anti-Snipe Extension ───
+
+    function _enableAntiSnipe() internal {
+        vm.prank(admin);
+        betting.setAntiSnipe(5000, 5 minutes, 2 minutes, 4 minutes); // 50% of pool
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0, 1 hours);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_LargeLateBetExtendsWindow() public {
+        _enableAntiSnipe();
+        uint256 closesAt = block.timestamp + 1 hours;
+
+        vm.warp(closesAt - 1 minutes);
+        vm.prank(bob);
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.BettingWindowExtended(matchId, uint64(closesAt + 2 minutes));
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        // Still bettable past the original close
+        vm.warp(closesAt + 1 minutes);
+        vm.prank(charlie);
+        betting.placeBet{value: 0.1 ether}(matchId, 0);
+    }
+
+    function test_SmallLateBetDoesNotExtend() public {
+        _enableAntiSnipe();
+        vm.warp(block.timestamp + 1 hours - 1 minutes);
+
+        vm.prank(bob);
+        betting.placeBet{value: 0.1 ether}(matchId, 1);
+        assertEq(betting.snipeExtensions(matchId), 0);
+    }
+
+    function test_EarlyLargeBetDoesNotExtend() public {
+        _enableAntiSnipe();
+
+        vm.prank(bob);
+        betting.placeBet{value: 5 ether}(matchId, 1);
+        assertEq(betting.snipeExtensions(matchId), 0);
+    }
+
+    function test_SnipeExtensionIsBounded() public {
+        _enableAntiSnipe();
+        uint256 closesAt = block.timestamp + 1 hours;
+        address[3] memory snipers = [bob, charlie, address(0xBEEF)];
+        vm.deal(address(0xBEEF), 100 ether);
+
+        vm.warp(closesAt - 1 minutes);
+        for (uint256 i = 0; i < snipers.length; i++) {
+            vm.prank(snipers[i]);
+            betting.placeBet{value: 10 ether * (i + 1)}(matchId, 1);
+        }
+        assertEq(betting.snipeExtensions(matchId), 4 minutes);
+
+        vm.warp(closesAt + 4 minutes + 1);
+        vm.deal(address(0xCAFE), 1 ether);
+        vm.prank(address(0xCAFE));
+        vm.expectRevert(RawlBettingBase.BettingWindowClosed.selector);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_SnipeAfterCapLoweredBelowUsedDoesNotExtend() public {
+        _enableAntiSnipe();
+        uint256 closesAt = block.timestamp + 1 hours;
+
+        vm.warp(closesAt - 1 minutes);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        assertEq(betting.snipeExtensions(matchId), 2 minutes);
+
+        vm.prank(admin);
+        betting.setAntiSnipe(5000, 5 minutes, 2 minutes, 1 minutes);
+
+        vm.prank(charlie);
+        betting.placeBet{value: 5 ether}(matchId, 0);
+        assertEq(betting.snipeExtensions(matchId), 2 minutes);
+    }
+
+    // ─── Contract Bettors (multisig vaults) ───
+
+    function test_VaultBetsAndClaims() public {
+        MockVault vault = new MockVault(charlie);
+        vm.deal(address(vault), 5 ether);
+        _createMatch();
+
+        vm.prank(charlie);
+        vault.execute(address(betting), 2 ether, abi.encodeCall(RawlBetting.placeBet, (matchId, 0)));
+        vm.prank(bob);
+        betting.placeBet{value: 2 ether}(matchId, 1);
+
+        (uint128 amount,,) = betting.bets(matchId, address(vault));
+        assertEq(amount, 2 ether);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(charlie);
+        vault.execute(address(betting), 0, abi.encodeCall(RawlBetting.claimPayout, (matchId)));
+        assertEq(address(vault).balance, 3 ether + 3.88 ether);
+    }
+
+    function test_VaultRefundOnCancel() public {
+        MockVault vault = new MockVault(charlie);
+        vm.deal(address(vault), 1 ether);
+        _createMatch();
+
+        vm.prank(charlie);
+        vault.execute(address(betting), 1 ether, abi.encodeCall(RawlBetting.placeBet, (matchId, 1)));
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        vm.prank(charlie);
+        vault.execute(address(betting), 0, abi.encodeCall(RawlBetting.refundBet, (matchId)));
+        assertEq(address(vault).balance, 1 ether);
+    }
+
+    // ─── Odds Snapshots ───
+
+    function test_BetRecordsOddsBeforePlacement() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.prank(bob);
+        vm.expectEmit(true, true, false, true);
+        emit RawlBettingBase.BetPlaced(matchId, bob, 1, 2 ether, 1 ether, 0);
+        betting.placeBet{value: 2 ether}(matchId, 1);
+
+        (uint128 aliceA, uint128 aliceB) = betting.betOdds(matchId, alice);
+        assertEq(aliceA, 0);
+        assertEq(aliceB, 0);
+
+        (uint128 bobA, uint128 bobB) = betting.betOdds(matchId, bob);
+        assertEq(bobA, 1 ether);
+        assertEq(bobB, 0);
+    }
+
+    // ─── Cash-Out ───
+
+    function _openMatchWithCashOut() internal {
+        vm.prank(admin);
+        betting.setCashOutDiscountBps(500); // 5%
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 3 ether}(matchId, 1);
+    }
+
+    function test_CashOutPaysStakeLessFeeAndDiscount() public {
+        _openMatchWithCashOut();
+        uint256 aliceBefore = alice.balance;
+        uint256 treasuryBefore = treasury.balance;
+
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        // 1 ETH less the 3% fee = 0.97, less 5% = 0.9215; the haircut accrues in the vault
+        assertEq(alice.balance - aliceBefore, 0.9215 ether);
+        assertEq(betting.cashOutDust(), 0.0785 ether);
+        assertEq(treasury.balance, treasuryBefore);
+
+        (,,,,,,,,, uint128 sideATotal, uint128 sideBTotal,,,,,,,) = betting.matches(matchId);
+        assertEq(sideATotal, 0);
+        assertEq(sideBTotal, 3 ether);
+    }
+
+    function test_RemainingBettorsSettleWithoutCashedOutStake() public {
+        _openMatchWithCashOut();
+        vm.prank(charlie);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+
+        // Pool is charlie's 1 + bob's 3, less the 3% fee
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 3.88 ether);
+    }
+
+    function _lockedMatchWithReserve() internal {
+        _openMatchWithCashOut();
+        vm.deal(admin, 2 ether);
+        vm.startPrank(admin);
+        betting.fundCashOutReserve{value: 2 ether}();
+        betting.setCashOutMatchCap(1 ether);
+        vm.stopPrank();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function test_LockedCashOutSellsPositionToReserve() public {
+        _lockedMatchWithReserve();
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        // Implied value 1/4 × 3.88 = 0.97, less 5% = 0.9215, paid from the reserve
+        assertEq(alice.balance - aliceBefore, 0.9215 ether);
+        assertEq(betting.cashOutReserve(), 2 ether - 0.9215 ether);
+        assertEq(betting.cashOutSpent(matchId), 0.9215 ether);
+
+        // The stake stays in the pool, so the other side's payout doesn't move
+        (,,,,,,,,, uint128 sideATotal,,,,,,,,) = betting.matches(matchId);
+        assertEq(sideATotal, 1 ether);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 3.88 ether);
+
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
+        betting.settleCashOutDesk(matchId, 0);
+    }
+
+    function test_SoldWinningPositionPaysTheReserve() public {
+        _lockedMatchWithReserve();
+        vm.prank(alice);
+        betting.cashOut(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
+        betting.claimPayout(matchId);
+
+        vm.prank(charlie); // anyone
+        betting.settleCashOutDesk(matchId, 0);
+        assertEq(betting.cashOutReserve(), 2 ether - 0.9215 ether + 3.88 ether);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
+        betting.settleCashOutDesk(matchId, 0);
+    }
+
+    function test_LockedCashOutIsBounded() public {
+        _lockedMatchWithReserve();
+        vm.prank(admin);
+        betting.setCashOutMatchCap(0.5 ether);
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.CashOutLimitReached.selector);
+        betting.cashOut(matchId);
+
+        vm.startPrank(admin);
+        betting.setCashOutMatchCap(10 ether);
+        betting.withdrawCashOutReserve(2 ether);
+        vm.stopPrank();
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.InsufficientCashOutReserve.selector);
+        betting.cashOut(matchId);
+    }
+
+    function test_LockedCashOutClosesOnceResultIsIn() public {
+        _lockedMatchWithReserve();
+        vm.prank(admin);
+        betting.setDualSignPoolSize(1 ether);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1); // waits for the authority's co-sign
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.ResultPending.selector);
+        betting.cashOut(matchId);
+    }
+
+    function test_CashOutTwiceReverts() public {
+        _openMatchWithCashOut();
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
+        betting.cashOut(matchId);
+    }
+
+    function test_CashOutDisabledByDefault() public {
+        _createAndBetBothSides();
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.CashOutDisabled.selector);
+        betting.cashOut(matchId);
+    }
+
+    function test_CashOutUnwindsReferredVolume() public {
+        vm.prank(admin);
+        betting.setCashOutDiscountBps(500);
+        vm.prank(charlie);
+        betting.registerReferrer();
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBetWithReferrer{value: 1 ether}(matchId, 0, charlie);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        (uint128 referredVolume,) = betting.referralPools(matchId);
+        assertEq(referredVolume, 0);
+        (uint128 accrued,) = betting.referralAccruals(matchId, charlie);
+        assertEq(accrued, 0);
+    }
+
+    function test_WithdrawCashOutDustToDustTreasury() public {
+        address dustTreasury = makeAddr("dustTreasury");
+        vm.prank(admin);
+        betting.setTreasuryRouting(address(0), address(0), dustTreasury);
+        _openMatchWithCashOut();
+        vm.prank(alice);
+        betting.cashOut(matchId);
+
+        vm.prank(admin);
+        betting.withdrawCashOutDust();
+        assertEq(dustTreasury.balance, 0.0785 ether);
+        assertEq(betting.cashOutDust(), 0);
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.NoCashOutDust.selector);
+        betting.withdrawCashOutDust();
+    }
+
+    // ─── Loss Floor Markets ───
+
+    function _resolvedLossFloorMatch() internal {
+        vm.prank(oracle);
+        betting.createMatchWithLossFloor(matchId, fighterA, fighterB, 0, 0, 2000); // 20%
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+    }
+
+    function test_LoserRecoversLossFloor() public {
+        _resolvedLossFloorMatch();
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 0.2 ether);
+    }
+
+    function test_LossFloorReducesWinnerPayout() public {
+        _resolvedLossFloorMatch();
+
+        // Net pool 1.94 minus 0.2 rebate
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.74 ether);
+    }
+
+    function test_LoserWithoutFloorStillReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
+        betting.claimPayout(matchId);
+    }
+
+    function test_LossFloorAboveMaxReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidLossFloor.selector);
+        betting.createMatchWithLossFloor(matchId, fighterA, fighterB, 0, 0, 5001);
+    }
+
+    function test_LossFloorFeesStillWithdrawable() public {
+        _resolvedLossFloorMatch();
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        vm.warp(block.timestamp + 30 days + 1);
+
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        assertEq(treasury.balance, 0.06 ether);
+        assertEq(address(betting).balance, 0);
+    }
+
+    // ─── Bet Positions ───
+
+    function _enablePositions() internal returns (BetPositions positions) {
+        positions = new BetPositions(address(betting));
+        vm.prank(admin);
+        betting.setBetPositions(address(positions));
+    }
+
+    function test_PositionDisabledByDefault() public {
+        _createMatch();
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.PositionsDisabled.selector);
+        betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+    }
+
+    function test_PositionTransferMovesClaimRights() public {
+        BetPositions positions = _enablePositions();
+        _createMatch();
+        vm.prank(alice);
+        uint256 tokenId = betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        assertEq(positions.ownerOf(tokenId), alice);
+
+        vm.prank(alice);
+        positions.transferFrom(alice, charlie, tokenId);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        // The wallet that placed the bet has no claim of its own
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NoBetFound.selector);
+        betting.claimPayout(matchId);
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotPositionOwner.selector);
+        betting.redeemPosition(tokenId);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.redeemPosition(tokenId);
+        assertEq(charlie.balance - charlieBefore, 1.94 ether);
+        assertEq(positions.balanceOf(charlie), 0);
+    }
+
+    function test_PositionRedeemBeforeResolveReverts() public {
+        BetPositions positions = _enablePositions();
+        _createMatch();
+        vm.prank(alice);
+        uint256 tokenId = betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.MatchNotResolved.selector);
+        betting.redeemPosition(tokenId);
+        assertEq(positions.ownerOf(tokenId), alice); // burn rolled back
+    }
+
+    function test_PositionRedeemOnCancelRefundsInFull() public {
+        _enablePositions();
+        _createMatch();
+        vm.prank(alice);
+        uint256 tokenId = betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.redeemPosition(tokenId);
+        assertEq(alice.balance - aliceBefore, 1 ether);
+    }
+
+    function test_PositionRedeemWithNoWinners() public {
+        _enablePositions();
+        _createMatch();
+        vm.prank(alice);
+        uint256 tokenId = betting.placeBetAsPosition{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.redeemPosition(tokenId);
+        assertEq(alice.balance - aliceBefore, 0.97 ether);
+    }
+
+    function test_SweepCancelledPaysPositionHolder() public {
+        BetPositions positions = _enablePositions();
+        _createMatch();
+        vm.prank(alice);
+        uint256 tokenId = betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+        vm.prank(alice);
+        positions.transferFrom(alice, charlie, tokenId);
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+        vm.warp(block.timestamp + 30 days + 1);
+
+        uint256 charlieBefore = charlie.balance;
+        betting.sweepCancelled(matchId, _positionKey(tokenId));
+        assertEq(charlie.balance - charlieBefore, 1 ether);
+        vm.expectRevert();
+        positions.ownerOf(tokenId);
+    }
+
+    function test_BetPositionsSetOnce() public {
+        _enablePositions();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.BetPositionsAlreadySet.selector);
+        betting.setBetPositions(address(1));
+    }
+
+    function test_OnlyBettingMintsPositions() public {
+        BetPositions positions = _enablePositions();
+        vm.prank(alice);
+        vm.expectRevert(BetPositions.OnlyBetting.selector);
+        positions.mint(alice);
+    }
+
+    /// @dev Mirrors RawlBettingBase._positionKey
+    function _positionKey(uint256 tokenId) internal pure returns (address) {
+        return address(uint160(uint256(keccak256(abi.encode("rawl.position", tokenId)))));
+    }
+
+    // ─── Address Screening ───
+
+    function _enableScreening() internal returns (MockScreeningOracle screening) {
+        screening = new MockScreeningOracle();
+        screening.setSanctioned(alice, true);
+        vm.prank(admin);
+        betting.setScreeningOracle(address(screening));
+    }
+
+    function test_SanctionedBettorRejected() public {
+        _enableScreening();
+        _createMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AddressSanctioned.selector, alice));
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+    }
+
+    function test_SanctionedBettorCannotUsePosition() public {
+        _enableScreening();
+        BetPositions positions = new BetPositions(address(betting));
+        vm.prank(admin);
+        betting.setBetPositions(address(positions));
+        _createMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AddressSanctioned.selector, alice));
+        betting.placeBetAsPosition{value: 1 ether}(matchId, 0);
+    }
+
+    function test_ScreeningCanBeTurnedOff() public {
+        _enableScreening();
+        vm.prank(admin);
+        betting.setScreeningOracle(address(0));
+        _createMatch();
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_ScreenedBettorCanStillRefund() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        _enableScreening();
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        // Screening gates new exposure only — existing funds stay withdrawable
+        vm.prank(alice);
+        betting.refundBet(matchId);
+    }
+
+    // ─── Gift Bets ───
+
+    function test_GiftBetOwnedByRecipient() public {
+        _createMatch();
+        vm.prank(charlie);
+        betting.placeBetFor{value: 1 ether}(matchId, 0, alice);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        (uint128 amount, uint8 side,) = betting.bets(matchId, alice);
+        assertEq(amount, 1 ether);
+        assertEq(side, 0);
+        (uint128 payerAmount,,) = betting.bets(matchId, charlie);
+        assertEq(payerAmount, 0);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.NoBetFound.selector);
+        betting.claimPayout(matchId);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.94 ether);
+    }
+
+    function test_GiftBetRecipientCannotBetAgain() public {
+        _createMatch();
+        vm.prank(charlie);
+        betting.placeBetFor{value: 1 ether}(matchId, 0, alice);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.AlreadyBet.selector);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+    }
+
+    function test_GiftBetToZeroAddressReverts() public {
+        _createMatch();
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.InvalidBettor.selector);
+        betting.placeBetFor{value: 1 ether}(matchId, 0, address(0));
+    }
+
+    function test_GiftBetScreensRecipient() public {
+        _enableScreening();
+        _createMatch();
+
+        vm.prank(charlie);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AddressSanctioned.selector, alice));
+        betting.placeBetFor{value: 1 ether}(matchId, 0, alice);
+    }
+
+    function test_GiftBetScreensPayer() public {
+        _enableScreening();
+        _createMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AddressSanctioned.selector, alice));
+        betting.placeBetFor{value: 1 ether}(matchId, 0, bob);
+    }
+
+    // ─── Claim Delegates ───
+
+    function test_DelegateClaimPaysBettor() public {
+        _createAndBetBothSides();
+        vm.prank(alice);
+        betting.setClaimDelegate(charlie);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        uint256 aliceBefore = alice.balance;
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.claimPayoutFor(matchId, alice);
+        assertEq(alice.balance - aliceBefore, 1.94 ether);
+        assertEq(charlie.balance, charlieBefore);
+    }
+
+    function test_DelegateRefundPaysBettor() public {
+        _createAndBetBothSides();
+        vm.prank(bob);
+        betting.setClaimDelegate(charlie);
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(charlie);
+        betting.refundBetFor(matchId, bob);
+        assertEq(bob.balance - bobBefore, 1 ether);
+    }
+
+    function test_NonDelegateCannotClaimFor() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.NotClaimDelegate.selector);
+        betting.claimPayoutFor(matchId, alice);
+    }
+
+    function test_RevokedDelegateCannotClaim() public {
+        _createAndBetBothSides();
+        vm.prank(alice);
+        betting.setClaimDelegate(charlie);
+        vm.prank(alice);
+        betting.setClaimDelegate(address(0));
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.NotClaimDelegate.selector);
+        betting.refundBetFor(matchId, alice);
+    }
+
+    // ─── Alternate Payout Recipient ───
+
+    function test_ClaimPayoutToColdWallet() public {
+        address cold = makeAddr("cold");
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayoutTo(matchId, cold);
+        assertEq(cold.balance, 1.94 ether);
+        assertEq(alice.balance, aliceBefore);
+
+        (,, bool claimed) = betting.bets(matchId, alice);
+        assertTrue(claimed);
+    }
+
+    function test_ClaimPayoutToZeroReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.InvalidRecipient.selector);
+        betting.claimPayoutTo(matchId, address(0));
+    }
+
+    // ─── Cancellation Reasons ───
+
+    function _cancelReason() internal view returns (RawlBettingBase.CancelReason) {
+        return betting.cancelReasons(matchId);
+    }
+
+    function test_OperatorCancelReason() public {
+        _createMatch();
+        vm.prank(admin);
+        vm.expectEmit(true, true, false, true);
+        emit RawlBettingBase.MatchCancelled(
+            matchId, RawlBettingBase.CancelReason.OperatorCancel, uint64(block.timestamp)
+        );
+        betting.cancelMatch(matchId);
+    }
+
+    function test_EmergencyCancelReason() public {
+        _createMatch();
+        vm.prank(admin);
+        betting.emergencyCancel(matchId);
+        assertEq(uint8(_cancelReason()), uint8(RawlBettingBase.CancelReason.Emergency));
+    }
+
+    function test_TimeoutCancelReason() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.warp(block.timestamp + 1801);
+        betting.timeoutMatch(matchId);
+        assertEq(uint8(_cancelReason()), uint8(RawlBettingBase.CancelReason.Timeout));
+    }
+
+    function test_EmptySideCancelReason() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        betting.cancelUnbalanced(matchId);
+        assertEq(uint8(_cancelReason()), uint8(RawlBettingBase.CancelReason.EmptySide));
+    }
+
+    function test_ExpiredCancelReason() public {
+        _createMatch();
+        vm.warp(block.timestamp + 7 days);
+        betting.forceCancelExpired(matchId);
+        assertEq(uint8(_cancelReason()), uint8(RawlBettingBase.CancelReason.Expired));
+    }
+
+    function test_UndersubscribedCancelReason() public {
+        vm.prank(admin);
+        betting.setParticipationMinimums(1, 0);
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0.001 ether, 60);
+        vm.warp(block.timestamp + 61);
+        betting.lockExpired(matchId);
+        assertEq(uint8(_cancelReason()), uint8(RawlBettingBase.CancelReason.Undersubscribed));
+    }
+
+    // ─── Roll-Over ───
+
+    bytes32 nextMatchId = keccak256("match-2");
+
+    function _resolveAndOpenNext() internal {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        vm.prank(oracle);
+        betting.createMatch(nextMatchId, fighterA, fighterB, 0.001 ether, 0);
+    }
+
+    function test_RollOverRestakesPayout() public {
+        _resolveAndOpenNext();
+
+        uint256 aliceBefore = alice.balance;
+        uint256 contractBefore = address(betting).balance;
+        vm.prank(alice);
+        betting.rollOver(matchId, nextMatchId, 1);
+
+        assertEq(alice.balance, aliceBefore);
+        assertEq(address(betting).balance, contractBefore);
+        (uint128 amount, uint8 side, bool claimed) = betting.bets(nextMatchId, alice);
+        assertEq(amount, 1.94 ether);
+        assertEq(side, 1);
+        assertFalse(claimed);
+        (,, claimed) = betting.bets(matchId, alice);
+        assertTrue(claimed);
+    }
+
+    function test_RollOverLosingBetReverts() public {
+        _resolveAndOpenNext();
+
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
+        betting.rollOver(matchId, nextMatchId, 0);
+    }
+
+    function test_RollOverIntoClosedMatchReverts() public {
+        _resolveAndOpenNext();
+        vm.prank(oracle);
+        betting.lockMatch(nextMatchId);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
+        betting.rollOver(matchId, nextMatchId, 0);
+
+        // Nothing was claimed, so the payout is still there
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+    }
+
+    // ─── Vouchers ───
+
+    bytes32 voucherId = keccak256("voucher-1");
+
+    function _issueVoucher(address redeemer) internal {
+        vm.deal(admin, 10 ether);
+        vm.startPrank(admin);
+        betting.fundPromo{value: 2 ether}();
+        betting.issueVoucher(voucherId, redeemer, 1 ether, uint64(block.timestamp + 1 days));
+        vm.stopPrank();
+    }
+
+    function _voucherBetAgainstBob() internal {
+        _issueVoucher(charlie);
+        _createMatch();
+        vm.prank(charlie);
+        betting.placeBetWithVoucher(matchId, 0, voucherId);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function test_IssueVoucherReservesBudget() public {
+        _issueVoucher(charlie);
+        assertEq(betting.promoBudget(), 1 ether);
+        assertEq(address(betting).balance, 2 ether);
+    }
+
+    function test_IssueVoucherOverBudgetReverts() public {
+        _issueVoucher(charlie);
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InsufficientPromoBudget.selector);
+        betting.issueVoucher(keccak256("voucher-2"), alice, 2 ether, uint64(block.timestamp + 1));
+    }
+
+    function test_VoucherWinReturnsPrincipalToPromo() public {
+        _voucherBetAgainstBob();
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.claimPayout(matchId);
+
+        // 1.94 ETH payout: 1 ETH principal back to promo, 0.94 ETH winnings to charlie
+        assertEq(charlie.balance - charlieBefore, 0.94 ether);
+        assertEq(betting.promoBudget(), 2 ether);
+        assertEq(betting.voucherStakes(matchId, charlie), 0);
+    }
+
+    function test_VoucherLossSpendsPrincipal() public {
+        _voucherBetAgainstBob();
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
+        betting.claimPayout(matchId);
+        assertEq(betting.promoBudget(), 1 ether);
+    }
+
+    function test_VoucherRefundGoesToPromo() public {
+        _voucherBetAgainstBob();
+        vm.prank(oracle);
+        betting.cancelMatch(matchId);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.refundBet(matchId);
+
+        assertEq(charlie.balance, charlieBefore);
+        assertEq(betting.promoBudget(), 2 ether);
+    }
+
+    function test_VoucherSingleUse() public {
+        _issueVoucher(charlie);
+        _createMatch();
+        vm.prank(oracle);
+        betting.createMatch(keccak256("match-2"), fighterA, fighterB, 0.001 ether, 0);
+
+        vm.startPrank(charlie);
+        betting.placeBetWithVoucher(matchId, 0, voucherId);
+        vm.expectRevert(RawlBettingBase.VoucherAlreadyRedeemed.selector);
+        betting.placeBetWithVoucher(keccak256("match-2"), 0, voucherId);
+        vm.stopPrank();
+    }
+
+    function test_VoucherWrongRedeemerReverts() public {
+        _issueVoucher(charlie);
+        _createMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.VoucherNotFound.selector);
+        betting.placeBetWithVoucher(matchId, 0, voucherId);
+    }
+
+    function test_ReclaimExpiredVoucher() public {
+        _issueVoucher(charlie);
+        _createMatch();
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.VoucherNotExpired.selector);
+        betting.reclaimVoucher(voucherId);
+
+        vm.warp(block.timestamp + 1 days);
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.VoucherExpired.selector);
+        betting.placeBetWithVoucher(matchId, 0, voucherId);
+
+        vm.prank(admin);
+        betting.reclaimVoucher(voucherId);
+        assertEq(betting.promoBudget(), 2 ether);
+
+        uint256 treasuryBefore = treasury.balance;
+        vm.prank(admin);
+        betting.withdrawPromo(2 ether);
+        assertEq(treasury.balance - treasuryBefore, 2 ether);
+        assertEq(betting.promoBudget(), 0);
+    }
+
+    // ─── Pending Bets ───
+
+    function test_BetAfterLockIsEscrowed() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.placeBetOrEscrow{value: 0.5 ether}(matchId, 0);
+
+        assertEq(betting.pendingBets(matchId, charlie), 0.5 ether);
+        (uint128 amount,,) = betting.bets(matchId, charlie);
+        assertEq(amount, 0);
+
+        // Anyone can crank the refund; it always goes to the bettor
+        betting.refundPendingBet(matchId, charlie);
+        assertEq(charlie.balance, charlieBefore);
+        assertEq(betting.pendingBets(matchId, charlie), 0);
+    }
+
+    function test_BetAfterWindowIsEscrowed() public {
+        vm.prank(oracle);
+        betting.createMatch(matchId, fighterA, fighterB, 0.001 ether, 60);
+        vm.warp(block.timestamp + 61);
+
+        vm.prank(charlie);
+        betting.placeBetOrEscrow{value: 0.5 ether}(matchId, 1);
+        assertEq(betting.pendingBets(matchId, charlie), 0.5 ether);
+    }
+
+    function test_PlaceBetOrEscrowWhileOpenPlacesBet() public {
+        _createMatch();
+        vm.prank(charlie);
+        betting.placeBetOrEscrow{value: 0.5 ether}(matchId, 1);
+
+        (uint128 amount, uint8 side,) = betting.bets(matchId, charlie);
+        assertEq(amount, 0.5 ether);
+        assertEq(side, 1);
+        assertEq(betting.pendingBets(matchId, charlie), 0);
+    }
+
+    function test_PlaceBetOrEscrowUnknownMatchReverts() public {
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
+        betting.placeBetOrEscrow{value: 0.5 ether}(matchId, 0);
+    }
+
+    function test_RefundPendingBetTwiceReverts() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(charlie);
+        betting.placeBetOrEscrow{value: 0.5 ether}(matchId, 0);
+
+        betting.refundPendingBet(matchId, charlie);
+        vm.expectRevert(RawlBettingBase.NoPendingBet.selector);
+        betting.refundPendingBet(matchId, charlie);
+    }
+
+    // ─── Sponsor Matching ───
+
+    address sponsor = makeAddr("sponsor");
+
+    function _sponsorMatch() internal {
+        _createMatch();
+        vm.deal(sponsor, 10 ether);
+        vm.prank(sponsor);
+        betting.sponsorMatch{value: 0.15 ether}(matchId, 0.1 ether, 10_000);
+    }
+
+    function test_SponsorMatchesBetUpToCap() public {
+        _sponsorMatch();
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        (uint128 amount,,) = betting.bets(matchId, alice);
+        assertEq(amount, 1.1 ether);
+        assertEq(betting.sponsorMatched(matchId, alice), 0.1 ether);
+
+        // Only 0.05 left in the budget
+        vm.prank(bob);
+        betting.placeBet{value: 0.08 ether}(matchId, 1);
+        (amount,,) = betting.bets(matchId, bob);
+        assertEq(amount, 0.13 ether);
+
+        (,, uint128 budget,) = betting.sponsorships(matchId);
+        assertEq(budget, 0);
+    }
+
+    function test_SponsorWithdrawsUnmatchedAfterLock() public {
+        _sponsorMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 0.05 ether}(matchId, 0);
+
+        vm.prank(sponsor);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.withdrawSponsorship(matchId);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        uint256 sponsorBefore = sponsor.balance;
+        vm.prank(sponsor);
+        betting.withdrawSponsorship(matchId);
+        assertEq(sponsor.balance - sponsorBefore, 0.1 ether);
+    }
+
+    function test_CancelReturnsMatchToSponsor() public {
+        _sponsorMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(oracle);
+        betting.cancelMatch(matchId);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - aliceBefore, 1 ether);
+
+        uint256 sponsorBefore = sponsor.balance;
+        vm.prank(sponsor);
+        betting.withdrawSponsorship(matchId);
+        assertEq(sponsor.balance - sponsorBefore, 0.15 ether);
+    }
+
+    function test_MatchedWinningsGoToBettor() public {
+        _sponsorMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        // Whole 2.15 ETH pool less the 3% fee
+        assertEq(alice.balance - aliceBefore, 2.0855 ether);
+    }
+
+    function test_GiftBetGetsSponsorMatch() public {
+        _sponsorMatch();
+        vm.prank(charlie);
+        betting.placeBetFor{value: 1 ether}(matchId, 0, alice);
+
+        (uint128 amount,,) = betting.bets(matchId, alice);
+        assertEq(amount, 1.1 ether);
+        assertEq(betting.sponsorMatched(matchId, alice), 0.1 ether);
+
+        // The match is the recipient's, so a refund returns it to the sponsor from their stake
+        vm.prank(oracle);
+        betting.cancelMatch(matchId);
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - aliceBefore, 1 ether);
+        (,, uint128 budget,) = betting.sponsorships(matchId);
+        assertEq(budget, 0.15 ether);
+    }
+
+    function test_SecondSponsorReverts() public {
+        _sponsorMatch();
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotSponsor.selector);
+        betting.sponsorMatch{value: 1 ether}(matchId, 0.1 ether, 5_000);
+    }
+
+    // ─── House Seeding ───
+
+    address lp = makeAddr("lp");
+
+    function _seed() internal {
+        vm.deal(lp, 10 ether);
+        vm.prank(admin);
+        betting.setSeeder(lp, true);
+        _createMatch();
+        vm.prank(lp);
+        betting.seedMatch{value: 2 ether}(matchId, 1 ether, 1 ether);
+    }
+
+    function test_SeedBootstrapsBothSides() public {
+        _seed();
+        (,,,,,,,,, uint128 sideATotal, uint128 sideBTotal,,,,,,,) = betting.matches(matchId);
+        assertEq(sideATotal, 1 ether);
+        assertEq(sideBTotal, 1 ether);
+    }
+
+    function test_SeedRedeemsProRataShare() public {
+        _seed();
+        vm.prank(alice);
+        betting.placeBet{value: 2 ether}(matchId, 0);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        // 4 ETH pool, 3.88 net; side A holds 3 ETH of which the seed is 1
+        uint256 lpBefore = lp.balance;
+        vm.prank(lp);
+        uint256 redeemed = betting.redeemSeed(matchId);
+        assertEq(redeemed, uint256(3.88 ether) / 3);
+        assertEq(lp.balance - lpBefore, redeemed);
+
+        vm.prank(lp);
+        vm.expectRevert(RawlBettingBase.NoSeed.selector);
+        betting.redeemSeed(matchId);
+    }
+
+    function test_SeedRefundedInFullOnCancel() public {
+        _seed();
+        vm.prank(oracle);
+        betting.cancelMatch(matchId);
+
+        uint256 lpBefore = lp.balance;
+        vm.prank(lp);
+        betting.redeemSeed(matchId);
+        assertEq(lp.balance - lpBefore, 2 ether);
+    }
+
+    function test_UnapprovedSeederReverts() public {
+        _createMatch();
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotSeeder.selector);
+        betting.seedMatch{value: 2 ether}(matchId, 1 ether, 1 ether);
+    }
+
+    // ─── Claim All ───
+
+    function test_ClaimAllPaysEveryMatchInOneTransfer() public {
+        _resolveAndOpenNext();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(nextMatchId, 1);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(nextMatchId, 0);
+        vm.prank(oracle);
+        betting.lockMatch(nextMatchId);
+        vm.prank(oracle);
+        betting.resolveMatch(nextMatchId, 1);
+
+        bytes32[] memory ids = new bytes32[](2);
+        ids[0] = matchId;
+        ids[1] = nextMatchId;
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        uint256 total = betting.claimAll(ids);
+        assertEq(total, 3.88 ether);
+        assertEq(alice.balance - aliceBefore, 3.88 ether);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.AlreadyClaimed.selector);
+        betting.claimAll(ids);
+    }
+
+    function test_ClaimAllRevertsOnLosingMatch() public {
+        _resolveAndOpenNext();
+        bytes32[] memory ids = new bytes32[](1);
+        ids[0] = matchId;
+
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.BetOnLosingSide.selector);
+        betting.claimAll(ids);
+    }
+
+    function test_ClaimAllBatchSizeBounded() public {
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.InvalidBatchSize.selector);
+        betting.claimAll(new bytes32[](21));
+    }
+
+    // ─── Creator Limits ───
+
+    function test_OpenMatchLimitPerCreator() public {
+        vm.prank(admin);
+        betting.setMaxOpenMatchesPerCreator(1);
+        _createMatch();
+        assertEq(betting.openMatchCounts(oracle), 1);
+
+        vm.prank(oracle);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.TooManyOpenMatches.selector, 1));
+        betting.createMatch(nextMatchId, fighterA, fighterB, 0.001 ether, 0);
+
+        // Settling the first match frees the slot
+        vm.prank(oracle);
+        betting.cancelMatch(matchId);
+        assertEq(betting.openMatchCounts(oracle), 0);
+        vm.prank(oracle);
+        betting.createMatch(nextMatchId, fighterA, fighterB, 0.001 ether, 0);
+    }
+
+    function test_ResolveReleasesCreatorSlot() public {
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        assertEq(betting.openMatchCounts(oracle), 1);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        assertEq(betting.openMatchCounts(oracle), 0);
+    }
+
+    // ─── Risk-Free First Bet ───
+
+    function _enableFirstBetInsurance() internal {
+        vm.deal(admin, 10 ether);
+        vm.startPrank(admin);
+        betting.fundPromo{value: 1 ether}();
+        betting.setFirstBetInsuranceCap(0.5 ether);
+        vm.stopPrank();
+    }
+
+    function test_LosingFirstBetRefundedUpToCap() public {
+        _enableFirstBetInsurance();
+        _createAndBetBothSides();
+        assertEq(betting.promoBudget(), 0); // 0.5 reserved for each of alice and bob
+
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimFirstBetRefund();
+        assertEq(bob.balance - bobBefore, 0.5 ether);
+
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.NoFirstBetInsurance.selector);
+        betting.claimFirstBetRefund();
+
+        // Alice won, so her reservation goes back to the promo budget
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.FirstBetNotLost.selector);
+        betting.claimFirstBetRefund();
+        betting.releaseFirstBetInsurance(alice);
+        assertEq(betting.promoBudget(), 0.5 ether);
+    }
+
+    function test_OnlyFirstBetIsInsured() public {
+        _enableFirstBetInsurance();
+        _resolveAndOpenNext();
+        betting.releaseFirstBetInsurance(alice);
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(nextMatchId, 0);
+        (bytes32 firstMatch, uint128 insured,) = betting.firstBets(alice);
+        assertEq(firstMatch, matchId);
+        assertEq(insured, 0);
+        assertTrue(betting.hasBet(alice));
+    }
+
+    function test_ReleaseBeforeSettlementReverts() public {
+        _enableFirstBetInsurance();
+        _createAndBetBothSides();
+
+        vm.expectRevert(RawlBettingBase.FirstBetNotSettled.selector);
+        betting.releaseFirstBetInsurance(bob);
+    }
+
+    // ─── Volume Fee Tiers ───
+
+    function _setSingleFeeTier(uint128 threshold, uint16 discountBps) internal {
+        uint128[] memory thresholds = new uint128[](1);
+        thresholds[0] = threshold;
+        uint16[] memory discounts = new uint16[](1);
+        discounts[0] = discountBps;
+        vm.prank(admin);
+        betting.setFeeTiers(thresholds, discounts);
+    }
+
+    function test_FeeTierDiscountPaidFromPlatformFee() public {
+        _setSingleFeeTier(1 ether, 5000);
+        _createMatch();
+
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.FeeTierChanged(alice, 0, 1);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+
+        // fee = 0.06 ETH, alice holds the whole winning side, so half of it comes back
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.97 ether);
+        assertEq(betting.feeDiscounts(matchId), 0.03 ether);
+
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        assertEq(treasury.balance, 0.03 ether);
+    }
+
+    function test_RollingVolumeDecaysOutOfTier() public {
+        _setSingleFeeTier(1 ether, 5000);
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        assertEq(betting.feeTierOf(alice), 1);
+
+        vm.warp(block.timestamp + 45 days);
+        assertEq(betting.rollingVolume(alice), 0.5 ether);
+        assertEq(betting.feeTierOf(alice), 0);
+
+        vm.warp(block.timestamp + 15 days);
+        assertEq(betting.rollingVolume(alice), 0);
+    }
+
+    function test_SetFeeTiersValidation() public {
+        uint128[] memory thresholds = new uint128[](2);
+        thresholds[0] = 2 ether;
+        thresholds[1] = 1 ether;
+        uint16[] memory discounts = new uint16[](2);
+        discounts[0] = 1000;
+        discounts[1] = 2000;
+
+        vm.startPrank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidFeeTiers.selector);
+        betting.setFeeTiers(thresholds, discounts); // thresholds not ascending
+
+        thresholds[1] = 3 ether;
+        discounts[1] = 10_001;
+        vm.expectRevert(RawlBettingBase.InvalidFeeTiers.selector);
+        betting.setFeeTiers(thresholds, discounts);
+
+        vm.expectRevert(RawlBettingBase.InvalidFeeTiers.selector);
+        betting.setFeeTiers(thresholds, new uint16[](1));
+        vm.stopPrank();
+
+        discounts[1] = 2000;
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.setFeeTiers(thresholds, discounts);
+    }
+
+    // ─── Creator Bond ───
+
+    function _createBondedMatch() internal {
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        vm.deal(oracle, 1 ether);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidCreatorBond.selector);
+        betting.createMatch(matchId, fighterA, fighterB, 0.001 ether, 0);
+
+        vm.prank(oracle);
+        betting.createMatch{value: 0.1 ether}(matchId, fighterA, fighterB, 0.001 ether, 0);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+    }
+
+    function test_CreatorBondReturnedAfterCleanup() public {
+        _createBondedMatch();
+        assertEq(betting.creatorBonds(matchId), 0.1 ether);
+
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+        vm.prank(alice);
+        betting.refundBet(matchId);
+
+        vm.expectRevert(RawlBettingBase.MatchNotCleanedUp.selector);
+        betting.settleCreatorBond(matchId);
+
+        vm.prank(bob);
+        betting.refundBet(matchId);
+
+        uint256 oracleBefore = oracle.balance;
+        betting.settleCreatorBond(matchId);
+        assertEq(oracle.balance - oracleBefore, 0.1 ether);
+
+        vm.expectRevert(RawlBettingBase.NoCreatorBond.selector);
+        betting.settleCreatorBond(matchId);
+    }
+
+    function test_AbandonedMatchBondSlashed() public {
+        _createBondedMatch();
+
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.settleCreatorBond(matchId);
+
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.warp(block.timestamp + 1801);
+        betting.timeoutMatch(matchId);
+
+        // Slashed right away — bettors' refunds are untouched
+        betting.settleCreatorBond(matchId);
+        assertEq(treasury.balance, 0.1 ether);
+        assertEq(address(betting).balance, 2 ether);
+    }
+
+    // ─── Solvency ───
+
+    function test_SolvencyTracksLifecycle() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 2 ether}(matchId, 1);
+
+        (uint256 vault, uint256 liability, uint256 fee, int256 surplus) =
+            betting.getSolvency(matchId);
+        assertEq(vault, 3 ether);
+        assertEq(liability, 3 ether);
+        assertEq(fee, 0);
+        assertEq(surplus, 0);
+
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+
+        // Alice is owed 2.91 ETH, the 0.09 ETH fee is held for the treasury
+        (, liability, fee, surplus) = betting.getSolvency(matchId);
+        assertEq(liability, 2.91 ether);
+        assertEq(fee, 0.09 ether);
+        assertEq(surplus, 0);
+
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        (vault, liability, fee, surplus) = betting.getSolvency(matchId);
+        assertEq(vault, 0.09 ether);
+        assertEq(liability, 0);
+        assertEq(surplus, 0);
+
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        (,, fee,) = betting.getSolvency(matchId);
+        assertEq(fee, 0);
+    }
+
+    function test_SolvencyAfterPartialRefunds() public {
+        _createAndBetBothSides();
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        (uint256 vault, uint256 liability,, int256 surplus) = betting.getSolvency(matchId);
+        assertEq(betting.settledStakes(matchId, 0), 1 ether);
+        assertEq(vault, 1 ether);
+        assertEq(liability, 1 ether);
+        assertEq(surplus, 0);
+    }
+
+    // ─── Match Metadata ───
+
+    function test_CreateMatchWithMetadata() public {
+        bytes32 recordHash = keccak256("match-1 record");
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.MatchMetadataSet(
+            matchId, "Ryu vs Ken", "ipfs://bafy-match-1", recordHash
+        );
+        vm.prank(oracle);
+        betting.createMatchWithMetadata(
+            matchId, fighterA, fighterB, 0.001 ether, 0, "Ryu vs Ken", "ipfs://bafy-match-1",
+            recordHash
+        );
+
+        (bytes32 name, string memory uri, bytes32 metadataHash, bytes32 resultHash) =
+            betting.matchMetadata(matchId);
+        assertEq(name, bytes32("Ryu vs Ken"));
+        assertEq(uri, "ipfs://bafy-match-1");
+        assertEq(metadataHash, recordHash);
+        assertEq(resultHash, bytes32(0));
+    }
+
+    function test_ResolveCommitsResultHash() public {
+        _createAndBetBothSides();
+        bytes32 resultRecord = keccak256("match-1 result");
+
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        vm.expectEmit(true, false, false, true);
+        emit RawlBettingBase.MatchResultCommitted(matchId, resultRecord);
+        betting.resolveMatchWithResultHash(matchId, 0, resultRecord);
+        vm.stopPrank();
+
+        (,,, bytes32 resultHash) = betting.matchMetadata(matchId);
+        assertEq(resultHash, resultRecord);
+    }
+
+    function test_MetadataUriTooLongReverts() public {
+        bytes memory uri = new bytes(129);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.UriTooLong.selector);
+        betting.createMatchWithMetadata(
+            matchId, fighterA, fighterB, 0.001 ether, 0, "Ryu vs Ken", string(uri), bytes32(0)
+        );
+    }
+
+    // ─── Lock / Resolve Authorities ───
+
+    function test_SeparateLockAndResolveAuthorities() public {
+        address gameServer = makeAddr("gameServer");
+        address adjudicator = makeAddr("adjudicator");
+        vm.startPrank(admin);
+        betting.grantRole(betting.LOCK_AUTHORITY_ROLE(), gameServer);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), adjudicator);
+        betting.revokeRole(betting.LOCK_AUTHORITY_ROLE(), oracle);
+        betting.revokeRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle);
+        vm.stopPrank();
+
+        _createAndBetBothSides();
+
+        vm.prank(oracle);
+        vm.expectRevert();
+        betting.lockMatch(matchId);
+        vm.prank(adjudicator);
+        vm.expectRevert();
+        betting.lockMatch(matchId);
+        vm.prank(gameServer);
+        betting.lockMatch(matchId);
+
+        vm.prank(gameServer);
+        vm.expectRevert();
+        betting.resolveMatch(matchId, 0);
+        vm.prank(adjudicator);
+        betting.resolveMatch(matchId, 0);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    // ─── Match Categories ───
+
+    function test_CreateMatchInCategory() public {
+        vm.expectEmit(true, false, true, true);
+        emit RawlBettingBase.MatchCreated(
+            matchId,
+            fighterA,
+            fighterB,
+            0.001 ether,
+            0,
+            300,
+            RawlBettingBase.MatchCategory.Tournament
+        );
+        vm.prank(oracle);
+        betting.createMatchInCategory(
+            matchId, fighterA, fighterB, 0.001 ether, 0, RawlBettingBase.MatchCategory.Tournament
+        );
+        assertEq(
+            uint8(betting.matchCategories(matchId)), uint8(RawlBettingBase.MatchCategory.Tournament)
+        );
+    }
+
+    function test_DefaultCategoryIsRanked() public {
+        _createMatch();
+        assertEq(
+            uint8(betting.matchCategories(matchId)), uint8(RawlBettingBase.MatchCategory.Ranked)
+        );
+    }
+
+    // ─── Result Correction ───
+
+    function _resolveClaimAndOverturn() internal {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 2 ether}(matchId, 1);
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        vm.prank(alice);
+        betting.claimPayout(matchId); // 2.91 ETH at the wrong result
+
+        vm.deal(admin, 10 ether);
+        vm.startPrank(admin);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.InvalidBackstop.selector, 2.91 ether)
+        );
+        betting.overturnResult(matchId, 1);
+        betting.overturnResult{value: 2.91 ether}(matchId, 1);
+        vm.stopPrank();
+    }
+
+    function test_OverturnPaysNewWinnersFromBackstop() public {
+        _resolveClaimAndOverturn();
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 2.91 ether);
+
+        (uint32 winsA, uint32 lossesA) = _fighterRecord(fighterA);
+        assertEq(winsA, 0);
+        assertEq(lossesA, 1);
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.CannotOverturn.selector);
+        betting.overturnResult(matchId, 0);
+    }
+
+    function test_ClawbackBlocksClaimsUntilRepaid() public {
+        _resolveClaimAndOverturn();
+        betting.recordClawback(matchId, alice);
+        assertEq(betting.clawbackDebt(alice), 2.91 ether);
+
+        vm.expectRevert(RawlBettingBase.NoClawback.selector);
+        betting.recordClawback(matchId, alice);
+        vm.expectRevert(RawlBettingBase.NoClawback.selector);
+        betting.recordClawback(matchId, bob);
+
+        // A fresh win elsewhere stays locked behind the debt
+        vm.prank(oracle);
+        betting.createMatch(nextMatchId, fighterA, fighterB, 0.001 ether, 0);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(nextMatchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(nextMatchId, 1);
+        vm.startPrank(oracle);
+        betting.lockMatch(nextMatchId);
+        betting.resolveMatch(nextMatchId, 0);
+        vm.stopPrank();
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.OutstandingClawback.selector);
+        betting.claimPayout(nextMatchId);
+
+        vm.startPrank(alice);
+        betting.repayClawback{value: 2.91 ether}(matchId);
+        betting.claimPayout(nextMatchId);
+        vm.stopPrank();
+        assertEq(treasury.balance, 2.91 ether);
+        assertEq(betting.clawbackDebt(alice), 0);
+    }
+
+    function _fighterRecord(address fighter) internal view returns (uint32 wins, uint32 losses) {
+        (,, wins, losses,,) = betting.fighters(fighter);
+    }
+
+    // ─── Batch Creation ───
+
+    function _matchSpecs(uint256 count)
+        internal
+        view
+        returns (RawlBettingBase.MatchSpec[] memory specs)
+    {
+        specs = new RawlBettingBase.MatchSpec[](count);
+        for (uint256 i; i < count; ++i) {
+            specs[i] = RawlBettingBase.MatchSpec({
+                matchId: keccak256(abi.encode("card", i)),
+                fighterA: fighterA,
+                fighterB: fighterB
+            });
+        }
+    }
+
+    function test_CreateMatchesOpensWholeCard() public {
+        RawlBettingBase.MatchSpec[] memory specs = _matchSpecs(20);
+        vm.prank(oracle);
+        betting.createMatches(specs, 0.001 ether, 0);
+
+        for (uint256 i; i < specs.length; ++i) {
+            (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) =
+                betting.matches(specs[i].matchId);
+            assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Open));
+        }
+        assertEq(betting.openMatchCounts(oracle), 20);
+    }
+
+    function test_CreateMatchesIsAllOrNothing() public {
+        RawlBettingBase.MatchSpec[] memory specs = _matchSpecs(3);
+        specs[2].matchId = specs[0].matchId;
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchAlreadyExists.selector);
+        betting.createMatches(specs, 0.001 ether, 0);
+
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(specs[0].matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.None));
+    }
+
+    function test_CreateMatchesBondAndSizeChecks() public {
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        vm.deal(oracle, 10 ether);
+
+        vm.startPrank(oracle);
+        vm.expectRevert(RawlBettingBase.InvalidCreatorBond.selector);
+        betting.createMatches{value: 0.1 ether}(_matchSpecs(2), 0.001 ether, 0);
+        vm.expectRevert(RawlBettingBase.InvalidBatchSize.selector);
+        betting.createMatches(_matchSpecs(0), 0.001 ether, 0);
+
+        RawlBettingBase.MatchSpec[] memory specs = _matchSpecs(2);
+        betting.createMatches{value: 0.2 ether}(specs, 0.001 ether, 0);
+        vm.stopPrank();
+        assertEq(betting.creatorBonds(specs[1].matchId), 0.1 ether);
+    }
+
+    // ─── Odds Snapshot ───
+
+    function test_LockFreezesOddsSnapshot() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 3 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        (uint128 totalA, uint128 totalB, uint16 impliedA, uint16 impliedB, uint64 lockedAt) =
+            betting.oddsSnapshots(matchId);
+        assertEq(totalA, 1 ether);
+        assertEq(totalB, 3 ether);
+        assertEq(impliedA, 2500);
+        assertEq(impliedB, 7500);
+        assertEq(lockedAt, block.timestamp);
+    }
+
+    // ─── Treasury Diversification Hook ───
+
+    function _withdrawFeesWithHook(bool failing) internal returns (MockTreasuryHook hook) {
+        hook = new MockTreasuryHook();
+        hook.setFailing(failing);
+        vm.prank(admin);
+        betting.setTreasuryHook(address(hook), 5000);
+
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+    }
+
+    function test_TreasuryHookReceivesFeeShare() public {
+        MockTreasuryHook hook = _withdrawFeesWithHook(false);
+        assertEq(hook.received(), 0.03 ether);
+        assertEq(treasury.balance, 0.03 ether);
+    }
+
+    function test_FailingTreasuryHookFallsBackToTreasury() public {
+        MockTreasuryHook hook = _withdrawFeesWithHook(true);
+        assertEq(hook.received(), 0);
+        assertEq(treasury.balance, 0.06 ether);
+    }
+
+    // ─── Recurring Schedules ───
+
+    bytes32 scheduleId = keccak256("hourly-arena");
+
+    function _createHourlySchedule(uint256 budget) internal {
+        vm.deal(oracle, 10 ether);
+        vm.prank(oracle);
+        betting.createRecurringSchedule{value: budget}(
+            scheduleId, fighterA, fighterB, 0.001 ether, 0, 1 hours, uint64(block.timestamp)
+        );
+    }
+
+    function test_CrankCreatesMatchesOnCadence() public {
+        _createHourlySchedule(0);
+
+        vm.prank(charlie);
+        bytes32 first = betting.crankSchedule(scheduleId);
+        assertEq(first, keccak256(abi.encode(scheduleId, uint32(0))));
+        assertEq(betting.matchCreators(first), oracle);
+        (,,RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(first);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Open));
+
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                RawlBettingBase.ScheduleNotDue.selector, uint64(block.timestamp + 1 hours)
+            )
+        );
+        betting.crankSchedule(scheduleId);
+
+        // Three missed hours collapse into one match, then the cadence resumes
+        vm.warp(block.timestamp + 3 hours + 10 minutes);
+        betting.crankSchedule(scheduleId);
+        (,,,, uint64 nextAt,,,,,) = betting.recurringSchedules(scheduleId);
+        assertEq(nextAt, block.timestamp - 10 minutes + 1 hours);
+    }
+
+    function test_CrankDrawsBondsFromScheduleBudget() public {
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        _createHourlySchedule(0.1 ether);
+
+        bytes32 first = betting.crankSchedule(scheduleId);
+        assertEq(betting.creatorBonds(first), 0.1 ether);
+
+        vm.warp(block.timestamp + 1 hours);
+        vm.expectRevert(RawlBettingBase.InsufficientScheduleBudget.selector);
+        betting.crankSchedule(scheduleId);
+    }
+
+    function test_StopScheduleRefundsBudget() public {
+        _createHourlySchedule(1 ether);
+
+        vm.prank(charlie);
+        vm.expectRevert();
+        betting.stopRecurringSchedule(scheduleId);
+
+        uint256 oracleBefore = oracle.balance;
+        vm.prank(oracle);
+        betting.stopRecurringSchedule(scheduleId);
+        assertEq(oracle.balance - oracleBefore, 1 ether);
+
+        vm.expectRevert(RawlBettingBase.ScheduleInactive.selector);
+        betting.crankSchedule(scheduleId);
+    }
+
+    // ─── Two-Phase Creation ───
+
+    function _initSeededMatch() internal {
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        vm.prank(admin);
+        betting.setSeeder(lp, true);
+        vm.deal(lp, 10 ether);
+        vm.deal(oracle, 1 ether);
+        vm.prank(oracle);
+        betting.initMatch{value: 0.05 ether}(
+            matchId, fighterA, fighterB, 0.001 ether, 0, 1 ether, 1 ether
+        );
+    }
+
+    function test_PendingMatchActivatesOnlyWhenFunded() public {
+        _initSeededMatch();
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchUnderfunded.selector);
+        betting.activateMatch(matchId);
+
+        vm.prank(oracle);
+        betting.depositCreatorBond{value: 0.05 ether}(matchId);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchUnderfunded.selector);
+        betting.activateMatch(matchId);
+
+        vm.prank(lp);
+        betting.fundPendingSeed{value: 2 ether}(matchId);
+        vm.prank(oracle);
+        betting.activateMatch(matchId);
+
+        (
+            ,,
+            RawlBettingBase.MatchStatus status,,,,,,,
+            uint128 sideATotal,
+            uint128 sideBTotal,,,,,,,
+        ) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Open));
+        assertEq(sideATotal, 1 ether);
+        assertEq(sideBTotal, 1 ether);
+        assertEq(betting.creatorBonds(matchId), 0.1 ether);
+
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+    }
+
+    function test_AbortPendingMatchRefundsDeposits() public {
+        _initSeededMatch();
+        vm.prank(lp);
+        betting.fundPendingSeed{value: 2 ether}(matchId);
+
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.abortPendingMatch(matchId);
+
+        uint256 lpBefore = lp.balance;
+        vm.prank(oracle);
+        betting.abortPendingMatch(matchId);
+        assertEq(lp.balance - lpBefore, 2 ether);
+
+        // The partial bond goes back to the creator, not the treasury
+        uint256 oracleBefore = oracle.balance;
+        betting.settleCreatorBond(matchId);
+        assertEq(oracle.balance - oracleBefore, 0.05 ether);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchNotPending.selector);
+        betting.activateMatch(matchId);
+    }
+
+    function test_CancelMatchCantStrandPendingSeed() public {
+        _initSeededMatch();
+        vm.prank(lp);
+        betting.fundPendingSeed{value: 2 ether}(matchId);
+
+        // Only the creator-side abort may leave Pending, and it refunds the seed
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.cancelMatch(matchId);
+    }
+
+    // ─── Derived Match Ids ───
+
+    function test_DerivedMatchIdIsVerified() public {
+        uint64 startTime = uint64(block.timestamp + 1 hours);
+        bytes32 derived = keccak256(abi.encode(fighterA, fighterB, startTime, uint64(7)));
+        assertEq(betting.deriveMatchId(fighterA, fighterB, startTime, 7), derived);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchIdMismatch.selector);
+        betting.createMatchWithDerivedId(derived, fighterA, fighterB, startTime, 8, 0, 0);
+
+        vm.prank(oracle);
+        betting.createMatchWithDerivedId(derived, fighterA, fighterB, startTime, 7, 0, 0);
+        assertEq(betting.matchCreators(derived), oracle);
+    }
+
+    // ─── Dead-Man Switch ───
+
+    address heir = makeAddr("heir");
+
+    function test_SuccessorClaimsSilentAuthority() public {
+        vm.prank(admin);
+        betting.setSuccessor(heir, 90 days);
+
+        vm.warp(block.timestamp + 60 days);
+        vm.prank(admin);
+        betting.pingAuthority();
+
+        vm.warp(block.timestamp + 89 days);
+        vm.prank(heir);
+        vm.expectRevert(
+            abi.encodeWithSelector(
+                RawlBettingBase.AuthorityStillActive.selector, uint64(block.timestamp + 1 days)
+            )
+        );
+        betting.claimAuthority();
+
+        vm.warp(block.timestamp + 1 days);
+        vm.prank(heir);
+        betting.claimAuthority();
+
+        assertEq(betting.authority(), heir);
+        assertTrue(betting.hasRole(betting.ADMIN_ROLE(), heir));
+        assertFalse(betting.hasRole(betting.ADMIN_ROLE(), admin));
+        assertFalse(betting.hasRole(betting.DEFAULT_ADMIN_ROLE(), admin));
+
+        vm.prank(heir);
+        betting.updateConfig(0, 0, charlie);
+        assertEq(betting.treasury(), charlie);
+    }
+
+    function test_OnlySuccessorCanClaim() public {
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidDeadManPeriod.selector);
+        betting.setSuccessor(heir, 1 days);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotAuthority.selector);
+        betting.setSuccessor(alice, 90 days);
+
+        vm.warp(block.timestamp + 365 days);
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotSuccessor.selector);
+        betting.claimAuthority();
+    }
+
+    // ─── M-of-N Resolution ───
+
+    function test_ThresholdResolutionNeedsMAgreeingOracles() public {
+        address oracle2 = makeAddr("oracle2");
+        address oracle3 = makeAddr("oracle3");
+        vm.startPrank(admin);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle2);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle3);
+        betting.setResolutionThreshold(2);
+        vm.stopPrank();
+
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.AlreadyAttested.selector);
+        betting.resolveMatch(matchId, 0);
+
+        // A dissenting vote doesn't resolve, the second matching one does
+        vm.prank(oracle2);
+        betting.resolveMatch(matchId, 1);
+        (,, RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Locked));
+
+        vm.prank(oracle3);
+        betting.resolveMatch(matchId, 0);
+        (
+            ,,
+            RawlBettingBase.MatchStatus resolved,
+            RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,
+        ) = betting.matches(matchId);
+        assertEq(uint8(resolved), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideA));
+        (uint8 votesA, uint8 votesB) = betting.resultVotesOf(matchId);
+        assertEq(votesA, 2);
+        assertEq(votesB, 1);
+    }
+
+    function test_InvalidResolutionThresholdReverts() public {
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidResolutionThreshold.selector);
+        betting.setResolutionThreshold(0);
+    }
+
+    // ─── Fee Donation ───
+
+    address charity = makeAddr("charity");
+
+    function test_DonationAppliesOnlyAfterTimelock() public {
+        vm.prank(admin);
+        betting.proposeDonation(charity, 1000);
+        uint64 eta = uint64(block.timestamp + 2 days);
+
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.TimelockNotElapsed.selector, eta));
+        betting.applyDonation();
+
+        vm.warp(eta);
+        betting.applyDonation();
+        assertEq(betting.donationAddress(), charity);
+        assertEq(betting.donationBps(), 1000);
+
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+
+        assertEq(charity.balance, 0.006 ether);
+        assertEq(treasury.balance, 0.054 ether);
+        assertEq(betting.donatedFees(matchId), 0.006 ether);
+        assertEq(betting.totalDonated(), 0.006 ether);
+    }
+
+    function test_DonationProposalIsAdminOnly() public {
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.proposeDonation(alice, 10_000);
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidDonation.selector);
+        betting.proposeDonation(address(0), 1000);
+    }
+
+    // ─── Signed Result Submission ───
+
+    function _signResult(uint256 key, uint8 winner, uint64 timestamp)
+        internal
+        view
+        returns (bytes memory)
+    {
+        return _signTyped(
+            key, keccak256(abi.encode(betting.RESULT_TYPEHASH(), matchId, winner, timestamp))
+        );
+    }
+
+    /// @dev EIP-712 signature over `structHash` in RawlBetting's domain
+    function _signTyped(uint256 key, bytes32 structHash) internal view returns (bytes memory) {
+        bytes32 domainSeparator = keccak256(
+            abi.encode(
+                keccak256(
+                    "EIP712Domain(string name,string version,uint256 chainId,"
+                    "address verifyingContract)"
+                ),
+                keccak256("RawlBetting"),
+                keccak256("1"),
+                block.chainid,
+                address(betting)
+            )
+        );
+        (uint8 v, bytes32 r, bytes32 s) = vm.sign(
+            key, keccak256(abi.encodePacked("\x19\x01", domainSeparator, structHash))
+        );
+        return abi.encodePacked(r, s, v);
+    }
+
+    function test_RelayerLandsSignedResult() public {
+        (address signer, uint256 key) = makeAddrAndKey("coldOracle");
+        vm.startPrank(admin);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), signer);
+        vm.stopPrank();
+
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        uint64 signedAt = uint64(block.timestamp);
+        bytes memory signature = _signResult(key, 1, signedAt);
+
+        // Tampering with the winner breaks the signature
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.InvalidResultSignature.selector);
+        betting.resolveMatchSigned(matchId, 0, signedAt, signature);
+
+        vm.prank(charlie);
+        betting.resolveMatchSigned(matchId, 1, signedAt, signature);
+        (,, RawlBettingBase.MatchStatus status, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) =
+            betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+    }
+
+    function test_SignedResultFromNonOracleReverts() public {
+        (, uint256 key) = makeAddrAndKey("stranger");
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        bytes memory signature = _signResult(key, 0, uint64(block.timestamp));
+        vm.expectRevert(RawlBettingBase.InvalidResultSignature.selector);
+        betting.resolveMatchSigned(matchId, 0, uint64(block.timestamp), signature);
+    }
+
+    // ─── Observer Subscriptions ───
+
+    function test_SubscribersReceiveSummaryOnSettle() public {
+        _createAndBetBothSides();
+        vm.prank(charlie);
+        betting.subscribe(matchId);
+        vm.prank(alice);
+        betting.subscribe(matchId);
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.AlreadySubscribed.selector);
+        betting.subscribe(matchId);
+
+        assertEq(betting.pendingSummaries(matchId), 0);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.pushSummaries(matchId, 10);
+
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        assertEq(betting.pendingSummaries(matchId), 2);
+
+        vm.expectEmit(true, true, false, true);
+        emit RawlBettingBase.MatchSummary(
+            matchId,
+            charlie,
+            RawlBettingBase.MatchStatus.Resolved,
+            RawlBettingBase.MatchWinner.SideA,
+            1 ether,
+            1 ether
+        );
+        assertEq(betting.pushSummaries(matchId, 1), 1);
+        assertEq(betting.pushSummaries(matchId, 10), 1);
+        assertEq(betting.pendingSummaries(matchId), 0);
+    }
+
+    function test_UnsubscribeSwapsLastIntoSlot() public {
+        _createMatch();
+        vm.prank(alice);
+        betting.subscribe(matchId);
+        vm.prank(bob);
+        betting.subscribe(matchId);
+        vm.prank(charlie);
+        betting.subscribe(matchId);
+
+        vm.prank(alice);
+        betting.unsubscribe(matchId);
+        address[] memory list = betting.subscribersOf(matchId);
+        assertEq(list.length, 2);
+        assertEq(list[0], charlie);
+        assertEq(list[1], bob);
+
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.NotSubscribed.selector);
+        betting.unsubscribe(matchId);
+    }
+
+    // ─── Switchboard Result Feeds ───
+
+    bytes32 feedId = keccak256("switchboard-feed");
+
+    function _feedBoundMatch() internal returns (MockSwitchboard board) {
+        board = new MockSwitchboard();
+        vm.prank(admin);
+        betting.setSwitchboard(address(board));
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.bindResultFeed(matchId, feedId);
+    }
+
+    function test_ResolveFromFeed() public {
+        MockSwitchboard board = _feedBoundMatch();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        // A value published before the lock is stale
+        board.setUpdate(feedId, 2, block.timestamp - 1);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.StaleFeedResult.selector, block.timestamp - 1)
+        );
+        betting.resolveFromFeed(matchId);
+
+        board.setUpdate(feedId, 3, block.timestamp);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.InvalidFeedResult.selector, int128(3))
+        );
+        betting.resolveFromFeed(matchId);
+
+        board.setUpdate(feedId, 2, block.timestamp);
+        vm.prank(charlie);
+        betting.resolveFromFeed(matchId);
+        (,, RawlBettingBase.MatchStatus status, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) =
+            betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+    }
+
+    function test_FeedBoundMatchRejectsOracleResolve() public {
+        _feedBoundMatch();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+    }
+
+    function test_BindResultFeedRules() public {
+        _feedBoundMatch();
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.FeedAlreadyBound.selector);
+        betting.bindResultFeed(matchId, keccak256("other-feed"));
+
+        bytes32 otherMatch = keccak256("match-2");
+        vm.prank(oracle);
+        betting.createMatch(otherMatch, fighterA, fighterB, 0.001 ether, 0);
+        vm.prank(charlie);
+        vm.expectRevert();
+        betting.bindResultFeed(otherMatch, feedId);
+    }
+
+    // ─── Treasury Routing ───
+
+    function test_TreasuryRoutingSplitsDestinations() public {
+        address opsWallet = makeAddr("opsWallet");
+        address coldWallet = makeAddr("coldWallet");
+        vm.prank(admin);
+        betting.setTreasuryRouting(opsWallet, coldWallet, address(0));
+
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+        vm.warp(block.timestamp + 30 days + 1);
+
+        vm.startPrank(admin);
+        betting.sweepUnclaimed(matchId, alice);
+        betting.withdrawFees(matchId);
+        vm.stopPrank();
+
+        // Fee: 2 ETH × 3% = 0.06 ETH to ops; Alice's 1.94 ETH payout to cold storage
+        assertEq(opsWallet.balance, 0.06 ether);
+        assertEq(coldWallet.balance, 1.94 ether);
+        assertEq(treasury.balance, 0);
+    }
+
+    function test_UnsetRouteFallsBackToTreasury() public {
+        vm.prank(admin);
+        betting.setTreasuryRouting(address(0), makeAddr("coldWallet"), address(0));
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        vm.warp(block.timestamp + 30 days + 1);
+        vm.prank(admin);
+        betting.withdrawFees(matchId);
+        assertEq(treasury.balance, 0.06 ether);
+    }
+
+    function test_NonAdminCantSetTreasuryRouting() public {
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.setTreasuryRouting(alice, alice, alice);
+    }
+
+    // ─── Price-Prediction Markets ───
+
+    bytes32 ethUsd = keccak256("ETH/USD");
+
+    /// @dev ETH above $3,000 (expo -8) at +2h, betting closes at +1h, one bet per side
+    function _priceMarket() internal returns (MockPyth pyth, uint64 resolveAt) {
+        pyth = new MockPyth(1 wei);
+        vm.prank(admin);
+        betting.setPyth(address(pyth));
+
+        resolveAt = uint64(block.timestamp + 2 hours);
+        vm.prank(oracle);
+        betting.createPriceMarket(
+            matchId,
+            fighterA,
+            fighterB,
+            0.001 ether,
+            uint64(block.timestamp + 1 hours),
+            RawlBettingBase.PriceMarket({
+                priceId: ethUsd, strike: 3000e8, expo: -8, resolveAt: resolveAt
+            })
+        );
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+
+        vm.warp(block.timestamp + 1 hours);
+        betting.lockExpired(matchId);
+    }
+
+    function _priceUpdate(MockPyth pyth, int64 price, uint256 publishTime)
+        internal
+        view
+        returns (bytes[] memory update)
+    {
+        update = new bytes[](1);
+        update[0] = pyth.encodeUpdate(ethUsd, price, -8, publishTime);
+    }
+
+    function test_PriceMarketResolvesAboveStrike() public {
+        (MockPyth pyth, uint64 resolveAt) = _priceMarket();
+        bytes[] memory update = _priceUpdate(pyth, 3100e8, resolveAt + 5);
+
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.PriceNotDue.selector, resolveAt));
+        betting.resolvePriceMarket{value: 1 wei}(matchId, update);
+
+        vm.warp(resolveAt + 10);
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(charlie);
+        betting.resolvePriceMarket{value: 1 ether}(matchId, update);
+        assertEq(charlieBefore - charlie.balance, 1 wei); // excess over Pyth's fee returned
+
+        (,, RawlBettingBase.MatchStatus status, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) =
+            betting.matches(matchId);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideA));
+    }
+
+    function test_PriceAtStrikeResolvesToSideB() public {
+        (MockPyth pyth, uint64 resolveAt) = _priceMarket();
+        vm.warp(resolveAt);
+        betting.resolvePriceMarket{value: 1 wei}(matchId, _priceUpdate(pyth, 3000e8, resolveAt));
+
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+    }
+
+    function test_PriceOutsidePublishWindowReverts() public {
+        (MockPyth pyth, uint64 resolveAt) = _priceMarket();
+        vm.warp(resolveAt + 2 minutes);
+
+        vm.expectRevert(MockPyth.PriceFeedNotFoundWithinRange.selector);
+        betting.resolvePriceMarket{value: 1 wei}(
+            matchId, _priceUpdate(pyth, 3100e8, resolveAt - 1)
+        );
+        vm.expectRevert(MockPyth.PriceFeedNotFoundWithinRange.selector);
+        betting.resolvePriceMarket{value: 1 wei}(
+            matchId, _priceUpdate(pyth, 3100e8, resolveAt + 61)
+        );
+    }
+
+    function test_PriceMarketRejectsOracleResolve() public {
+        _priceMarket();
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.resolveMatch(matchId, 0);
+    }
+
+    // ─── Dispute Window ───
+
+    function _resolvedWithDisputeWindow() internal {
+        vm.prank(admin);
+        betting.setDisputeParams(1 days, 0.5 ether);
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.resolveMatch(matchId, 0);
+        vm.stopPrank();
+    }
+
+    function test_ClaimsWaitForDisputeWindow() public {
+        _resolvedWithDisputeWindow();
+        uint64 endsAt = uint64(block.timestamp + 1 days);
+
+        vm.prank(alice);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.DisputeWindowActive.selector, endsAt)
+        );
+        betting.claimPayout(matchId);
+
+        vm.warp(endsAt);
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.DisputeWindowClosed.selector);
+        betting.challengeResult{value: 0.5 ether}(matchId);
+    }
+
+    function test_UpheldChallengeFlipsResultAndReturnsBond() public {
+        _resolvedWithDisputeWindow();
+        vm.prank(charlie);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.InvalidDisputeBond.selector, 0.5 ether)
+        );
+        betting.challengeResult{value: 0.1 ether}(matchId);
+        vm.prank(charlie);
+        betting.challengeResult{value: 0.5 ether}(matchId);
+
+        // Still blocked after the window while the challenge is open
+        vm.warp(block.timestamp + 1 days);
+        vm.prank(bob);
+        vm.expectRevert(RawlBettingBase.ResultDisputed.selector);
+        betting.claimPayout(matchId);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(admin);
+        betting.adjudicateDispute(matchId, true);
+        assertEq(charlie.balance - charlieBefore, 0.5 ether);
+
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+        (uint32 wins, uint32 losses) = _fighterRecord(fighterB);
+        assertEq(wins, 1);
+        assertEq(losses, 0);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 1.94 ether);
+    }
+
+    function test_RejectedChallengeSlashesBond() public {
+        _resolvedWithDisputeWindow();
+        vm.prank(charlie);
+        betting.challengeResult{value: 0.5 ether}(matchId);
+
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.adjudicateDispute(matchId, false);
+
+        vm.prank(admin);
+        betting.adjudicateDispute(matchId, false);
+        assertEq(treasury.balance, 0.5 ether);
+
+        // Adjudication ends the window early
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.NoDispute.selector);
+        betting.adjudicateDispute(matchId, true);
+    }
+
+    // ─── Optimistic Resolution ───
+
+    function _lockedWithProposal(uint8 winner) internal {
+        vm.prank(admin);
+        betting.setDisputeParams(1 days, 0.5 ether);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(charlie);
+        betting.proposeResult{value: 0.5 ether}(matchId, winner);
+    }
+
+    function test_UnchallengedProposalFinalizes() public {
+        _lockedWithProposal(0);
+        uint64 liveUntil = uint64(block.timestamp + 1 days);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResultProposed.selector);
+        betting.resolveMatch(matchId, 1);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.ProposalLive.selector, liveUntil));
+        betting.finalizeProposal(matchId);
+
+        vm.warp(liveUntil);
+        uint256 charlieBefore = charlie.balance;
+        betting.finalizeProposal(matchId);
+        assertEq(charlie.balance - charlieBefore, 0.5 ether);
+
+        // No second dispute window — Alice claims straight away
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.94 ether);
+    }
+
+    function test_ChallengedProposalGoesToArbitration() public {
+        _lockedWithProposal(0);
+        vm.prank(bob);
+        betting.challengeProposal{value: 0.5 ether}(matchId);
+
+        vm.warp(block.timestamp + 1 days);
+        vm.expectRevert(RawlBettingBase.ResultDisputed.selector);
+        betting.finalizeProposal(matchId);
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.arbitrateProposal(matchId, 1);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(oracle);
+        betting.arbitrateProposal(matchId, 1);
+        assertEq(bob.balance - bobBefore, 1 ether); // both bonds to the challenger
+
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+    }
+
+    function test_PendingProposalBlocksTimeoutAndRefundsOnCancel() public {
+        _lockedWithProposal(1);
+        vm.warp(block.timestamp + 2 hours);
+        vm.expectRevert(RawlBettingBase.ResultProposed.selector);
+        betting.timeoutMatch(matchId);
+
+        vm.expectRevert(RawlBettingBase.MatchNotCancelled.selector);
+        betting.refundProposalBonds(matchId);
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+
+        uint256 charlieBefore = charlie.balance;
+        betting.refundProposalBonds(matchId);
+        assertEq(charlie.balance - charlieBefore, 0.5 ether);
+        vm.expectRevert(RawlBettingBase.NoProposal.selector);
+        betting.refundProposalBonds(matchId);
+    }
+
+    // ─── Mystery Matches ───
+
+    function _mysteryVrf() internal returns (MockVRFCoordinator vrf) {
+        vrf = new MockVRFCoordinator();
+        vm.prank(admin);
+        betting.setVrfConfig(address(vrf), keccak256("key"), 1, 500_000);
+        vm.prank(oracle);
+        betting.registerFighter(makeAddr("fighterC"), keccak256("FighterC"), charlie);
+    }
+
+    function test_MysteryMatchPairsFightersFromVrfWord() public {
+        MockVRFCoordinator vrf = _mysteryVrf();
+        vm.prank(oracle);
+        uint256 requestId = betting.requestMysteryMatch(matchId, 0.001 ether, 0);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.MatchAlreadyExists.selector);
+        betting.requestMysteryMatch(matchId, 0.001 ether, 0);
+
+        vm.expectRevert(RawlBettingBase.UnauthorizedCallback.selector);
+        betting.rawFulfillRandomWords(requestId, new uint256[](1));
+
+        uint256 word = uint256(keccak256("mystery"));
+        vrf.fulfill(requestId, word);
+
+        (address expectedA, address expectedB) = betting.selectMysteryFighters(word);
+        assertTrue(expectedA != expectedB);
+        (address fA, address fB, RawlBettingBase.MatchStatus status,,,,,,,,,,,,,,,) =
+            betting.matches(matchId);
+        assertEq(fA, expectedA);
+        assertEq(fB, expectedB);
+        assertEq(uint8(status), uint8(RawlBettingBase.MatchStatus.Open));
+        assertEq(
+            uint8(betting.matchCategories(matchId)), uint8(RawlBettingBase.MatchCategory.Exhibition)
+        );
+        assertEq(betting.mysterySeeds(matchId), word);
+        (bytes32 name,,,) = betting.matchMetadata(matchId);
+        assertEq(name, betting.MYSTERY_MATCH_NAME());
+        assertEq(betting.matchCreators(matchId), oracle);
+    }
+
+    function test_MysteryMatchRefundsBondWhenCreationFails() public {
+        MockVRFCoordinator vrf = _mysteryVrf();
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        vm.deal(oracle, 1 ether);
+
+        vm.prank(oracle);
+        uint256 requestId = betting.requestMysteryMatch{value: 0.1 ether}(matchId, 0.001 ether, 0);
+        // The id gets taken by a regular match before the word arrives
+        vm.prank(oracle);
+        betting.createMatch{value: 0.1 ether}(matchId, fighterA, fighterB, 0.001 ether, 0);
+
+        uint256 oracleBefore = oracle.balance;
+        vrf.fulfill(requestId, 42);
+        assertEq(oracle.balance - oracleBefore, 0.1 ether);
+        assertEq(betting.mysterySeeds(matchId), 0);
+        assertFalse(betting.mysteryReserved(matchId));
+    }
+
+    function test_MysteryMatchNeedsVrfAndTwoFighters() public {
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.VrfNotConfigured.selector);
+        betting.requestMysteryMatch(matchId, 0.001 ether, 0);
+        assertEq(betting.fighterCount(), 2);
+
+        vm.expectRevert();
+        betting.selectMysteryFighters(7);
+    }
+
+    // ─── Community Matches ───
+
+    function _communityMatch() internal {
+        vm.prank(admin);
+        betting.setVetoParams(1 hours, 0.5 ether);
+        vm.prank(admin);
+        betting.setCreatorBond(0.1 ether);
+        vm.prank(charlie);
+        betting.createCommunityMatch{value: 0.1 ether}(matchId, fighterA, fighterB, 0.001 ether, 0);
+    }
+
+    function test_CommunityMatchCapsBetsUntilVetoWindowEnds() public {
+        _communityMatch();
+        assertEq(betting.matchCreators(matchId), charlie);
+        assertEq(
+            uint8(betting.matchCategories(matchId)), uint8(RawlBettingBase.MatchCategory.Community)
+        );
+
+        vm.prank(alice);
+        betting.placeBet{value: 0.4 ether}(matchId, 0);
+        vm.prank(bob);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.VetoWindowCapExceeded.selector, 0.5 ether)
+        );
+        betting.placeBet{value: 0.2 ether}(matchId, 1);
+
+        vm.warp(block.timestamp + 1 hours);
+        vm.prank(bob);
+        betting.placeBet{value: 5 ether}(matchId, 1);
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.VetoWindowClosed.selector);
+        betting.vetoMatch(matchId);
+    }
+
+    function test_VetoRefundsBettorsAndForfeitsBond() public {
+        _communityMatch();
+        vm.prank(alice);
+        betting.placeBet{value: 0.4 ether}(matchId, 0);
+
+        vm.prank(charlie);
+        vm.expectRevert();
+        betting.vetoMatch(matchId);
+        vm.prank(admin);
+        betting.vetoMatch(matchId);
+        assertEq(uint8(betting.cancelReasons(matchId)), uint8(RawlBettingBase.CancelReason.Vetoed));
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - aliceBefore, 0.4 ether);
+
+        uint256 treasuryBefore = treasury.balance;
+        betting.settleCreatorBond(matchId);
+        assertEq(treasury.balance - treasuryBefore, 0.1 ether);
+    }
+
+    function test_CommunityCreationNeedsVetoWindow() public {
+        vm.prank(charlie);
+        vm.expectRevert(RawlBettingBase.CommunityCreationDisabled.selector);
+        betting.createCommunityMatch(matchId, fighterA, fighterB, 0.001 ether, 0);
+        // Oracle-created matches have no veto window
+        _createMatch();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.VetoWindowClosed.selector);
+        betting.vetoMatch(matchId);
+    }
+
+    // ─── Resolution Amendments ───
+
+    function _resolvedSideA() internal {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+    }
+
+    function test_AmendResolutionFixesWinnerInsideWindow() public {
+        _resolvedSideA();
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.amendResolution(matchId, 1);
+
+        vm.warp(block.timestamp + 9 minutes);
+        vm.prank(oracle);
+        betting.amendResolution(matchId, 1);
+
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+        (uint32 winsA, uint32 lossesA) = _fighterRecord(fighterA);
+        (uint32 winsB,) = _fighterRecord(fighterB);
+        assertEq(winsA, 0);
+        assertEq(lossesA, 1);
+        assertEq(winsB, 1);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        betting.claimPayout(matchId);
+        assertEq(bob.balance - bobBefore, 1.94 ether);
+    }
+
+    function test_ResolutionFinalAfterFirstClaimOrWindow() public {
+        _resolvedSideA();
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResultFinal.selector);
+        betting.amendResolution(matchId, 1);
+
+        matchId = keccak256("match-2");
+        _resolvedSideA();
+        vm.warp(block.timestamp + betting.AMEND_WINDOW());
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResultFinal.selector);
+        betting.amendResolution(matchId, 1);
+    }
+
+    // ─── Spending Limits ───
+
+    function test_PayoutAboveSpendLimitNeedsGuardianCoSign() public {
+        vm.prank(admin);
+        betting.setSpendLimit(5000);
+        _resolvedSideA();
+
+        vm.prank(alice);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.SpendLimitExceeded.selector, 1 ether)
+        );
+        betting.claimPayout(matchId);
+
+        vm.prank(oracle);
+        vm.expectRevert();
+        betting.approveLargeDebit(matchId, alice);
+        vm.prank(admin);
+        betting.approveLargeDebit(matchId, alice);
+
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - aliceBefore, 1.94 ether);
+        assertFalse(betting.largeDebitApprovals(matchId, alice));
+    }
+
+    function test_SpendLimitLeavesSmallDebitsAlone() public {
+        vm.prank(admin);
+        betting.setSpendLimit(9800);
+        _resolvedSideA();
+
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidShareBps.selector);
+        betting.setSpendLimit(10_001);
+    }
+
+    // ─── Dual-Signature Resolution ───
+
+    function _lockedDualSign(uint128 poolSize) internal {
+        vm.prank(admin);
+        betting.setDualSignPoolSize(poolSize);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function _status() internal view returns (RawlBettingBase.MatchStatus status) {
+        (,, status,,,,,,,,,,,,,,,) = betting.matches(matchId);
+    }
+
+    function test_LargePoolWaitsForAuthorityCosign() public {
+        _lockedDualSign(2 ether);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Locked));
+        assertEq(betting.oracleResults(matchId), 1);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.NotAuthority.selector);
+        betting.cosignResolution(matchId, 0);
+
+        // A co-sign for the other side doesn't resolve
+        vm.prank(admin);
+        betting.cosignResolution(matchId, 1);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Locked));
+
+        vm.prank(admin);
+        betting.cosignResolution(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    function test_CosignFirstThenOracleResolves() public {
+        _lockedDualSign(2 ether);
+        vm.prank(admin);
+        betting.cosignResolution(matchId, 1);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Locked));
+
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+    }
+
+    function test_CosignedPoolCannotBeAmended() public {
+        _lockedDualSign(2 ether);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        vm.prank(admin);
+        betting.cosignResolution(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResultFinal.selector);
+        betting.amendResolution(matchId, 1);
+    }
+
+    function test_PoolBelowDualSignSizeResolvesAlone() public {
+        _lockedDualSign(3 ether);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    // ─── Claim Cooldown ───
+
+    function test_ClaimWaitsForCooldown() public {
+        vm.prank(admin);
+        betting.setClaimCooldown(1 hours);
+        _resolvedSideA();
+        uint64 claimableAt = uint64(block.timestamp + 1 hours);
+
+        vm.prank(alice);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.ClaimCooldownActive.selector, claimableAt)
+        );
+        betting.claimPayout(matchId);
+
+        vm.warp(claimableAt);
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+    }
+
+    function test_ClaimCooldownIsBounded() public {
+        uint64 max = betting.MAX_CLAIM_COOLDOWN();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setClaimCooldown(max + 1);
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.setClaimCooldown(1 hours);
+    }
+
+    // ─── Minimum Resolution Delay ───
+
+    function test_ResolveWaitsMinDelayAfterLock() public {
+        vm.prank(admin);
+        betting.setMinResolutionDelay(5 minutes);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        uint64 earliest = uint64(block.timestamp + 5 minutes);
+
+        vm.prank(oracle);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.ResolutionTooEarly.selector, earliest)
+        );
+        betting.resolveMatch(matchId, 0);
+
+        vm.warp(earliest);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    function test_MinResolutionDelayStaysBelowTimeout() public {
+        uint64 timeout = betting.matchTimeout();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setMinResolutionDelay(timeout);
+    }
+
+    // ─── Unlock ───
+
+    function test_UnlockMatchReopensBettingInsideWindow() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.unlockMatch(matchId);
+
+        vm.warp(block.timestamp + 4 minutes);
+        vm.prank(oracle);
+        betting.unlockMatch(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Open));
+        (,,,, uint64 lockedAt) = betting.oddsSnapshots(matchId);
+        assertEq(lockedAt, 0);
+
+        // Betting resumes on the same pool, and the match can be locked again
+        vm.prank(charlie);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        (uint128 totalA,,,,) = betting.oddsSnapshots(matchId);
+        assertEq(totalA, 2 ether);
+    }
+
+    function test_UnlockMatchRefusedAfterWindowOrAttestation() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        uint64 closedAt = uint64(block.timestamp) + betting.UNLOCK_WINDOW();
+
+        vm.warp(closedAt);
+        vm.prank(oracle);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.UnlockWindowClosed.selector, closedAt)
+        );
+        betting.unlockMatch(matchId);
+
+        // A recorded vote toward the result also pins the lock
+        bytes32 other = keccak256("match-2");
+        vm.startPrank(admin);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), makeAddr("oracle2"));
+        betting.setResolutionThreshold(2);
+        vm.stopPrank();
+        vm.startPrank(oracle);
+        betting.createMatch(other, fighterA, fighterB, 0.01 ether, 0);
+        betting.lockMatch(other);
+        betting.resolveMatch(other, 0);
+        vm.expectRevert(RawlBettingBase.ResultPending.selector);
+        betting.unlockMatch(other);
+        vm.stopPrank();
+    }
+
+    // ─── Forfeits ───
+
+    function test_ForfeitMatchAwardsWalkoverFromOpen() public {
+        _createAndBetBothSides();
+        vm.prank(alice);
+        vm.expectRevert();
+        betting.forfeitMatch(matchId, 0);
+
+        vm.prank(oracle);
+        betting.forfeitMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(uint8(betting.resultKinds(matchId)), uint8(RawlBettingBase.ResultKind.Walkover));
+
+        uint256 balBefore = alice.balance;
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+        assertEq(alice.balance - balBefore, 1.94 ether);
+
+        // Once a match has locked, only the regular result path applies
+        bytes32 other = keccak256("match-2");
+        vm.startPrank(oracle);
+        betting.createMatch(other, fighterA, fighterB, 0.001 ether, 0);
+        betting.lockMatch(other);
+        vm.expectRevert(RawlBettingBase.MatchNotOpen.selector);
+        betting.forfeitMatch(other, 0);
+        vm.stopPrank();
+    }
+
+    function test_ForfeitNoContestCancelsWithRefunds() public {
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.forfeitNoContest(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+        assertEq(uint8(betting.cancelReasons(matchId)), uint8(RawlBettingBase.CancelReason.NoShow));
+
+        uint256 balBefore = bob.balance;
+        vm.prank(bob);
+        betting.refundBet(matchId);
+        assertEq(bob.balance - balBefore, 1 ether);
+    }
+
+    function test_WalkoverNeedsCancelWhenQuorumRequired() public {
+        vm.prank(admin);
+        betting.setDualSignPoolSize(1 ether);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.WalkoverNeedsQuorum.selector);
+        betting.forfeitMatch(matchId, 0);
+    }
+
+    function test_ForfeitRefusedOnFeedBoundMatch() public {
+        _feedBoundMatch();
+        vm.startPrank(oracle);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.forfeitMatch(matchId, 0);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.forfeitNoContest(matchId);
+        vm.stopPrank();
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Open));
+    }
+
+    function test_ForfeitRefusedOnChaosMatch() public {
+        _mysteryVrf();
+        vm.startPrank(oracle);
+        betting.createChaosMatch(matchId, fighterA, fighterB, 0.001 ether, 0);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.forfeitMatch(matchId, 1);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.forfeitNoContest(matchId);
+        vm.stopPrank();
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Open));
+    }
+
+    // ─── Sweep Preview ───
+
+    function test_PreviewSweepMatchesWhatTheSweepSends() public {
+        _resolvedSideA();
+        (uint256 amount, address recipient) = betting.previewSweep(matchId, alice);
+        assertEq(amount, 1.94 ether);
+        assertEq(recipient, treasury);
+        (amount, recipient) = betting.previewSweep(matchId, bob); // lost, no loss floor
+        assertEq(amount, 0);
+        assertEq(recipient, address(0));
+
+        vm.warp(block.timestamp + 30 days + 1);
+        uint256 treasuryBefore = treasury.balance;
+        vm.prank(admin);
+        betting.sweepUnclaimed(matchId, alice);
+        assertEq(treasury.balance - treasuryBefore, 1.94 ether);
+        (amount,) = betting.previewSweep(matchId, alice);
+        assertEq(amount, 0);
+    }
+
+    function test_PreviewSweepOfCancelledBetRefundsBettor() public {
+        _createAndBetBothSides();
+        vm.prank(admin);
+        betting.cancelMatch(matchId);
+        (uint256 amount, address recipient) = betting.previewSweep(matchId, bob);
+        assertEq(amount, 1 ether);
+        assertEq(recipient, bob);
+    }
+
+    // ─── Chaos Exhibitions ───
+
+    function _lockedChaosMatch() internal returns (MockVRFCoordinator vrf) {
+        vrf = _mysteryVrf();
+        vm.prank(oracle);
+        betting.createChaosMatch(matchId, fighterA, fighterB, 0.001 ether, 0);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(matchId, 1);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function test_ChaosMatchWinnerIsDrawnByVrf() public {
+        MockVRFCoordinator vrf = _lockedChaosMatch();
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.ResolvedByFeed.selector);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(charlie);
+        uint256 requestId = betting.requestChaosResult(matchId);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.ChaosResultPending.selector, requestId)
+        );
+        betting.requestChaosResult(matchId);
+
+        vrf.fulfill(requestId, 7);
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+        assertEq(betting.chaosSeeds(matchId), 7);
+        assertEq(betting.chaosWinner(7), 1);
+    }
+
+    function test_ChaosDrawAfterTimeoutIsDropped() public {
+        vm.expectRevert(RawlBettingBase.NotChaosMatch.selector);
+        betting.requestChaosResult(matchId);
+
+        MockVRFCoordinator vrf = _lockedChaosMatch();
+        uint256 requestId = betting.requestChaosResult(matchId);
+        vm.warp(block.timestamp + betting.matchTimeout() + 1);
+        betting.timeoutMatch(matchId);
+
+        vrf.fulfill(requestId, 8);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+        assertEq(betting.chaosSeeds(matchId), 0);
+        assertEq(betting.chaosRequestIds(matchId), 0);
+    }
+
+    // ─── Commit–Reveal Resolution ───
+
+    function test_CommittedMatchResolvesOnlyToRevealedWinner() public {
+        bytes32 salt = keccak256("salt");
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatchWithCommitment(matchId, betting.resultCommitment(matchId, 0, salt));
+
+        vm.startPrank(oracle);
+        vm.expectRevert(RawlBettingBase.CommitmentMismatch.selector);
+        betting.resolveMatch(matchId, 0); // not revealed yet
+        vm.expectRevert(RawlBettingBase.CommitmentMismatch.selector);
+        betting.revealResult(matchId, 1, salt);
+        vm.expectRevert(RawlBettingBase.CommitmentMismatch.selector);
+        betting.revealResult(matchId, 0, keccak256("other"));
+
+        betting.revealResult(matchId, 0, salt);
+        vm.stopPrank();
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+        assertEq(betting.revealedResults(matchId), 1);
+    }
+
+    function test_CommitRevealRequiredRefusesPlainLock() public {
+        vm.prank(admin);
+        betting.setCommitRevealRequired(true);
+        _createAndBetBothSides();
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.CommitmentRequired.selector);
+        betting.lockMatch(matchId);
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.NoCommitment.selector);
+        betting.lockMatchWithCommitment(matchId, bytes32(0));
+    }
+
+    // ─── Delegated Match Administration ───
+
+    function test_MatchAdminManagesItsOwnCard() public {
+        address organizer = makeAddr("organizer");
+        vm.prank(oracle);
+        betting.createMatchWithAdmin(matchId, fighterA, fighterB, 0.001 ether, 1 hours, organizer);
+
+        vm.prank(oracle); // platform roles don't carry over
+        vm.expectRevert(RawlBettingBase.NotMatchAdmin.selector);
+        betting.updateMatchMetadata(matchId, "Main event", "ipfs://card", bytes32(0));
+
+        vm.startPrank(organizer);
+        betting.updateMatchMetadata(matchId, "Main event", "ipfs://card", keccak256("card"));
+        betting.extendBettingWindow(matchId, 90 minutes);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.ExtensionTooLong.selector, 30 minutes)
+        );
+        betting.extendBettingWindow(matchId, 31 minutes);
+        vm.stopPrank();
+
+        (bytes32 name,, bytes32 metadataHash,) = betting.matchMetadata(matchId);
+        assertEq(name, bytes32("Main event"));
+        assertEq(metadataHash, keccak256("card"));
+        (,,,,,,,,,,,,,,,, uint64 bettingWindow,) = betting.matches(matchId);
+        assertEq(bettingWindow, 150 minutes);
+    }
+
+    function test_OrganizerCancellationWaitsForGuardian() public {
+        address organizer = makeAddr("organizer");
+        vm.prank(oracle);
+        betting.createMatchWithAdmin(matchId, fighterA, fighterB, 0.001 ether, 0, organizer);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.NoCancellationRequest.selector);
+        betting.approveCancellation(matchId);
+
+        vm.prank(organizer);
+        betting.requestCancellation(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Open));
+        vm.prank(organizer);
+        vm.expectRevert();
+        betting.approveCancellation(matchId);
+
+        vm.prank(admin);
+        betting.approveCancellation(matchId);
+        assertEq(
+            uint8(betting.cancelReasons(matchId)),
+            uint8(RawlBettingBase.CancelReason.OrganizerRequest)
+        );
+        uint256 balBefore = alice.balance;
+        vm.prank(alice);
+        betting.refundBet(matchId);
+        assertEq(alice.balance - balBefore, 1 ether);
+    }
+
+    // ─── Per-Match Oracle ───
+
+    function test_RotatedOracleTakesCompromisedSeatInQuorum() public {
+        address oracle2 = makeAddr("oracle2");
+        address oracle3 = makeAddr("oracle3");
+        vm.startPrank(admin);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle2);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle3);
+        betting.setResolutionThreshold(2);
+        vm.stopPrank();
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(oracle3);
+        betting.resolveMatch(matchId, 0);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1); // the compromised key votes mid-match
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.NotAuthority.selector);
+        betting.setMatchOracle(matchId, oracle, oracle2);
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidMatchOracle.selector);
+        betting.setMatchOracle(matchId, oracle, charlie);
+        vm.prank(admin);
+        betting.setMatchOracle(matchId, oracle, oracle2);
+
+        // Its vote is struck, the honest one stands
+        (uint8 votesA, uint8 votesB) = betting.resultVotesOf(matchId);
+        assertEq(votesA, 1);
+        assertEq(votesB, 0);
+        assertEq(betting.resultAttested(matchId, oracle), 0);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.NotMatchOracle.selector);
+        betting.resolveMatch(matchId, 1);
+        vm.prank(oracle2);
+        betting.resolveMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideA));
+    }
+
+    function test_RotatedOracleStillNeedsQuorum() public {
+        address oracle2 = makeAddr("oracle2");
+        vm.startPrank(admin);
+        betting.grantRole(betting.RESOLVE_AUTHORITY_ROLE(), oracle2);
+        betting.setResolutionThreshold(2);
+        vm.stopPrank();
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(admin);
+        betting.setMatchOracle(matchId, oracle, oracle2);
+
+        vm.prank(oracle2);
+        betting.resolveMatch(matchId, 0);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Locked));
+    }
+
+    function test_MatchOracleOnlyMovesBeforeResolution() public {
+        address oracle2 = makeAddr("oracle2");
+        _resolvedSideA();
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.setMatchOracle(matchId, oracle, oracle2);
+    }
+
+    // ─── Correlated Markets ───
+
+    function _lockedCorrelatedPair(bytes32 methodMarket) internal {
+        vm.startPrank(oracle);
+        betting.createCorrelatedMatch(matchId, fighterA, fighterB, 0.001 ether, 0, "fight-1");
+        betting.createCorrelatedMatch(methodMarket, fighterA, fighterB, 0.001 ether, 0, "fight-1");
+        vm.stopPrank();
+        vm.startPrank(alice);
+        betting.placeBet{value: 1 ether}(matchId, 0);
+        betting.placeBet{value: 1 ether}(methodMarket, 0);
+        vm.stopPrank();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.lockMatch(methodMarket);
+        vm.stopPrank();
+    }
+
+    function test_CorrelatedMarketsResolveTogether() public {
+        bytes32 methodMarket = keccak256("method");
+        _lockedCorrelatedPair(methodMarket);
+
+        bytes32[] memory ids = new bytes32[](2);
+        ids[0] = matchId;
+        ids[1] = methodMarket;
+        uint8[] memory winners = new uint8[](2);
+        vm.prank(oracle);
+        betting.resolveCorrelated("fight-1", ids, winners, keccak256("card"));
+
+        assertEq(betting.groupResultHashes("fight-1"), keccak256("card"));
+        assertFalse(betting.correlationFlags(methodMarket));
+        vm.prank(alice);
+        betting.claimPayout(methodMarket);
+    }
+
+    function test_MismatchedCorrelatedResultWaitsForGuardian() public {
+        bytes32 methodMarket = keccak256("method");
+        _lockedCorrelatedPair(methodMarket);
+        vm.startPrank(oracle);
+        betting.resolveMatchWithResultHash(matchId, 0, keccak256("card"));
+        betting.resolveMatchWithResultHash(methodMarket, 0, keccak256("other card"));
+        vm.stopPrank();
+
+        assertTrue(betting.correlationFlags(methodMarket));
+        vm.prank(alice);
+        vm.expectRevert(RawlBettingBase.CorrelationUnderReview.selector);
+        betting.claimPayout(methodMarket);
+
+        vm.prank(admin);
+        betting.clearCorrelationFlag(methodMarket);
+        vm.prank(alice);
+        betting.claimPayout(methodMarket);
+    }
+
+    // ─── Oracle Liveness ───
+
+    function test_SilentOracleShortensTimeout() public {
+        vm.prank(admin);
+        betting.setOracleLiveness(10 minutes, 5 minutes);
+        _createAndBetBothSides();
+        vm.startPrank(oracle);
+        betting.lockMatch(matchId);
+        betting.oracleHeartbeat();
+        vm.stopPrank();
+
+        vm.warp(block.timestamp + 6 minutes);
+        assertFalse(betting.oracleSilent());
+        assertEq(betting.currentMatchTimeout(), betting.matchTimeout());
+        vm.expectRevert(RawlBettingBase.TimeoutNotElapsed.selector);
+        betting.timeoutMatch(matchId);
+
+        // Silent now — the 5 minute timeout applies, well before the usual 30
+        vm.warp(block.timestamp + 4 minutes);
+        assertTrue(betting.oracleSilent());
+        assertEq(betting.currentMatchTimeout(), 5 minutes);
+        betting.timeoutMatch(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+    }
+
+    function test_OracleLivenessBounds() public {
+        uint64 timeout = betting.matchTimeout();
+        vm.startPrank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setOracleLiveness(1 hours, timeout);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setOracleLiveness(1 hours, 1 minutes);
+        betting.setOracleLiveness(0, 0);
+        vm.stopPrank();
+        assertFalse(betting.oracleSilent());
+
+        vm.prank(charlie);
+        vm.expectRevert();
+        betting.oracleHeartbeat();
+    }
+
+    // ─── Arbitration Committee ───
+
+    function _seatCommittee() internal returns (address[] memory members) {
+        members = new address[](3);
+        members[0] = makeAddr("arbiter1");
+        members[1] = makeAddr("arbiter2");
+        members[2] = makeAddr("arbiter3");
+        vm.prank(admin);
+        betting.setCommittee(members);
+    }
+
+    function test_CommitteeMajorityUpholdsChallenge() public {
+        address[] memory members = _seatCommittee();
+        _resolvedWithDisputeWindow();
+        vm.prank(charlie);
+        betting.challengeResult{value: 0.5 ether}(matchId);
+
+        vm.prank(oracle);
+        vm.expectRevert(RawlBettingBase.NotCommitteeMember.selector);
+        betting.castArbitrationVote(matchId, 1);
+        vm.prank(members[0]);
+        betting.castArbitrationVote(matchId, 1);
+        vm.prank(members[0]);
+        vm.expectRevert(RawlBettingBase.AlreadyVoted.selector);
+        betting.castArbitrationVote(matchId, 1);
+        vm.prank(members[1]);
+        betting.castArbitrationVote(matchId, 0);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(members[2]);
+        betting.castArbitrationVote(matchId, 1);
+        assertEq(charlie.balance - charlieBefore, 0.5 ether);
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideB));
+        (, bool adjudicated,) = _dispute();
+        assertTrue(adjudicated);
+    }
+
+    function test_NewCommitteeVoidsOldVotesOnProposal() public {
+        address[] memory members = _seatCommittee();
+        _lockedWithProposal(0);
+        vm.prank(bob);
+        betting.challengeProposal{value: 0.5 ether}(matchId);
+        vm.prank(members[0]);
+        betting.castArbitrationVote(matchId, 0);
+
+        address[] memory next = new address[](1);
+        next[0] = members[1];
+        vm.prank(admin);
+        betting.setCommittee(next);
+        vm.prank(members[0]);
+        vm.expectRevert(RawlBettingBase.NotCommitteeMember.selector);
+        betting.castArbitrationVote(matchId, 0);
+
+        uint256 charlieBefore = charlie.balance;
+        vm.prank(members[1]);
+        betting.castArbitrationVote(matchId, 0);
+        assertEq(charlie.balance - charlieBefore, 1 ether); // both bonds to the proposer
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    function _dispute() internal view returns (address challenger, bool adjudicated, uint128 bond) {
+        (challenger,, adjudicated, bond) = betting.disputes(matchId);
+    }
+
+    // ─── Usage metrics ───
+
+    function test_MetricsCountBetsClaimsRefundsAndSweeps() public {
+        _resolvedSideA();
+        vm.prank(alice);
+        betting.claimPayout(matchId);
+
+        bytes32 cancelledId = keccak256("metrics-cancelled");
+        vm.prank(oracle);
+        betting.createMatch(cancelledId, fighterA, fighterB, 0.001 ether, 0);
+        vm.prank(alice);
+        betting.placeBet{value: 1 ether}(cancelledId, 0);
+        vm.prank(bob);
+        betting.placeBet{value: 1 ether}(cancelledId, 1);
+        vm.prank(admin);
+        betting.cancelMatch(cancelledId);
+        vm.prank(alice);
+        betting.refundBet(cancelledId);
+        vm.warp(block.timestamp + 30 days + 1);
+        betting.sweepCancelled(cancelledId, bob);
+
+        (uint64 betsPlaced, uint64 claims, uint64 refunds, uint64 sweeps) = betting.metrics();
+        assertEq(betsPlaced, 4);
+        assertEq(claims, 1);
+        assertEq(refunds, 1);
+        assertEq(sweeps, 1);
+    }
+
+    // ─── Game-server attestations ───
+
+    function _signRound(uint256 key, uint16 round, uint8 leader, bool finished)
+        internal
+        view
+        returns (bytes memory)
+    {
+        bytes32 stateHash = keccak256(abi.encode("round", round));
+        return _signTyped(
+            key,
+            keccak256(
+                abi.encode(
+                    betting.ATTESTATION_TYPEHASH(), matchId, round, stateHash, leader, finished
+                )
+            )
+        );
+    }
+
+    function _lockedWithGameServer() internal returns (uint256 key) {
+        address server;
+        (server, key) = makeAddrAndKey("gameServer");
+        vm.startPrank(admin);
+        betting.grantRole(betting.GAME_SERVER_ROLE(), server);
+        vm.stopPrank();
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+    }
+
+    function test_FinishedAttestationBindsOracleResult() public {
+        uint256 key = _lockedWithGameServer();
+        bytes32 stateHash = keccak256(abi.encode("round", uint16(1)));
+        betting.attestRound(matchId, 1, stateHash, 0, false, _signRound(key, 1, 0, false));
+        // Rounds only move forward
+        bytes memory replay = _signRound(key, 1, 1, false);
+        vm.expectRevert(RawlBettingBase.StaleAttestationRound.selector);
+        betting.attestRound(matchId, 1, stateHash, 1, false, replay);
+
+        stateHash = keccak256(abi.encode("round", uint16(3)));
+        bytes memory closing = _signRound(key, 3, 1, true);
+        vm.prank(charlie); // any relayer
+        betting.attestRound(matchId, 3, stateHash, 1, true, closing);
+        (, address server,, uint16 round, uint8 leader, bool finished) =
+            betting.attestations(matchId);
+        assertEq(server, vm.addr(key));
+        assertEq(round, 3);
+        assertEq(leader, 1);
+        assertTrue(finished);
+
+        vm.prank(oracle);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AttestationMismatch.selector, 1));
+        betting.resolveMatch(matchId, 0);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    function test_FinishedAttestationBindsAmendment() public {
+        uint256 key = _lockedWithGameServer();
+        bytes32 stateHash = keccak256(abi.encode("round", uint16(2)));
+        betting.attestRound(matchId, 2, stateHash, 0, true, _signRound(key, 2, 0, true));
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 0);
+
+        vm.prank(oracle);
+        vm.expectRevert(abi.encodeWithSelector(RawlBettingBase.AttestationMismatch.selector, 0));
+        betting.amendResolution(matchId, 1);
+        (,,, RawlBettingBase.MatchWinner winner,,,,,,,,,,,,,,) = betting.matches(matchId);
+        assertEq(uint8(winner), uint8(RawlBettingBase.MatchWinner.SideA));
+    }
+
+    function test_AttestationFromUnregisteredServerReverts() public {
+        uint256 key = _lockedWithGameServer();
+        (, uint256 strangerKey) = makeAddrAndKey("stranger");
+        bytes32 stateHash = keccak256(abi.encode("round", uint16(1)));
+        bytes memory signature = _signRound(strangerKey, 1, 0, true);
+        vm.expectRevert(RawlBettingBase.InvalidAttestation.selector);
+        betting.attestRound(matchId, 1, stateHash, 0, true, signature);
+
+        // A guardian clears a wrong final round so the oracle's result can land
+        betting.attestRound(matchId, 1, stateHash, 0, true, _signRound(key, 1, 0, true));
+        vm.prank(admin);
+        betting.clearAttestation(matchId);
+        vm.prank(oracle);
+        betting.resolveMatch(matchId, 1);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Resolved));
+    }
+
+    // ─── Resolution deadline ───
+
+    function test_ResolutionDeadlineOutlastsMatchTimeout() public {
+        vm.prank(admin);
+        betting.setResolutionDeadline(6 hours);
+        _createAndBetBothSides();
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        // Past the expected fight length, but the oracle still has time
+        vm.warp(block.timestamp + betting.matchTimeout() + 1);
+        vm.expectRevert(RawlBettingBase.TimeoutNotElapsed.selector);
+        betting.timeoutMatch(matchId);
+
+        vm.warp(block.timestamp + 6 hours);
+        betting.timeoutMatch(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+    }
+
+    function test_MatchResolutionDeadlineOverridesGlobal() public {
+        _createAndBetBothSides();
+        vm.startPrank(admin);
+        vm.expectRevert(
+            abi.encodeWithSelector(RawlBettingBase.TimeoutOutOfBounds.selector, 5 minutes, 7 days)
+        );
+        betting.setMatchResolutionDeadline(matchId, 8 days);
+        betting.setMatchResolutionDeadline(matchId, 2 days);
+        vm.stopPrank();
+        assertEq(betting.resolutionDeadlineOf(matchId), 2 days);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+
+        vm.warp(block.timestamp + 1 days);
+        vm.expectRevert(RawlBettingBase.TimeoutNotElapsed.selector);
+        betting.timeoutMatch(matchId);
+
+        // Clearing the override falls back to the global deadline, long passed by now
+        vm.prank(admin);
+        betting.setMatchResolutionDeadline(matchId, 0);
+        assertEq(betting.resolutionDeadlineOf(matchId), betting.matchTimeout());
+        betting.timeoutMatch(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+
+        vm.prank(admin);
+        vm.expectRevert(RawlBettingBase.InvalidMatchStatus.selector);
+        betting.setMatchResolutionDeadline(matchId, 2 days);
+    }
+
+    function test_MatchResolutionDeadlineOnlyLengthensGlobal() public {
+        _createAndBetBothSides();
+        vm.startPrank(admin);
+        betting.setResolutionDeadline(10 minutes);
+        vm.expectRevert(RawlBettingBase.InvalidTimeout.selector);
+        betting.setMatchResolutionDeadline(matchId, 10 minutes);
+        betting.setMatchResolutionDeadline(matchId, 15 minutes);
+
+        // Raising the global past the override, then the delay over it, can't strand the match
+        betting.setResolutionDeadline(40 minutes);
+        betting.setMinResolutionDelay(20 minutes);
+        vm.stopPrank();
+        assertEq(betting.resolutionDeadlineOf(matchId), 40 minutes);
+    }
+
+    function test_ForceCancelWaitsOutMatchResolutionDeadline() public {
+        _createAndBetBothSides();
+        vm.warp(block.timestamp + 1 days);
+        vm.prank(oracle);
+        betting.lockMatch(matchId);
+        vm.prank(admin);
+        betting.setMatchResolutionDeadline(matchId, 7 days);
+
+        // Past maxMatchAge, but the oracle still has until lock + 7 days
+        vm.warp(block.timestamp + 6 days + 1);
+        vm.expectRevert(RawlBettingBase.MatchNotExpired.selector);
+        betting.forceCancelExpired(matchId);
+
+        vm.warp(block.timestamp + 1 days);
+        betting.forceCancelExpired(matchId);
+        assertEq(uint8(_status()), uint8(RawlBettingBase.MatchStatus.Cancelled));
+    }
+
+    // ─── Module routing ───
+
+    function test_RevertUnknownSelector() public {
+        (bool success, bytes memory data) =
+            address(betting).call(abi.encodeWithSelector(bytes4(0xdeadbeef)));
+        assertFalse(success);
+        assertEq(
+            data, abi.encodeWithSelector(RawlBetting.UnknownSelector.selector, bytes4(0xdeadbeef))
+        );
+    }
+
+    function test_RevertDuplicateModuleSelector() public {
+        RawlBettingModule[] memory modules = new RawlBettingModule[](2);
+        modules[0] = RawlBettingModule(deployCode("ViewsModule.sol:ViewsModule"));
+        modules[1] = RawlBettingModule(deployCode("ViewsModule.sol:ViewsModule"));
+        bytes4 first = modules[0].selectors()[0];
+
+        vm.expectRevert(abi.encodeWithSelector(RawlBetting.DuplicateSelector.selector, first));
+        new RawlBetting(admin, oracle, treasury, modules);
+    }
+
+    function test_ModuleCalledDirectlyIsPaused() public {
+        address module = betting.moduleOf(IRawlBetting.createMatch.selector);
+        assertTrue(module != address(0));
+        assertTrue(RawlBettingModule(module).paused());
+        assertFalse(betting.paused());
+
+        vm.prank(oracle);
+        vm.expectRevert(Pausable.EnforcedPause.selector);
+        IRawlBetting(module).createMatch(matchId, fighterA, fighterB, 0, 0);
     }
 }
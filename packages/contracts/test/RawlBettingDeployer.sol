@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "../src/IRawlBetting.sol";
+import "../src/RawlBetting.sol";
+
+/// @notice Deploys the RawlBetting router with every module. Modules come from the build
+///         artifacts so each test contract doesn't embed all of their bytecode
+abstract contract RawlBettingDeployer is Test {
+    function _deployRawlBetting(address admin, address oracle, address treasury)
+        internal
+        returns (IRawlBetting)
+    {
+        return IRawlBetting(address(new RawlBetting(admin, oracle, treasury, _deployModules())));
+    }
+
+    function _deployModules() internal returns (RawlBettingModule[] memory modules) {
+        string[12] memory names = _moduleNames();
+        modules = new RawlBettingModule[](names.length);
+        for (uint256 i; i < names.length; ++i) {
+            modules[i] = RawlBettingModule(deployCode(string.concat(names[i], ".sol:", names[i])));
+        }
+    }
+
+    function _moduleNames() internal pure returns (string[12] memory) {
+        return [
+            "MatchesModule",
+            "SchedulingModule",
+            "LifecycleModule",
+            "ResolutionModule",
+            "OraclesModule",
+            "RandomnessModule",
+            "DisputesModule",
+            "PromosModule",
+            "TreasuryModule",
+            "AdminModule",
+            "AccountsModule",
+            "ViewsModule"
+        ];
+    }
+}
@@ -2,10 +2,10 @@
 pragma solidity ^0.8.24;
 
 import "forge-std/Test.sol";
-import "../src/RawlBetting.sol";
+import "./RawlBettingDeployer.sol";
 
-contract RawlBettingFuzzTest is Test {
-    RawlBetting public betting;
+contract RawlBettingFuzzTest is RawlBettingDeployer {
+    IRawlBetting public betting;
 
     address admin = makeAddr("admin");
     address oracle = makeAddr("oracle");
@@ -14,7 +14,12 @@ contract RawlBettingFuzzTest is Test {
     bytes32 matchId = keccak256("fuzz-match");
 
     function setUp() public {
-        betting = new RawlBetting(admin, oracle, treasury);
+        betting = _deployRawlBetting(admin, oracle, treasury);
+
+        vm.startPrank(oracle);
+        betting.registerFighter(makeAddr("fA"), keccak256("fA"), makeAddr("ownerA"));
+        betting.registerFighter(makeAddr("fB"), keccak256("fB"), makeAddr("ownerB"));
+        vm.stopPrank();
     }
 
     function testFuzz_PayoutNeverExceedsPool(
@@ -2,11 +2,11 @@
 pragma solidity ^0.8.24;
 
 import "forge-std/Test.sol";
-import "../src/RawlBetting.sol";
+import "./RawlBettingDeployer.sol";
 import "./Handler.sol";
 
-contract RawlBettingInvariantTest is Test {
-    RawlBetting public betting;
+contract RawlBettingInvariantTest is RawlBettingDeployer {
+    IRawlBetting public betting;
     Handler public handler;
 
     address admin = makeAddr("admin");
@@ -14,9 +14,13 @@ contract RawlBettingInvariantTest is Test {
     address treasury = makeAddr("treasury");
 
     function setUp() public {
-        betting = new RawlBetting(admin, oracle, treasury);
+        betting = _deployRawlBetting(admin, oracle, treasury);
         handler = new Handler(betting, admin, oracle);
 
+        // Cash-outs are disabled by default; turn them on so the fuzzer can reach them
+        vm.prank(admin);
+        betting.setCashOutDiscountBps(500);
+
         targetContract(address(handler));
     }
 
@@ -37,4 +41,60 @@ contract RawlBettingInvariantTest is Test {
             handler.ghost_totalDeposited()
         );
     }
+
+    /// @notice The vault covers every tracked match's liabilities at the contract's own
+    ///         getSolvency definition, plus the creator bonds it still holds, the cash-out
+    ///         haircuts not yet withdrawn and the cash-out reserve
+    function invariant_VaultCoversLiabilities() public view {
+        uint256 owed;
+        for (uint256 i; i < handler.matchCountTracked(); ++i) {
+            bytes32 mId = handler.matchAt(i);
+            (, uint256 liability, uint256 feeReserved,) = betting.getSolvency(mId);
+            owed += liability + feeReserved + betting.creatorBonds(mId);
+        }
+        owed += betting.cashOutDust() + betting.cashOutReserve();
+        assertGe(address(betting).balance, owed);
+    }
+
+    /// @notice Pool totals and bet counters agree with the handler's ledger of successful bets
+    ///         and cash-outs, and the unsettled counters never exceed what was placed
+    function invariant_CountersConsistent() public view {
+        for (uint256 i; i < handler.matchCountTracked(); ++i) {
+            bytes32 mId = handler.matchAt(i);
+            (
+                ,,,,
+                uint32 sideABetCount,
+                uint32 sideBBetCount,
+                uint32 winningBetCount,
+                uint32 betCount,,
+                uint128 sideATotal,
+                uint128 sideBTotal,,,,,,,
+            ) = betting.matches(mId);
+
+            assertEq(sideATotal, handler.ghost_sideTotals(mId, 0));
+            assertEq(sideBTotal, handler.ghost_sideTotals(mId, 1));
+            assertEq(sideABetCount, handler.ghost_sideBetCounts(mId, 0));
+            assertEq(sideBBetCount, handler.ghost_sideBetCounts(mId, 1));
+            assertLe(betCount, uint256(sideABetCount) + sideBBetCount);
+            assertLe(winningBetCount, betCount);
+            assertEq(betCount, _unsettledBets(mId));
+        }
+    }
+
+    /// @notice No match ever moves back through None → Pending → Open → Locked → final
+    function invariant_NoStatusRegression() public view {
+        assertEq(handler.ghost_statusRegressions(), 0);
+    }
+
+    function invariant_AdminCallsGated() public view {
+        assertEq(handler.ghost_unauthorizedCalls(), 0);
+    }
+
+    /// @dev Handler bettors are the only accounts that bet, so they hold every unsettled bet
+    function _unsettledBets(bytes32 mId) internal view returns (uint256 count) {
+        for (uint256 j; j < handler.bettorCount(); ++j) {
+            (uint128 amount,, bool claimed) = betting.bets(mId, handler.bettorAt(j));
+            if (amount > 0 && !claimed) count++;
+        }
+    }
 }
@@ -0,0 +1,376 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "../src/SeasonFutures.sol";
+
+contract SeasonFuturesTest is Test {
+    SeasonFutures public futures;
+
+    address admin = makeAddr("admin");
+    address oracle = makeAddr("oracle");
+    address treasury = makeAddr("treasury");
+    address alice = makeAddr("alice");
+    address bob = makeAddr("bob");
+    address charlie = makeAddr("charlie");
+
+    bytes32 marketId = keccak256("season-1-stables");
+    uint64 closesAt;
+    uint64 seasonEndsAt;
+
+    function setUp() public {
+        futures = new SeasonFutures(admin, oracle, treasury);
+        vm.deal(alice, 100 ether);
+        vm.deal(bob, 100 ether);
+        vm.deal(charlie, 100 ether);
+
+        closesAt = uint64(block.timestamp + 6 weeks);
+        seasonEndsAt = uint64(block.timestamp + 12 weeks);
+        vm.prank(oracle);
+        futures.createMarket(marketId, 1, 4, closesAt, seasonEndsAt);
+    }
+
+    // ─── Helpers ───
+
+    function _betAll() internal {
+        vm.prank(alice);
+        futures.placeBet{value: 1 ether}(marketId, 0);
+        vm.prank(bob);
+        futures.placeBet{value: 2 ether}(marketId, 1);
+        vm.prank(charlie);
+        futures.placeBet{value: 1 ether}(marketId, 2);
+    }
+
+    // ─── Full Season ───
+
+    function test_SeasonLifecycle() public {
+        _betAll();
+
+        // Weeks later — still open for bets, and stakes can be topped up
+        vm.warp(block.timestamp + 4 weeks);
+        vm.prank(alice);
+        futures.placeBet{value: 1 ether}(marketId, 0);
+
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        futures.settle(marketId, 0);
+
+        // Pool 5 ETH, 3% fee → 4.85 ETH to alice's 2 ETH winning stake
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        futures.claim(marketId);
+        assertEq(alice.balance - aliceBefore, 4.85 ether);
+
+        vm.prank(admin);
+        futures.withdrawFees(marketId);
+        assertEq(treasury.balance, 0.15 ether);
+        assertEq(address(futures).balance, 0);
+    }
+
+    function test_BetAfterCloseReverts() public {
+        vm.warp(closesAt);
+        vm.prank(alice);
+        vm.expectRevert(SeasonFutures.BettingClosed.selector);
+        futures.placeBet{value: 1 ether}(marketId, 0);
+    }
+
+    // ─── Checkpoints ───
+
+    function test_CheckpointBlocksEliminatedOutcome() public {
+        _betAll();
+        vm.prank(oracle);
+        futures.checkpoint(marketId, uint32(1) << 2);
+
+        vm.prank(charlie);
+        vm.expectRevert(SeasonFutures.OutcomeEliminated.selector);
+        futures.placeBet{value: 1 ether}(marketId, 2);
+
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.OutcomeEliminated.selector);
+        futures.settle(marketId, 2);
+    }
+
+    function test_CheckpointCannotEliminateEveryOutcome() public {
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.InvalidOutcome.selector);
+        futures.checkpoint(marketId, 0xF);
+    }
+
+    function test_CheckpointOutOfRangeReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.InvalidOutcome.selector);
+        futures.checkpoint(marketId, uint32(1) << 4);
+    }
+
+    // ─── Settlement ───
+
+    function test_SettleBeforeSeasonEndReverts() public {
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.SeasonNotEnded.selector);
+        futures.settle(marketId, 0);
+    }
+
+    function test_NoWinnerStakesRefundMinusFee() public {
+        _betAll();
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        futures.settle(marketId, 3);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        futures.claim(marketId);
+        assertEq(bob.balance - bobBefore, 1.94 ether);
+    }
+
+    function test_LoserClaimReverts() public {
+        _betAll();
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        futures.settle(marketId, 0);
+
+        vm.prank(bob);
+        vm.expectRevert(SeasonFutures.NothingToClaim.selector);
+        futures.claim(marketId);
+    }
+
+    // ─── Unclaimed Sweep ───
+
+    function test_SweepSendsUnclaimedAfterWindow() public {
+        _betAll();
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        futures.settle(marketId, 1);
+
+        vm.prank(admin);
+        vm.expectRevert(SeasonFutures.ClaimWindowNotElapsed.selector);
+        futures.sweepUnclaimed(marketId);
+
+        vm.prank(admin);
+        futures.withdrawFees(marketId);
+
+        // Bob never claims his 3.88 ETH; the fee already went out
+        vm.warp(seasonEndsAt + futures.CLAIM_WINDOW());
+        vm.expectEmit(true, false, false, true);
+        emit SeasonFutures.UnclaimedSwept(marketId, 3.88 ether, treasury);
+        vm.prank(admin);
+        futures.sweepUnclaimed(marketId);
+        assertEq(treasury.balance, 4 ether);
+        assertEq(address(futures).balance, 0);
+
+        vm.prank(bob);
+        vm.expectRevert(SeasonFutures.AlreadySwept.selector);
+        futures.claim(marketId);
+    }
+
+    function test_SweepSkipsClaimedPayoutsAndTakesFee() public {
+        vm.prank(alice);
+        futures.placeBet{value: 1 ether}(marketId, 0);
+        vm.prank(bob);
+        futures.placeBet{value: 1 ether}(marketId, 0);
+        vm.warp(seasonEndsAt);
+        vm.prank(oracle);
+        futures.settle(marketId, 0);
+
+        vm.prank(alice);
+        futures.claim(marketId);
+
+        vm.warp(seasonEndsAt + futures.CLAIM_WINDOW());
+        vm.startPrank(admin);
+        futures.sweepUnclaimed(marketId);
+        assertEq(treasury.balance, 1.03 ether);
+        assertEq(address(futures).balance, 0);
+
+        vm.expectRevert(SeasonFutures.AlreadySwept.selector);
+        futures.sweepUnclaimed(marketId);
+        vm.expectRevert(SeasonFutures.FeesAlreadyWithdrawn.selector);
+        futures.withdrawFees(marketId);
+        vm.stopPrank();
+    }
+
+    function test_SweepRequiresSettledMarket() public {
+        _betAll();
+        vm.warp(seasonEndsAt + futures.CLAIM_WINDOW());
+        vm.prank(admin);
+        vm.expectRevert(SeasonFutures.MarketNotSettled.selector);
+        futures.sweepUnclaimed(marketId);
+    }
+
+    // ─── Config ───
+
+    function test_UpdateConfigEmitsAndBoundsFee() public {
+        address newTreasury = makeAddr("newTreasury");
+
+        vm.startPrank(admin);
+        vm.expectEmit(false, false, false, true);
+        emit SeasonFutures.ConfigUpdated(500, newTreasury);
+        futures.updateConfig(500, newTreasury);
+        assertEq(futures.feeBps(), 500);
+        assertEq(futures.treasury(), newTreasury);
+
+        vm.expectRevert(SeasonFutures.InvalidFeeBps.selector);
+        futures.updateConfig(0, address(0));
+        uint16 tooHigh = futures.MAX_FEE_BPS() + 1;
+        vm.expectRevert(SeasonFutures.InvalidFeeBps.selector);
+        futures.updateConfig(tooHigh, address(0));
+        vm.stopPrank();
+    }
+
+    // ─── Cancellation ───
+
+    function test_UnsettledSeasonCancellableAfterGrace() public {
+        _betAll();
+
+        vm.warp(seasonEndsAt);
+        vm.expectRevert(SeasonFutures.SettlementGraceNotElapsed.selector);
+        futures.cancelExpired(marketId);
+
+        vm.warp(seasonEndsAt + 14 days);
+        futures.cancelExpired(marketId);
+
+        uint256 bobBefore = bob.balance;
+        vm.prank(bob);
+        futures.refund(marketId);
+        assertEq(bob.balance - bobBefore, 2 ether);
+    }
+
+    // ─── Tiered Payouts ───
+
+    bytes32 royaleId = keccak256("season-1-royale");
+
+    function _createRoyale() internal {
+        uint16[] memory weights = new uint16[](3);
+        weights[0] = 6000;
+        weights[1] = 3000;
+        weights[2] = 1000;
+        vm.prank(oracle);
+        futures.createTieredMarket(royaleId, 1, 4, closesAt, seasonEndsAt, weights);
+
+        vm.prank(alice);
+        futures.placeBet{value: 1 ether}(royaleId, 0);
+        vm.prank(bob);
+        futures.placeBet{value: 2 ether}(royaleId, 1);
+        vm.prank(charlie);
+        futures.placeBet{value: 1 ether}(royaleId, 2);
+        vm.warp(seasonEndsAt);
+    }
+
+    function _ranking(uint8 first, uint8 second, uint8 third)
+        internal
+        pure
+        returns (uint8[] memory ranking)
+    {
+        ranking = new uint8[](3);
+        ranking[0] = first;
+        ranking[1] = second;
+        ranking[2] = third;
+    }
+
+    function _claimed(address bettor) internal returns (uint256) {
+        uint256 before = bettor.balance;
+        vm.prank(bettor);
+        futures.claim(royaleId);
+        return bettor.balance - before;
+    }
+
+    function test_TieredPayoutSplitsByPlacing() public {
+        _createRoyale();
+        vm.prank(oracle);
+        futures.settleRanked(royaleId, _ranking(0, 1, 2));
+
+        // Net pool 3.88 ETH split 60/30/10
+        assertEq(_claimed(alice), 2.328 ether);
+        assertEq(_claimed(bob), 1.164 ether);
+        assertEq(_claimed(charlie), 0.388 ether);
+    }
+
+    function test_UnbackedPlacingWeightIsShared() public {
+        _createRoyale();
+        vm.prank(oracle);
+        futures.settleRanked(royaleId, _ranking(0, 3, 1));
+
+        // Nobody backed outcome 3, so 1st and 3rd split the pool 6000:1000
+        uint256 alicePaid = _claimed(alice);
+        uint256 bobPaid = _claimed(bob);
+        assertApproxEqAbs(alicePaid, 3.88 ether * 6 / 7, 1);
+        assertApproxEqAbs(alicePaid + bobPaid, 3.88 ether, 2);
+
+        vm.prank(charlie);
+        vm.expectRevert(SeasonFutures.NothingToClaim.selector);
+        futures.claim(royaleId);
+    }
+
+    function test_TieredMarketRequiresRanking() public {
+        _createRoyale();
+        vm.startPrank(oracle);
+        vm.expectRevert(SeasonFutures.RankingRequired.selector);
+        futures.settle(royaleId, 0);
+        vm.expectRevert(SeasonFutures.InvalidRanking.selector);
+        futures.settleRanked(royaleId, _ranking(0, 1, 0));
+        vm.expectRevert(SeasonFutures.NotTieredMarket.selector);
+        futures.settleRanked(marketId, _ranking(0, 1, 2));
+        vm.stopPrank();
+    }
+
+    function test_TierWeightsMustSumToWhole() public {
+        uint16[] memory weights = new uint16[](2);
+        weights[0] = 6000;
+        weights[1] = 3000;
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.InvalidTierWeights.selector);
+        futures.createTieredMarket(royaleId, 1, 4, closesAt, seasonEndsAt, weights);
+    }
+
+    // ─── Method-of-Victory Props ───
+
+    bytes32 propId = keccak256("match-1-method");
+
+    function _createProp() internal {
+        vm.prank(oracle);
+        futures.createMethodMarket(propId, keccak256("match-1"), uint64(block.timestamp + 1 hours));
+
+        vm.prank(alice);
+        futures.placeBet{value: 1 ether}(propId, uint8(SeasonFutures.VictoryMethod.KO));
+        vm.prank(bob);
+        futures.placeBet{value: 2 ether}(propId, uint8(SeasonFutures.VictoryMethod.Decision));
+    }
+
+    function test_MethodMarketPaysWinningBucket() public {
+        _createProp();
+
+        vm.prank(charlie);
+        vm.expectRevert(SeasonFutures.InvalidOutcome.selector);
+        futures.placeBet{value: 1 ether}(propId, 3);
+
+        vm.warp(block.timestamp + 1 hours);
+        vm.prank(oracle);
+        futures.resolveMethod(propId, SeasonFutures.VictoryMethod.KO);
+
+        // 3 ETH pool, 3% fee → 2.91 ETH to the only KO backer
+        uint256 aliceBefore = alice.balance;
+        vm.prank(alice);
+        futures.claim(propId);
+        assertEq(alice.balance - aliceBefore, 2.91 ether);
+    }
+
+    function test_MethodMarketRejectsSeasonActions() public {
+        _createProp();
+        vm.warp(block.timestamp + 1 hours);
+
+        vm.startPrank(oracle);
+        vm.expectRevert(SeasonFutures.WrongMarketKind.selector);
+        futures.checkpoint(propId, 1);
+        vm.expectRevert(SeasonFutures.WrongMarketKind.selector);
+        futures.settle(propId, 0);
+        vm.expectRevert(SeasonFutures.WrongMarketKind.selector);
+        futures.resolveMethod(marketId, SeasonFutures.VictoryMethod.KO);
+        vm.stopPrank();
+    }
+
+    function test_MethodMarketNotResolvableBeforeFight() public {
+        _createProp();
+        vm.prank(oracle);
+        vm.expectRevert(SeasonFutures.SeasonNotEnded.selector);
+        futures.resolveMethod(propId, SeasonFutures.VictoryMethod.Submission);
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+pragma solidity ^0.8.24;
+
+import "forge-std/Test.sol";
+import "../src/modules/ViewsModule.sol";
+import "./RawlBettingDeployer.sol";
+
+/// @notice Checks the hand-written "Slot n (packed: ...)" comments on RawlBetting's structs
+///         against the compiled layout. A struct getter loads every slot of its struct, so
+///         the distinct slots it reads is the struct's real size — a new field that spills
+///         into another slot fails here until the comments and this table are updated.
+///         RawlBetting is not upgradeable and its structs live behind mappings, so fields
+///         are appended on redeploy rather than carved out of reserved padding. The getters
+///         are read off ViewsModule directly: through the router, its moduleOf lookup would
+///         add a slot to every count.
+///
+///         Module code runs against the router's storage, so every module must also have
+///         exactly RawlBettingBase's layout, and the router only its own moduleOf on top
+contract StorageLayoutTest is RawlBettingDeployer {
+    ViewsModule betting;
+    bytes32 constant KEY = keccak256("layout");
+    address constant ACCOUNT = address(0xBEEF);
+
+    function setUp() public {
+        betting = new ViewsModule();
+    }
+
+    /// @dev Distinct storage slots the call reads
+    function _slotsRead(bytes memory data) internal returns (uint256 count) {
+        vm.record();
+        (bool success,) = address(betting).staticcall(data);
+        assertTrue(success);
+        (bytes32[] memory reads,) = vm.accesses(address(betting));
+        for (uint256 i; i < reads.length; ++i) {
+            bool seen;
+            for (uint256 j; j < i && !seen; ++j) {
+                seen = reads[j] == reads[i];
+            }
+            if (!seen) count++;
+        }
+    }
+
+    function _expectSlots(bytes4 getter, bytes memory args, uint256 slots, string memory name)
+        internal
+    {
+        assertEq(_slotsRead(abi.encodePacked(getter, args)), slots, name);
+    }
+
+    /// @dev Every state variable as "label @ slot+offset type", in declaration order, from the
+    ///      build artifact. Type ids carry AST ids, so a struct or enum redeclared anywhere
+    ///      else shows up as a mismatch too
+    function _layout(string memory name) internal view returns (string[] memory entries) {
+        string memory json = vm.readFile(
+            string.concat(vm.projectRoot(), "/out/", name, ".sol/", name, ".json")
+        );
+        string memory path = ".storageLayout.storage[*]";
+        string[] memory labels = vm.parseJsonStringArray(json, string.concat(path, ".label"));
+        string[] memory slots = vm.parseJsonStringArray(json, string.concat(path, ".slot"));
+        uint256[] memory offsets = vm.parseJsonUintArray(json, string.concat(path, ".offset"));
+        string[] memory types = vm.parseJsonStringArray(json, string.concat(path, ".type"));
+
+        entries = new string[](labels.length);
+        for (uint256 i; i < labels.length; ++i) {
+            entries[i] = string.concat(
+                labels[i], " @ ", slots[i], "+", vm.toString(offsets[i]), " ", types[i]
+            );
+        }
+    }
+
+    function test_ModulesShareBaseLayout() public view {
+        string[] memory base = _layout("RawlBettingBase");
+        string[12] memory names = _moduleNames();
+        for (uint256 i; i < names.length; ++i) {
+            string[] memory module = _layout(names[i]);
+            assertEq(module.length, base.length, string.concat(names[i], " declares state"));
+            for (uint256 j; j < base.length; ++j) {
+                assertEq(module[j], base[j], names[i]);
+            }
+        }
+    }
+
+    function test_RouterAppendsOnlyModuleOf() public view {
+        string[] memory base = _layout("RawlBettingBase");
+        string[] memory router = _layout("RawlBetting");
+        assertEq(router.length, base.length + 1, "RawlBetting state");
+        for (uint256 j; j < base.length; ++j) {
+            assertEq(router[j], base[j], "RawlBetting");
+        }
+        assertEq(vm.split(router[base.length], " ")[0], "moduleOf");
+    }
+
+    function test_StructSlotCommentsMatchLayout() public {
+        // matches() adds in an Open match's shards, one slot each
+        _expectSlots(
+            betting.matches.selector, abi.encode(KEY), 6 + betting.POOL_SHARDS(), "MatchPool"
+        );
+        _expectSlots(betting.bets.selector, abi.encode(KEY, ACCOUNT), 1, "BetInfo");
+        _expectSlots(betting.betOdds.selector, abi.encode(KEY, ACCOUNT), 1, "BetOdds");
+        _expectSlots(betting.poolShards.selector, abi.encode(KEY, uint256(0)), 1, "PoolShard");
+        _expectSlots(betting.oddsSnapshots.selector, abi.encode(KEY), 2, "OddsSnapshot");
+        _expectSlots(
+            betting.recurringSchedules.selector, abi.encode(KEY), 4, "RecurringSchedule"
+        );
+        _expectSlots(betting.priceMarkets.selector, abi.encode(KEY), 2, "PriceMarket");
+        _expectSlots(betting.disputes.selector, abi.encode(KEY), 2, "Dispute");
+        _expectSlots(betting.resultProposals.selector, abi.encode(KEY), 3, "ResultProposal");
+        _expectSlots(
+            betting.arbitrationTallies.selector, abi.encode(KEY), 1, "ArbitrationTally"
+        );
+        _expectSlots(betting.attestations.selector, abi.encode(KEY), 2, "Attestation");
+        _expectSlots(betting.mysteryRequests.selector, abi.encode(uint256(1)), 3, "MysteryRequest");
+        _expectSlots(betting.pendingDonation.selector, "", 1, "PendingDonation");
+        _expectSlots(betting.metrics.selector, "", 1, "UsageMetrics");
+        _expectSlots(betting.pendingFundings.selector, abi.encode(KEY), 3, "PendingFunding");
+        _expectSlots(betting.schedules.selector, abi.encode(KEY), 1, "MatchSchedule");
+        _expectSlots(betting.vouchers.selector, abi.encode(KEY), 2, "Voucher");
+        _expectSlots(betting.sponsorships.selector, abi.encode(KEY), 2, "Sponsorship");
+        _expectSlots(betting.bettorProfiles.selector, abi.encode(ACCOUNT), 2, "BettorProfile");
+        _expectSlots(betting.firstBets.selector, abi.encode(ACCOUNT), 2, "FirstBet");
+        _expectSlots(betting.fighters.selector, abi.encode(ACCOUNT), 3, "FighterRecord");
+        _expectSlots(betting.royaltyPools.selector, abi.encode(KEY), 1, "RoyaltyPool");
+        _expectSlots(betting.referralPools.selector, abi.encode(KEY), 1, "ReferralPool");
+        _expectSlots(
+            betting.referralAccruals.selector, abi.encode(KEY, ACCOUNT), 1, "ReferralAccrual"
+        );
+    }
+}
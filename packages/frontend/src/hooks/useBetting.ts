@@ -6,11 +6,14 @@ import { parseEther } from "viem";
 import { BetSide } from "@/types";
 import { CONTRACT_ADDRESS, BETTING_ABI, matchIdToBytes32 } from "@/lib/contracts";
 import { syncBetStatus } from "@/lib/api";
+import { contractErrorMessage } from "@/lib/messages";
+import { assertClientSupported } from "@/lib/version";
 
 const API_URL = process.env.NEXT_PUBLIC_API_URL ?? "http://localhost:8080/api";
 
 /**
  * Hook for placing bets on Base and recording them in the backend.
+ * Pass a registered referrer address to route part of the fee to them.
  */
 export function usePlaceBet() {
   const { address } = useAccount();
@@ -20,7 +23,12 @@ export function usePlaceBet() {
   const [error, setError] = useState<string | null>(null);
 
   const placeBet = useCallback(
-    async (matchId: string, side: BetSide, amountEth: number): Promise<string | null> => {
+    async (
+      matchId: string,
+      side: BetSide,
+      amountEth: number,
+      referrer?: `0x${string}`,
+    ): Promise<string | null> => {
       if (!address) {
         setError("Wallet not connected");
         return null;
@@ -34,14 +42,24 @@ export function usePlaceBet() {
       setError(null);
 
       try {
+        await assertClientSupported(publicClient!);
         const sideNum = side === "a" ? 0 : 1;
-        const hash = await writeContractAsync({
-          address: CONTRACT_ADDRESS,
-          abi: BETTING_ABI,
-          functionName: 'placeBet',
-          args: [matchIdToBytes32(matchId), sideNum],
-          value: parseEther(amountEth.toString()),
-        });
+        const value = parseEther(amountEth.toString());
+        const hash = referrer
+          ? await writeContractAsync({
+              address: CONTRACT_ADDRESS,
+              abi: BETTING_ABI,
+              functionName: 'placeBetWithReferrer',
+              args: [matchIdToBytes32(matchId), sideNum, referrer],
+              value,
+            })
+          : await writeContractAsync({
+              address: CONTRACT_ADDRESS,
+              abi: BETTING_ABI,
+              functionName: 'placeBet',
+              args: [matchIdToBytes32(matchId), sideNum],
+              value,
+            });
         await publicClient!.waitForTransactionReceipt({ hash, confirmations: 1 });
 
         // Record the bet in the backend for tracking (non-critical)
@@ -62,7 +80,7 @@ export function usePlaceBet() {
 
         return hash;
       } catch (err) {
-        const msg = err instanceof Error ? err.message : "Failed to place bet";
+        const msg = contractErrorMessage(err);
         setError(msg);
         return null;
       } finally {
@@ -77,6 +95,7 @@ export function usePlaceBet() {
 
 /**
  * Hook for claiming payouts after a match resolves.
+ * Pass a recipient to send the winnings somewhere other than the connected wallet.
  */
 export function useClaimPayout() {
   const { address } = useAccount();
@@ -86,7 +105,11 @@ export function useClaimPayout() {
   const [error, setError] = useState<string | null>(null);
 
   const claimPayout = useCallback(
-    async (matchId: string, betId?: string): Promise<string | null> => {
+    async (
+      matchId: string,
+      betId?: string,
+      recipient?: `0x${string}`,
+    ): Promise<string | null> => {
       if (!address) {
         setError("Wallet not connected");
         return null;
@@ -100,12 +123,19 @@ export function useClaimPayout() {
       setError(null);
 
       try {
-        const hash = await writeContractAsync({
-          address: CONTRACT_ADDRESS,
-          abi: BETTING_ABI,
-          functionName: 'claimPayout',
-          args: [matchIdToBytes32(matchId)],
-        });
+        const hash = recipient
+          ? await writeContractAsync({
+              address: CONTRACT_ADDRESS,
+              abi: BETTING_ABI,
+              functionName: 'claimPayoutTo',
+              args: [matchIdToBytes32(matchId), recipient],
+            })
+          : await writeContractAsync({
+              address: CONTRACT_ADDRESS,
+              abi: BETTING_ABI,
+              functionName: 'claimPayout',
+              args: [matchIdToBytes32(matchId)],
+            });
         await publicClient!.waitForTransactionReceipt({ hash, confirmations: 1 });
 
         // Sync bet status in backend (non-critical)
@@ -119,7 +149,7 @@ export function useClaimPayout() {
 
         return hash;
       } catch (err) {
-        const msg = err instanceof Error ? err.message : "Failed to claim payout";
+        const msg = contractErrorMessage(err);
         setError(msg);
         return null;
       } finally {
@@ -176,7 +206,7 @@ export function useRefundBet() {
 
         return hash;
       } catch (err) {
-        const msg = err instanceof Error ? err.message : "Failed to refund bet";
+        const msg = contractErrorMessage(err);
         setError(msg);
         return null;
       } finally {
@@ -233,7 +263,7 @@ export function useRefundNoWinners() {
 
         return hash;
       } catch (err) {
-        const msg = err instanceof Error ? err.message : "Failed to refund bet";
+        const msg = contractErrorMessage(err);
         setError(msg);
         return null;
       } finally {
@@ -245,3 +275,52 @@ export function useRefundNoWinners() {
 
   return { refundNoWinners, submitting, error };
 }
+
+/**
+ * Hook for cashing out a position: before lock it leaves the pool, once the fight is
+ * live it is sold to the cash-out reserve. The backend picks up the CashedOut and
+ * PositionSold events, so there is no bet-status sync here.
+ */
+export function useCashOut() {
+  const { address } = useAccount();
+  const publicClient = usePublicClient();
+  const { writeContractAsync } = useWriteContract();
+  const [submitting, setSubmitting] = useState(false);
+  const [error, setError] = useState<string | null>(null);
+
+  const cashOut = useCallback(
+    async (matchId: string): Promise<string | null> => {
+      if (!address) {
+        setError("Wallet not connected");
+        return null;
+      }
+      if (!CONTRACT_ADDRESS) {
+        setError("Contract address not configured");
+        return null;
+      }
+
+      setSubmitting(true);
+      setError(null);
+
+      try {
+        const hash = await writeContractAsync({
+          address: CONTRACT_ADDRESS,
+          abi: BETTING_ABI,
+          functionName: 'cashOut',
+          args: [matchIdToBytes32(matchId)],
+        });
+        await publicClient!.waitForTransactionReceipt({ hash, confirmations: 1 });
+        return hash;
+      } catch (err) {
+        const msg = contractErrorMessage(err);
+        setError(msg);
+        return null;
+      } finally {
+        setSubmitting(false);
+      }
+    },
+    [address, publicClient, writeContractAsync],
+  );
+
+  return { cashOut, submitting, error };
+}
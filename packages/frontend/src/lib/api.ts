@@ -1,4 +1,14 @@
-import type { Match, Fighter, PaginatedResponse, LeaderboardEntry, PretrainedModel, Bet } from "@/types";
+import { verifyMessage } from "viem";
+import type {
+  Match,
+  Fighter,
+  PaginatedResponse,
+  LeaderboardEntry,
+  PretrainedModel,
+  Bet,
+  SignedWalletActivity,
+  WalletActivityExport,
+} from "@/types";
 
 export interface PlatformStats {
   total_matches: number;
@@ -93,6 +103,53 @@ export async function syncBetStatus(
   });
 }
 
+/** Fetch one signed page of a wallet's betting activity. */
+export async function exportWalletActivity(
+  wallet: string,
+  params?: { cursor?: string; limit?: number },
+): Promise<SignedWalletActivity> {
+  const searchParams = new URLSearchParams({ wallet });
+  if (params?.cursor) searchParams.set("cursor", params.cursor);
+  if (params?.limit) searchParams.set("limit", String(params.limit));
+  return fetchJson(`/bets/export?${searchParams.toString()}`);
+}
+
+/**
+ * Check an export page against the expected Rawl signer and parse it.
+ * Throws if the page is unsigned or was signed by anyone else.
+ */
+export async function verifyWalletActivity(
+  page: SignedWalletActivity,
+  expectedSigner: `0x${string}`,
+): Promise<WalletActivityExport> {
+  if (!page.signature) throw new Error("Export is not signed");
+  const valid = await verifyMessage({
+    address: expectedSigner,
+    message: page.payload,
+    signature: page.signature as `0x${string}`,
+  });
+  if (!valid) throw new Error("Export signature does not match the expected signer");
+  return JSON.parse(page.payload);
+}
+
+/** Walk every page of a wallet's activity, verifying each one. */
+export async function exportAllWalletActivity(
+  wallet: string,
+  expectedSigner: `0x${string}`,
+): Promise<WalletActivityExport["items"]> {
+  const items: WalletActivityExport["items"] = [];
+  let cursor: string | undefined;
+  do {
+    const page = await verifyWalletActivity(
+      await exportWalletActivity(wallet, { cursor, limit: 500 }),
+      expectedSigner,
+    );
+    items.push(...page.items);
+    cursor = page.next_cursor ?? undefined;
+  } while (cursor);
+  return items;
+}
+
 export async function getStats(): Promise<PlatformStats> {
   return fetchJson("/stats");
 }
@@ -0,0 +1,104 @@
+import { verifyMessage, type Account, type WalletClient } from 'viem'
+import type { BetSide } from '@/types'
+
+/**
+ * Bet tickets: a signed bet intent compact enough for a QR code at live events.
+ *
+ *   rawl:bet?c=<chainId>&k=<contract>&m=<match uuid hex>&s=<a|b>&v=<wei>&x=<expiry>&sig=<0x…>
+ *
+ * The issuer signs the canonical message with personal_sign (EIP-191), so a scanner can
+ * check a printed ticket wasn't altered before turning it into a placeBet. The chain and
+ * contract are part of the message, so a ticket can't be replayed against another deploy.
+ * scripts/rawl_cli.py redeem-ticket reads the same format.
+ */
+
+export interface BetTicket {
+  chainId: number
+  contract: `0x${string}`
+  matchId: string // match UUID, with or without dashes
+  side: BetSide
+  amount: bigint // wei
+  expiry: number // unix seconds
+}
+
+export interface SignedBetTicket extends BetTicket {
+  signature: `0x${string}`
+}
+
+const SCHEME = 'rawl:bet'
+
+/** Canonical signed payload — keep in sync with rawl.evm.tickets.ticket_message. */
+export function betTicketMessage(ticket: BetTicket): string {
+  const matchHex = ticket.matchId.replace(/-/g, '').toLowerCase()
+  return [
+    'rawl-bet-ticket:v1',
+    ticket.chainId,
+    ticket.contract.toLowerCase(),
+    matchHex,
+    ticket.side,
+    ticket.amount.toString(),
+    ticket.expiry,
+  ].join(':')
+}
+
+export async function signBetTicket(
+  wallet: WalletClient,
+  account: Account | `0x${string}`,
+  ticket: BetTicket,
+): Promise<SignedBetTicket> {
+  const signature = await wallet.signMessage({ account, message: betTicketMessage(ticket) })
+  return { ...ticket, signature }
+}
+
+export function encodeBetTicket(ticket: SignedBetTicket): string {
+  const params = new URLSearchParams({
+    c: String(ticket.chainId),
+    k: ticket.contract,
+    m: ticket.matchId.replace(/-/g, ''),
+    s: ticket.side,
+    v: ticket.amount.toString(),
+    x: String(ticket.expiry),
+    sig: ticket.signature,
+  })
+  return `${SCHEME}?${params.toString()}`
+}
+
+/** Parse a scanned ticket URI. Throws on anything malformed; does not check the signature. */
+export function decodeBetTicket(uri: string): SignedBetTicket {
+  if (!uri.startsWith(`${SCHEME}?`)) throw new Error('Not a Rawl bet ticket')
+  const params = new URLSearchParams(uri.slice(SCHEME.length + 1))
+  const get = (key: string) => {
+    const value = params.get(key)
+    if (!value) throw new Error(`Bet ticket is missing "${key}"`)
+    return value
+  }
+
+  const side = get('s')
+  if (side !== 'a' && side !== 'b') throw new Error('Bet ticket side must be a or b')
+  const matchId = get('m')
+  if (!/^[0-9a-f]{32}$/i.test(matchId)) throw new Error('Bet ticket match id is malformed')
+
+  return {
+    chainId: Number(get('c')),
+    contract: get('k') as `0x${string}`,
+    matchId,
+    side,
+    amount: BigInt(get('v')),
+    expiry: Number(get('x')),
+    signature: get('sig') as `0x${string}`,
+  }
+}
+
+/** True if `issuer` signed this ticket and it hasn't expired. */
+export async function verifyBetTicket(
+  ticket: SignedBetTicket,
+  issuer: `0x${string}`,
+  now: number = Math.floor(Date.now() / 1000),
+): Promise<boolean> {
+  if (ticket.expiry <= now) return false
+  return verifyMessage({
+    address: issuer,
+    message: betTicketMessage(ticket),
+    signature: ticket.signature,
+  })
+}
@@ -1,3 +1,5 @@
+import { encodeFunctionData } from 'viem'
+
 export const CONTRACT_ADDRESS = process.env.NEXT_PUBLIC_CONTRACT_ADDRESS as `0x${string}` | undefined
 
 /**
@@ -22,7 +24,35 @@ export const BETTING_ABI = [
       { name: 'bettingWindow', type: 'uint64' },
     ],
     outputs: [],
-    stateMutability: 'nonpayable',
+    stateMutability: 'payable',
+  },
+  // matchMetadata — display name (bytes32, zero-padded UTF-8), metadata URI and the
+  // creation / resolution record commitments
+  {
+    type: 'function',
+    name: 'matchMetadata',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [
+      { name: 'name', type: 'bytes32' },
+      { name: 'uri', type: 'string' },
+      { name: 'metadataHash', type: 'bytes32' },
+      { name: 'resultHash', type: 'bytes32' },
+    ],
+    stateMutability: 'view',
+  },
+  // oddsSnapshots — final odds frozen at lock
+  {
+    type: 'function',
+    name: 'oddsSnapshots',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [
+      { name: 'sideATotal', type: 'uint128' },
+      { name: 'sideBTotal', type: 'uint128' },
+      { name: 'impliedABps', type: 'uint16' },
+      { name: 'impliedBBps', type: 'uint16' },
+      { name: 'lockedAt', type: 'uint64' },
+    ],
+    stateMutability: 'view',
   },
   // placeBet
   {
@@ -35,6 +65,46 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'payable',
   },
+  // placeBetWithReferrer
+  {
+    type: 'function',
+    name: 'placeBetWithReferrer',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+      { name: 'referrer', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // placeBetFor
+  {
+    type: 'function',
+    name: 'placeBetFor',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // registerReferrer
+  {
+    type: 'function',
+    name: 'registerReferrer',
+    inputs: [],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // claimReferralRewards
+  {
+    type: 'function',
+    name: 'claimReferralRewards',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
   // lockMatch
   {
     type: 'function',
@@ -54,6 +124,171 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  // resolveMatchWithOpId
+  {
+    type: 'function',
+    name: 'resolveMatchWithOpId',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'winner', type: 'uint8' },
+      { name: 'opId', type: 'bytes32' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // resolveMatchSigned
+  {
+    type: 'function',
+    name: 'resolveMatchSigned',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'winner', type: 'uint8' },
+      { name: 'timestamp', type: 'uint64' },
+      { name: 'signature', type: 'bytes' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // Switchboard-resolved matches: feed id per match, 0x0 = oracle-resolved
+  {
+    type: 'function',
+    name: 'resultFeeds',
+    inputs: [{ name: '', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'bytes32' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'resolveFromFeed',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // Price-prediction markets: anyone resolves with a Hermes update, paying Pyth's fee
+  {
+    type: 'function',
+    name: 'priceMarkets',
+    inputs: [{ name: '', type: 'bytes32' }],
+    outputs: [
+      { name: 'priceId', type: 'bytes32' },
+      { name: 'strike', type: 'int64' },
+      { name: 'expo', type: 'int32' },
+      { name: 'resolveAt', type: 'uint64' },
+    ],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'resolvePriceMarket',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'priceUpdate', type: 'bytes[]' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // Dispute window: claims wait until disputeDeadlines passes and any challenge is settled
+  {
+    type: 'function',
+    name: 'disputeDeadlines',
+    inputs: [{ name: '', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'uint64' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'challengeResult',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // Optimistic resolution: anyone proposes with disputeBond, final after disputeWindow
+  {
+    type: 'function',
+    name: 'proposeResult',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'winner', type: 'uint8' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  {
+    type: 'function',
+    name: 'challengeProposal',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  {
+    type: 'function',
+    name: 'finalizeProposal',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // Mystery matches: the VRF word that paired the fighters, 0 for a regular match
+  {
+    type: 'function',
+    name: 'mysterySeeds',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'view',
+  },
+  // Chaos exhibitions: the winner is drawn by VRF after lock, the word kept in chaosSeeds
+  {
+    type: 'function',
+    name: 'chaosMatches',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'bool' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'chaosSeeds',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'view',
+  },
+  // Community matches: bets are capped until the guardian veto window closes
+  {
+    type: 'function',
+    name: 'vetoDeadlines',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [{ name: '', type: 'uint64' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'createCommunityMatch',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'fighterA', type: 'address' },
+      { name: 'fighterB', type: 'address' },
+      { name: 'minBet', type: 'uint128' },
+      { name: 'bettingWindow', type: 'uint64' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // Access control readers
+  {
+    type: 'function',
+    name: 'RESOLVE_AUTHORITY_ROLE',
+    inputs: [],
+    outputs: [{ name: '', type: 'bytes32' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'hasRole',
+    inputs: [
+      { name: 'role', type: 'bytes32' },
+      { name: 'account', type: 'address' },
+    ],
+    outputs: [{ name: '', type: 'bool' }],
+    stateMutability: 'view',
+  },
   // claimPayout
   {
     type: 'function',
@@ -62,6 +297,174 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  // claimPayoutTo
+  {
+    type: 'function',
+    name: 'claimPayoutTo',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'recipient', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // claimAll
+  {
+    type: 'function',
+    name: 'claimAll',
+    inputs: [{ name: 'matchIds', type: 'bytes32[]' }],
+    outputs: [{ name: 'total', type: 'uint256' }],
+    stateMutability: 'nonpayable',
+  },
+  // claimFirstBetRefund — risk-free first bet promotion
+  {
+    type: 'function',
+    name: 'claimFirstBetRefund',
+    inputs: [],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // firstBets
+  {
+    type: 'function',
+    name: 'firstBets',
+    inputs: [{ name: 'bettor', type: 'address' }],
+    outputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'insured', type: 'uint128' },
+      { name: 'refunded', type: 'bool' },
+    ],
+    stateMutability: 'view',
+  },
+  // feeTierOf — volume fee discount tier (0 = none)
+  {
+    type: 'function',
+    name: 'feeTierOf',
+    inputs: [{ name: 'bettor', type: 'address' }],
+    outputs: [{ name: 'tier', type: 'uint8' }],
+    stateMutability: 'view',
+  },
+  // rollingVolume — 30-day staked volume
+  {
+    type: 'function',
+    name: 'rollingVolume',
+    inputs: [{ name: 'bettor', type: 'address' }],
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'view',
+  },
+  // repayClawback — settle a clawback recorded after a result overturn
+  {
+    type: 'function',
+    name: 'repayClawback',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // clawbackDebt — outstanding clawbacks blocking payout claims
+  {
+    type: 'function',
+    name: 'clawbackDebt',
+    inputs: [{ name: 'bettor', type: 'address' }],
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'view',
+  },
+  // rollOver
+  {
+    type: 'function',
+    name: 'rollOver',
+    inputs: [
+      { name: 'fromMatchId', type: 'bytes32' },
+      { name: 'toMatchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // placeBetOrEscrow — late bets are held for refund instead of reverting
+  {
+    type: 'function',
+    name: 'placeBetOrEscrow',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // refundPendingBet
+  {
+    type: 'function',
+    name: 'refundPendingBet',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // pendingBets
+  {
+    type: 'function',
+    name: 'pendingBets',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [{ name: '', type: 'uint256' }],
+    stateMutability: 'view',
+  },
+  // sponsorMatch
+  {
+    type: 'function',
+    name: 'sponsorMatch',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'capPerBet', type: 'uint128' },
+      { name: 'matchBps', type: 'uint16' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // withdrawSponsorship
+  {
+    type: 'function',
+    name: 'withdrawSponsorship',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // seedMatch — approved LPs bootstrap both sides
+  {
+    type: 'function',
+    name: 'seedMatch',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'sideA', type: 'uint128' },
+      { name: 'sideB', type: 'uint128' },
+    ],
+    outputs: [],
+    stateMutability: 'payable',
+  },
+  // redeemSeed
+  {
+    type: 'function',
+    name: 'redeemSeed',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [{ name: 'amount', type: 'uint256' }],
+    stateMutability: 'nonpayable',
+  },
+  // placeBetWithVoucher
+  {
+    type: 'function',
+    name: 'placeBetWithVoucher',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+      { name: 'voucherId', type: 'bytes32' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
   // refundNoWinners
   {
     type: 'function',
@@ -70,6 +473,14 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  // cashOut
+  {
+    type: 'function',
+    name: 'cashOut',
+    inputs: [{ name: 'matchId', type: 'bytes32' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
   // cancelMatch
   {
     type: 'function',
@@ -86,6 +497,32 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  // programVersion / minClientVersion — see lib/version.ts
+  {
+    type: 'function',
+    name: 'programVersion',
+    inputs: [],
+    outputs: [{ name: '', type: 'uint32' }],
+    stateMutability: 'view',
+  },
+  {
+    type: 'function',
+    name: 'minClientVersion',
+    inputs: [],
+    outputs: [{ name: '', type: 'uint32' }],
+    stateMutability: 'view',
+  },
+  // timeoutBounds — range the admin may set matchTimeout to
+  {
+    type: 'function',
+    name: 'timeoutBounds',
+    inputs: [],
+    outputs: [
+      { name: 'min', type: 'uint64' },
+      { name: 'max', type: 'uint64' },
+    ],
+    stateMutability: 'pure',
+  },
   // refundBet
   {
     type: 'function',
@@ -94,6 +531,74 @@ export const BETTING_ABI = [
     outputs: [],
     stateMutability: 'nonpayable',
   },
+  // placeBetAsPosition
+  {
+    type: 'function',
+    name: 'placeBetAsPosition',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'side', type: 'uint8' },
+    ],
+    outputs: [{ name: 'tokenId', type: 'uint256' }],
+    stateMutability: 'payable',
+  },
+  // redeemPosition
+  {
+    type: 'function',
+    name: 'redeemPosition',
+    inputs: [{ name: 'tokenId', type: 'uint256' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // setClaimDelegate
+  {
+    type: 'function',
+    name: 'setClaimDelegate',
+    inputs: [{ name: 'delegate', type: 'address' }],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // claimPayoutFor (delegate session key, proceeds go to bettor)
+  {
+    type: 'function',
+    name: 'claimPayoutFor',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // refundBetFor
+  {
+    type: 'function',
+    name: 'refundBetFor',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // refundNoWinnersFor
+  {
+    type: 'function',
+    name: 'refundNoWinnersFor',
+    inputs: [
+      { name: 'matchId', type: 'bytes32' },
+      { name: 'bettor', type: 'address' },
+    ],
+    outputs: [],
+    stateMutability: 'nonpayable',
+  },
+  // claimDelegates mapping reader
+  {
+    type: 'function',
+    name: 'claimDelegates',
+    inputs: [{ name: 'bettor', type: 'address' }],
+    outputs: [{ name: 'delegate', type: 'address' }],
+    stateMutability: 'view',
+  },
   // matches mapping reader
   {
     type: 'function',
@@ -145,6 +650,19 @@ export const BETTING_ABI = [
       { name: 'bettor', type: 'address', indexed: true },
       { name: 'side', type: 'uint8', indexed: false },
       { name: 'amount', type: 'uint256', indexed: false },
+      { name: 'sideATotal', type: 'uint128', indexed: false },
+      { name: 'sideBTotal', type: 'uint128', indexed: false },
+    ],
+  },
+  {
+    type: 'event',
+    name: 'MatchResolved',
+    inputs: [
+      { name: 'matchId', type: 'bytes32', indexed: true },
+      { name: 'winner', type: 'uint8', indexed: false },
+      { name: 'sideATotal', type: 'uint128', indexed: false },
+      { name: 'sideBTotal', type: 'uint128', indexed: false },
+      { name: 'timestamp', type: 'uint64', indexed: false },
     ],
   },
   {
@@ -165,6 +683,16 @@ export const BETTING_ABI = [
       { name: 'amount', type: 'uint256', indexed: false },
     ],
   },
+  {
+    type: 'event',
+    name: 'CashedOut',
+    inputs: [
+      { name: 'matchId', type: 'bytes32', indexed: true },
+      { name: 'bettor', type: 'address', indexed: true },
+      { name: 'amount', type: 'uint256', indexed: false },
+      { name: 'retained', type: 'uint256', indexed: false },
+    ],
+  },
   {
     type: 'event',
     name: 'NoWinnersRefunded',
@@ -174,4 +702,88 @@ export const BETTING_ABI = [
       { name: 'amount', type: 'uint256', indexed: false },
     ],
   },
+  // Errors (bettor-facing — localized in lib/messages.ts)
+  { type: 'error', name: 'MatchNotOpen', inputs: [] },
+  { type: 'error', name: 'MatchNotLocked', inputs: [] },
+  { type: 'error', name: 'MatchNotResolved', inputs: [] },
+  { type: 'error', name: 'MatchNotCancelled', inputs: [] },
+  { type: 'error', name: 'ZeroBetAmount', inputs: [] },
+  { type: 'error', name: 'BetBelowMinimum', inputs: [{ name: 'min', type: 'uint128' }] },
+  { type: 'error', name: 'BettingWindowClosed', inputs: [] },
+  { type: 'error', name: 'BettingNotOpen', inputs: [{ name: 'opensAt', type: 'uint64' }] },
+  { type: 'error', name: 'AlreadyBet', inputs: [] },
+  { type: 'error', name: 'NoBetFound', inputs: [] },
+  { type: 'error', name: 'AlreadyClaimed', inputs: [] },
+  { type: 'error', name: 'BetOnLosingSide', inputs: [] },
+  { type: 'error', name: 'WinnersExist', inputs: [] },
+  { type: 'error', name: 'PoolCapExceeded', inputs: [{ name: 'cap', type: 'uint128' }] },
+  { type: 'error', name: 'UnpauseGracePeriodActive', inputs: [{ name: 'resumesAt', type: 'uint64' }] },
+  { type: 'error', name: 'CashOutDisabled', inputs: [] },
+  { type: 'error', name: 'PositionsDisabled', inputs: [] },
+  { type: 'error', name: 'AddressSanctioned', inputs: [{ name: 'account', type: 'address' }] },
+  { type: 'error', name: 'NotPositionOwner', inputs: [] },
+  { type: 'error', name: 'InvalidBettor', inputs: [] },
+  { type: 'error', name: 'NotClaimDelegate', inputs: [] },
+  { type: 'error', name: 'InvalidRecipient', inputs: [] },
+  { type: 'error', name: 'VoucherNotFound', inputs: [] },
+  { type: 'error', name: 'VoucherExpired', inputs: [] },
+  { type: 'error', name: 'VoucherAlreadyRedeemed', inputs: [] },
+  { type: 'error', name: 'NoPendingBet', inputs: [] },
+  { type: 'error', name: 'NotSponsor', inputs: [] },
+  { type: 'error', name: 'NoSponsorBudget', inputs: [] },
+  { type: 'error', name: 'InvalidBatchSize', inputs: [] },
+  { type: 'error', name: 'NoFirstBetInsurance', inputs: [] },
+  { type: 'error', name: 'FirstBetNotLost', inputs: [] },
+  { type: 'error', name: 'OutstandingClawback', inputs: [] },
+  { type: 'error', name: 'DisputeWindowActive', inputs: [{ name: 'endsAt', type: 'uint64' }] },
+  { type: 'error', name: 'ResultDisputed', inputs: [] },
+  { type: 'error', name: 'VetoWindowCapExceeded', inputs: [{ name: 'cap', type: 'uint128' }] },
+  { type: 'error', name: 'SpendLimitExceeded', inputs: [{ name: 'limit', type: 'uint256' }] },
+  {
+    type: 'error',
+    name: 'ClaimCooldownActive',
+    inputs: [{ name: 'claimableAt', type: 'uint64' }],
+  },
+  { type: 'error', name: 'ReferrerNotRegistered', inputs: [] },
+  { type: 'error', name: 'SelfReferral', inputs: [] },
+  { type: 'error', name: 'EnforcedPause', inputs: [] },
 ] as const
+
+/**
+ * Transaction payload for betting from a multisig vault (e.g. a Safe proposal).
+ * The vault executes the call itself, so it is the on-chain bettor and later claims
+ * claimPayout / refundBet from its own address the same way.
+ */
+export function buildVaultBetTx(matchId: string, side: 0 | 1, valueWei: bigint) {
+  if (!CONTRACT_ADDRESS) throw new Error('Contract address not configured')
+  return {
+    to: CONTRACT_ADDRESS,
+    value: valueWei,
+    data: encodeFunctionData({
+      abi: BETTING_ABI,
+      functionName: 'placeBet',
+      args: [matchIdToBytes32(matchId), side],
+    }),
+  }
+}
+
+/** Mirrors RawlBetting.MAX_CLAIM_BATCH */
+export const MAX_CLAIM_BATCH = 20
+
+/**
+ * claimAll payloads for a wallet's unclaimed winning matches, chunked to MAX_CLAIM_BATCH.
+ * claimAll reverts if any entry is not claimable, so pass only won, unclaimed matches.
+ * For wallets with signing limits, use planSettlement in lib/settlementPlan.ts instead.
+ */
+export function buildClaimAllTxs(matchIds: string[]) {
+  if (!CONTRACT_ADDRESS) throw new Error('Contract address not configured')
+  const txs = []
+  for (let i = 0; i < matchIds.length; i += MAX_CLAIM_BATCH) {
+    const batch = matchIds.slice(i, i + MAX_CLAIM_BATCH).map(matchIdToBytes32)
+    txs.push({
+      to: CONTRACT_ADDRESS,
+      data: encodeFunctionData({ abi: BETTING_ABI, functionName: 'claimAll', args: [batch] }),
+    })
+  }
+  return txs
+}
@@ -0,0 +1,419 @@
+import { BaseError, ContractFunctionRevertedError } from 'viem'
+
+/**
+ * Localization tables for contract codes.
+ *
+ * ConfigUpdated emits a numeric ConfigField code and reverts carry a custom-error name,
+ * so non-English UIs look them up here instead of parsing English strings out of logs.
+ */
+
+export type Locale = 'en' | 'es' | 'pt'
+
+/** Index = on-chain RawlBetting.ConfigField code (append only, mirrors the contract enum). */
+export const CONFIG_FIELDS = [
+  'feeBps',
+  'matchTimeout',
+  'treasury',
+  'referralShareBps',
+  'maxPoolSize',
+  'royaltyShareBps',
+  'fighterCollection',
+  'unpauseGracePeriod',
+  'maxMatchAge',
+  'eventLogBetThreshold',
+  'minBetsPerSide',
+  'minTotalPool',
+  'snipeThresholdBps',
+  'snipeWindow',
+  'snipeExtension',
+  'maxSnipeExtension',
+  'cashOutDiscountBps',
+  'betPositions',
+  'screeningOracle',
+  'programVersion',
+  'minClientVersion',
+  'maxOpenMatchesPerCreator',
+  'firstBetInsuranceCap',
+  'feeTiers',
+  'creatorBond',
+  'treasuryHook',
+  'resolutionThreshold',
+  'donation',
+  'switchboard',
+  'feeTreasury',
+  'sweepTreasury',
+  'dustTreasury',
+  'pyth',
+  'disputeWindow',
+  'disputeBond',
+  'vrfCoordinator',
+  'vetoWindow',
+  'vetoBetCap',
+  'spendLimit',
+  'dualSignPoolSize',
+  'claimCooldown',
+  'minResolutionDelay',
+  'commitRevealRequired',
+  'oracleSilencePeriod',
+  'silentOracleTimeout',
+  'resolutionDeadline',
+  'cashOutMatchCap',
+] as const
+export type ConfigField = (typeof CONFIG_FIELDS)[number]
+
+const CONFIG_FIELD_LABELS: Record<Locale, Record<ConfigField, string>> = {
+  en: {
+    feeBps: 'Platform fee',
+    matchTimeout: 'Match timeout',
+    treasury: 'Treasury address',
+    referralShareBps: 'Referral share',
+    maxPoolSize: 'Maximum pool size',
+    royaltyShareBps: 'Fighter royalty share',
+    fighterCollection: 'Fighter collection',
+    unpauseGracePeriod: 'Unpause grace period',
+    maxMatchAge: 'Maximum match age',
+    eventLogBetThreshold: 'Event log bet threshold',
+    minBetsPerSide: 'Minimum bets per side',
+    minTotalPool: 'Minimum total pool',
+    snipeThresholdBps: 'Anti-snipe bet threshold',
+    snipeWindow: 'Anti-snipe window',
+    snipeExtension: 'Anti-snipe extension',
+    maxSnipeExtension: 'Maximum anti-snipe extension',
+    cashOutDiscountBps: 'Cash-out discount',
+    betPositions: 'Bet position NFT contract',
+    screeningOracle: 'Address screening oracle',
+    programVersion: 'Contract version',
+    minClientVersion: 'Minimum app version',
+    maxOpenMatchesPerCreator: 'Open matches per creator',
+    firstBetInsuranceCap: 'Risk-free first bet cap',
+    feeTiers: 'Volume fee tiers',
+    creatorBond: 'Match creator bond',
+    treasuryHook: 'Treasury diversification hook',
+    resolutionThreshold: 'Oracle resolution threshold',
+    donation: 'Fee donation share',
+    switchboard: 'Switchboard result feeds',
+    feeTreasury: 'Fee withdrawal destination',
+    sweepTreasury: 'Unclaimed sweep destination',
+    dustTreasury: 'Cash-out haircut destination',
+    pyth: 'Pyth price oracle',
+    disputeWindow: 'Result dispute window',
+    disputeBond: 'Dispute challenge bond',
+    vrfCoordinator: 'VRF matchmaking coordinator',
+    vetoWindow: 'Community match veto window',
+    vetoBetCap: 'Bet cap during veto window',
+    spendLimit: 'Co-signed debit threshold',
+    dualSignPoolSize: 'Dual-signature pool size',
+    claimCooldown: 'Claim cooldown after resolution',
+    minResolutionDelay: 'Minimum delay from lock to result',
+    commitRevealRequired: 'Results must be committed at lock',
+    oracleSilencePeriod: 'Oracle silence before it counts as down',
+    silentOracleTimeout: 'Match timeout while the oracle is down',
+    resolutionDeadline: 'Deadline for a match result',
+    cashOutMatchCap: 'In-play cash-out limit per match',
+  },
+  es: {
+    feeBps: 'Comisión de la plataforma',
+    matchTimeout: 'Tiempo límite del combate',
+    treasury: 'Dirección de tesorería',
+    referralShareBps: 'Participación de referidos',
+    maxPoolSize: 'Tamaño máximo del pozo',
+    royaltyShareBps: 'Regalía del luchador',
+    fighterCollection: 'Colección de luchadores',
+    unpauseGracePeriod: 'Periodo de gracia tras reanudar',
+    maxMatchAge: 'Antigüedad máxima del combate',
+    eventLogBetThreshold: 'Umbral de apuestas del registro',
+    minBetsPerSide: 'Apuestas mínimas por lado',
+    minTotalPool: 'Pozo total mínimo',
+    snipeThresholdBps: 'Umbral anti-francotirador',
+    snipeWindow: 'Ventana anti-francotirador',
+    snipeExtension: 'Extensión anti-francotirador',
+    maxSnipeExtension: 'Extensión anti-francotirador máxima',
+    cashOutDiscountBps: 'Descuento por retiro anticipado',
+    betPositions: 'Contrato NFT de posiciones',
+    screeningOracle: 'Oráculo de verificación de direcciones',
+    programVersion: 'Versión del contrato',
+    minClientVersion: 'Versión mínima de la app',
+    maxOpenMatchesPerCreator: 'Combates abiertos por creador',
+    firstBetInsuranceCap: 'Tope de primera apuesta sin riesgo',
+    feeTiers: 'Niveles de comisión por volumen',
+    creatorBond: 'Fianza del creador de combate',
+    treasuryHook: 'Hook de diversificación de tesorería',
+    resolutionThreshold: 'Umbral de resolución de oráculos',
+    donation: 'Parte de comisiones donada',
+    switchboard: 'Feeds de resultados de Switchboard',
+    feeTreasury: 'Destino de retiro de comisiones',
+    sweepTreasury: 'Destino de barridos no reclamados',
+    dustTreasury: 'Destino de descuentos de cash-out',
+    pyth: 'Oráculo de precios Pyth',
+    disputeWindow: 'Periodo de disputa de resultados',
+    disputeBond: 'Fianza para impugnar',
+    vrfCoordinator: 'Coordinador VRF de emparejamientos',
+    vetoWindow: 'Plazo de veto de combates comunitarios',
+    vetoBetCap: 'Tope de apuestas durante el veto',
+    spendLimit: 'Umbral de retiros con cofirma',
+    dualSignPoolSize: 'Bolsa con doble firma',
+    claimCooldown: 'Espera de cobro tras el resultado',
+    minResolutionDelay: 'Espera mínima entre cierre y resultado',
+    commitRevealRequired: 'Resultado comprometido al cierre',
+    oracleSilencePeriod: 'Silencio del oráculo antes de darlo por caído',
+    silentOracleTimeout: 'Tiempo límite con el oráculo caído',
+    resolutionDeadline: 'Plazo para el resultado del combate',
+    cashOutMatchCap: 'Límite de retiro anticipado en juego por combate',
+  },
+  pt: {
+    feeBps: 'Taxa da plataforma',
+    matchTimeout: 'Tempo limite da luta',
+    treasury: 'Endereço da tesouraria',
+    referralShareBps: 'Participação de indicações',
+    maxPoolSize: 'Tamanho máximo do pool',
+    royaltyShareBps: 'Royalty do lutador',
+    fighterCollection: 'Coleção de lutadores',
+    unpauseGracePeriod: 'Período de carência após retomada',
+    maxMatchAge: 'Idade máxima da luta',
+    eventLogBetThreshold: 'Limite de apostas do registro',
+    minBetsPerSide: 'Apostas mínimas por lado',
+    minTotalPool: 'Pool total mínimo',
+    snipeThresholdBps: 'Limite anti-sniping',
+    snipeWindow: 'Janela anti-sniping',
+    snipeExtension: 'Extensão anti-sniping',
+    maxSnipeExtension: 'Extensão anti-sniping máxima',
+    cashOutDiscountBps: 'Desconto de saque antecipado',
+    betPositions: 'Contrato NFT de posições',
+    screeningOracle: 'Oráculo de verificação de endereços',
+    programVersion: 'Versão do contrato',
+    minClientVersion: 'Versão mínima do app',
+    maxOpenMatchesPerCreator: 'Lutas abertas por criador',
+    firstBetInsuranceCap: 'Limite da primeira aposta sem risco',
+    feeTiers: 'Níveis de taxa por volume',
+    creatorBond: 'Caução do criador da luta',
+    treasuryHook: 'Hook de diversificação da tesouraria',
+    resolutionThreshold: 'Limite de resolução dos oráculos',
+    donation: 'Parcela das taxas doada',
+    switchboard: 'Feeds de resultado do Switchboard',
+    feeTreasury: 'Destino dos saques de taxas',
+    sweepTreasury: 'Destino das varreduras não resgatadas',
+    dustTreasury: 'Destino dos descontos de cash-out',
+    pyth: 'Oráculo de preços Pyth',
+    disputeWindow: 'Janela de contestação de resultados',
+    disputeBond: 'Caução de contestação',
+    vrfCoordinator: 'Coordenador VRF de confrontos',
+    vetoWindow: 'Prazo de veto de lutas da comunidade',
+    vetoBetCap: 'Limite de apostas durante o veto',
+    spendLimit: 'Limite de débitos com coassinatura',
+    dualSignPoolSize: 'Pool com dupla assinatura',
+    claimCooldown: 'Espera de resgate após o resultado',
+    minResolutionDelay: 'Espera mínima entre fechamento e resultado',
+    commitRevealRequired: 'Resultado comprometido no fechamento',
+    oracleSilencePeriod: 'Silêncio do oráculo antes de considerá-lo fora do ar',
+    silentOracleTimeout: 'Tempo limite com o oráculo fora do ar',
+    resolutionDeadline: 'Prazo para o resultado da luta',
+    cashOutMatchCap: 'Limite de saque antecipado ao vivo por luta',
+  },
+}
+
+/** Custom errors a bettor can hit — each has a matching entry in BETTING_ABI. */
+export type ContractErrorName =
+  | 'MatchNotOpen'
+  | 'MatchNotLocked'
+  | 'MatchNotResolved'
+  | 'MatchNotCancelled'
+  | 'ZeroBetAmount'
+  | 'BetBelowMinimum'
+  | 'BettingWindowClosed'
+  | 'BettingNotOpen'
+  | 'AlreadyBet'
+  | 'NoBetFound'
+  | 'AlreadyClaimed'
+  | 'BetOnLosingSide'
+  | 'WinnersExist'
+  | 'PoolCapExceeded'
+  | 'UnpauseGracePeriodActive'
+  | 'CashOutDisabled'
+  | 'InsufficientCashOutReserve'
+  | 'CashOutLimitReached'
+  | 'PositionsDisabled'
+  | 'NotPositionOwner'
+  | 'InvalidBettor'
+  | 'NotClaimDelegate'
+  | 'InvalidRecipient'
+  | 'VoucherNotFound'
+  | 'VoucherExpired'
+  | 'VoucherAlreadyRedeemed'
+  | 'NoPendingBet'
+  | 'NotSponsor'
+  | 'NoSponsorBudget'
+  | 'InvalidBatchSize'
+  | 'NoFirstBetInsurance'
+  | 'FirstBetNotLost'
+  | 'OutstandingClawback'
+  | 'DisputeWindowActive'
+  | 'ResultDisputed'
+  | 'VetoWindowCapExceeded'
+  | 'SpendLimitExceeded'
+  | 'ClaimCooldownActive'
+  | 'ReferrerNotRegistered'
+  | 'SelfReferral'
+  | 'AddressSanctioned'
+  | 'EnforcedPause'
+
+const CONTRACT_ERROR_MESSAGES: Record<Locale, Record<ContractErrorName, string>> = {
+  en: {
+    MatchNotOpen: 'Betting is closed for this match.',
+    MatchNotLocked: 'This match is not in progress.',
+    MatchNotResolved: 'This match has not been resolved yet.',
+    MatchNotCancelled: 'This match was not cancelled.',
+    ZeroBetAmount: 'Enter an amount greater than zero.',
+    BetBelowMinimum: 'Your bet is below the minimum for this match.',
+    BettingWindowClosed: 'The betting window has closed.',
+    BettingNotOpen: 'Betting has not opened yet.',
+    AlreadyBet: 'You already have a bet on this match.',
+    NoBetFound: 'No bet found for this wallet.',
+    AlreadyClaimed: 'This bet has already been settled.',
+    BetOnLosingSide: 'This bet was on the losing side.',
+    WinnersExist: 'This match has winners — claim your payout instead.',
+    PoolCapExceeded: 'This bet would exceed the pool limit.',
+    UnpauseGracePeriodActive: 'Betting resumes shortly after maintenance.',
+    CashOutDisabled: 'Cash-out is not available.',
+    InsufficientCashOutReserve: 'Cash-out is unavailable right now. Try again later.',
+    CashOutLimitReached: 'In-play cash-out is full for this match.',
+    PositionsDisabled: 'Bet positions are not enabled.',
+    NotPositionOwner: 'You do not hold this bet position.',
+    InvalidBettor: 'Enter a valid recipient wallet.',
+    NotClaimDelegate: 'This device is not authorized to claim for that wallet.',
+    InvalidRecipient: 'Enter a valid payout address.',
+    VoucherNotFound: 'This free bet is not available to your wallet.',
+    VoucherExpired: 'This free bet has expired.',
+    VoucherAlreadyRedeemed: 'This free bet has already been used.',
+    NoPendingBet: 'There is no late bet waiting to be refunded.',
+    NotSponsor: 'Another wallet already sponsors this match.',
+    NoSponsorBudget: 'There is no sponsor budget left to withdraw.',
+    InvalidBatchSize: 'Select between 1 and 20 matches to claim at once.',
+    NoFirstBetInsurance: 'This wallet has no risk-free first bet to claim.',
+    FirstBetNotLost: 'Your first bet did not lose, so there is nothing to refund.',
+    OutstandingClawback:
+      'A corrected result left this wallet owing a clawback. Repay it before claiming payouts.',
+    DisputeWindowActive: 'The result can still be challenged. Claims open when the window ends.',
+    ResultDisputed: 'This result is under dispute. Claims open once it is settled.',
+    VetoWindowCapExceeded: 'This community match is still in review. Try a smaller bet or wait.',
+    SpendLimitExceeded: 'This payout is large enough to need a second approval. Try again later.',
+    ClaimCooldownActive: 'Payouts open shortly after the result is posted. Try again soon.',
+    ReferrerNotRegistered: 'This referral link is not valid.',
+    SelfReferral: 'You cannot refer yourself.',
+    AddressSanctioned: 'This wallet cannot place bets.',
+    EnforcedPause: 'Betting is paused for maintenance.',
+  },
+  es: {
+    MatchNotOpen: 'Las apuestas están cerradas para este combate.',
+    MatchNotLocked: 'Este combate no está en curso.',
+    MatchNotResolved: 'Este combate aún no se ha resuelto.',
+    MatchNotCancelled: 'Este combate no fue cancelado.',
+    ZeroBetAmount: 'Introduce un monto mayor que cero.',
+    BetBelowMinimum: 'Tu apuesta está por debajo del mínimo de este combate.',
+    BettingWindowClosed: 'El periodo de apuestas ha terminado.',
+    BettingNotOpen: 'Las apuestas aún no se han abierto.',
+    AlreadyBet: 'Ya tienes una apuesta en este combate.',
+    NoBetFound: 'No se encontró ninguna apuesta para esta billetera.',
+    AlreadyClaimed: 'Esta apuesta ya fue liquidada.',
+    BetOnLosingSide: 'Esta apuesta fue al lado perdedor.',
+    WinnersExist: 'Este combate tiene ganadores: reclama tu pago.',
+    PoolCapExceeded: 'Esta apuesta superaría el límite del pozo.',
+    UnpauseGracePeriodActive: 'Las apuestas se reanudarán en breve tras el mantenimiento.',
+    CashOutDisabled: 'El retiro anticipado no está disponible.',
+    InsufficientCashOutReserve: 'El retiro anticipado no está disponible. Inténtalo más tarde.',
+    CashOutLimitReached: 'Se alcanzó el límite de retiro anticipado en juego de este combate.',
+    PositionsDisabled: 'Las posiciones de apuesta no están habilitadas.',
+    NotPositionOwner: 'No posees esta posición de apuesta.',
+    InvalidBettor: 'Introduce una billetera destinataria válida.',
+    NotClaimDelegate: 'Este dispositivo no está autorizado a cobrar por esa billetera.',
+    InvalidRecipient: 'Introduce una dirección de pago válida.',
+    VoucherNotFound: 'Esta apuesta gratis no está disponible para tu billetera.',
+    VoucherExpired: 'Esta apuesta gratis ha caducado.',
+    VoucherAlreadyRedeemed: 'Esta apuesta gratis ya fue utilizada.',
+    NoPendingBet: 'No hay ninguna apuesta tardía pendiente de reembolso.',
+    NotSponsor: 'Otra billetera ya patrocina este combate.',
+    NoSponsorBudget: 'No queda presupuesto de patrocinio por retirar.',
+    InvalidBatchSize: 'Selecciona entre 1 y 20 combates para cobrar a la vez.',
+    NoFirstBetInsurance: 'Esta billetera no tiene una primera apuesta sin riesgo por cobrar.',
+    FirstBetNotLost: 'Tu primera apuesta no perdió, así que no hay nada que reembolsar.',
+    OutstandingClawback:
+      'Un resultado corregido dejó a esta billetera con una deuda. Págala antes de cobrar.',
+    DisputeWindowActive: 'El resultado aún puede impugnarse. Los cobros abren al cerrar el plazo.',
+    ResultDisputed: 'Este resultado está en disputa. Los cobros abren cuando se resuelva.',
+    VetoWindowCapExceeded: 'Este combate comunitario sigue en revisión. Apuesta menos o espera.',
+    SpendLimitExceeded: 'Este pago es grande y necesita una segunda aprobación. Reintenta luego.',
+    ClaimCooldownActive: 'Los cobros abren poco después del resultado. Reintenta pronto.',
+    ReferrerNotRegistered: 'Este enlace de referido no es válido.',
+    SelfReferral: 'No puedes referirte a ti mismo.',
+    AddressSanctioned: 'Esta billetera no puede realizar apuestas.',
+    EnforcedPause: 'Las apuestas están en pausa por mantenimiento.',
+  },
+  pt: {
+    MatchNotOpen: 'As apostas estão fechadas para esta luta.',
+    MatchNotLocked: 'Esta luta não está em andamento.',
+    MatchNotResolved: 'Esta luta ainda não foi resolvida.',
+    MatchNotCancelled: 'Esta luta não foi cancelada.',
+    ZeroBetAmount: 'Informe um valor maior que zero.',
+    BetBelowMinimum: 'Sua aposta está abaixo do mínimo desta luta.',
+    BettingWindowClosed: 'O período de apostas foi encerrado.',
+    BettingNotOpen: 'As apostas ainda não foram abertas.',
+    AlreadyBet: 'Você já tem uma aposta nesta luta.',
+    NoBetFound: 'Nenhuma aposta encontrada para esta carteira.',
+    AlreadyClaimed: 'Esta aposta já foi liquidada.',
+    BetOnLosingSide: 'Esta aposta foi no lado perdedor.',
+    WinnersExist: 'Esta luta tem vencedores — resgate seu pagamento.',
+    PoolCapExceeded: 'Esta aposta excederia o limite do pool.',
+    UnpauseGracePeriodActive: 'As apostas serão retomadas em breve após a manutenção.',
+    CashOutDisabled: 'O saque antecipado não está disponível.',
+    InsufficientCashOutReserve: 'O saque antecipado está indisponível agora. Tente mais tarde.',
+    CashOutLimitReached: 'O limite de saque antecipado ao vivo desta luta foi atingido.',
+    PositionsDisabled: 'As posições de aposta não estão habilitadas.',
+    NotPositionOwner: 'Você não possui esta posição de aposta.',
+    InvalidBettor: 'Informe uma carteira de destino válida.',
+    NotClaimDelegate: 'Este dispositivo não está autorizado a resgatar por essa carteira.',
+    InvalidRecipient: 'Informe um endereço de pagamento válido.',
+    VoucherNotFound: 'Esta aposta grátis não está disponível para sua carteira.',
+    VoucherExpired: 'Esta aposta grátis expirou.',
+    VoucherAlreadyRedeemed: 'Esta aposta grátis já foi usada.',
+    NoPendingBet: 'Não há nenhuma aposta atrasada aguardando reembolso.',
+    NotSponsor: 'Outra carteira já patrocina esta luta.',
+    NoSponsorBudget: 'Não há orçamento de patrocínio para sacar.',
+    InvalidBatchSize: 'Selecione entre 1 e 20 lutas para resgatar de uma vez.',
+    NoFirstBetInsurance: 'Esta carteira não tem primeira aposta sem risco para resgatar.',
+    FirstBetNotLost: 'Sua primeira aposta não perdeu, então não há nada a reembolsar.',
+    OutstandingClawback:
+      'Um resultado corrigido deixou esta carteira com um débito. Pague-o antes de resgatar.',
+    DisputeWindowActive: 'O resultado ainda pode ser contestado. Resgates abrem ao fim do prazo.',
+    ResultDisputed: 'Este resultado está em disputa. Os resgates abrem quando for decidido.',
+    VetoWindowCapExceeded: 'Esta luta da comunidade segue em revisão. Aposte menos ou aguarde.',
+    SpendLimitExceeded: 'Este pagamento precisa de uma segunda aprovação. Tente mais tarde.',
+    ClaimCooldownActive: 'Os resgates abrem logo após o resultado. Tente novamente em breve.',
+    ReferrerNotRegistered: 'Este link de indicação não é válido.',
+    SelfReferral: 'Você não pode indicar a si mesmo.',
+    AddressSanctioned: 'Esta carteira não pode fazer apostas.',
+    EnforcedPause: 'As apostas estão pausadas para manutenção.',
+  },
+}
+
+/** Label for a ConfigUpdated field code, or null for codes newer than this table. */
+export function configFieldLabel(code: number, locale: Locale = 'en'): string | null {
+  const field = CONFIG_FIELDS[code]
+  return field ? CONFIG_FIELD_LABELS[locale][field] : null
+}
+
+/**
+ * Localized message for a failed contract write, falling back to the raw error message
+ * for reverts this table doesn't cover (wallet rejections, RPC errors, ...).
+ */
+export function contractErrorMessage(err: unknown, locale: Locale = 'en'): string {
+  if (err instanceof BaseError) {
+    const reverted = err.walk((e) => e instanceof ContractFunctionRevertedError)
+    if (reverted instanceof ContractFunctionRevertedError) {
+      const name = reverted.data?.errorName as ContractErrorName | undefined
+      if (name && name in CONTRACT_ERROR_MESSAGES[locale]) {
+        return CONTRACT_ERROR_MESSAGES[locale][name]
+      }
+    }
+    return err.shortMessage
+  }
+  return err instanceof Error ? err.message : String(err)
+}
@@ -0,0 +1,126 @@
+import { encodeFunctionData } from 'viem'
+import { BETTING_ABI, CONTRACT_ADDRESS, MAX_CLAIM_BATCH, matchIdToBytes32 } from './contracts'
+
+/**
+ * Size-aware signing plan for settling many matches from one wallet.
+ *
+ * Mobile and QR-signing wallets reject transactions above their own gas or payload limits,
+ * and a claimAll of MAX_CLAIM_BATCH matches can exceed both. The planner packs claims into
+ * claimAll batches that stay under the configured limits and gives every step an explicit
+ * gas limit, so the wallet never has to estimate one. Refunds have no batch entry point
+ * and are planned one per transaction. (Base has no address lookup tables — calldata is
+ * already 32 bytes per match id.)
+ */
+
+export type SettlementKind = 'claim' | 'refund' | 'refundNoWinners'
+
+export interface SettlementItem {
+  matchId: string
+  kind: SettlementKind
+}
+
+export interface SigningLimits {
+  maxGasPerTx?: bigint // default DEFAULT_MAX_GAS_PER_TX
+  maxCalldataBytes?: number // unlimited when omitted
+}
+
+export interface SigningStep {
+  functionName: 'claimAll' | 'claimPayout' | 'refundBet' | 'refundNoWinners'
+  matchIds: string[]
+  tx: { to: `0x${string}`; data: `0x${string}`; gas: bigint }
+}
+
+// Deliberately generous per-step gas — a step that runs out of gas costs the user a failed
+// tx, one that over-reserves costs nothing
+export const CLAIM_ALL_BASE_GAS = 60_000n
+export const CLAIM_ALL_PER_MATCH_GAS = 45_000n
+export const SINGLE_SETTLE_GAS = 110_000n
+export const DEFAULT_MAX_GAS_PER_TX = 1_000_000n
+
+// selector + array offset + array length, then one word per match id
+const CLAIM_ALL_BASE_BYTES = 4 + 32 + 32
+const SINGLE_SETTLE_BYTES = 4 + 32
+
+/** Largest claimAll batch that fits the limits, capped at MAX_CLAIM_BATCH. */
+export function maxClaimBatch(limits: SigningLimits = {}): number {
+  const maxGas = limits.maxGasPerTx ?? DEFAULT_MAX_GAS_PER_TX
+  if (maxGas < CLAIM_ALL_BASE_GAS + CLAIM_ALL_PER_MATCH_GAS) return 0
+  let size = Math.min(
+    MAX_CLAIM_BATCH,
+    Number((maxGas - CLAIM_ALL_BASE_GAS) / CLAIM_ALL_PER_MATCH_GAS),
+  )
+  if (limits.maxCalldataBytes !== undefined) {
+    size = Math.min(size, Math.floor((limits.maxCalldataBytes - CLAIM_ALL_BASE_BYTES) / 32))
+  }
+  return Math.max(size, 0)
+}
+
+/**
+ * Order of signing steps that settles every item. Throws if the limits can't fit even a
+ * single settlement, since no plan could be signed.
+ */
+export function planSettlement(items: SettlementItem[], limits: SigningLimits = {}): SigningStep[] {
+  if (!CONTRACT_ADDRESS) throw new Error('Contract address not configured')
+  const maxGas = limits.maxGasPerTx ?? DEFAULT_MAX_GAS_PER_TX
+  const singleFits =
+    maxGas >= SINGLE_SETTLE_GAS &&
+    (limits.maxCalldataBytes === undefined || limits.maxCalldataBytes >= SINGLE_SETTLE_BYTES)
+  if (items.length > 0 && !singleFits) {
+    throw new Error('Signing limits are too small for a single settlement')
+  }
+
+  const steps: SigningStep[] = []
+  const claims = items.filter((item) => item.kind === 'claim').map((item) => item.matchId)
+  // When not even two claims fit a claimAll, every claim goes out as its own claimPayout
+  const batch = Math.max(maxClaimBatch(limits), 1)
+
+  for (let i = 0; i < claims.length; i += batch) {
+    const chunk = claims.slice(i, i + batch)
+    // A lone claim is cheaper as claimPayout than as a one-entry claimAll
+    if (chunk.length === 1) {
+      steps.push(singleStep(CONTRACT_ADDRESS, 'claimPayout', chunk[0]))
+      continue
+    }
+    steps.push({
+      functionName: 'claimAll',
+      matchIds: chunk,
+      tx: {
+        to: CONTRACT_ADDRESS,
+        data: encodeFunctionData({
+          abi: BETTING_ABI,
+          functionName: 'claimAll',
+          args: [chunk.map(matchIdToBytes32)],
+        }),
+        gas: CLAIM_ALL_BASE_GAS + CLAIM_ALL_PER_MATCH_GAS * BigInt(chunk.length),
+      },
+    })
+  }
+
+  for (const item of items) {
+    if (item.kind === 'refund') steps.push(singleStep(CONTRACT_ADDRESS, 'refundBet', item.matchId))
+    if (item.kind === 'refundNoWinners') {
+      steps.push(singleStep(CONTRACT_ADDRESS, 'refundNoWinners', item.matchId))
+    }
+  }
+  return steps
+}
+
+function singleStep(
+  to: `0x${string}`,
+  functionName: 'claimPayout' | 'refundBet' | 'refundNoWinners',
+  matchId: string,
+): SigningStep {
+  return {
+    functionName,
+    matchIds: [matchId],
+    tx: {
+      to,
+      data: encodeFunctionData({
+        abi: BETTING_ABI,
+        functionName,
+        args: [matchIdToBytes32(matchId)],
+      }),
+      gas: SINGLE_SETTLE_GAS,
+    },
+  }
+}
@@ -0,0 +1,156 @@
+import {
+  decodeEventLog,
+  decodeFunctionData,
+  isAddressEqual,
+  recoverTransactionAddress,
+  recoverTypedDataAddress,
+  serializeTransaction,
+  type Hash,
+  type PublicClient,
+  type TransactionSerializable,
+} from 'viem'
+import type { BetSide } from '@/types'
+import { BETTING_ABI, CONTRACT_ADDRESS, matchIdToBytes32 } from '@/lib/contracts'
+
+/**
+ * Light-client check that a transaction really resolved a match the way an app claims.
+ *
+ * Everything is re-derived from the RPC rather than the Rawl API: the signer is recovered
+ * from the raw signature, the role and match state are read at the resolution block, and
+ * the calldata and MatchResolved log are decoded against the contract ABI. Reading state
+ * at an old block needs an archive RPC.
+ *
+ * A relayed resolveMatchSigned is checked against the oracle that signed the result, not
+ * the relayer that sent the transaction.
+ */
+
+export type ResolutionCheck =
+  | 'receipt' // transaction succeeded
+  | 'contract' // sent to the RawlBetting contract
+  | 'calldata' // resolveMatch / resolveMatchWithOpId / resolveMatchSigned for this result
+  | 'signature' // signature recovers to the sender, and a signed result to its oracle
+  | 'oracle' // sender held RESOLVE_AUTHORITY_ROLE at that block
+  | 'event' // MatchResolved emitted for this match and winner
+  | 'state' // matches(matchId) reads Resolved with this winner after the block
+
+export interface ResolutionVerification {
+  ok: boolean
+  failed: ResolutionCheck[]
+  oracle: `0x${string}`
+  blockNumber: bigint
+}
+
+const STATUS_RESOLVED = 3
+const RESULT_TYPES = {
+  ResolutionResult: [
+    { name: 'matchId', type: 'bytes32' },
+    { name: 'winner', type: 'uint8' },
+    { name: 'timestamp', type: 'uint64' },
+  ],
+} as const
+const WINNER_CODES: Record<BetSide, number> = { a: 1, b: 2 } // on-chain MatchWinner enum
+
+export async function verifyResolution(
+  client: PublicClient,
+  txHash: Hash,
+  expected: { matchId: string; winner: BetSide },
+  contract: `0x${string}` | undefined = CONTRACT_ADDRESS,
+): Promise<ResolutionVerification> {
+  if (!contract) throw new Error('Contract address not configured')
+
+  const [tx, receipt] = await Promise.all([
+    client.getTransaction({ hash: txHash }),
+    client.getTransactionReceipt({ hash: txHash }),
+  ])
+  const matchId = matchIdToBytes32(expected.matchId)
+  const winnerArg = expected.winner === 'a' ? 0 : 1
+  const blockNumber = receipt.blockNumber
+  const failed: ResolutionCheck[] = []
+  let oracle: `0x${string}` = tx.from
+
+  if (receipt.status !== 'success') failed.push('receipt')
+  if (!tx.to || !isAddressEqual(tx.to, contract)) failed.push('contract')
+
+  try {
+    const call = decodeFunctionData({ abi: BETTING_ABI, data: tx.input })
+    const resolves =
+      call.functionName === 'resolveMatch' ||
+      call.functionName === 'resolveMatchWithOpId' ||
+      call.functionName === 'resolveMatchSigned'
+    if (!resolves || call.args[0] !== matchId || call.args[1] !== winnerArg) {
+      failed.push('calldata')
+    }
+    if (call.functionName === 'resolveMatchSigned') {
+      oracle = await recoverTypedDataAddress({
+        domain: {
+          name: 'RawlBetting',
+          version: '1',
+          chainId: tx.chainId,
+          verifyingContract: contract,
+        },
+        types: RESULT_TYPES,
+        primaryType: 'ResolutionResult',
+        message: { matchId: call.args[0], winner: call.args[1], timestamp: call.args[2] },
+        signature: call.args[3],
+      })
+    }
+  } catch {
+    failed.push('calldata')
+  }
+
+  try {
+    const signer = await recoverTransactionAddress({
+      serializedTransaction: serializeTransaction(
+        { ...tx, data: tx.input } as TransactionSerializable,
+        { r: tx.r, s: tx.s, v: tx.v, yParity: tx.yParity },
+      ),
+    })
+    if (!isAddressEqual(signer, tx.from)) failed.push('signature')
+  } catch {
+    failed.push('signature')
+  }
+
+  const resolveRole = await client.readContract({
+    address: contract,
+    abi: BETTING_ABI,
+    functionName: 'RESOLVE_AUTHORITY_ROLE',
+    blockNumber,
+  })
+  const isOracle = await client.readContract({
+    address: contract,
+    abi: BETTING_ABI,
+    functionName: 'hasRole',
+    args: [resolveRole, oracle],
+    blockNumber,
+  })
+  if (!isOracle) failed.push('oracle')
+
+  const resolvedLog = receipt.logs.some((log) => {
+    if (!isAddressEqual(log.address, contract)) return false
+    try {
+      const event = decodeEventLog({ abi: BETTING_ABI, data: log.data, topics: log.topics })
+      return (
+        event.eventName === 'MatchResolved' &&
+        event.args.matchId === matchId &&
+        event.args.winner === winnerArg
+      )
+    } catch {
+      return false
+    }
+  })
+  if (!resolvedLog) failed.push('event')
+
+  const pool = await client.readContract({
+    address: contract,
+    abi: BETTING_ABI,
+    functionName: 'matches',
+    args: [matchId],
+    blockNumber,
+  })
+  // Tuple order mirrors MatchPool: fighterA, fighterB, status, winner, ...
+  if (pool[2] !== STATUS_RESOLVED || pool[3] !== WINNER_CODES[expected.winner]) {
+    failed.push('state')
+  }
+
+  return { ok: failed.length === 0, failed, oracle, blockNumber }
+}
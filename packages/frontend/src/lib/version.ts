@@ -0,0 +1,63 @@
+import type { PublicClient } from 'viem'
+import pkg from '../../package.json'
+import { BETTING_ABI, CONTRACT_ADDRESS } from '@/lib/contracts'
+
+/**
+ * Version beacon check against RawlBetting.programVersion / minClientVersion.
+ *
+ * Both are semver packed as major * 1e6 + minor * 1e3 + patch. A build older than
+ * minClientVersion refuses to send transactions rather than risk encoding calls for an
+ * ABI the deployed contract no longer has.
+ */
+
+export const CLIENT_VERSION = pkg.version
+
+export function encodeVersion(semver: string): number {
+  const [major = 0, minor = 0, patch = 0] = semver.split('.').map((part) => parseInt(part, 10))
+  return major * 1_000_000 + minor * 1_000 + patch
+}
+
+export function decodeVersion(encoded: number): string {
+  const major = Math.floor(encoded / 1_000_000)
+  const minor = Math.floor(encoded / 1_000) % 1_000
+  return `${major}.${minor}.${encoded % 1_000}`
+}
+
+export interface ProgramVersion {
+  programVersion: number
+  minClientVersion: number
+  supported: boolean
+}
+
+let cached: Promise<ProgramVersion> | null = null
+
+export function readProgramVersion(client: PublicClient): Promise<ProgramVersion> {
+  if (!CONTRACT_ADDRESS) throw new Error('Contract address not configured')
+  const contract = { address: CONTRACT_ADDRESS, abi: BETTING_ABI } as const
+  cached ??= Promise.all([
+    client.readContract({ ...contract, functionName: 'programVersion' }),
+    client.readContract({ ...contract, functionName: 'minClientVersion' }),
+  ])
+    .then(([programVersion, minClientVersion]) => ({
+      programVersion,
+      minClientVersion,
+      // 0 = the admin has not published a version yet
+      supported: minClientVersion === 0 || encodeVersion(CLIENT_VERSION) >= minClientVersion,
+    }))
+    .catch((err) => {
+      cached = null
+      throw err
+    })
+  return cached
+}
+
+/** Throws if this build is older than the contract's minClientVersion. */
+export async function assertClientSupported(client: PublicClient): Promise<void> {
+  const { minClientVersion, supported } = await readProgramVersion(client)
+  if (!supported) {
+    throw new Error(
+      `This app (v${CLIENT_VERSION}) is out of date — reload to get ` +
+        `v${decodeVersion(minClientVersion)} or newer.`,
+    )
+  }
+}
@@ -27,7 +27,13 @@ export interface PretrainedModel {
   description: string;
 }
 
-export type BetStatus = "pending" | "confirmed" | "claimed" | "refunded" | "expired";
+export type BetStatus =
+  | "pending"
+  | "confirmed"
+  | "claimed"
+  | "refunded"
+  | "cashed_out"
+  | "expired";
 
 export interface Bet {
   id: string;
@@ -35,7 +41,11 @@ export interface Bet {
   wallet_address: string;
   side: BetSide;
   amount_eth: number;
+  odds_side_a_eth: number | null;
+  odds_side_b_eth: number | null;
   onchain_bet_id: string | null;
+  tx_hash: string | null;
+  settled_eth: number | null;
   status: BetStatus;
   created_at: string;
   claimed_at: string | null;
@@ -46,3 +56,36 @@ export interface Bet {
   match_winner_id: string | null;
   winner_side: "a" | "b" | null;
 }
+
+/** One bet in a wallet-activity export (format "rawl.wallet-activity/1"). */
+export interface WalletActivityRecord {
+  id: string;
+  type: "bet";
+  timestamp: string;
+  match_id: string;
+  side: BetSide;
+  amount_eth: number;
+  status: BetStatus;
+  counterparty: string;
+  tx_hash: string | null;
+  settled_eth: number | null;
+  settled_at: string | null;
+  realized_pnl_eth: number | null;
+}
+
+export interface WalletActivityExport {
+  format: string;
+  wallet: string;
+  chain_id: number;
+  generated_at: string;
+  items: WalletActivityRecord[];
+  next_cursor: string | null;
+  has_more: boolean;
+}
+
+/** Raw export page — `payload` is the exact JSON string the signature covers. */
+export interface SignedWalletActivity {
+  payload: string;
+  signer: string | null;
+  signature: string | null;
+}
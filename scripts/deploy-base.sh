@@ -16,6 +16,10 @@ cd "$(dirname "$0")/../packages/contracts"
 echo "=== Building contracts ==="
 forge build --sizes
 
+echo "=== Refreshing bundled backend ABI and event schema ==="
+python3 ../../scripts/sync_abi.py
+cp ../shared/schema/events.schema.json ../backend/src/rawl/evm/events.schema.json
+
 echo "=== Running tests ==="
 forge test -vvv
 
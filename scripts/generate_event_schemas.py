@@ -0,0 +1,172 @@
+"""
+Generate the language-neutral JSON Schema for every Rawl contract event.
+
+Reads the event and enum declarations straight from packages/contracts/src, so
+it runs without a Foundry build, and writes packages/shared/schema/events.schema.json.
+The file is versioned with the contracts: regenerate it in the same commit as
+any event change, and CI fails when it is stale. The indexer, webhook consumers and the
+frontend validate decoded event payloads against it instead of each keeping
+their own idea of the shapes.
+
+Payload encoding (one object per log, keyed by parameter name):
+  address            0x-prefixed hex, any case
+  bool               JSON boolean
+  uintN / intN       JSON integer up to 48 bits, decimal string above (JSON
+                     numbers lose precision past 2^53)
+  enums              JSON integer, names listed under x-enum
+  bytesN / bytes     0x-prefixed hex;  string  JSON string;  T[]  JSON array
+
+Each event's canonical signature is in x-signature (topic0 is its keccak256);
+indexed parameters carry x-indexed. Events inherited from OpenZeppelin
+(RoleGranted, Paused, Transfer, ...) keep their standard shapes and aren't listed.
+
+Usage:
+  python scripts/generate_event_schemas.py          # rewrite the schema
+  python scripts/generate_event_schemas.py --check  # exit 1 if it is out of date
+"""
+from __future__ import annotations
+
+import argparse
+import json
+import re
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+SOURCES = ROOT / "packages" / "contracts" / "src"
+OUTPUT = ROOT / "packages" / "shared" / "schema" / "events.schema.json"
+SCHEMA_ID = "rawl.events/1"
+MAX_JSON_INT_BITS = 48
+
+_COMMENTS = re.compile(r"//[^\n]*|/\*.*?\*/", re.S)
+_CONTRACT = re.compile(r"\b(?:abstract\s+)?(contract|library|interface)\s+(\w+)[^{]*\{")
+_ENUM = re.compile(r"\benum\s+(\w+)\s*\{([^}]*)\}")
+_EVENT = re.compile(r"\bevent\s+(\w+)\s*\(([^)]*)\)\s*;")
+# Declared in a shared base but only ever emitted from the deployed contract's address
+_EMITTED_BY = {"RawlBettingBase": "RawlBetting"}
+
+
+def _blocks(source: str) -> list[tuple[str, str]]:
+    """(contract name, body) for every top-level contract, library or interface."""
+    blocks = []
+    for match in _CONTRACT.finditer(source):
+        depth, start = 1, match.end()
+        i = start
+        while depth and i < len(source):
+            depth += {"{": 1, "}": -1}.get(source[i], 0)
+            i += 1
+        blocks.append((match.group(2), source[start : i - 1]))
+    return blocks
+
+
+def _params(raw: str) -> list[tuple[str, bool, str]]:
+    """(type, indexed, name) per event parameter."""
+    params = []
+    for part in filter(None, (p.strip() for p in raw.split(","))):
+        words = part.split()
+        indexed = "indexed" in words
+        words = [w for w in words if w != "indexed"]
+        params.append((words[0], indexed, words[1] if len(words) > 1 else ""))
+    return params
+
+
+def _value_schema(sol_type: str, enums: dict[str, list[str]]) -> tuple[dict, str]:
+    """JSON Schema for one Solidity type and its canonical ABI spelling."""
+    if sol_type.endswith("[]"):
+        items, canonical = _value_schema(sol_type[:-2], enums)
+        return {"type": "array", "items": items}, canonical + "[]"
+    base = sol_type.rsplit(".", 1)[-1]
+    if base in enums:
+        names = enums[base]
+        return {"type": "integer", "enum": list(range(len(names))), "x-enum": names}, "uint8"
+    if base == "address":
+        return {"type": "string", "pattern": "^0x[0-9a-fA-F]{40}$"}, base
+    if base == "bool":
+        return {"type": "boolean"}, base
+    if base == "string":
+        return {"type": "string"}, base
+    if base == "bytes":
+        return {"type": "string", "pattern": "^0x([0-9a-fA-F]{2})*$"}, base
+    if match := re.fullmatch(r"bytes(\d+)", base):
+        size = int(match.group(1))
+        return {"type": "string", "pattern": f"^0x[0-9a-fA-F]{{{size * 2}}}$"}, base
+    if match := re.fullmatch(r"(u?)int(\d*)", base):
+        unsigned, bits = match.group(1) == "u", int(match.group(2) or 256)
+        canonical = f"{'u' if unsigned else ''}int{bits}"
+        if bits <= MAX_JSON_INT_BITS:
+            low = 0 if unsigned else -(2 ** (bits - 1))
+            high = 2**bits - 1 if unsigned else 2 ** (bits - 1) - 1
+            return {"type": "integer", "minimum": low, "maximum": high}, canonical
+        pattern = "^[0-9]+$" if unsigned else "^-?[0-9]+$"
+        return {"type": "string", "pattern": pattern}, canonical
+    raise ValueError(f"unsupported event parameter type: {sol_type}")
+
+
+def build_schema() -> dict:
+    sources = [_COMMENTS.sub("", path.read_text()) for path in sorted(SOURCES.glob("*.sol"))]
+    enums = {
+        name: [v.strip() for v in body.split(",") if v.strip()]
+        for source in sources
+        for name, body in _ENUM.findall(source)
+    }
+
+    defs: dict[str, dict] = {}
+    for source in sources:
+        for contract, body in _blocks(source):
+            for name, raw in _EVENT.findall(body):
+                properties, types = {}, []
+                for sol_type, indexed, param in _params(raw):
+                    value, canonical = _value_schema(sol_type, enums)
+                    if indexed:
+                        value["x-indexed"] = True
+                    properties[param] = value
+                    types.append(canonical)
+                defs[f"{_EMITTED_BY.get(contract, contract)}.{name}"] = {
+                    "type": "object",
+                    "x-signature": f"{name}({','.join(types)})",
+                    "properties": properties,
+                    "required": list(properties),
+                    "additionalProperties": False,
+                }
+
+    return {
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": SCHEMA_ID,
+        "title": "Rawl contract events",
+        "description": "Generated by scripts/generate_event_schemas.py — do not edit by hand",
+        "type": "object",
+        "properties": {
+            "contract": {"type": "string"},
+            "event": {"type": "string"},
+            "args": {"type": "object"},
+        },
+        "required": ["contract", "event", "args"],
+        "$defs": dict(sorted(defs.items())),
+    }
+
+
+def render() -> str:
+    return json.dumps(build_schema(), indent=2, ensure_ascii=False) + "\n"
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__.split("\n\n")[0].strip())
+    parser.add_argument("--check", action="store_true", help="fail if the schema is stale")
+    args = parser.parse_args()
+
+    rendered = render()
+    if args.check:
+        if not OUTPUT.exists() or OUTPUT.read_text() != rendered:
+            print(f"{OUTPUT.relative_to(ROOT)} is out of date — run make event-schemas")
+            return 1
+        print(f"{OUTPUT.relative_to(ROOT)} is up to date")
+        return 0
+
+    OUTPUT.parent.mkdir(parents=True, exist_ok=True)
+    OUTPUT.write_text(rendered)
+    print(f"Wrote {len(json.loads(rendered)['$defs'])} event schemas to {OUTPUT.relative_to(ROOT)}")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())
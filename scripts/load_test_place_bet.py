@@ -0,0 +1,235 @@
+"""
+Load test for RawlBetting.placeBet against a local Anvil node.
+
+Creates a fresh match, funds N throwaway bettors, then fires one placeBet
+from each with bounded concurrency and reports success rate, submit-to-
+receipt latency, gas used per bet, and how many bets landed per block.
+Side totals go to one of POOL_SHARDS slots round-robin and are folded
+back into MatchPool at lock, but every bet still writes the MatchPool
+bet counts, so bets-per-block is the number to watch for hot-slot
+contention before a big event. Gas per bet includes the shard reads for
+the odds the bettor saw.
+
+Usage:
+  anvil --block-time 2 &
+  forge script script/Deploy.s.sol --rpc-url http://127.0.0.1:8545 --broadcast
+  python scripts/load_test_place_bet.py --bettors 2000 --concurrency 200
+
+Required env vars:
+  CONTRACT_ADDRESS   — Deployed RawlBetting contract
+  ORACLE_PRIVATE_KEY — Private key for oracle account (has ORACLE_ROLE)
+Optional:
+  FUNDER_PRIVATE_KEY — Funds the bettors (defaults to Anvil account #0)
+"""
+from __future__ import annotations
+
+import argparse
+import asyncio
+import os
+import statistics
+import time
+import uuid
+from collections import Counter
+from dataclasses import dataclass
+
+from eth_account import Account
+from web3 import AsyncHTTPProvider, AsyncWeb3
+
+ANVIL_DEFAULT_KEY = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcad5c1a2ee8c91f8e"
+BET_WEI = 10**15  # 0.001 ETH, the default minBet
+FUND_WEI = 10**16  # stake plus plenty of gas
+
+ABI = [
+    {
+        "type": "function",
+        "name": "registerFighter",
+        "inputs": [
+            {"name": "fighter", "type": "address"},
+            {"name": "nameHash", "type": "bytes32"},
+            {"name": "owner", "type": "address"},
+        ],
+        "outputs": [],
+        "stateMutability": "nonpayable",
+    },
+    {
+        "type": "function",
+        "name": "createMatch",
+        "inputs": [
+            {"name": "matchId", "type": "bytes32"},
+            {"name": "fighterA", "type": "address"},
+            {"name": "fighterB", "type": "address"},
+            {"name": "minBet", "type": "uint128"},
+            {"name": "bettingWindow", "type": "uint64"},
+        ],
+        "outputs": [],
+        "stateMutability": "nonpayable",
+    },
+    {
+        "type": "function",
+        "name": "placeBet",
+        "inputs": [
+            {"name": "matchId", "type": "bytes32"},
+            {"name": "side", "type": "uint8"},
+        ],
+        "outputs": [],
+        "stateMutability": "payable",
+    },
+]
+
+
+@dataclass
+class BetResult:
+    ok: bool
+    latency: float
+    gas_used: int = 0
+    block: int = 0
+    error: str = ""
+
+
+def _percentile(values: list[float], pct: float) -> float:
+    if not values:
+        return 0.0
+    ordered = sorted(values)
+    idx = min(len(ordered) - 1, int(round(pct / 100 * (len(ordered) - 1))))
+    return ordered[idx]
+
+
+async def _send_and_wait(w3: AsyncWeb3, signed, timeout: int = 120):
+    tx_hash = await w3.eth.send_raw_transaction(signed.raw_transaction)
+    return await w3.eth.wait_for_transaction_receipt(tx_hash, timeout=timeout)
+
+
+async def _setup_match(w3: AsyncWeb3, contract, oracle, chain_id: int) -> bytes:
+    """Register two throwaway fighters and open a match with no betting window."""
+    fighter_a, fighter_b = Account.create().address, Account.create().address
+    match_id = uuid.uuid4().bytes.ljust(32, b"\x00")
+    nonce = await w3.eth.get_transaction_count(oracle.address, "pending")
+    calls = [
+        contract.functions.registerFighter(fighter_a, os.urandom(32), oracle.address),
+        contract.functions.registerFighter(fighter_b, os.urandom(32), oracle.address),
+        contract.functions.createMatch(match_id, fighter_a, fighter_b, BET_WEI, 0),
+    ]
+    for i, call in enumerate(calls):
+        tx = await call.build_transaction(
+            {"from": oracle.address, "nonce": nonce + i, "chainId": chain_id}
+        )
+        receipt = await _send_and_wait(w3, oracle.sign_transaction(tx))
+        if receipt["status"] != 1:
+            raise RuntimeError("Match setup reverted — is ORACLE_PRIVATE_KEY the oracle?")
+    return match_id
+
+
+async def _fund(w3: AsyncWeb3, funder, bettors: list, chain_id: int) -> None:
+    nonce = await w3.eth.get_transaction_count(funder.address, "pending")
+    gas_price = await w3.eth.gas_price
+    hashes = []
+    for i, bettor in enumerate(bettors):
+        signed = funder.sign_transaction(
+            {
+                "to": bettor.address,
+                "value": FUND_WEI,
+                "gas": 21_000,
+                "gasPrice": gas_price,
+                "nonce": nonce + i,
+                "chainId": chain_id,
+            }
+        )
+        hashes.append(await w3.eth.send_raw_transaction(signed.raw_transaction))
+    await asyncio.gather(*(w3.eth.wait_for_transaction_receipt(h, timeout=300) for h in hashes))
+
+
+async def _place_bet(w3, contract, bettor, match_id, side, chain_id, sem) -> BetResult:
+    async with sem:
+        start = time.perf_counter()
+        try:
+            tx = await contract.functions.placeBet(match_id, side).build_transaction(
+                {
+                    "from": bettor.address,
+                    "nonce": 0,
+                    "value": BET_WEI,
+                    "chainId": chain_id,
+                    "gas": 300_000,  # skip estimateGas so it doesn't skew latency
+                }
+            )
+            receipt = await _send_and_wait(w3, bettor.sign_transaction(tx))
+        except Exception as e:  # noqa: BLE001 — every failure mode is a data point
+            return BetResult(ok=False, latency=time.perf_counter() - start, error=type(e).__name__)
+        return BetResult(
+            ok=receipt["status"] == 1,
+            latency=time.perf_counter() - start,
+            gas_used=receipt["gasUsed"],
+            block=receipt["blockNumber"],
+            error="" if receipt["status"] == 1 else "reverted",
+        )
+
+
+def _report(results: list[BetResult], elapsed: float) -> None:
+    ok = [r for r in results if r.ok]
+    latencies = [r.latency for r in ok]
+    gas = [r.gas_used for r in ok]
+    per_block = Counter(r.block for r in ok)
+
+    print(f"\nBets sent:      {len(results)}")
+    print(f"Succeeded:      {len(ok)} ({100 * len(ok) / max(len(results), 1):.1f}%)")
+    print(f"Wall time:      {elapsed:.1f}s ({len(ok) / max(elapsed, 1e-9):.1f} bets/s)")
+    if latencies:
+        print(
+            "Latency (s):    "
+            f"p50={_percentile(latencies, 50):.2f} p90={_percentile(latencies, 90):.2f} "
+            f"p99={_percentile(latencies, 99):.2f} max={max(latencies):.2f}"
+        )
+    if gas:
+        print(
+            "Gas used:       "
+            f"min={min(gas)} p50={int(_percentile(gas, 50))} "
+            f"p90={int(_percentile(gas, 90))} max={max(gas)} mean={int(statistics.mean(gas))}"
+        )
+        # First bet on a side pays cold SSTOREs, so expect a small high bucket
+        buckets = Counter(g // 5_000 * 5_000 for g in gas)
+        for floor in sorted(buckets):
+            print(f"  {floor:>7}-{floor + 4_999:<7} {buckets[floor]}")
+    if per_block:
+        counts = list(per_block.values())
+        print(
+            f"Bets per block: blocks={len(counts)} mean={statistics.mean(counts):.1f} "
+            f"max={max(counts)}"
+        )
+    errors = Counter(r.error for r in results if not r.ok)
+    for name, count in errors.most_common():
+        print(f"  failed: {name} x{count}")
+
+
+async def main(rpc_url: str, n_bettors: int, concurrency: int) -> None:
+    w3 = AsyncWeb3(AsyncHTTPProvider(rpc_url))
+    chain_id = await w3.eth.chain_id
+    contract = w3.eth.contract(address=os.environ["CONTRACT_ADDRESS"], abi=ABI)
+    oracle = Account.from_key(os.environ["ORACLE_PRIVATE_KEY"])
+    funder = Account.from_key(os.environ.get("FUNDER_PRIVATE_KEY", ANVIL_DEFAULT_KEY))
+
+    print("Creating match...")
+    match_id = await _setup_match(w3, contract, oracle, chain_id)
+    print(f"  matchId 0x{match_id.hex()}")
+
+    bettors = [Account.create() for _ in range(n_bettors)]
+    print(f"Funding {n_bettors} bettors...")
+    await _fund(w3, funder, bettors, chain_id)
+
+    print(f"Placing bets (concurrency {concurrency})...")
+    sem = asyncio.Semaphore(concurrency)
+    start = time.perf_counter()
+    results = await asyncio.gather(
+        *(
+            _place_bet(w3, contract, b, match_id, i % 2, chain_id, sem)
+            for i, b in enumerate(bettors)
+        )
+    )
+    _report(list(results), time.perf_counter() - start)
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="placeBet load test")
+    parser.add_argument("--rpc", default="http://127.0.0.1:8545")
+    parser.add_argument("--bettors", type=int, default=1000)
+    parser.add_argument("--concurrency", type=int, default=100)
+    args = parser.parse_args()
+    asyncio.run(main(args.rpc, args.bettors, args.concurrency))
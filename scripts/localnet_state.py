@@ -0,0 +1,162 @@
+"""
+Snapshot Rawl contract state from any chain and restore it onto a local Anvil.
+
+``snapshot`` copies code, balance, nonce and every storage slot of the Rawl
+contracts at a pinned block into a JSON file. ``restore`` writes them onto a
+fresh Anvil at the same addresses with ``anvil_setCode`` / ``anvil_setStorageAt``,
+so a bug report against production state can be replayed locally. The
+oracle and admin keys aren't in the file — impersonate them on Anvil
+(``cast rpc anvil_impersonateAccount <addr>``) to drive the restored contracts.
+
+Storage is enumerated with ``debug_storageRangeAt``, so the source RPC must
+expose the debug namespace and return slot preimages (Erigon/Reth archive
+nodes, or Geth with ``--cache.preimages``). Public Base RPCs do not.
+
+Usage:
+  python scripts/localnet_state.py snapshot --rpc $ARCHIVE_RPC --out state.json
+  anvil &
+  python scripts/localnet_state.py restore state.json
+
+Required env vars (snapshot):
+  CONTRACT_ADDRESS — Deployed RawlBetting contract
+Optional:
+  SEASON_FUTURES_ADDRESS — also snapshot SeasonFutures
+"""
+from __future__ import annotations
+
+import argparse
+import asyncio
+import json
+import os
+
+from web3 import AsyncHTTPProvider, AsyncWeb3
+
+FORMAT = "rawl.localnet-state/1"
+PAGE_SIZE = 1024
+ZERO_KEY = "0x" + "00" * 32
+
+ABI = [
+    {
+        "type": "function",
+        "name": "betPositions",
+        "inputs": [],
+        "outputs": [{"name": "", "type": "address"}],
+        "stateMutability": "view",
+    },
+]
+
+
+async def _rpc(w3: AsyncWeb3, method: str, params: list):
+    response = await w3.provider.make_request(method, params)
+    if "error" in response:
+        raise RuntimeError(f"{method} failed: {response['error'].get('message')}")
+    return response["result"]
+
+
+async def _rawl_accounts(w3: AsyncWeb3, block: int) -> list[str]:
+    """RawlBetting, its BetPositions NFT if enabled, and SeasonFutures if configured."""
+    betting = AsyncWeb3.to_checksum_address(os.environ["CONTRACT_ADDRESS"])
+    accounts = [betting]
+    contract = w3.eth.contract(address=betting, abi=ABI)
+    positions = await contract.functions.betPositions().call(block_identifier=block)
+    if int(positions, 16) != 0:
+        accounts.append(positions)
+    if os.environ.get("SEASON_FUTURES_ADDRESS"):
+        accounts.append(AsyncWeb3.to_checksum_address(os.environ["SEASON_FUTURES_ADDRESS"]))
+    return accounts
+
+
+async def _storage(w3: AsyncWeb3, block_hash: str, address: str) -> dict[str, str]:
+    """Every non-zero slot, keyed by its plain (un-hashed) slot number."""
+    slots: dict[str, str] = {}
+    start = ZERO_KEY
+    while start is not None:
+        page = await _rpc(
+            w3, "debug_storageRangeAt", [block_hash, 0, address, start, PAGE_SIZE]
+        )
+        for entry in page["storage"].values():
+            if entry["key"] is None:
+                raise RuntimeError(
+                    f"{address}: RPC returned a slot without its preimage — use a node "
+                    "that records preimages (see module docstring)"
+                )
+            slots[entry["key"]] = entry["value"]
+        start = page.get("nextKey")
+    return slots
+
+
+async def snapshot(rpc_url: str, out: str, block: int | None) -> None:
+    w3 = AsyncWeb3(AsyncHTTPProvider(rpc_url))
+    target = block if block is not None else await w3.eth.block_number
+    # debug_storageRangeAt(blockHash, txIndex 0) is the state *before* that block,
+    # so storage is read at the next block — wait for it when capturing head
+    while (await w3.eth.block_number) <= target:
+        await asyncio.sleep(1)
+    after = await w3.eth.get_block(target + 1)
+
+    accounts = []
+    for address in await _rawl_accounts(w3, target):
+        code = await w3.eth.get_code(address, target)
+        storage = await _storage(w3, after["hash"].to_0x_hex(), address)
+        accounts.append(
+            {
+                "address": address,
+                "code": code.to_0x_hex(),
+                "balance": hex(await w3.eth.get_balance(address, target)),
+                "nonce": hex(await w3.eth.get_transaction_count(address, target)),
+                "storage": storage,
+            }
+        )
+        print(f"  {address}: {len(code)} bytes code, {len(storage)} slots")
+
+    state = {
+        "format": FORMAT,
+        "chain_id": await w3.eth.chain_id,
+        "block": target,
+        "accounts": accounts,
+    }
+    with open(out, "w") as f:
+        json.dump(state, f, indent=2)
+        f.write("\n")
+    print(f"Wrote {len(accounts)} accounts at block {target} to {out}")
+
+
+async def restore(rpc_url: str, path: str) -> None:
+    with open(path) as f:
+        state = json.load(f)
+    if state.get("format") != FORMAT:
+        raise SystemExit(f"{path}: unknown snapshot format {state.get('format')!r}")
+
+    w3 = AsyncWeb3(AsyncHTTPProvider(rpc_url))
+    for account in state["accounts"]:
+        address = account["address"]
+        await _rpc(w3, "anvil_setCode", [address, account["code"]])
+        await _rpc(w3, "anvil_setBalance", [address, account["balance"]])
+        await _rpc(w3, "anvil_setNonce", [address, account["nonce"]])
+        for slot, value in account["storage"].items():
+            await _rpc(w3, "anvil_setStorageAt", [address, slot, value])
+        print(f"  {address}: restored {len(account['storage'])} slots")
+    print(
+        f"Restored {len(state['accounts'])} accounts from chain {state['chain_id']} "
+        f"block {state['block']}"
+    )
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Rawl localnet snapshot / restore")
+    sub = parser.add_subparsers(dest="command", required=True)
+
+    snap = sub.add_parser("snapshot", help="Capture Rawl contract state to a file")
+    snap.add_argument("--rpc", default=os.environ.get("BASE_RPC_URL", "http://127.0.0.1:8545"))
+    snap.add_argument("--block", type=int, help="Block to capture (default: head)")
+    snap.add_argument("--out", default="rawl-state.json")
+
+    rest = sub.add_parser("restore", help="Load a snapshot onto a local Anvil")
+    rest.add_argument("path")
+    rest.add_argument("--rpc", default="http://127.0.0.1:8545")
+
+    args = parser.parse_args()
+    if args.command == "snapshot":
+        asyncio.run(snapshot(args.rpc, args.out, args.block))
+    else:
+        asyncio.run(restore(args.rpc, args.path))
@@ -0,0 +1,55 @@
+"""
+Export the proof-of-reserves feed for the RawlBetting contract.
+
+Lists every active match pool with the minimum wei the contract must hold
+for it (derived from on-chain match/bet state at a pinned block) and
+compares the total against the contract balance at that same block.
+
+Usage:
+  python scripts/proof_of_reserves.py
+  python scripts/proof_of_reserves.py --out reserves.json
+
+Required env vars (or root .env):
+  DATABASE_URL, BASE_RPC_URL, CONTRACT_ADDRESS, ORACLE_PRIVATE_KEY
+"""
+from __future__ import annotations
+
+import argparse
+import asyncio
+import json
+import sys
+
+sys.path.insert(0, "packages/backend/src")
+
+
+async def main(out: str | None) -> int:
+    from rawl.db.session import async_session_factory
+    from rawl.evm.client import evm_client
+    from rawl.services.reserves import build_reserves_report
+
+    await evm_client.initialize()
+    try:
+        async with async_session_factory() as db:
+            report = await build_reserves_report(db)
+    finally:
+        await evm_client.close()
+
+    payload = json.dumps(report, indent=2)
+    if out:
+        with open(out, "w") as f:
+            f.write(payload + "\n")
+        print(f"Wrote {len(report['matches'])} match entries to {out}")
+    else:
+        print(payload)
+
+    if report["surplus_wei"] < 0:
+        print(f"WARNING: contract is short {-report['surplus_wei']} wei", file=sys.stderr)
+        return 1
+    return 0
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(description="Proof-of-reserves export")
+    parser.add_argument("--out", help="Write JSON to this file instead of stdout")
+    args = parser.parse_args()
+    sys.exit(asyncio.run(main(args.out)))
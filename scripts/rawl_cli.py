@@ -0,0 +1,409 @@
+"""
+Operator CLI for the RawlBetting contract.
+
+  watch — tail contract logs live, decoding each event and the function that
+          emitted it, with optional filters. Use it during live events
+          instead of grepping raw `cast logs` output.
+  sign-result — sign a match result offline with an oracle key and print the
+          resolveMatchSigned arguments, for any relayer to submit.
+  redeem-ticket — check a scanned bet ticket URI against its issuer and send
+          the placeBet it describes from the bettor's key.
+  sweep-plan — list every outstanding bet on a match with what its sweep would
+          transfer and to whom, whether the sweep would go through right now, and
+          the total treasury impact. Nothing is sent without --execute.
+
+Usage:
+  python scripts/rawl_cli.py watch
+  python scripts/rawl_cli.py watch --match-id 0b6f…-uuid --event BetPlaced --event MatchLocked
+  python scripts/rawl_cli.py watch --from-block 18200000 --no-color
+  python scripts/rawl_cli.py sign-result --match-id 0b6f…-uuid --winner a --chain-id 8453
+  python scripts/rawl_cli.py redeem-ticket 'rawl:bet?c=8453&k=0x…&m=…&sig=0x…' --issuer 0x…
+  python scripts/rawl_cli.py sweep-plan 0b6f…-uuid --from-block 18200000
+  python scripts/rawl_cli.py sweep-plan 0b6f…-uuid --from-block 18200000 --execute
+
+Required env vars (or flags):
+  BASE_RPC_URL, CONTRACT_ADDRESS (watch); CONTRACT_ADDRESS, ORACLE_PRIVATE_KEY (sign-result);
+  BASE_RPC_URL, CONTRACT_ADDRESS, TICKET_ISSUER, BETTOR_PRIVATE_KEY (redeem-ticket);
+  BASE_RPC_URL, CONTRACT_ADDRESS, ADMIN_PRIVATE_KEY (sweep-plan; the key only for --execute,
+  or pass --sender to simulate as the admin without it)
+"""
+from __future__ import annotations
+
+import argparse
+import asyncio
+import json
+import os
+import sys
+import time
+import uuid
+from datetime import datetime
+
+sys.path.insert(0, "packages/backend/src")
+
+from eth_account import Account  # noqa: E402
+from web3 import AsyncHTTPProvider, AsyncWeb3  # noqa: E402
+from web3._utils.events import get_event_data  # noqa: E402
+
+from rawl.evm.abi import CONTRACT_ABI  # noqa: E402
+from rawl.evm.tickets import decode_ticket, verify_ticket  # noqa: E402
+
+POLL_INTERVAL = 2  # seconds, same as the backend event listener
+MAX_BLOCK_RANGE = 2000
+STATUS_RESOLVED, STATUS_CANCELLED = 3, 4  # RawlBetting.MatchStatus
+
+# ANSI colours by event family
+COLORS = {
+    "BetPlaced": "32",  # green
+    "MatchCreated": "36",  # cyan
+    "MatchLocked": "33",  # yellow
+    "MatchResolved": "35",  # magenta
+    "MatchCancelled": "31",  # red
+    "PayoutClaimed": "34",  # blue
+}
+DIM = "2"
+
+
+def _paint(text: str, code: str, enabled: bool) -> str:
+    return f"\033[{code}m{text}\033[0m" if enabled else text
+
+
+def _match_id_bytes(value: str) -> bytes:
+    """Accept a match UUID or a raw 0x bytes32."""
+    if value.startswith("0x"):
+        return bytes.fromhex(value[2:])
+    return uuid.UUID(value).bytes.ljust(32, b"\x00")
+
+
+def _format_arg(value) -> str:
+    if isinstance(value, bytes):
+        return "0x" + value.hex()
+    return str(value)
+
+
+class Watcher:
+    def __init__(
+        self,
+        w3: AsyncWeb3,
+        address: str,
+        match_id: bytes | None,
+        events: set[str],
+        color: bool,
+    ):
+        self._w3 = w3
+        self._address = w3.to_checksum_address(address)
+        self._contract = w3.eth.contract(address=self._address, abi=CONTRACT_ABI)
+        self._match_id = match_id
+        self._events = events
+        self._color = color
+        self._by_topic = {
+            w3.keccak(text=self._signature(e)): e
+            for e in CONTRACT_ABI
+            if e.get("type") == "event"
+        }
+        self._tx_functions: dict[bytes, str] = {}
+
+    @staticmethod
+    def _signature(event: dict) -> str:
+        return f"{event['name']}({','.join(i['type'] for i in event['inputs'])})"
+
+    async def _function_name(self, tx_hash: bytes) -> str:
+        if tx_hash not in self._tx_functions:
+            tx = await self._w3.eth.get_transaction(tx_hash)
+            try:
+                fn, _ = self._contract.decode_function_input(tx["input"])
+                self._tx_functions[tx_hash] = fn.fn_name
+            except ValueError:
+                self._tx_functions[tx_hash] = "?"  # proxied call or unknown selector
+        return self._tx_functions[tx_hash]
+
+    async def _print(self, log) -> None:
+        abi = self._by_topic.get(bytes(log["topics"][0])) if log["topics"] else None
+        if abi is None:
+            name, args = "Unknown", {"topic0": _format_arg(bytes(log["topics"][0]))}
+        else:
+            decoded = get_event_data(self._w3.codec, abi, log)
+            name, args = decoded["event"], dict(decoded["args"])
+
+        if self._events and name not in self._events:
+            return
+        if self._match_id is not None and args.get("matchId") != self._match_id:
+            return
+
+        fn = await self._function_name(log["transactionHash"])
+        header = (
+            f"{datetime.now().strftime('%H:%M:%S')} #{log['blockNumber']} "
+            f"{_paint(name, COLORS.get(name, '37'), self._color)} "
+            f"{_paint(f'via {fn}()', DIM, self._color)}"
+        )
+        body = " ".join(f"{k}={_format_arg(v)}" for k, v in args.items())
+        print(f"{header} {body}", flush=True)
+
+    async def run(self, from_block: int | None) -> None:
+        last = (from_block - 1) if from_block is not None else await self._w3.eth.block_number
+        print(f"Watching {self._address} from block {last + 1}…", file=sys.stderr)
+        while True:
+            head = await self._w3.eth.block_number
+            while last < head:
+                end = min(last + MAX_BLOCK_RANGE, head)
+                logs = await self._w3.eth.get_logs(
+                    {"address": self._address, "fromBlock": last + 1, "toBlock": end}
+                )
+                for log in logs:
+                    await self._print(log)
+                last = end
+            await asyncio.sleep(POLL_INTERVAL)
+
+
+async def watch(args: argparse.Namespace) -> None:
+    if not CONTRACT_ABI:
+        raise SystemExit("No RawlBetting ABI found — run `make contracts-build` first")
+    w3 = AsyncWeb3(AsyncHTTPProvider(args.rpc))
+    watcher = Watcher(
+        w3,
+        args.contract,
+        _match_id_bytes(args.match_id) if args.match_id else None,
+        set(args.event or []),
+        color=not args.no_color and sys.stdout.isatty(),
+    )
+    await watcher.run(args.from_block)
+
+
+def sign_result(args) -> dict:
+    """EIP-712 ResolutionResult signature, as checked by RawlBetting.resolveMatchSigned.
+
+    The timestamp must not be earlier than the match's lock, so sign after locking.
+    """
+    match_id = _match_id_bytes(args.match_id)
+    winner = 0 if args.winner == "a" else 1
+    timestamp = args.timestamp or int(time.time())
+    signed = Account.sign_typed_data(
+        args.key,
+        full_message={
+            "types": {
+                "EIP712Domain": [
+                    {"name": "name", "type": "string"},
+                    {"name": "version", "type": "string"},
+                    {"name": "chainId", "type": "uint256"},
+                    {"name": "verifyingContract", "type": "address"},
+                ],
+                "ResolutionResult": [
+                    {"name": "matchId", "type": "bytes32"},
+                    {"name": "winner", "type": "uint8"},
+                    {"name": "timestamp", "type": "uint64"},
+                ],
+            },
+            "primaryType": "ResolutionResult",
+            "domain": {
+                "name": "RawlBetting",
+                "version": "1",
+                "chainId": args.chain_id,
+                "verifyingContract": args.contract,
+            },
+            "message": {"matchId": match_id, "winner": winner, "timestamp": timestamp},
+        },
+    )
+    return {
+        "matchId": "0x" + match_id.hex(),
+        "winner": winner,
+        "timestamp": timestamp,
+        "signature": "0x" + signed.signature.hex().removeprefix("0x"),
+        "oracle": Account.from_key(args.key).address,
+    }
+
+
+async def redeem_ticket(args: argparse.Namespace) -> str:
+    """Verify a scanned ticket and place the bet it encodes. Returns the tx hash."""
+    ticket = decode_ticket(args.uri)
+    if not verify_ticket(ticket, args.issuer, int(time.time())):
+        raise SystemExit("Ticket is expired or not signed by the issuer")
+    if ticket.contract.lower() != args.contract.lower():
+        raise SystemExit(f"Ticket is for contract {ticket.contract}, not {args.contract}")
+
+    w3 = AsyncWeb3(AsyncHTTPProvider(args.rpc))
+    chain_id = await w3.eth.chain_id
+    if ticket.chain_id != chain_id:
+        raise SystemExit(f"Ticket is for chain {ticket.chain_id}, RPC is on {chain_id}")
+
+    account = Account.from_key(args.key)
+    contract = w3.eth.contract(address=w3.to_checksum_address(args.contract), abi=CONTRACT_ABI)
+    tx = await contract.functions.placeBet(
+        _match_id_bytes(str(uuid.UUID(ticket.match_id))), 0 if ticket.side == "a" else 1
+    ).build_transaction(
+        {
+            "from": account.address,
+            "value": ticket.amount,
+            "nonce": await w3.eth.get_transaction_count(account.address, "pending"),
+            "chainId": chain_id,
+        }
+    )
+    signed = account.sign_transaction(tx)
+    tx_hash = await w3.eth.send_raw_transaction(signed.raw_transaction)
+    receipt = await w3.eth.wait_for_transaction_receipt(tx_hash, timeout=120)
+    if receipt["status"] != 1:
+        raise SystemExit(f"placeBet reverted in {tx_hash.hex()}")
+    return "0x" + tx_hash.hex().removeprefix("0x")
+
+
+def _revert_reason(exc: Exception, errors: dict[bytes, str]) -> str:
+    """Custom error name from a failed eth_call, falling back to the client's message."""
+    data = getattr(exc, "data", None)
+    if isinstance(data, str) and data.startswith("0x") and len(data) >= 10:
+        return errors.get(bytes.fromhex(data[2:10]), data[:10])
+    return str(exc) or type(exc).__name__
+
+
+async def _bettors(w3: AsyncWeb3, address: str, match_id: bytes, from_block: int) -> list[str]:
+    """Every address that placed a bet on the match, in first-bet order."""
+    event = next(e for e in CONTRACT_ABI if e.get("type") == "event" and e["name"] == "BetPlaced")
+    topic = w3.keccak(text=Watcher._signature(event))
+    head = await w3.eth.block_number
+    seen: dict[str, None] = {}
+    start = from_block
+    while start <= head:
+        end = min(start + MAX_BLOCK_RANGE - 1, head)
+        logs = await w3.eth.get_logs(
+            {
+                "address": address,
+                "fromBlock": start,
+                "toBlock": end,
+                "topics": [topic, "0x" + match_id.hex()],
+            }
+        )
+        for log in logs:
+            seen.setdefault(get_event_data(w3.codec, event, log)["args"]["bettor"])
+        start = end + 1
+    return list(seen)
+
+
+async def sweep_plan(args: argparse.Namespace) -> None:
+    """Print what sweeping every outstanding bet would do; send the sweeps with --execute."""
+    if not CONTRACT_ABI:
+        raise SystemExit("No RawlBetting ABI found — run `make contracts-build` first")
+    w3 = AsyncWeb3(AsyncHTTPProvider(args.rpc))
+    address = w3.to_checksum_address(args.contract)
+    contract = w3.eth.contract(address=address, abi=CONTRACT_ABI)
+    match_id = _match_id_bytes(args.match_id)
+    account = Account.from_key(args.key) if args.key else None
+    sender = w3.to_checksum_address(args.sender) if args.sender else None
+    if sender is None and account is not None:
+        sender = account.address
+
+    pool = await contract.functions.matches(match_id).call()
+    status, resolved_at, cancelled_at = pool[2], pool[13], pool[14]
+    if status not in (STATUS_RESOLVED, STATUS_CANCELLED):
+        raise SystemExit("Only resolved or cancelled matches can be swept")
+    fn_name = "sweepUnclaimed" if status == STATUS_RESOLVED else "sweepCancelled"
+    claim_window = await contract.functions.claimWindow().call()
+    due_at = (resolved_at if status == STATUS_RESOLVED else cancelled_at) + claim_window
+    errors = {
+        bytes(w3.keccak(text=Watcher._signature(e)))[:4]: e["name"]
+        for e in CONTRACT_ABI
+        if e.get("type") == "error"
+    }
+
+    print(
+        f"{fn_name} plan for match 0x{match_id.hex()} — "
+        f"sweepable from {datetime.fromtimestamp(due_at)}"
+    )
+    steps, total, treasury_total = [], 0, 0
+    for bettor in await _bettors(w3, address, match_id, args.from_block):
+        stake, side, claimed = await contract.functions.bets(match_id, bettor).call()
+        if stake == 0 or claimed:
+            continue
+        amount, recipient = await contract.functions.previewSweep(match_id, bettor).call()
+        fn = getattr(contract.functions, fn_name)(match_id, bettor)
+        try:
+            await fn.call({"from": sender} if sender else {})
+            blocked = None
+        except Exception as exc:  # any revert just marks the step blocked
+            blocked = _revert_reason(exc, errors)
+        side_label = "a" if side == 0 else "b"
+        to = recipient if amount else "—"
+        note = f"  BLOCKED: {blocked}" if blocked else ""
+        print(f"  {bettor} side {side_label} stake {stake} → {amount} wei to {to}{note}")
+        if blocked is None:
+            steps.append(fn)
+            total += amount
+            if status == STATUS_RESOLVED:
+                treasury_total += amount
+
+    print(
+        f"{len(steps)} sweep(s) ready, {total} wei in total; "
+        f"treasury impact +{treasury_total} wei"
+    )
+    if not args.execute:
+        print("Dry run — rerun with --execute to send these transactions")
+        return
+    if account is None:
+        raise SystemExit("ADMIN_PRIVATE_KEY or --key is required with --execute")
+
+    chain_id = await w3.eth.chain_id
+    nonce = await w3.eth.get_transaction_count(account.address, "pending")
+    for fn in steps:
+        tx = await fn.build_transaction(
+            {"from": account.address, "nonce": nonce, "chainId": chain_id}
+        )
+        signed = account.sign_transaction(tx)
+        tx_hash = await w3.eth.send_raw_transaction(signed.raw_transaction)
+        receipt = await w3.eth.wait_for_transaction_receipt(tx_hash, timeout=120)
+        state = "ok" if receipt["status"] == 1 else "REVERTED"
+        print(f"  {state} 0x{tx_hash.hex().removeprefix('0x')}")
+        nonce += 1
+
+
+if __name__ == "__main__":
+    parser = argparse.ArgumentParser(prog="rawl-cli", description="RawlBetting operator CLI")
+    sub = parser.add_subparsers(dest="command", required=True)
+
+    w = sub.add_parser("watch", help="Stream decoded contract events")
+    w.add_argument("--rpc", default=os.environ.get("BASE_RPC_URL", "http://127.0.0.1:8545"))
+    w.add_argument("--contract", default=os.environ.get("CONTRACT_ADDRESS"))
+    w.add_argument("--match-id", help="Only events for this match (UUID or 0x bytes32)")
+    w.add_argument("--event", action="append", help="Only this event name (repeatable)")
+    w.add_argument("--from-block", type=int, help="Replay from this block (default: head)")
+    w.add_argument("--no-color", action="store_true")
+
+    s = sub.add_parser("sign-result", help="Sign a result for resolveMatchSigned")
+    s.add_argument("--contract", default=os.environ.get("CONTRACT_ADDRESS"))
+    s.add_argument("--key", default=os.environ.get("ORACLE_PRIVATE_KEY"))
+    s.add_argument("--chain-id", type=int, default=int(os.environ.get("BASE_CHAIN_ID", 8453)))
+    s.add_argument("--match-id", required=True, help="Match UUID or 0x bytes32")
+    s.add_argument("--winner", required=True, choices=["a", "b"])
+    s.add_argument("--timestamp", type=int, help="Unix seconds (default: now)")
+
+    r = sub.add_parser("redeem-ticket", help="Place the bet described by a scanned ticket")
+    r.add_argument("uri", help="rawl:bet?… ticket URI")
+    r.add_argument("--rpc", default=os.environ.get("BASE_RPC_URL", "http://127.0.0.1:8545"))
+    r.add_argument("--contract", default=os.environ.get("CONTRACT_ADDRESS"))
+    r.add_argument("--issuer", default=os.environ.get("TICKET_ISSUER"))
+    r.add_argument("--key", default=os.environ.get("BETTOR_PRIVATE_KEY"))
+
+    p = sub.add_parser("sweep-plan", help="Preview (and with --execute, send) a match's sweeps")
+    p.add_argument("match_id", help="Match UUID or 0x bytes32")
+    p.add_argument("--rpc", default=os.environ.get("BASE_RPC_URL", "http://127.0.0.1:8545"))
+    p.add_argument("--contract", default=os.environ.get("CONTRACT_ADDRESS"))
+    p.add_argument("--key", default=os.environ.get("ADMIN_PRIVATE_KEY"))
+    p.add_argument("--sender", help="Simulate as this address (default: the --key account)")
+    p.add_argument("--from-block", type=int, default=0, help="First block to scan for bets")
+    p.add_argument("--execute", action="store_true", help="Send the sweeps that would succeed")
+
+    args = parser.parse_args()
+    if not args.contract:
+        parser.error("CONTRACT_ADDRESS or --contract is required")
+    if args.command == "sign-result":
+        if not args.key:
+            parser.error("ORACLE_PRIVATE_KEY or --key is required")
+        print(json.dumps(sign_result(args), indent=2))
+        raise SystemExit(0)
+    if args.command == "redeem-ticket":
+        if not args.issuer or not args.key:
+            parser.error("TICKET_ISSUER/--issuer and BETTOR_PRIVATE_KEY/--key are required")
+        print(asyncio.run(redeem_ticket(args)))
+        raise SystemExit(0)
+    if args.command == "sweep-plan":
+        asyncio.run(sweep_plan(args))
+        raise SystemExit(0)
+    try:
+        asyncio.run(watch(args))
+    except KeyboardInterrupt:
+        pass
@@ -0,0 +1,151 @@
+"""
+Refresh the contract ABI bundled with the backend from the Foundry build.
+
+The backend, rawl_cli.py and the load-test scripts load
+packages/backend/src/rawl/evm/RawlBetting.json before falling back to
+packages/contracts/out, so a stale bundle breaks every call whose selector
+changed even though the contracts build fine. Regenerate it in the same commit
+as any ABI change; CI fails when it no longer matches `forge inspect`.
+
+RawlBetting serves most of its entry points from modules its fallback
+delegatecalls, so the bundle is the router's ABI plus every module function
+(minus each module's own constructor and selectors()). --check also holds
+IRawlBetting to that same function list.
+
+Only the ABI is bundled. Deploy scripts take bytecode from packages/contracts/out.
+
+Usage (needs forge on PATH):
+  python scripts/sync_abi.py          # rewrite the bundled ABI
+  python scripts/sync_abi.py --check  # exit 1 if it is out of date
+"""
+from __future__ import annotations
+
+import argparse
+import json
+import subprocess
+import sys
+from pathlib import Path
+
+ROOT = Path(__file__).resolve().parent.parent
+CONTRACTS_DIR = ROOT / "packages" / "contracts"
+OUTPUT = ROOT / "packages" / "backend" / "src" / "rawl" / "evm" / "RawlBetting.json"
+ROUTER = "RawlBetting"
+INTERFACE = "IRawlBetting"
+MODULES = (
+    "MatchesModule",
+    "SchedulingModule",
+    "LifecycleModule",
+    "ResolutionModule",
+    "OraclesModule",
+    "RandomnessModule",
+    "DisputesModule",
+    "PromosModule",
+    "TreasuryModule",
+    "AdminModule",
+    "AccountsModule",
+    "ViewsModule",
+)
+
+_ORDER = {"constructor": 0, "receive": 1, "fallback": 1, "function": 2, "event": 3, "error": 4}
+
+
+def _canonical_type(param: dict) -> str:
+    if param["type"].startswith("tuple"):
+        inner = ",".join(_canonical_type(c) for c in param["components"])
+        return f"({inner}){param['type'][len('tuple'):]}"
+    return param["type"]
+
+
+def signature(entry: dict) -> str:
+    """e.g. `function placeBet(bytes32,uint8)`, unique per ABI entry."""
+    args = ",".join(_canonical_type(p) for p in entry.get("inputs", []))
+    return f"{entry['type']} {entry.get('name', '')}({args})"
+
+
+def _normalized(entry: dict) -> dict:
+    """The entry without internalType, which differs between solc versions but not on the wire."""
+    if isinstance(entry, dict):
+        return {k: _normalized(v) for k, v in entry.items() if k != "internalType"}
+    if isinstance(entry, list):
+        return [_normalized(v) for v in entry]
+    return entry
+
+
+def _inspect(name: str) -> list[dict]:
+    out = subprocess.run(
+        ["forge", "inspect", name, "abi", "--json"],
+        cwd=CONTRACTS_DIR,
+        check=True,
+        capture_output=True,
+        text=True,
+    ).stdout
+    return json.loads(out)
+
+
+def _routed(entry: dict) -> bool:
+    """Whether a module's ABI entry is reachable at the router's address."""
+    return entry["type"] != "constructor" and entry.get("name") != "selectors"
+
+
+def forge_abi() -> list[dict]:
+    """The router's ABI plus its modules' routed entries, one entry per signature."""
+    entries: dict[str, dict] = {}
+    for entry in _inspect(ROUTER):
+        entries.setdefault(signature(entry), entry)
+    for name in MODULES:
+        for entry in filter(_routed, _inspect(name)):
+            entries.setdefault(signature(entry), entry)
+    return sorted(entries.values(), key=lambda e: (_ORDER[e["type"]], signature(e)))
+
+
+def interface_drift(abi: list[dict]) -> list[str]:
+    """Functions IRawlBetting is missing or declares beyond the deployed set."""
+    want = {signature(e) for e in abi if e["type"] == "function"}
+    have = {signature(e) for e in _inspect(INTERFACE) if e["type"] == "function"}
+    return sorted(
+        [f"missing from {INTERFACE}  {s}" for s in want - have]
+        + [f"not deployed, in {INTERFACE}  {s}" for s in have - want]
+    )
+
+
+def render(abi: list[dict]) -> str:
+    return json.dumps({"abi": abi}, indent=2) + "\n"
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__.split("\n\n")[0].strip())
+    parser.add_argument("--check", action="store_true", help="fail if the bundle is stale")
+    args = parser.parse_args()
+
+    abi = forge_abi()
+    if not args.check:
+        OUTPUT.write_text(render(abi))
+        print(f"wrote {len(abi)} entries to {OUTPUT.relative_to(ROOT)}")
+        return 0
+
+    bundled = json.loads(OUTPUT.read_text())["abi"] if OUTPUT.exists() else []
+    want = {signature(e): _normalized(e) for e in abi}
+    have = {signature(e): _normalized(e) for e in bundled}
+    stale = sorted(
+        [f"missing  {s}" for s in want.keys() - have.keys()]
+        + [f"removed  {s}" for s in have.keys() - want.keys()]
+        + [f"changed  {s}" for s in want.keys() & have.keys() if want[s] != have[s]]
+    )
+    if stale:
+        print(f"{OUTPUT.relative_to(ROOT)} is out of date:", file=sys.stderr)
+        for line in stale:
+            print(f"  {line}", file=sys.stderr)
+        print("run `make abi` and commit the result", file=sys.stderr)
+        return 1
+    drift = interface_drift(abi)
+    if drift:
+        print(f"src/{INTERFACE}.sol does not match the deployed functions:", file=sys.stderr)
+        for line in drift:
+            print(f"  {line}", file=sys.stderr)
+        return 1
+    print(f"{OUTPUT.relative_to(ROOT)} is up to date ({len(abi)} entries)")
+    return 0
+
+
+if __name__ == "__main__":
+    sys.exit(main())